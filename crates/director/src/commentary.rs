@@ -3,14 +3,16 @@
 //! Generates text overlays and captions for the visualization based on
 //! events, tensions, and dramatic irony situations.
 
-use std::collections::{HashMap, HashSet};
+use std::collections::{BTreeMap, HashMap, HashSet};
 use std::path::Path;
 
 use rand::seq::SliceRandom;
+use rand::rngs::SmallRng;
+use rand::{Rng, SeedableRng};
 use serde::{Deserialize, Serialize};
-use sim_events::{Event, EventSubtype, EventType, Tension, WorldSnapshot};
+use sim_events::{Event, EventOutcome, EventSubtype, EventType, GrudgeSnapshot, Tension, WorldSnapshot};
 
-use crate::config::CommentaryConfig;
+use crate::config::{CommentaryConfig, ConfigError, DirectorConfig};
 use crate::output::{generate_commentary_id, CommentaryItem, CommentaryType};
 
 /// Templates for generating commentary text.
@@ -18,7 +20,7 @@ use crate::output::{generate_commentary_id, CommentaryItem, CommentaryType};
 pub struct CommentaryTemplates {
     /// Event captions keyed by "event_type.subtype" (e.g., "betrayal.secret_shared_with_enemy")
     #[serde(default)]
-    pub event_captions: HashMap<String, Vec<String>>,
+    pub event_captions: HashMap<String, Vec<TemplateLine>>,
 
     /// Dramatic irony templates
     #[serde(default)]
@@ -41,29 +43,220 @@ impl CommentaryTemplates {
     }
 
     /// Parses templates from a TOML string.
+    ///
+    /// Teasers naming an unknown tension type are rejected with one error per
+    /// offending field.
     pub fn from_str(content: &str) -> Result<Self, TemplateError> {
-        toml::from_str(content).map_err(TemplateError::TomlError)
+        let templates: Self = toml::from_str(content).map_err(TemplateError::TomlError)?;
+        DirectorConfig::validate_teasers(&templates).map_err(TemplateError::Invalid)?;
+        Ok(templates)
     }
 
     /// Gets templates for a specific event type and subtype.
-    pub fn get_event_templates(&self, event_type: &str, subtype: &str) -> Option<&Vec<String>> {
+    pub fn get_event_templates(&self, event_type: &str, subtype: &str) -> Option<&Vec<TemplateLine>> {
         let key = format!("{}.{}", event_type, subtype);
         self.event_captions.get(&key)
     }
 
     /// Gets templates for an event type (without subtype).
-    pub fn get_type_templates(&self, event_type: &str) -> Option<&Vec<String>> {
+    pub fn get_type_templates(&self, event_type: &str) -> Option<&Vec<TemplateLine>> {
         self.event_captions.get(event_type)
     }
 }
 
+/// A single template string with a selection weight.
+///
+/// In TOML this is either a plain string (weight 1.0) or a table such as
+/// `{ text = "...", weight = 3.0 }`, so existing plain arrays keep working.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum TemplateLine {
+    /// Plain template string with the default weight
+    Plain(String),
+    /// Template string with an explicit weight
+    Weighted {
+        text: String,
+        #[serde(default = "default_template_weight")]
+        weight: f32,
+    },
+}
+
+fn default_template_weight() -> f32 {
+    1.0
+}
+
+impl TemplateLine {
+    /// Returns the template text.
+    pub fn text(&self) -> &str {
+        match self {
+            TemplateLine::Plain(text) => text,
+            TemplateLine::Weighted { text, .. } => text,
+        }
+    }
+
+    /// Returns the selection weight (never negative).
+    pub fn weight(&self) -> f32 {
+        match self {
+            TemplateLine::Plain(_) => default_template_weight(),
+            TemplateLine::Weighted { weight, .. } => weight.max(0.0),
+        }
+    }
+}
+
+impl From<&str> for TemplateLine {
+    fn from(text: &str) -> Self {
+        TemplateLine::Plain(text.to_string())
+    }
+}
+
+impl From<String> for TemplateLine {
+    fn from(text: String) -> Self {
+        TemplateLine::Plain(text)
+    }
+}
+
+/// Picks a template at random, proportionally to weight.
+///
+/// Returns `None` if the list is empty or every weight is zero.
+pub fn choose_template<'a, R: Rng + ?Sized>(lines: &'a [TemplateLine], rng: &mut R) -> Option<&'a str> {
+    lines
+        .choose_weighted(rng, |line| line.weight())
+        .ok()
+        .map(|line| line.text())
+}
+
+/// Replaces the temporal placeholders shared by event and tension templates.
+///
+/// `{date}` reads like "the 3rd day of Winter, year 2", `{season}` is the
+/// capitalized season name, and `{tick}` is the raw simulation tick.
+fn fill_time_placeholders(template: &str, timestamp: &sim_events::SimTimestamp) -> String {
+    let date = &timestamp.date;
+    let season = capitalize(&date.season.to_string());
+    template
+        .replace(
+            "{date}",
+            &format!("the {} day of {}, year {}", ordinal(date.day as u32), season, date.year),
+        )
+        .replace("{season}", &season)
+        .replace("{tick}", &timestamp.tick.to_string())
+}
+
+/// Every placeholder any template kind understands. Anything else left in a
+/// filled template is treated as a typo.
+const KNOWN_PLACEHOLDERS: &[&str] = &[
+    "primary_name",
+    "primary_faction",
+    "primary_role",
+    "primary_role_display",
+    "primary_role_with_article",
+    "secondary_name",
+    "secondary_faction",
+    "location",
+    "affected_names",
+    "date",
+    "season",
+    "tick",
+    "unaware_agent",
+    "betrayer",
+    "betrayal_location",
+    "secret_info",
+    "summary",
+    "hook",
+    "victim",
+    "offender",
+    "offense_location",
+];
+
+fn is_placeholder_name(name: &str) -> bool {
+    !name.is_empty() && name.chars().all(|c| c.is_ascii_alphanumeric() || c == '_')
+}
+
+/// Collapses repeated whitespace, removes spaces before punctuation, and trims
+/// dangling separators left behind by dropped placeholders.
+fn tidy_whitespace(text: &str) -> String {
+    let mut result = text.split_whitespace().collect::<Vec<_>>().join(" ");
+    for punct in [",", ".", ":", ";", "!", "?"] {
+        result = result.replace(&format!(" {}", punct), punct);
+    }
+    result
+        .trim_end_matches(|c: char| c == ',' || c == ':' || c == ';' || c.is_whitespace())
+        .to_string()
+}
+
+/// Formats a number with its English ordinal suffix (1st, 2nd, 3rd, 11th, ...).
+fn ordinal(n: u32) -> String {
+    let suffix = match (n % 10, n % 100) {
+        (_, 11..=13) => "th",
+        (1, _) => "st",
+        (2, _) => "nd",
+        (3, _) => "rd",
+        _ => "th",
+    };
+    format!("{}{}", n, suffix)
+}
+
+/// Display names for role ids the simulation emits. Role ids show up both
+/// run together ("scoutcaptain") and snake_cased ("scout_captain").
+const ROLE_DISPLAY_NAMES: &[(&str, &str)] = &[
+    ("leader", "Leader"),
+    ("reader", "Reader"),
+    ("councilmember", "Council Member"),
+    ("council_member", "Council Member"),
+    ("scoutcaptain", "Scout Captain"),
+    ("scout_captain", "Scout Captain"),
+    ("skilledworker", "Skilled Worker"),
+    ("skilled_worker", "Skilled Worker"),
+    ("spymaster", "Spymaster"),
+];
+
+/// Roles a faction has only one of, so they take "the" rather than "a".
+const UNIQUE_ROLES: &[&str] = &["leader", "reader"];
+
+/// Renders a role id for captions, e.g. "scoutcaptain" -> "Scout Captain".
+///
+/// Roles missing from the table are humanized from the id itself.
+pub fn role_display_name(role: &str) -> String {
+    let key = role.to_lowercase();
+    match ROLE_DISPLAY_NAMES.iter().find(|(id, _)| *id == key) {
+        Some((_, display)) => display.to_string(),
+        None => key
+            .split(|c: char| c == '_' || c == '-' || c.is_whitespace())
+            .filter(|word| !word.is_empty())
+            .map(capitalize)
+            .collect::<Vec<_>>()
+            .join(" "),
+    }
+}
+
+/// Renders a role mid-sentence with its article, e.g. "a scout captain" or
+/// "the leader".
+pub fn role_with_article(role: &str) -> String {
+    let name = role_display_name(role).to_lowercase();
+    let article = if UNIQUE_ROLES.contains(&role.to_lowercase().as_str()) {
+        "the"
+    } else if name.starts_with(['a', 'e', 'i', 'o', 'u']) {
+        "an"
+    } else {
+        "a"
+    };
+    format!("{} {}", article, name)
+}
+
+fn capitalize(word: &str) -> String {
+    let mut chars = word.chars();
+    match chars.next() {
+        Some(first) => first.to_uppercase().chain(chars).collect(),
+        None => String::new(),
+    }
+}
+
 /// Template for dramatic irony situations.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct IronyTemplate {
     /// Pattern identifier (e.g., "unaware_of_betrayal")
     pub pattern: String,
     /// Template strings with placeholders
-    pub templates: Vec<String>,
+    pub templates: Vec<TemplateLine>,
     /// Required context keys for this template
     #[serde(default)]
     pub required_context: Vec<String>,
@@ -75,7 +268,7 @@ pub struct ReminderTemplate {
     /// Context type (e.g., "past_betrayal", "old_alliance")
     pub context_type: String,
     /// Template strings
-    pub templates: Vec<String>,
+    pub templates: Vec<TemplateLine>,
     /// Minimum ticks ago for this reminder to apply
     #[serde(default)]
     pub min_ticks_ago: u64,
@@ -87,14 +280,53 @@ pub struct TeaserTemplate {
     /// Tension type this applies to
     pub tension_type: String,
     /// Template strings
-    pub templates: Vec<String>,
+    pub templates: Vec<TemplateLine>,
     /// Minimum severity for this teaser
     #[serde(default)]
     pub min_severity: f32,
+    /// Templates naming the offense behind a grudge, preferred when its origin is known
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub grudge_templates: Vec<TemplateLine>,
+}
+
+/// Where a grudge started, resolved to display names for teasers.
+#[derive(Debug, Clone, PartialEq)]
+pub struct GrudgeOrigin {
+    /// Name of the agent holding the grudge
+    pub victim: String,
+    /// Name of the agent it is held against
+    pub offender: String,
+    /// Where the offense happened
+    pub offense_location: String,
+    /// Event that started the grudge, if known
+    pub origin_event: Option<String>,
+}
+
+impl GrudgeOrigin {
+    /// Resolves a grudge against the snapshot. Returns None if it is not
+    /// known where the offense happened.
+    pub fn from_snapshot(grudge: &GrudgeSnapshot, state: &WorldSnapshot) -> Option<Self> {
+        let location_id = grudge.location.as_ref()?;
+        let name_of = |agent_id: &String| {
+            state
+                .find_agent(agent_id)
+                .map(|a| a.name.clone())
+                .unwrap_or_else(|| agent_id.clone())
+        };
+        Some(Self {
+            victim: name_of(&grudge.holder_id),
+            offender: name_of(&grudge.offender_id),
+            offense_location: state
+                .find_location(location_id)
+                .map(|l| l.name.clone())
+                .unwrap_or_else(|| location_id.clone()),
+            origin_event: grudge.origin_event.clone(),
+        })
+    }
 }
 
 /// Situation where dramatic irony exists.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct IronySituation {
     /// Type of irony situation
     pub situation_type: String,
@@ -135,10 +367,33 @@ impl IronySituation {
             betrayal_event_id: Some(betrayal_event_id.into()),
         }
     }
+
+    /// Creates a new irony situation for a victim heading to where their betrayer is.
+    pub fn walking_into_trap(
+        unaware_agent_id: impl Into<String>,
+        unaware_agent_name: impl Into<String>,
+        betrayer_id: impl Into<String>,
+        betrayer_name: impl Into<String>,
+        betrayal_event_id: impl Into<String>,
+        destination: impl Into<String>,
+    ) -> Self {
+        Self {
+            situation_type: "walking_into_trap".to_string(),
+            betrayal_location: Some(destination.into()),
+            ..Self::unaware_of_betrayal(
+                unaware_agent_id,
+                unaware_agent_name,
+                betrayer_id,
+                betrayer_name,
+                betrayal_event_id,
+                None,
+            )
+        }
+    }
 }
 
 /// Record of a betrayal event for irony tracking.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct BetrayalRecord {
     /// The event ID of the betrayal
     pub event_id: String,
@@ -152,10 +407,21 @@ pub struct BetrayalRecord {
     pub tick: u64,
     /// Location where the betrayal occurred
     pub location: Option<String>,
-    /// Agents who have discovered this betrayal
+    /// Agents who have discovered this betrayal (serialized as a sorted list)
+    #[serde(serialize_with = "serialize_sorted_set")]
     pub discovered_by: HashSet<String>,
 }
 
+/// Serializes a set as a sorted list so exported state is deterministic.
+fn serialize_sorted_set<S: serde::Serializer>(
+    set: &HashSet<String>,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    let mut sorted: Vec<&String> = set.iter().collect();
+    sorted.sort();
+    serializer.collect_seq(sorted)
+}
+
 impl BetrayalRecord {
     /// Creates a new betrayal record from an event.
     pub fn from_event(event: &Event) -> Option<Self> {
@@ -214,8 +480,53 @@ impl BetrayalRecord {
     }
 }
 
+/// Serializable snapshot of an `IronyDetector`'s state.
+///
+/// Produced by `IronyDetector::export` and restored with `IronyDetector::import`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct IronyDetectorState {
+    /// Tracked betrayals, in recording order
+    pub betrayals: Vec<BetrayalRecord>,
+    /// Trust threshold the detector was using
+    pub trust_threshold: f32,
+}
+
+/// Collects the events each agent is known to remember, keyed by agent ID.
+///
+/// Starts from the memories recorded in the snapshot, which include events an
+/// agent witnessed firsthand. Memories shared in `events` are added on top,
+/// since the snapshot may predate them: both the teller and the listener hold
+/// a memory of the original event.
+pub fn remembered_events(state: &WorldSnapshot, events: &[Event]) -> HashMap<String, HashSet<String>> {
+    let mut memories: HashMap<String, HashSet<String>> = HashMap::new();
+    for agent in &state.agents {
+        if !agent.remembered_events.is_empty() {
+            memories
+                .entry(agent.agent_id.clone())
+                .or_default()
+                .extend(agent.remembered_events.iter().cloned());
+        }
+    }
+    for event in events {
+        let EventOutcome::Communication(outcome) = &event.outcome else {
+            continue;
+        };
+        let Some(original_event) = outcome.memory_shared.as_ref().and_then(|m| m.original_event.as_ref()) else {
+            continue;
+        };
+        let holders = std::iter::once(&event.actors.primary).chain(event.actors.secondary.as_ref());
+        for holder in holders {
+            memories
+                .entry(holder.agent_id.clone())
+                .or_default()
+                .insert(original_event.clone());
+        }
+    }
+    memories
+}
+
 /// Detects dramatic irony situations based on betrayals and trust relationships.
-#[derive(Debug, Clone, Default)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct IronyDetector {
     /// Recent betrayals that may create irony situations
     recent_betrayals: Vec<BetrayalRecord>,
@@ -240,6 +551,11 @@ impl IronyDetector {
         }
     }
 
+    /// Replaces the trust threshold, keeping tracked betrayals.
+    pub fn set_trust_threshold(&mut self, trust_threshold: f32) {
+        self.trust_threshold = trust_threshold;
+    }
+
     /// Records a betrayal event for tracking.
     ///
     /// Only betrayal-type events will be recorded.
@@ -268,15 +584,52 @@ impl IronyDetector {
     /// 1. For each recorded betrayal not yet discovered by affected parties
     /// 2. Check if any affected agent still has high trust in the betrayer
     /// 3. If reliability trust > threshold, they're still unaware = irony opportunity
-    pub fn detect_irony(&self, state: &WorldSnapshot) -> Vec<IronySituation> {
+    ///
+    /// An unaware agent whose latest movement in `recent_events` heads to where
+    /// the betrayer now is yields "walking_into_trap" instead, with the
+    /// destination as `betrayal_location`.
+    ///
+    /// Snapshot trust can lag behind what an agent knows. An agent whose
+    /// `memories` include the betrayal's event ID is aware whatever their trust,
+    /// and is marked as having discovered it.
+    pub fn detect_irony(
+        &mut self,
+        state: &WorldSnapshot,
+        recent_events: &[Event],
+        memories: &HashMap<String, HashSet<String>>,
+    ) -> Vec<IronySituation> {
         let mut situations = Vec::new();
+        let trust_threshold = self.trust_threshold;
 
-        for record in &self.recent_betrayals {
+        // Where each agent was last seen heading
+        let mut destinations: HashMap<&str, (u64, &str)> = HashMap::new();
+        for event in recent_events {
+            let EventOutcome::Movement(movement) = &event.outcome else {
+                continue;
+            };
+            let mover = event.actors.primary.agent_id.as_str();
+            let tick = event.timestamp.tick;
+            if destinations.get(mover).is_none_or(|(latest, _)| tick >= *latest) {
+                destinations.insert(mover, (tick, movement.new_location.as_str()));
+            }
+        }
+
+        for record in &mut self.recent_betrayals {
             // Skip fully discovered betrayals
             if record.is_fully_discovered() {
                 continue;
             }
 
+            // Anyone holding a memory of the betrayal knows about it
+            for affected_id in &record.affected_ids {
+                if memories
+                    .get(affected_id)
+                    .is_some_and(|remembered| remembered.contains(&record.event_id))
+                {
+                    record.discovered_by.insert(affected_id.clone());
+                }
+            }
+
             // Check each affected agent
             for affected_id in &record.affected_ids {
                 // Skip if this agent already discovered the betrayal
@@ -287,21 +640,39 @@ impl IronyDetector {
                 // Check the trust relationship from affected -> betrayer
                 if let Some(relationship) = state.get_relationship(affected_id, &record.betrayer_id) {
                     // If they still trust the betrayer, there's irony
-                    if relationship.reliability > self.trust_threshold {
+                    if relationship.reliability > trust_threshold {
                         // Get the agent's name for the situation
                         let agent_name = state
                             .find_agent(affected_id)
                             .map(|a| a.name.clone())
                             .unwrap_or_else(|| affected_id.clone());
 
-                        situations.push(IronySituation::unaware_of_betrayal(
-                            affected_id,
-                            agent_name,
-                            &record.betrayer_id,
-                            &record.betrayer_name,
-                            &record.event_id,
-                            record.location.clone(),
-                        ));
+                        let betrayer_location = state
+                            .find_agent(&record.betrayer_id)
+                            .map(|a| a.location.as_str());
+                        let heading_to_betrayer = destinations
+                            .get(affected_id.as_str())
+                            .is_some_and(|(_, destination)| Some(*destination) == betrayer_location);
+
+                        situations.push(if heading_to_betrayer {
+                            IronySituation::walking_into_trap(
+                                affected_id,
+                                agent_name,
+                                &record.betrayer_id,
+                                &record.betrayer_name,
+                                &record.event_id,
+                                betrayer_location.unwrap_or_default(),
+                            )
+                        } else {
+                            IronySituation::unaware_of_betrayal(
+                                affected_id,
+                                agent_name,
+                                &record.betrayer_id,
+                                &record.betrayer_name,
+                                &record.event_id,
+                                record.location.clone(),
+                            )
+                        });
                     }
                 }
             }
@@ -328,6 +699,22 @@ impl IronyDetector {
     pub fn betrayals(&self) -> &[BetrayalRecord] {
         &self.recent_betrayals
     }
+
+    /// Exports the detector's state for inspection or save/restore.
+    pub fn export(&self) -> IronyDetectorState {
+        IronyDetectorState {
+            betrayals: self.recent_betrayals.clone(),
+            trust_threshold: self.trust_threshold,
+        }
+    }
+
+    /// Rebuilds a detector from previously exported state.
+    pub fn import(state: IronyDetectorState) -> Self {
+        Self {
+            recent_betrayals: state.betrayals,
+            trust_threshold: state.trust_threshold,
+        }
+    }
 }
 
 /// Errors that can occur during template operations.
@@ -337,6 +724,8 @@ pub enum TemplateError {
     IoError(std::io::Error),
     /// Error parsing TOML
     TomlError(toml::de::Error),
+    /// Templates parsed but failed validation (all violations)
+    Invalid(Vec<ConfigError>),
 }
 
 impl std::fmt::Display for TemplateError {
@@ -344,6 +733,13 @@ impl std::fmt::Display for TemplateError {
         match self {
             TemplateError::IoError(e) => write!(f, "IO error: {}", e),
             TemplateError::TomlError(e) => write!(f, "TOML parse error: {}", e),
+            TemplateError::Invalid(errors) => {
+                write!(f, "Invalid templates ({} errors)", errors.len())?;
+                for error in errors {
+                    write!(f, "\n  - {}", error)?;
+                }
+                Ok(())
+            }
         }
     }
 }
@@ -353,10 +749,23 @@ impl std::error::Error for TemplateError {
         match self {
             TemplateError::IoError(e) => Some(e),
             TemplateError::TomlError(e) => Some(e),
+            TemplateError::Invalid(_) => None,
         }
     }
 }
 
+/// Cooldown state of a `CommentaryGenerator`, for save/restore.
+///
+/// Produced by `CommentaryGenerator::export_state` and restored with
+/// `CommentaryGenerator::restore_state`.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct CommentaryGeneratorState {
+    /// Tick and severity of the last teaser fired for each tension
+    pub last_teasers: BTreeMap<String, (u64, f32)>,
+    /// Tick each agent was last featured in a caption
+    pub last_featured: BTreeMap<String, u64>,
+}
+
 /// Generates commentary items from events and tensions.
 #[derive(Debug, Clone)]
 pub struct CommentaryGenerator {
@@ -368,6 +777,10 @@ pub struct CommentaryGenerator {
     current_tick: u64,
     /// Sequence number for IDs
     commentary_sequence: u32,
+    /// Tick and severity of the last teaser fired for each tension
+    last_teasers: BTreeMap<String, (u64, f32)>,
+    /// Tick each agent was last featured in a caption
+    last_featured: BTreeMap<String, u64>,
 }
 
 impl CommentaryGenerator {
@@ -378,6 +791,8 @@ impl CommentaryGenerator {
             config,
             current_tick: 0,
             commentary_sequence: 0,
+            last_teasers: BTreeMap::new(),
+            last_featured: BTreeMap::new(),
         }
     }
 
@@ -386,6 +801,25 @@ impl CommentaryGenerator {
         Self::new(default_templates(), CommentaryConfig::default())
     }
 
+    /// Replaces the configuration, keeping templates and the ID sequence.
+    pub fn set_config(&mut self, config: CommentaryConfig) {
+        self.config = config;
+    }
+
+    /// Exports the teaser and caption cooldowns for save/restore.
+    pub fn export_state(&self) -> CommentaryGeneratorState {
+        CommentaryGeneratorState {
+            last_teasers: self.last_teasers.clone(),
+            last_featured: self.last_featured.clone(),
+        }
+    }
+
+    /// Replaces the cooldowns with previously exported ones, keeping templates and config.
+    pub fn restore_state(&mut self, state: CommentaryGeneratorState) {
+        self.last_teasers = state.last_teasers;
+        self.last_featured = state.last_featured;
+    }
+
     /// Loads templates from a file.
     pub fn from_template_file(path: &Path, config: CommentaryConfig) -> Result<Self, TemplateError> {
         let templates = CommentaryTemplates::from_file(path)?;
@@ -405,33 +839,73 @@ impl CommentaryGenerator {
         &mut self,
         event: &Event,
         timestamp: sim_events::SimTimestamp,
+    ) -> Option<CommentaryItem> {
+        self.caption_event_with_reputation(event, timestamp, None)
+    }
+
+    /// Generates a caption, weighing in the highest reputation among the event's agents.
+    ///
+    /// When that reputation reaches `high_reputation_threshold`, the caption's
+    /// priority is boosted and `{subtype}_notable` templates (e.g.
+    /// `death.killed_notable`) are preferred when they exist. Unknown
+    /// reputation is treated as unremarkable.
+    pub fn caption_event_with_reputation(
+        &mut self,
+        event: &Event,
+        timestamp: sim_events::SimTimestamp,
+        reputation: Option<f32>,
     ) -> Option<CommentaryItem> {
         // Check minimum drama threshold
-        if event.drama_score < self.config.min_drama_for_caption {
+        if event.drama_score < self.config.caption_drama_threshold() {
             return None;
         }
 
+        let respected = reputation.is_some_and(|r| r >= self.config.high_reputation_threshold);
+
         // Get event type and subtype as strings
         let event_type_str = event_type_to_string(&event.event_type);
         let subtype_str = event_subtype_to_string(&event.subtype);
 
-        // Try to find a template
-        let template = self
-            .templates
-            .get_event_templates(&event_type_str, &subtype_str)
+        // Try to find a template, favouring momentous wording for respected figures
+        let notable_templates = if respected {
+            self.templates
+                .get_event_templates(&event_type_str, &format!("{}_notable", subtype_str))
+        } else {
+            None
+        };
+        let template = notable_templates
+            .or_else(|| self.templates.get_event_templates(&event_type_str, &subtype_str))
             .or_else(|| self.templates.get_type_templates(&event_type_str))
-            .and_then(|templates| templates.choose(&mut rand::thread_rng()))?;
+            .and_then(|templates| choose_template(templates, &mut self.template_rng()))?;
 
         // Fill the template
-        let content = self.fill_event_template(template, event);
+        let content = self.fill_event_template(template, event, &timestamp);
         let duration = self.calculate_duration(&content);
 
+        // Spread the spotlight: agents captioned recently lower the priority
+        let agents: Vec<String> = event.all_agent_ids().into_iter().map(String::from).collect();
+        let recently_featured = agents.iter().any(|id| {
+            self.last_featured
+                .get(id)
+                .is_some_and(|&tick| self.current_tick < tick + self.config.agent_cooldown_ticks)
+        });
+        let mut priority = event.drama_score;
+        if respected {
+            priority *= 1.0 + self.config.reputation_priority_boost;
+        }
+        if recently_featured {
+            priority *= 1.0 - self.config.agent_cooldown_penalty;
+        }
+        for id in &agents {
+            self.last_featured.insert(id.clone(), self.current_tick);
+        }
+
         let item_id = self.next_commentary_id();
         Some(
             CommentaryItem::new(item_id, timestamp, CommentaryType::EventCaption, content)
                 .with_duration(duration)
-                .with_priority(event.drama_score)
-                .with_agents(event.all_agent_ids().into_iter().map(String::from).collect()),
+                .with_priority(priority)
+                .with_agents(agents),
         )
     }
 
@@ -452,9 +926,7 @@ impl CommentaryGenerator {
             .iter()
             .find(|t| t.pattern == situation.situation_type)?;
 
-        let template = irony_template
-            .templates
-            .choose(&mut rand::thread_rng())?;
+        let template = choose_template(&irony_template.templates, &mut self.template_rng())?;
 
         // Fill the template
         let content = self.fill_irony_template(template, situation);
@@ -485,11 +957,34 @@ impl CommentaryGenerator {
         &mut self,
         tension: &Tension,
         timestamp: sim_events::SimTimestamp,
+    ) -> Option<CommentaryItem> {
+        self.generate_teaser_with_origin(tension, timestamp, None)
+    }
+
+    /// Generates a teaser, naming the offense behind a grudge when its origin is known.
+    ///
+    /// With an origin, the matching template's `grudge_templates` are preferred
+    /// when it has any, and `{victim}`, `{offender}` and `{offense_location}`
+    /// are filled in.
+    pub fn generate_teaser_with_origin(
+        &mut self,
+        tension: &Tension,
+        timestamp: sim_events::SimTimestamp,
+        origin: Option<&GrudgeOrigin>,
     ) -> Option<CommentaryItem> {
         if !self.config.enable_tension_teasers {
             return None;
         }
 
+        // Hold off repeating a tension's teaser unless it has sharply escalated
+        if let Some(&(last_tick, last_severity)) = self.last_teasers.get(&tension.tension_id) {
+            let cooling_down = self.current_tick < last_tick + self.config.teaser_cooldown_ticks;
+            let escalated = tension.severity - last_severity >= self.config.teaser_severity_bypass;
+            if cooling_down && !escalated {
+                return None;
+            }
+        }
+
         let tension_type_str = format!("{:?}", tension.tension_type).to_lowercase();
 
         // Find matching teaser template
@@ -499,14 +994,19 @@ impl CommentaryGenerator {
             .iter()
             .find(|t| t.tension_type == tension_type_str && tension.severity >= t.min_severity)?;
 
-        let template = teaser_template
-            .templates
-            .choose(&mut rand::thread_rng())?;
+        let lines = match origin {
+            Some(_) if !teaser_template.grudge_templates.is_empty() => &teaser_template.grudge_templates,
+            _ => &teaser_template.templates,
+        };
+        let template = choose_template(lines, &mut self.template_rng())?;
 
         // Fill the template
-        let content = self.fill_tension_template(template, tension);
+        let content = self.fill_tension_template(template, tension, origin, &timestamp);
         let duration = self.calculate_duration(&content);
 
+        self.last_teasers
+            .insert(tension.tension_id.clone(), (self.current_tick, tension.severity));
+
         let item_id = self.next_commentary_id();
         let agents: Vec<String> = tension
             .key_agents
@@ -526,33 +1026,36 @@ impl CommentaryGenerator {
     /// Fills an event template with data from the event.
     ///
     /// Supported placeholders:
-    /// - {primary_name}, {primary_faction}, {primary_role}
+    /// - {primary_name}, {primary_faction}, {primary_role} (raw role id)
+    /// - {primary_role_display} ("Scout Captain"), {primary_role_with_article} ("a scout captain")
     /// - {secondary_name}, {secondary_faction}
     /// - {location}
     /// - {affected_names} (comma-separated)
-    pub fn fill_event_template(&self, template: &str, event: &Event) -> String {
+    /// - {date}, {season}, {tick} (from `timestamp`)
+    ///
+    /// Placeholders with no value (e.g. a missing secondary actor) are dropped
+    /// and the surrounding text tidied. Unknown placeholders are left as-is, or
+    /// replaced by the configured `placeholder_fallback`.
+    pub fn fill_event_template(
+        &self,
+        template: &str,
+        event: &Event,
+        timestamp: &sim_events::SimTimestamp,
+    ) -> String {
         let mut result = template.to_string();
 
         // Primary actor
         result = result.replace("{primary_name}", &event.actors.primary.name);
         result = result.replace("{primary_faction}", &event.actors.primary.faction);
         result = result.replace("{primary_role}", &event.actors.primary.role);
+        result = result.replace("{primary_role_display}", &role_display_name(&event.actors.primary.role));
+        result = result.replace("{primary_role_with_article}", &role_with_article(&event.actors.primary.role));
 
-        // Secondary actor (empty string if none)
-        let secondary_name = event
-            .actors
-            .secondary
-            .as_ref()
-            .map(|s| s.name.as_str())
-            .unwrap_or("");
-        let secondary_faction = event
-            .actors
-            .secondary
-            .as_ref()
-            .map(|s| s.faction.as_str())
-            .unwrap_or("");
-        result = result.replace("{secondary_name}", secondary_name);
-        result = result.replace("{secondary_faction}", secondary_faction);
+        // Secondary actor (left for the cleanup pass if none)
+        if let Some(ref secondary) = event.actors.secondary {
+            result = result.replace("{secondary_name}", &secondary.name);
+            result = result.replace("{secondary_faction}", &secondary.faction);
+        }
 
         // Location
         result = result.replace("{location}", &event.actors.primary.location);
@@ -564,9 +1067,12 @@ impl CommentaryGenerator {
             .iter()
             .map(|a| a.name.as_str())
             .collect();
-        result = result.replace("{affected_names}", &affected_names.join(", "));
+        if !affected_names.is_empty() {
+            result = result.replace("{affected_names}", &affected_names.join(", "));
+        }
 
-        result
+        let result = fill_time_placeholders(&result, timestamp);
+        self.resolve_leftover_placeholders(&result)
     }
 
     /// Fills an irony template with situation data.
@@ -585,11 +1091,17 @@ impl CommentaryGenerator {
 
         result = result.replace("{secret_info}", &situation.secret_info);
 
-        result
+        self.resolve_leftover_placeholders(&result)
     }
 
-    /// Fills a tension template with tension data.
-    fn fill_tension_template(&self, template: &str, tension: &Tension) -> String {
+    /// Fills a tension template with tension data, any grudge origin, and the current date.
+    fn fill_tension_template(
+        &self,
+        template: &str,
+        tension: &Tension,
+        origin: Option<&GrudgeOrigin>,
+        timestamp: &sim_events::SimTimestamp,
+    ) -> String {
         let mut result = template.to_string();
 
         // Primary agent (if any)
@@ -611,7 +1123,55 @@ impl CommentaryGenerator {
             result = result.replace("{hook}", hook);
         }
 
-        result
+        // Grudge origin
+        if let Some(origin) = origin {
+            result = result.replace("{victim}", &origin.victim);
+            result = result.replace("{offender}", &origin.offender);
+            result = result.replace("{offense_location}", &origin.offense_location);
+        }
+
+        let result = fill_time_placeholders(&result, timestamp);
+        self.resolve_leftover_placeholders(&result)
+    }
+
+    /// Post-fill pass over any `{...}` tokens still in the text.
+    ///
+    /// Known placeholders that had no value are dropped. Unknown ones are
+    /// logged (so template authors can spot typos) and replaced with the
+    /// configured fallback, or kept verbatim if there is none.
+    fn resolve_leftover_placeholders(&self, filled: &str) -> String {
+        let mut result = String::with_capacity(filled.len());
+        let mut unknown = Vec::new();
+        let mut rest = filled;
+
+        while let Some(start) = rest.find('{') {
+            result.push_str(&rest[..start]);
+            let after = &rest[start + 1..];
+            match after.find('}') {
+                Some(end) if is_placeholder_name(&after[..end]) => {
+                    let name = &after[..end];
+                    if !KNOWN_PLACEHOLDERS.contains(&name) {
+                        unknown.push(name);
+                        match self.config.placeholder_fallback {
+                            Some(ref fallback) => result.push_str(fallback),
+                            None => result.push_str(&rest[start..start + end + 2]),
+                        }
+                    }
+                    rest = &after[end + 1..];
+                }
+                _ => {
+                    result.push('{');
+                    rest = after;
+                }
+            }
+        }
+        result.push_str(rest);
+
+        if !unknown.is_empty() {
+            tracing::debug!(?unknown, template = filled, "unknown placeholders in commentary template");
+        }
+
+        tidy_whitespace(&result)
     }
 
     /// Calculates display duration based on content length.
@@ -621,6 +1181,16 @@ impl CommentaryGenerator {
         base + extra
     }
 
+    /// RNG for picking a template, derived from the seed, tick, and sequence.
+    ///
+    /// The same seed replayed over the same ticks picks the same templates.
+    fn template_rng(&self) -> SmallRng {
+        let mixed = self.config.seed.wrapping_mul(0x9E37_79B9_7F4A_7C15)
+            ^ self.current_tick.rotate_left(32)
+            ^ u64::from(self.commentary_sequence);
+        SmallRng::seed_from_u64(mixed)
+    }
+
     /// Generates the next commentary ID.
     fn next_commentary_id(&mut self) -> String {
         self.commentary_sequence += 1;
@@ -644,6 +1214,66 @@ impl Default for CommentaryGenerator {
     }
 }
 
+/// Removes redundant commentary, keeping the highest-priority instance.
+///
+/// An item is redundant if its content matches an item already kept, or if
+/// an item of the same type about the same set of agents was already kept.
+/// The queue is left sorted by priority, highest first.
+pub fn dedup_commentary(items: &mut Vec<CommentaryItem>) {
+    items.sort_by(|a, b| b.priority.partial_cmp(&a.priority).unwrap_or(std::cmp::Ordering::Equal));
+
+    let mut seen_content: HashSet<String> = HashSet::new();
+    let mut seen_subjects: HashSet<(CommentaryType, Vec<String>)> = HashSet::new();
+
+    items.retain(|item| {
+        if !seen_content.insert(item.content.clone()) {
+            return false;
+        }
+        // Items that mention nobody are only deduplicated by content
+        if item.related_agents.is_empty() {
+            return true;
+        }
+        let mut agents = item.related_agents.clone();
+        agents.sort();
+        agents.dedup();
+        seen_subjects.insert((item.commentary_type, agents))
+    });
+}
+
+/// Staggers commentary so no more than `slots.len()` items are on screen at once.
+///
+/// Each slot holds the tick at which it next comes free, carried over between
+/// ticks. Items are placed in queue order (highest priority first after
+/// `dedup_commentary`) into the earliest free slot; their `start_offset_ticks`
+/// records how long they wait. Items that would wait longer than `max_delay_ticks`
+/// are dropped.
+pub fn schedule_commentary(
+    items: &mut Vec<CommentaryItem>,
+    slots: &mut [u64],
+    current_tick: u64,
+    max_delay_ticks: u64,
+) {
+    if slots.is_empty() {
+        return;
+    }
+
+    items.retain_mut(|item| {
+        let (slot, free_at) = slots
+            .iter()
+            .copied()
+            .enumerate()
+            .min_by_key(|(_, free_at)| *free_at)
+            .unwrap();
+        let start = free_at.max(current_tick);
+        if start - current_tick > max_delay_ticks {
+            return false;
+        }
+        item.start_offset_ticks = start - current_tick;
+        slots[slot] = start + u64::from(item.display_duration_ticks);
+        true
+    });
+}
+
 /// Converts EventType to a string for template lookup.
 fn event_type_to_string(event_type: &EventType) -> String {
     match event_type {
@@ -689,24 +1319,38 @@ pub fn default_templates() -> CommentaryTemplates {
     event_captions.insert(
         "betrayal.secretsharedwithenemy".to_string(),
         vec![
-            "{primary_name} shares faction secrets with {secondary_name}".to_string(),
-            "At {location}, {primary_name} crosses a line that cannot be uncrossed".to_string(),
-            "A whispered betrayal: {primary_name} reveals secrets to {secondary_name}".to_string(),
+            "{primary_name} shares faction secrets with {secondary_name}".into(),
+            "At {location}, {primary_name} crosses a line that cannot be uncrossed".into(),
+            "A whispered betrayal: {primary_name} reveals secrets to {secondary_name}".into(),
+        ],
+    );
+    event_captions.insert(
+        "betrayal.secretsharedwithenemy_notable".to_string(),
+        vec![
+            "Even {primary_name}, trusted by all, sells out {primary_faction}".into(),
+            "The most respected voice in {primary_faction} betrays it to {secondary_name}".into(),
         ],
     );
     event_captions.insert(
         "betrayal.defection".to_string(),
         vec![
-            "{primary_name} abandons {primary_faction}".to_string(),
-            "A traitor reveals themselves: {primary_name} defects".to_string(),
-            "{primary_name} turns their back on {primary_faction}".to_string(),
+            "{primary_name} abandons {primary_faction}".into(),
+            "A traitor reveals themselves: {primary_name} defects".into(),
+            "{primary_name} turns their back on {primary_faction}".into(),
+        ],
+    );
+    event_captions.insert(
+        "betrayal.defection_notable".to_string(),
+        vec![
+            "{primary_faction} reels as the revered {primary_name} defects".into(),
+            "No one saw it coming: {primary_name}, pillar of {primary_faction}, walks away".into(),
         ],
     );
     event_captions.insert(
         "betrayal.sabotage".to_string(),
         vec![
-            "{primary_name} sabotages their own faction".to_string(),
-            "Sabotage in {location}: {primary_name}'s loyalty fractures".to_string(),
+            "{primary_name} sabotages their own faction".into(),
+            "Sabotage in {location}: {primary_name}'s loyalty fractures".into(),
         ],
     );
 
@@ -714,23 +1358,36 @@ pub fn default_templates() -> CommentaryTemplates {
     event_captions.insert(
         "death.killed".to_string(),
         vec![
-            "{primary_name} has fallen".to_string(),
-            "Death claims {primary_name}".to_string(),
-            "{primary_name}'s story ends here".to_string(),
+            "{primary_name} has fallen".into(),
+            "Death claims {primary_name}".into(),
+            "{primary_name}'s story ends here".into(),
+        ],
+    );
+    event_captions.insert(
+        "death.killed_notable".to_string(),
+        vec![
+            "A giant falls: {primary_name} is slain".into(),
+            "All of {primary_faction} will mourn {primary_name}".into(),
         ],
     );
     event_captions.insert(
         "death.natural".to_string(),
         vec![
-            "{primary_name} passes peacefully".to_string(),
-            "Time claims {primary_name}".to_string(),
+            "{primary_name} passes peacefully".into(),
+            "Time claims {primary_name}".into(),
+        ],
+    );
+    event_captions.insert(
+        "death.natural_notable".to_string(),
+        vec![
+            "{primary_faction} loses its most honoured elder, {primary_name}".into(),
         ],
     );
     event_captions.insert(
         "death.executed".to_string(),
         vec![
-            "{primary_name} is executed".to_string(),
-            "Justice—or vengeance—claims {primary_name}".to_string(),
+            "{primary_name} is executed".into(),
+            "Justice—or vengeance—claims {primary_name}".into(),
         ],
     );
 
@@ -738,16 +1395,16 @@ pub fn default_templates() -> CommentaryTemplates {
     event_captions.insert(
         "ritual.readingheld".to_string(),
         vec![
-            "The faithful gather at {location}".to_string(),
-            "{primary_name} opens the book of {primary_faction}".to_string(),
-            "A ritual reading begins at {location}".to_string(),
+            "The faithful gather at {location}".into(),
+            "{primary_name} opens the book of {primary_faction}".into(),
+            "A ritual reading begins at {location}".into(),
         ],
     );
     event_captions.insert(
         "ritual.readingdisrupted".to_string(),
         vec![
-            "The reading is disrupted!".to_string(),
-            "Chaos at {location}: the ritual cannot continue".to_string(),
+            "The reading is disrupted!".into(),
+            "Chaos at {location}: the ritual cannot continue".into(),
         ],
     );
 
@@ -755,15 +1412,15 @@ pub fn default_templates() -> CommentaryTemplates {
     event_captions.insert(
         "movement.travel".to_string(),
         vec![
-            "{primary_name} journeys to {location}".to_string(),
-            "{primary_name} arrives at {location}".to_string(),
+            "{primary_name} journeys to {location}".into(),
+            "{primary_name} arrives at {location}".into(),
         ],
     );
     event_captions.insert(
         "movement.flee".to_string(),
         vec![
-            "{primary_name} flees in desperation".to_string(),
-            "Fear drives {primary_name} away".to_string(),
+            "{primary_name} flees in desperation".into(),
+            "Fear drives {primary_name} away".into(),
         ],
     );
 
@@ -771,15 +1428,15 @@ pub fn default_templates() -> CommentaryTemplates {
     event_captions.insert(
         "conflict.fight".to_string(),
         vec![
-            "Violence erupts between {primary_name} and {secondary_name}".to_string(),
-            "{primary_name} clashes with {secondary_name}".to_string(),
+            "Violence erupts between {primary_name} and {secondary_name}".into(),
+            "{primary_name} clashes with {secondary_name}".into(),
         ],
     );
     event_captions.insert(
         "conflict.duel".to_string(),
         vec![
-            "{primary_name} faces {secondary_name} in single combat".to_string(),
-            "A duel to settle old scores".to_string(),
+            "{primary_name} faces {secondary_name} in single combat".into(),
+            "A duel to settle old scores".into(),
         ],
     );
 
@@ -787,14 +1444,14 @@ pub fn default_templates() -> CommentaryTemplates {
     event_captions.insert(
         "cooperation.allianceformed".to_string(),
         vec![
-            "{primary_name} and {secondary_name} forge an alliance".to_string(),
-            "New bonds form between {primary_faction} and {secondary_faction}".to_string(),
+            "{primary_name} and {secondary_name} forge an alliance".into(),
+            "New bonds form between {primary_faction} and {secondary_faction}".into(),
         ],
     );
     event_captions.insert(
         "cooperation.trade".to_string(),
         vec![
-            "{primary_name} trades with {secondary_name}".to_string(),
+            "{primary_name} trades with {secondary_name}".into(),
         ],
     );
 
@@ -802,15 +1459,15 @@ pub fn default_templates() -> CommentaryTemplates {
     event_captions.insert(
         "faction.join".to_string(),
         vec![
-            "{primary_name} joins {primary_faction}".to_string(),
-            "A new member for {primary_faction}: {primary_name}".to_string(),
+            "{primary_name} joins {primary_faction}".into(),
+            "A new member for {primary_faction}: {primary_name}".into(),
         ],
     );
     event_captions.insert(
         "faction.exile".to_string(),
         vec![
-            "{primary_name} is exiled from {primary_faction}".to_string(),
-            "Cast out: {primary_name} loses everything".to_string(),
+            "{primary_name} is exiled from {primary_faction}".into(),
+            "Cast out: {primary_name} loses everything".into(),
         ],
     );
 
@@ -819,17 +1476,17 @@ pub fn default_templates() -> CommentaryTemplates {
         IronyTemplate {
             pattern: "unaware_of_betrayal".to_string(),
             templates: vec![
-                "{unaware_agent} still trusts {betrayer}—for now".to_string(),
-                "If only {unaware_agent} knew what {betrayer} did".to_string(),
-                "{unaware_agent} has no idea about {betrayer}'s treachery".to_string(),
+                "{unaware_agent} still trusts {betrayer}—for now".into(),
+                "If only {unaware_agent} knew what {betrayer} did".into(),
+                "{unaware_agent} has no idea about {betrayer}'s treachery".into(),
             ],
             required_context: vec!["unaware_agent".to_string(), "betrayer".to_string()],
         },
         IronyTemplate {
             pattern: "walking_into_trap".to_string(),
             templates: vec![
-                "{unaware_agent} walks unknowingly toward danger".to_string(),
-                "They don't know what awaits them at {betrayal_location}".to_string(),
+                "{unaware_agent} walks unknowingly toward danger".into(),
+                "They don't know what awaits them at {betrayal_location}".into(),
             ],
             required_context: vec!["unaware_agent".to_string()],
         },
@@ -840,36 +1497,52 @@ pub fn default_templates() -> CommentaryTemplates {
         TeaserTemplate {
             tension_type: "brewingbetrayal".to_string(),
             templates: vec![
-                "Loyalty frays at the edges...".to_string(),
-                "Someone is having second thoughts".to_string(),
-                "Trust is a fragile thing".to_string(),
+                "Loyalty frays at the edges...".into(),
+                "Someone is having second thoughts".into(),
+                "Trust is a fragile thing".into(),
             ],
             min_severity: 0.3,
+            grudge_templates: vec![],
         },
         TeaserTemplate {
             tension_type: "resourceconflict".to_string(),
             templates: vec![
-                "Resources grow scarce...".to_string(),
-                "Winter stores are running low...".to_string(),
-                "There isn't enough for everyone".to_string(),
+                "Resources grow scarce...".into(),
+                "Winter stores are running low...".into(),
+                "There isn't enough for everyone".into(),
             ],
             min_severity: 0.4,
+            grudge_templates: vec![],
         },
         TeaserTemplate {
             tension_type: "successioncrisis".to_string(),
             templates: vec![
-                "Leadership hangs in the balance".to_string(),
-                "Who will lead when the dust settles?".to_string(),
+                "Leadership hangs in the balance".into(),
+                "Who will lead when the dust settles?".into(),
             ],
             min_severity: 0.5,
+            grudge_templates: vec![],
         },
         TeaserTemplate {
             tension_type: "revengearc".to_string(),
             templates: vec![
-                "Old wounds refuse to heal".to_string(),
-                "Vengeance simmers beneath the surface".to_string(),
+                "Old wounds refuse to heal".into(),
+                "Vengeance simmers beneath the surface".into(),
             ],
             min_severity: 0.4,
+            grudge_templates: vec![
+                "{victim} hasn't forgotten what {offender} did at {offense_location}".into(),
+                "{victim} still blames {offender} for what happened at {offense_location}".into(),
+            ],
+        },
+        TeaserTemplate {
+            tension_type: "factionwar".to_string(),
+            templates: vec![
+                "Old rivals sharpen their blades".into(),
+                "Every skirmish brings war a step closer".into(),
+            ],
+            min_severity: 0.4,
+            grudge_templates: vec![],
         },
     ];
 
@@ -898,6 +1571,10 @@ pub fn default_templates_toml() -> String {
     "{primary_name} has fallen",
     "Death claims {primary_name}",
 ]
+"death.killed_notable" = [
+    "A giant falls: {primary_name} is slain",
+    "All of {primary_faction} will mourn {primary_name}",
+]
 "ritual.readingheld" = [
     "The faithful gather at {location}",
     "{primary_name} opens the book of {primary_faction}",
@@ -929,6 +1606,24 @@ templates = [
     "Winter stores are running low...",
 ]
 min_severity = 0.4
+
+[[tension_teasers]]
+tension_type = "revengearc"
+templates = [
+    "Old wounds refuse to heal",
+]
+grudge_templates = [
+    "{victim} hasn't forgotten what {offender} did at {offense_location}",
+]
+min_severity = 0.4
+
+[[tension_teasers]]
+tension_type = "factionwar"
+templates = [
+    "Old rivals sharpen their blades",
+    "Every skirmish brings war a step closer",
+]
+min_severity = 0.4
 "#
     .to_string()
 }
@@ -936,9 +1631,11 @@ min_severity = 0.4
 #[cfg(test)]
 mod tests {
     use super::*;
+    use std::collections::BTreeMap;
     use sim_events::{
-        ActorSet, ActorSnapshot, AffectedActor, BetrayalSubtype, EventContext, EventOutcome,
-        GeneralOutcome, MovementSubtype, Season, SimTimestamp, TensionStatus, TensionType,
+        create_movement_event, ActorSet, ActorSnapshot, AffectedActor, BetrayalSubtype,
+        EventContext, EventOutcome, GeneralOutcome, MovementSubtype, Season, SimTimestamp,
+        TensionStatus, TensionType,
     };
 
     fn test_timestamp() -> SimTimestamp {
@@ -1041,7 +1738,7 @@ mod tests {
         let event = make_betrayal_event();
 
         let template = "{primary_name} betrays {secondary_name} at {location}";
-        let filled = generator.fill_event_template(template, &event);
+        let filled = generator.fill_event_template(template, &event, &test_timestamp());
 
         assert_eq!(
             filled,
@@ -1049,16 +1746,113 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_fill_role_display_placeholders() {
+        let generator = CommentaryGenerator::with_defaults();
+        let mut event = make_betrayal_event();
+        event.actors.primary.role = "scoutcaptain".to_string();
+
+        let template = "{primary_name}, {primary_role_with_article} ({primary_role_display}, {primary_role})";
+        let filled = generator.fill_event_template(template, &event, &test_timestamp());
+
+        assert_eq!(filled, "Mira of Thornwood, a scout captain (Scout Captain, scoutcaptain)");
+    }
+
+    #[test]
+    fn test_role_display_names() {
+        assert_eq!(role_display_name("scoutcaptain"), "Scout Captain");
+        assert_eq!(role_display_name("council_member"), "Council Member");
+        assert_eq!(role_display_name("skilledworker"), "Skilled Worker");
+        assert_eq!(role_display_name("leader"), "Leader");
+        // Unknown roles are humanized from the id
+        assert_eq!(role_display_name("border_warden"), "Border Warden");
+        assert_eq!(role_display_name("scout"), "Scout");
+    }
+
+    #[test]
+    fn test_role_with_article() {
+        assert_eq!(role_with_article("scout"), "a scout");
+        assert_eq!(role_with_article("leader"), "the leader");
+        assert_eq!(role_with_article("reader"), "the reader");
+        assert_eq!(role_with_article("elder"), "an elder");
+        assert_eq!(role_with_article("scout_captain"), "a scout captain");
+    }
+
     #[test]
     fn test_fill_template_missing_secondary() {
         let generator = CommentaryGenerator::with_defaults();
         let event = make_movement_event();
 
         let template = "{primary_name} meets {secondary_name}";
-        let filled = generator.fill_event_template(template, &event);
+        let filled = generator.fill_event_template(template, &event, &test_timestamp());
+
+        // Dropped cleanly, without a trailing space
+        assert_eq!(filled, "Mira meets");
+    }
+
+    #[test]
+    fn test_unknown_placeholder_uses_fallback() {
+        let config = CommentaryConfig {
+            placeholder_fallback: Some("someone".to_string()),
+            ..CommentaryConfig::default()
+        };
+        let generator = CommentaryGenerator::new(default_templates(), config);
+        let event = make_betrayal_event();
+
+        let filled = generator.fill_event_template("{primary_name} greets {unknown}", &event, &test_timestamp());
 
-        // Secondary should be empty string
-        assert_eq!(filled, "Mira meets ");
+        assert_eq!(filled, "Mira of Thornwood greets someone");
+    }
+
+    #[test]
+    fn test_fill_date_placeholder() {
+        let generator = CommentaryGenerator::with_defaults();
+        let event = make_betrayal_event();
+        let timestamp = SimTimestamp::new(5000, 2, Season::Winter, 3);
+
+        let filled = generator.fill_event_template("On {date}, {primary_name} acts", &event, &timestamp);
+
+        assert_eq!(filled, "On the 3rd day of Winter, year 2, Mira of Thornwood acts");
+    }
+
+    #[test]
+    fn test_fill_season_placeholder() {
+        let generator = CommentaryGenerator::with_defaults();
+        let event = make_betrayal_event();
+        let timestamp = SimTimestamp::new(5000, 2, Season::Autumn, 11);
+
+        let filled = generator.fill_event_template("{season} brings treachery", &event, &timestamp);
+
+        assert_eq!(filled, "Autumn brings treachery");
+    }
+
+    #[test]
+    fn test_fill_tick_placeholder() {
+        let generator = CommentaryGenerator::with_defaults();
+        let event = make_betrayal_event();
+
+        let filled = generator.fill_event_template("[{tick}] {primary_name}", &event, &test_timestamp());
+
+        assert_eq!(filled, "[1000] Mira of Thornwood");
+    }
+
+    #[test]
+    fn test_unknown_placeholder_left_intact() {
+        let generator = CommentaryGenerator::with_defaults();
+        let event = make_betrayal_event();
+
+        let filled = generator.fill_event_template("{primary_nmae} in {season}", &event, &test_timestamp());
+
+        assert_eq!(filled, "{primary_nmae} in Spring");
+    }
+
+    #[test]
+    fn test_ordinal_suffixes() {
+        let formatted: Vec<String> = [1, 2, 3, 4, 11, 12, 13, 21, 22, 23].iter().map(|&n| ordinal(n)).collect();
+        assert_eq!(
+            formatted,
+            vec!["1st", "2nd", "3rd", "4th", "11th", "12th", "13th", "21st", "22nd", "23rd"]
+        );
     }
 
     #[test]
@@ -1124,6 +1918,62 @@ mod tests {
         assert!(teaser.related_tension.is_some());
     }
 
+    #[test]
+    fn test_recently_featured_agent_caption_deprioritized() {
+        let mut generator = CommentaryGenerator::with_defaults();
+        let event = make_betrayal_event();
+
+        generator.set_current_tick(1000);
+        let first = generator.caption_event(&event, test_timestamp()).unwrap();
+        generator.set_current_tick(1001);
+        let second = generator.caption_event(&event, test_timestamp()).unwrap();
+
+        let penalty = generator.config().agent_cooldown_penalty;
+        assert!((first.priority - event.drama_score).abs() < 1e-6);
+        assert!((second.priority - event.drama_score * (1.0 - penalty)).abs() < 1e-6);
+
+        // Once the cooldown lapses the agent gets full priority again
+        generator.set_current_tick(1001 + generator.config().agent_cooldown_ticks);
+        let third = generator.caption_event(&event, test_timestamp()).unwrap();
+        assert!((third.priority - event.drama_score).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_teaser_cooldown_suppresses_repeat() {
+        let mut generator = CommentaryGenerator::with_defaults();
+        let cooldown = generator.config().teaser_cooldown_ticks;
+        let tension = make_tension();
+
+        generator.set_current_tick(1000);
+        assert!(generator.generate_teaser(&tension, test_timestamp()).is_some());
+
+        generator.set_current_tick(1000 + cooldown - 1);
+        assert!(generator.generate_teaser(&tension, test_timestamp()).is_none());
+
+        generator.set_current_tick(1000 + cooldown);
+        assert!(generator.generate_teaser(&tension, test_timestamp()).is_some());
+    }
+
+    #[test]
+    fn test_teaser_severity_spike_bypasses_cooldown() {
+        let mut generator = CommentaryGenerator::with_defaults();
+        let mut tension = make_tension();
+        tension.severity = 0.5;
+
+        generator.set_current_tick(1000);
+        assert!(generator.generate_teaser(&tension, test_timestamp()).is_some());
+
+        // Small rise: still cooling down
+        generator.set_current_tick(1010);
+        tension.severity = 0.55;
+        assert!(generator.generate_teaser(&tension, test_timestamp()).is_none());
+
+        // Escalation deserves a fresh line
+        generator.set_current_tick(1020);
+        tension.severity = 0.5 + generator.config().teaser_severity_bypass + 0.05;
+        assert!(generator.generate_teaser(&tension, test_timestamp()).is_some());
+    }
+
     #[test]
     fn test_generate_teaser_low_severity_filtered() {
         let mut generator = CommentaryGenerator::with_defaults();
@@ -1297,7 +2147,7 @@ mod tests {
         ));
 
         // Add relationship from affected -> betrayer
-        let mut affected_relationships = HashMap::new();
+        let mut affected_relationships = BTreeMap::new();
         affected_relationships.insert(
             betrayer_id.to_string(),
             RelationshipSnapshot::new(trust_level, 0.5, 0.5),
@@ -1344,13 +2194,80 @@ mod tests {
         // Create world state where Corin still trusts Mira
         let state = make_world_snapshot_with_trust("agent_corin", "agent_mira", 0.8);
 
-        let situations = detector.detect_irony(&state);
+        let situations = detector.detect_irony(&state, &[], &HashMap::new());
         assert_eq!(situations.len(), 1);
         assert_eq!(situations[0].situation_type, "unaware_of_betrayal");
         assert_eq!(situations[0].unaware_agent_id, "agent_corin");
         assert_eq!(situations[0].betrayer_id, Some("agent_mira".to_string()));
     }
 
+    #[test]
+    fn test_victim_heading_to_betrayer_is_walking_into_trap() {
+        let mut detector = IronyDetector::new();
+        detector.record_betrayal(&make_betrayal_event_with_affected());
+        let state = make_world_snapshot_with_trust("agent_corin", "agent_mira", 0.8);
+
+        let actor = ActorSnapshot::new("agent_corin", "Corin", "thornwood", "leader", "thornwood_hall");
+        let to_market = create_movement_event(
+            "evt_00010",
+            SimTimestamp::new(1000, 1, Season::Spring, 10),
+            actor.clone(),
+            "errand",
+            "market",
+        );
+        let to_bridge = create_movement_event(
+            "evt_00011",
+            SimTimestamp::new(1010, 1, Season::Spring, 10),
+            actor,
+            "meeting",
+            "eastern_bridge",
+        );
+
+        // Only the latest movement counts, and Mira waits at the bridge
+        let situations = detector.detect_irony(&state, &[to_bridge, to_market.clone()], &HashMap::new());
+        assert_eq!(situations.len(), 1);
+        assert_eq!(situations[0].situation_type, "walking_into_trap");
+        assert_eq!(situations[0].unaware_agent_id, "agent_corin");
+        assert_eq!(situations[0].betrayal_location.as_deref(), Some("eastern_bridge"));
+
+        let situations = detector.detect_irony(&state, &[to_market], &HashMap::new());
+        assert_eq!(situations[0].situation_type, "unaware_of_betrayal");
+    }
+
+    #[test]
+    fn test_schedule_staggers_items_within_simultaneous_limit() {
+        let mut items: Vec<CommentaryItem> = (0..5)
+            .map(|i| {
+                let content = format!("Caption {}", i);
+                CommentaryItem::new(format!("com_{}", i), test_timestamp(), CommentaryType::EventCaption, content)
+                    .with_duration(100)
+                    .with_priority(1.0 - i as f32 * 0.1)
+            })
+            .collect();
+        // One slot is still busy with a caption from an earlier tick
+        let mut slots = vec![1050, 0];
+
+        schedule_commentary(&mut items, &mut slots, 1000, 150);
+
+        // The last item would wait 200 ticks, past the 150-tick window
+        let offsets: Vec<u64> = items.iter().map(|item| item.start_offset_ticks).collect();
+        assert_eq!(offsets, vec![0, 50, 100, 150]);
+        assert_eq!(items[3].item_id, "com_3");
+
+        // Never more than two items on screen at any tick
+        for tick in 1000..1400 {
+            let visible = items
+                .iter()
+                .filter(|item| {
+                    let start = 1000 + item.start_offset_ticks;
+                    (start..start + u64::from(item.display_duration_ticks)).contains(&tick)
+                })
+                .count();
+            assert!(visible + usize::from(tick < 1050) <= 2, "{} visible at tick {}", visible, tick);
+        }
+        assert_eq!(slots, vec![1250, 1200]);
+    }
+
     #[test]
     fn test_irony_clears_when_trust_drops() {
         let mut detector = IronyDetector::new();
@@ -1360,7 +2277,7 @@ mod tests {
         // Create world state where Corin no longer trusts Mira
         let state = make_world_snapshot_with_trust("agent_corin", "agent_mira", 0.3);
 
-        let situations = detector.detect_irony(&state);
+        let situations = detector.detect_irony(&state, &[], &HashMap::new());
         assert!(situations.is_empty()); // No irony - trust is low
     }
 
@@ -1375,10 +2292,43 @@ mod tests {
 
         // Even with high trust, no irony because it's discovered
         let state = make_world_snapshot_with_trust("agent_corin", "agent_mira", 0.9);
-        let situations = detector.detect_irony(&state);
+        let situations = detector.detect_irony(&state, &[], &HashMap::new());
         assert!(situations.is_empty());
     }
 
+    #[test]
+    fn test_victim_remembering_betrayal_is_aware_despite_trust() {
+        let mut detector = IronyDetector::new();
+        detector.record_betrayal(&make_betrayal_event_with_affected());
+
+        // Corin witnessed it, but his trust in Mira has not caught up yet
+        let state = make_world_snapshot_with_trust("agent_corin", "agent_mira", 0.9);
+        let memories = HashMap::from([(
+            "agent_corin".to_string(),
+            HashSet::from(["evt_00001".to_string()]),
+        )]);
+
+        assert!(detector.detect_irony(&state, &[], &memories).is_empty());
+        assert!(detector.betrayals()[0].is_discovered_by("agent_corin"));
+
+        // Once discovered it stays discovered, memory or not
+        assert!(detector.detect_irony(&state, &[], &HashMap::new()).is_empty());
+    }
+
+    #[test]
+    fn test_firsthand_witness_in_snapshot_is_aware() {
+        let mut detector = IronyDetector::new();
+        detector.record_betrayal(&make_betrayal_event_with_affected());
+
+        // Corin saw the betrayal happen; no memory of it was shared this tick
+        let mut state = make_world_snapshot_with_trust("agent_corin", "agent_mira", 0.9);
+        state.agents[0].remembered_events = vec!["evt_00001".to_string()];
+
+        let memories = remembered_events(&state, &[]);
+        assert!(detector.detect_irony(&state, &[], &memories).is_empty());
+        assert!(detector.betrayals()[0].is_discovered_by("agent_corin"));
+    }
+
     #[test]
     fn test_multiple_affected_agents() {
         let mut detector = IronyDetector::new();
@@ -1432,18 +2382,42 @@ mod tests {
         state.agents.push(SnapshotAgent::new("agent_elena", "Elena", "thornwood", "scout", "market"));
         state.agents.push(SnapshotAgent::new("agent_mira", "Mira", "thornwood", "scout", "bridge"));
 
-        let mut corin_rels = HashMap::new();
+        let mut corin_rels = BTreeMap::new();
         corin_rels.insert("agent_mira".to_string(), RelationshipSnapshot::new(0.8, 0.5, 0.5));
         state.relationships.insert("agent_corin".to_string(), corin_rels);
 
-        let mut elena_rels = HashMap::new();
+        let mut elena_rels = BTreeMap::new();
         elena_rels.insert("agent_mira".to_string(), RelationshipSnapshot::new(0.7, 0.5, 0.5));
         state.relationships.insert("agent_elena".to_string(), elena_rels);
 
-        let situations = detector.detect_irony(&state);
+        let situations = detector.detect_irony(&state, &[], &HashMap::new());
         assert_eq!(situations.len(), 2); // Both have irony situations
     }
 
+    #[test]
+    fn test_export_import_round_trip() {
+        let mut detector = IronyDetector::with_trust_threshold(0.6);
+        detector.record_betrayal(&make_betrayal_event_with_affected());
+        detector.mark_discovered("evt_00001", "agent_elena");
+        detector.mark_discovered("evt_00001", "agent_corin");
+
+        let json = serde_json::to_string(&detector.export()).unwrap();
+        // The discovered set is written as a sorted list
+        assert!(json.contains(r#""discovered_by":["agent_corin","agent_elena"]"#));
+
+        let state: IronyDetectorState = serde_json::from_str(&json).unwrap();
+        let restored = IronyDetector::import(state);
+
+        assert_eq!(restored.betrayal_count(), detector.betrayal_count());
+        assert_eq!(
+            restored.betrayals()[0].discovered_by,
+            detector.betrayals()[0].discovered_by
+        );
+        assert_eq!(restored.export().trust_threshold, 0.6);
+        // Re-exporting produces identical output
+        assert_eq!(serde_json::to_string(&restored.export()).unwrap(), json);
+    }
+
     #[test]
     fn test_cleanup_old_betrayals() {
         let mut detector = IronyDetector::new();
@@ -1515,4 +2489,114 @@ mod tests {
         assert_eq!(situation.betrayer_name, Some("Mira".to_string()));
         assert_eq!(situation.betrayal_event_id, Some("evt_00001".to_string()));
     }
+
+    #[test]
+    fn test_dedup_commentary_keeps_highest_priority() {
+        let item = |id: &str, content: &str, priority: f32, agents: &[&str]| {
+            CommentaryItem::new(id, test_timestamp(), CommentaryType::DramaticIrony, content)
+                .with_priority(priority)
+                .with_agents(agents.iter().map(|a| a.to_string()).collect())
+        };
+
+        let mut queue = vec![
+            item("a", "Corin doesn't know...", 0.5, &["agent_corin", "agent_mira"]),
+            item("b", "Little does Corin suspect...", 0.9, &["agent_mira", "agent_corin"]),
+            item("c", "Corin doesn't know...", 0.4, &["agent_voss"]),
+            item("d", "Voss waits.", 0.3, &["agent_voss"]),
+        ];
+        dedup_commentary(&mut queue);
+
+        let ids: Vec<&str> = queue.iter().map(|c| c.item_id.as_str()).collect();
+        // "a" loses to the higher-priority "b" about the same pair; "c" repeats "a"'s text
+        assert_eq!(ids, vec!["b", "d"]);
+    }
+
+    #[test]
+    fn test_same_seed_and_tick_yield_same_caption() {
+        let captions = |seed: u64| -> Vec<String> {
+            let config = CommentaryConfig { seed, agent_cooldown_ticks: 0, ..CommentaryConfig::default() };
+            let mut generator = CommentaryGenerator::new(default_templates(), config);
+            let event = make_betrayal_event();
+            (1000..1020)
+                .map(|tick| {
+                    generator.set_current_tick(tick);
+                    generator.caption_event(&event, test_timestamp()).unwrap().content
+                })
+                .collect()
+        };
+
+        assert_eq!(captions(7), captions(7));
+        // Seeds still vary the wording
+        assert_ne!(captions(7), captions(8));
+    }
+
+    #[test]
+    fn test_weighted_template_selection() {
+        use rand::rngs::StdRng;
+        use rand::SeedableRng;
+
+        let lines = vec![
+            TemplateLine::Weighted { text: "never".to_string(), weight: 0.0 },
+            TemplateLine::Weighted { text: "often".to_string(), weight: 99.0 },
+            TemplateLine::from("rarely"),
+        ];
+        let mut rng = StdRng::seed_from_u64(42);
+
+        let mut often = 0;
+        for _ in 0..1000 {
+            let chosen = choose_template(&lines, &mut rng).unwrap();
+            assert_ne!(chosen, "never");
+            if chosen == "often" {
+                often += 1;
+            }
+        }
+        assert!(often > 900, "heavily weighted template chosen only {} times", often);
+
+        let silent = vec![TemplateLine::Weighted { text: "never".to_string(), weight: 0.0 }];
+        assert!(choose_template(&silent, &mut rng).is_none());
+    }
+
+    #[test]
+    fn test_templates_toml_accepts_weighted_entries() {
+        let toml = r#"
+[event_captions]
+"betrayal" = [
+    "{primary_name} betrays their own",
+    { text = "{primary_name} turns traitor", weight = 3.0 },
+]
+"#;
+        let templates = CommentaryTemplates::from_str(toml).unwrap();
+        let lines = templates.get_type_templates("betrayal").unwrap();
+
+        assert_eq!(lines[0], TemplateLine::from("{primary_name} betrays their own"));
+        assert_eq!(lines[0].weight(), 1.0);
+        assert_eq!(lines[1].text(), "{primary_name} turns traitor");
+        assert_eq!(lines[1].weight(), 3.0);
+    }
+
+    #[test]
+    fn test_templates_reject_unknown_teaser_tension_type() {
+        let toml = r#"
+[[tension_teasers]]
+tension_type = "brewingbetrayal"
+templates = ["Loyalty frays at the edges..."]
+
+[[tension_teasers]]
+tension_type = "dragonattack"
+templates = ["Something stirs in the mountains..."]
+"#;
+        match CommentaryTemplates::from_str(toml) {
+            Err(TemplateError::Invalid(errors)) => {
+                assert_eq!(errors.len(), 1);
+                assert!(matches!(
+                    &errors[0],
+                    ConfigError::UnknownTensionType { field, value }
+                        if field == "tension_teasers[1].tension_type" && value == "dragonattack"
+                ));
+            }
+            other => panic!("expected teaser validation error, got {:?}", other),
+        }
+
+        assert!(CommentaryTemplates::from_str(&default_templates_toml()).is_ok());
+    }
 }
@@ -3,8 +3,12 @@
 //! All director settings are loaded from a TOML configuration file.
 
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 use std::path::Path;
 
+use sim_events::{EventType, TensionType};
+
+use crate::commentary::CommentaryTemplates;
 use crate::scorer::EventWeights;
 use crate::threads::ThreadTrackerConfig;
 
@@ -23,6 +27,9 @@ pub struct DirectorConfig {
     /// Thread tracking settings
     #[serde(default)]
     pub threads: ThreadTrackerConfig,
+    /// Highlight clip window settings
+    #[serde(default)]
+    pub highlights: HighlightConfig,
     /// General director settings
     #[serde(default)]
     pub director: GeneralConfig,
@@ -35,16 +42,72 @@ impl Default for DirectorConfig {
             focus: FocusConfig::default(),
             commentary: CommentaryConfig::default(),
             threads: ThreadTrackerConfig::default(),
+            highlights: HighlightConfig::default(),
             director: GeneralConfig::default(),
         }
     }
 }
 
+/// Names accepted by [`DirectorConfig::preset`].
+pub const PRESET_NAMES: [&str; 3] = ["documentary", "fast_cut", "observational"];
+
 impl DirectorConfig {
+    /// Returns a curated configuration by name, or `None` if the name is unknown.
+    ///
+    /// - `documentary`: long holds on a storyline, frequent commentary
+    /// - `fast_cut`: short holds, only the most notable events make the cut
+    /// - `observational`: minimal commentary, wide shots when nothing is pressing
+    pub fn preset(name: &str) -> Option<DirectorConfig> {
+        let mut config = DirectorConfig::default();
+        match name {
+            "documentary" => {
+                config.focus.thread_fatigue_threshold_ticks = 10000;
+                config.focus.focus_continuity_boost = 1.6;
+                config.focus.min_event_score = 0.15;
+                config.focus.establishing_hold_ticks = 200;
+                config.focus.max_shot_ticks = 900;
+                config.commentary.max_queue_size = 8;
+                config.commentary.min_drama_for_caption = 0.2;
+                config.commentary.base_display_duration_ticks = 150;
+                config.commentary.commentary_cooldown_ticks = 250;
+            }
+            "fast_cut" => {
+                config.focus.thread_fatigue_threshold_ticks = 1500;
+                config.focus.focus_continuity_boost = 1.0;
+                config.focus.fatigue_multiplier = 0.3;
+                config.focus.min_event_score = 0.5;
+                config.focus.establishing_hold_ticks = 40;
+                config.focus.min_shot_ticks = 20;
+                config.focus.max_shot_ticks = 300;
+                config.commentary.max_queue_size = 3;
+                config.commentary.base_display_duration_ticks = 60;
+                config.director.default_camera_mode = DefaultCameraMode::HighActivity;
+            }
+            "observational" => {
+                config.focus.thread_fatigue_threshold_ticks = 7500;
+                config.focus.min_event_score = 0.35;
+                config.focus.min_tension_severity = 0.5;
+                config.commentary.max_queue_size = 1;
+                config.commentary.min_drama_for_caption = 0.7;
+                config.commentary.commentary_cooldown_ticks = 2000;
+                config.commentary.enable_dramatic_irony = false;
+                config.commentary.enable_context_reminders = false;
+                config.director.default_camera_mode = DefaultCameraMode::Overview;
+            }
+            _ => return None,
+        }
+        Some(config)
+    }
+
     /// Loads configuration from a TOML file.
+    ///
+    /// The parsed config is validated; all violations are reported together
+    /// as `ConfigError::Invalid`.
     pub fn from_file(path: &Path) -> Result<Self, ConfigError> {
         let content = std::fs::read_to_string(path).map_err(ConfigError::IoError)?;
-        Self::from_str(&content)
+        let config = Self::from_str(&content)?;
+        config.validate().map_err(ConfigError::Invalid)?;
+        Ok(config)
     }
 
     /// Parses configuration from a TOML string.
@@ -52,6 +115,150 @@ impl DirectorConfig {
         toml::from_str(content).map_err(ConfigError::TomlError)
     }
 
+    /// Checks every invariant and returns all violations, not just the first.
+    pub fn validate(&self) -> Result<(), Vec<ConfigError>> {
+        let mut errors = Vec::new();
+
+        let thresholds = [
+            ("focus.min_tension_severity", self.focus.min_tension_severity),
+            ("focus.fatigue_multiplier", self.focus.fatigue_multiplier),
+            ("commentary.min_drama_for_caption", self.commentary.min_drama_for_caption),
+            ("commentary.teaser_severity_bypass", self.commentary.teaser_severity_bypass),
+            ("commentary.agent_cooldown_penalty", self.commentary.agent_cooldown_penalty),
+            ("event_weights.novelty_decay", self.event_weights.novelty_decay),
+            ("event_weights.novelty_floor", self.event_weights.novelty_floor),
+            ("threads.min_severity_for_thread", self.threads.min_severity_for_thread),
+        ];
+        for (field, value) in thresholds {
+            if !(0.0..=1.0).contains(&value) {
+                errors.push(ConfigError::OutOfRange { field: field.to_string(), value, min: 0.0, max: 1.0 });
+            }
+        }
+
+        // Highlights are picked from notable events, so their bar can't sit below
+        // notability (a notability bar above 1.0 still leaves 1.0 as a valid highlight bar)
+        let highlight_floor = self.focus.min_event_score.clamp(0.0, 1.0);
+        if !(highlight_floor..=1.0).contains(&self.director.min_highlight_score) {
+            errors.push(ConfigError::OutOfRange {
+                field: "director.min_highlight_score".to_string(),
+                value: self.director.min_highlight_score,
+                min: highlight_floor,
+                max: 1.0,
+            });
+        }
+
+        // Trust spans [-1, 1], so the irony threshold may sit below zero
+        let irony_threshold = self.commentary.irony_trust_threshold;
+        if !(-1.0..=1.0).contains(&irony_threshold) {
+            errors.push(ConfigError::OutOfRange {
+                field: "commentary.irony_trust_threshold".to_string(),
+                value: irony_threshold,
+                min: -1.0,
+                max: 1.0,
+            });
+        }
+
+        // Event scores can exceed 1.0, so min_event_score is only bounded below
+        let non_negative = [
+            ("focus.min_event_score", self.focus.min_event_score),
+            ("focus.focus_continuity_boost", self.focus.focus_continuity_boost),
+            ("focus.scarcity_grain_days", self.focus.scarcity_grain_days),
+            ("focus.dominant_event_score", self.focus.dominant_event_score),
+            ("event_weights.narrative_continuity_bonus", self.event_weights.narrative_continuity_bonus),
+            ("event_weights.tension_severity_bonus", self.event_weights.tension_severity_bonus),
+            ("event_weights.upset_victory_bonus", self.event_weights.upset_victory_bonus),
+            ("event_weights.desperate_theft_bonus", self.event_weights.desperate_theft_bonus),
+            ("commentary.ticks_per_character", self.commentary.ticks_per_character),
+            ("commentary.reputation_priority_boost", self.commentary.reputation_priority_boost),
+            ("highlights.ticks_per_score", self.highlights.ticks_per_score),
+            ("highlights.climax_post_roll_multiplier", self.highlights.climax_post_roll_multiplier),
+        ];
+        for (field, value) in non_negative {
+            if !value.is_finite() || value < 0.0 {
+                errors.push(ConfigError::Negative { field: field.to_string(), value });
+            }
+        }
+
+        // Walked in declaration order so errors are reported in a stable order
+        for event_type in EventType::all() {
+            if let Some(&value) = self.focus.event_type_thresholds.get(event_type) {
+                if !value.is_finite() || value < 0.0 {
+                    let field = format!("focus.event_type_thresholds.{:?}", event_type).to_lowercase();
+                    errors.push(ConfigError::Negative { field, value });
+                }
+            }
+        }
+
+        let weight_tables = [
+            ("event_weights.base_scores", &self.event_weights.base_scores),
+            ("event_weights.subtype_modifiers", &self.event_weights.subtype_modifiers),
+            ("event_weights.drama_tag_scores", &self.event_weights.drama_tag_scores),
+        ];
+        for (table, weights) in weight_tables {
+            // Sorted so errors are reported in a stable order
+            let mut keys: Vec<&String> = weights.keys().collect();
+            keys.sort();
+            for key in keys {
+                let value = weights[key];
+                if !value.is_finite() || value < 0.0 {
+                    errors.push(ConfigError::Negative { field: format!("{}.{}", table, key), value });
+                }
+            }
+        }
+
+        if self.focus.max_shot_ticks == 0 {
+            errors.push(ConfigError::NotPositive { field: "focus.max_shot_ticks".to_string() });
+        } else if self.focus.min_shot_ticks > self.focus.max_shot_ticks {
+            errors.push(ConfigError::OutOfRange {
+                field: "focus.min_shot_ticks".to_string(),
+                value: self.focus.min_shot_ticks as f32,
+                min: 0.0,
+                max: self.focus.max_shot_ticks as f32,
+            });
+        }
+
+        if self.commentary.max_queue_size == 0 {
+            errors.push(ConfigError::NotPositive { field: "commentary.max_queue_size".to_string() });
+        }
+        if self.commentary.max_simultaneous == 0 {
+            errors.push(ConfigError::NotPositive { field: "commentary.max_simultaneous".to_string() });
+        }
+        if self.commentary.base_display_duration_ticks == 0 {
+            errors.push(ConfigError::NotPositive { field: "commentary.base_display_duration_ticks".to_string() });
+        }
+
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(errors)
+        }
+    }
+
+    /// Checks that every tension teaser refers to a tension type that exists.
+    pub fn validate_teasers(templates: &CommentaryTemplates) -> Result<(), Vec<ConfigError>> {
+        let known: Vec<String> = TensionType::ALL
+            .iter()
+            .map(|t| format!("{:?}", t).to_lowercase())
+            .collect();
+
+        let errors: Vec<ConfigError> = templates
+            .tension_teasers
+            .iter()
+            .enumerate()
+            .filter(|(_, teaser)| !known.contains(&teaser.tension_type))
+            .map(|(i, teaser)| ConfigError::UnknownTensionType {
+                field: format!("tension_teasers[{}].tension_type", i),
+                value: teaser.tension_type.clone(),
+            })
+            .collect();
+
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(errors)
+        }
+    }
+
     /// Returns a default configuration as a TOML string.
     pub fn to_toml(&self) -> Result<String, TomlSerializeError> {
         toml::to_string_pretty(self).map_err(TomlSerializeError)
@@ -74,6 +281,35 @@ pub struct FocusConfig {
     pub min_event_score: f32,
     /// Boost for current focus continuity
     pub focus_continuity_boost: f32,
+    /// Ticks to hold the establishing shot of a new thread (0 disables it)
+    pub establishing_hold_ticks: u64,
+    /// Conflict and death events in one tick that make pacing urgent (0 disables it)
+    pub conflict_burst_events: usize,
+    /// Participants at one place that make a dominant event about the location (0 disables it)
+    pub location_focus_min_agents: usize,
+    /// Most events a tick can have and still read as quiet
+    pub quiet_tick_max_events: usize,
+    /// Days of grain per agent below which the world counts as scarce
+    pub scarcity_grain_days: f32,
+    /// Event score at which a single event dominates the tick; below it the
+    /// camera follows the top thread's protagonist
+    pub dominant_event_score: f32,
+    /// Shortest a camera shot may hold, in ticks
+    pub min_shot_ticks: u32,
+    /// Longest a camera shot may hold, in ticks
+    pub max_shot_ticks: u32,
+    /// Per-event-type notability thresholds, overriding `min_event_score`
+    pub event_type_thresholds: HashMap<EventType, f32>,
+}
+
+impl FocusConfig {
+    /// Minimum score for an event of this type to be notable.
+    pub fn notability_threshold(&self, event_type: EventType) -> f32 {
+        self.event_type_thresholds
+            .get(&event_type)
+            .copied()
+            .unwrap_or(self.min_event_score)
+    }
 }
 
 impl Default for FocusConfig {
@@ -85,16 +321,67 @@ impl Default for FocusConfig {
             fatigue_multiplier: 0.5,
             min_event_score: 0.2,
             focus_continuity_boost: 1.2,
+            establishing_hold_ticks: 100,
+            conflict_burst_events: 3,
+            location_focus_min_agents: 3,
+            quiet_tick_max_events: 2,
+            scarcity_grain_days: 10.0,
+            dominant_event_score: 0.8,
+            min_shot_ticks: 30,
+            max_shot_ticks: 600,
+            event_type_thresholds: HashMap::new(),
         }
     }
 }
 
+/// How much commentary the director emits.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum Verbosity {
+    /// Captions for climactic events only, with no irony or teasers
+    Silent,
+    /// Captions and teasers only for the more dramatic moments
+    Sparse,
+    /// Thresholds as configured
+    #[default]
+    Normal,
+    /// Captions for every notable event and teasers for every active tension
+    Verbose,
+}
+
+impl Verbosity {
+    /// Multiplier applied to the caption drama and teaser severity thresholds.
+    pub fn threshold_multiplier(self) -> f32 {
+        match self {
+            Verbosity::Silent | Verbosity::Normal => 1.0,
+            Verbosity::Sparse => 2.0,
+            Verbosity::Verbose => 0.0,
+        }
+    }
+
+    /// Returns true if only Climax-tier events are captioned.
+    pub fn climax_only(self) -> bool {
+        self == Verbosity::Silent
+    }
+
+    /// Returns true if irony and teaser commentary may be emitted.
+    pub fn allows_asides(self) -> bool {
+        self != Verbosity::Silent
+    }
+}
+
 /// Commentary generation configuration.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(default)]
 pub struct CommentaryConfig {
+    /// How much commentary to emit overall
+    pub verbosity: Verbosity,
     /// Maximum items in the commentary queue
     pub max_queue_size: usize,
+    /// Maximum commentary items on screen at once; the rest are staggered
+    pub max_simultaneous: usize,
+    /// Items that would have to wait longer than this to be shown are dropped
+    pub max_commentary_delay_ticks: u64,
     /// Minimum drama score to generate a caption
     pub min_drama_for_caption: f32,
     /// Base display duration in ticks
@@ -105,23 +392,88 @@ pub struct CommentaryConfig {
     pub commentary_cooldown_ticks: u64,
     /// Enable dramatic irony commentary
     pub enable_dramatic_irony: bool,
+    /// Reliability above which a betrayed agent still counts as unaware of the betrayal
+    pub irony_trust_threshold: f32,
     /// Enable tension teaser commentary
     pub enable_tension_teasers: bool,
+    /// Minimum ticks between teasers for the same tension
+    pub teaser_cooldown_ticks: u64,
+    /// Severity rise that lets a teaser bypass its cooldown
+    pub teaser_severity_bypass: f32,
+    /// Ticks after featuring an agent during which their captions are deprioritized
+    pub agent_cooldown_ticks: u64,
+    /// Fraction of priority removed from captions featuring a recently shown agent
+    pub agent_cooldown_penalty: f32,
+    /// Reputation at or above which an agent counts as a respected figure
+    pub high_reputation_threshold: f32,
+    /// Fractional priority boost for captions about a respected figure
+    pub reputation_priority_boost: f32,
     /// Enable context reminder commentary
     pub enable_context_reminders: bool,
+    /// Text substituted for unknown template placeholders (kept verbatim if unset)
+    pub placeholder_fallback: Option<String>,
+    /// Seed for template selection; with the tick, it makes commentary reproducible
+    pub seed: u64,
 }
 
 impl Default for CommentaryConfig {
     fn default() -> Self {
         Self {
+            verbosity: Verbosity::Normal,
             max_queue_size: 5,
+            max_simultaneous: 2,
+            max_commentary_delay_ticks: 300,
             min_drama_for_caption: 0.3,
             base_display_duration_ticks: 100,
             ticks_per_character: 1.0,
             commentary_cooldown_ticks: 500,
             enable_dramatic_irony: true,
+            irony_trust_threshold: 0.5,
             enable_tension_teasers: true,
+            teaser_cooldown_ticks: 500,
+            teaser_severity_bypass: 0.2,
+            agent_cooldown_ticks: 200,
+            agent_cooldown_penalty: 0.4,
+            high_reputation_threshold: 0.6,
+            reputation_priority_boost: 0.5,
             enable_context_reminders: true,
+            placeholder_fallback: None,
+            seed: 0,
+        }
+    }
+}
+
+impl CommentaryConfig {
+    /// Minimum drama score to caption an event, scaled by verbosity.
+    pub fn caption_drama_threshold(&self) -> f32 {
+        self.min_drama_for_caption * self.verbosity.threshold_multiplier()
+    }
+}
+
+/// Highlight clip window configuration.
+///
+/// Each side of a clip is `base + score * ticks_per_score` ticks long. A
+/// Climax stretches its post-roll to show the aftermath.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct HighlightConfig {
+    /// Ticks shown before the event regardless of score
+    pub base_pre_roll_ticks: u64,
+    /// Ticks shown after the event regardless of score
+    pub base_post_roll_ticks: u64,
+    /// Extra ticks on each side per unit of event score
+    pub ticks_per_score: f32,
+    /// Post-roll multiplier for Climax highlights
+    pub climax_post_roll_multiplier: f32,
+}
+
+impl Default for HighlightConfig {
+    fn default() -> Self {
+        Self {
+            base_pre_roll_ticks: 20,
+            base_post_roll_ticks: 20,
+            ticks_per_score: 40.0,
+            climax_post_roll_multiplier: 2.0,
         }
     }
 }
@@ -134,11 +486,16 @@ pub struct GeneralConfig {
     pub foresight_ticks: u64,
     /// Enable highlight marking
     pub enable_highlights: bool,
-    /// Minimum score for highlight
+    /// Minimum event score for a highlight, independent of notability
+    /// (must be between `focus.min_event_score` and 1.0)
     pub min_highlight_score: f32,
     /// Default camera mode when no focus is selected
     #[serde(default)]
     pub default_camera_mode: DefaultCameraMode,
+    /// Ticks between automatic cleanups during `process_tick` (0 disables it)
+    pub auto_cleanup_interval_ticks: u64,
+    /// Seasons after which an undiscovered betrayal is dropped by automatic cleanup
+    pub betrayal_max_age_seasons: u32,
 }
 
 impl Default for GeneralConfig {
@@ -148,6 +505,8 @@ impl Default for GeneralConfig {
             enable_highlights: true,
             min_highlight_score: 0.7,
             default_camera_mode: DefaultCameraMode::Overview,
+            auto_cleanup_interval_ticks: 0,
+            betrayal_max_age_seasons: 16,
         }
     }
 }
@@ -172,6 +531,16 @@ pub enum ConfigError {
     IoError(std::io::Error),
     /// Error parsing TOML config
     TomlError(toml::de::Error),
+    /// A threshold outside its allowed range
+    OutOfRange { field: String, value: f32, min: f32, max: f32 },
+    /// A weight or multiplier that must not be negative
+    Negative { field: String, value: f32 },
+    /// A count or duration that must be greater than zero
+    NotPositive { field: String },
+    /// A template refers to a tension type that doesn't exist
+    UnknownTensionType { field: String, value: String },
+    /// Config parsed but failed validation (all violations)
+    Invalid(Vec<ConfigError>),
 }
 
 /// Error that can occur during TOML serialization.
@@ -195,6 +564,23 @@ impl std::fmt::Display for ConfigError {
         match self {
             ConfigError::IoError(e) => write!(f, "IO error: {}", e),
             ConfigError::TomlError(e) => write!(f, "TOML parse error: {}", e),
+            ConfigError::OutOfRange { field, value, min, max } => {
+                write!(f, "{} = {} is outside [{}, {}]", field, value, min, max)
+            }
+            ConfigError::Negative { field, value } => {
+                write!(f, "{} = {} must be non-negative", field, value)
+            }
+            ConfigError::NotPositive { field } => write!(f, "{} must be greater than zero", field),
+            ConfigError::UnknownTensionType { field, value } => {
+                write!(f, "{} = \"{}\" is not a known tension type", field, value)
+            }
+            ConfigError::Invalid(errors) => {
+                write!(f, "Invalid config ({} errors)", errors.len())?;
+                for error in errors {
+                    write!(f, "\n  - {}", error)?;
+                }
+                Ok(())
+            }
         }
     }
 }
@@ -204,6 +590,7 @@ impl std::error::Error for ConfigError {
         match self {
             ConfigError::IoError(e) => Some(e),
             ConfigError::TomlError(e) => Some(e),
+            _ => None,
         }
     }
 }
@@ -212,6 +599,17 @@ impl std::error::Error for ConfigError {
 pub fn default_config_toml() -> String {
     r#"# Director Configuration
 
+[event_weights]
+narrative_continuity_bonus = 0.1
+tension_severity_bonus = 0.3
+upset_victory_bonus = 0.15
+desperate_theft_bonus = 0.2
+# Repeats of the same event type, subtype and faction within the window are
+# scored down by novelty_decay each, to no less than novelty_floor
+novelty_decay = 0.75
+novelty_floor = 0.25
+novelty_window_ticks = 3000
+
 [event_weights.base_scores]
 betrayal = 0.9
 death = 0.85
@@ -244,27 +642,60 @@ thread_fatigue_threshold_ticks = 5000
 fatigue_multiplier = 0.5
 min_event_score = 0.2
 focus_continuity_boost = 1.2
+establishing_hold_ticks = 100
+conflict_burst_events = 3
+location_focus_min_agents = 3
+quiet_tick_max_events = 2
+scarcity_grain_days = 10.0
+dominant_event_score = 0.8
+min_shot_ticks = 30
+max_shot_ticks = 600
+
+# Per-event-type overrides of min_event_score
+[focus.event_type_thresholds]
+# betrayal = 0.1
 
 [commentary]
+# silent, sparse, normal, or verbose
+verbosity = "normal"
 max_queue_size = 5
+max_simultaneous = 2
+max_commentary_delay_ticks = 300
 min_drama_for_caption = 0.3
 base_display_duration_ticks = 100
 ticks_per_character = 1.0
 commentary_cooldown_ticks = 500
 enable_dramatic_irony = true
+irony_trust_threshold = 0.5
 enable_tension_teasers = true
+teaser_cooldown_ticks = 500
+teaser_severity_bypass = 0.2
+agent_cooldown_ticks = 200
+agent_cooldown_penalty = 0.4
+high_reputation_threshold = 0.6
+reputation_priority_boost = 0.5
 enable_context_reminders = true
+# placeholder_fallback = "someone"
+seed = 0
 
 [threads]
 min_severity_for_thread = 0.3
 dormant_threshold_ticks = 5000
 max_threads = 20
 
+[highlights]
+base_pre_roll_ticks = 20
+base_post_roll_ticks = 20
+ticks_per_score = 40.0
+climax_post_roll_multiplier = 2.0
+
 [director]
 foresight_ticks = 1000
 enable_highlights = true
 min_highlight_score = 0.7
 default_camera_mode = "overview"
+auto_cleanup_interval_ticks = 0
+betrayal_max_age_seasons = 16
 "#.to_string()
 }
 
@@ -359,6 +790,26 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_config_with_event_type_thresholds() {
+        let toml = r#"
+            [focus]
+            min_event_score = 0.4
+
+            [focus.event_type_thresholds]
+            betrayal = 0.1
+        "#;
+
+        let config = DirectorConfig::from_str(toml).unwrap();
+
+        assert_eq!(config.focus.notability_threshold(EventType::Betrayal), 0.1);
+        assert_eq!(config.focus.notability_threshold(EventType::Movement), 0.4);
+
+        // Round-trips through TOML
+        let reparsed = DirectorConfig::from_str(&config.to_toml().unwrap()).unwrap();
+        assert_eq!(reparsed.focus.notability_threshold(EventType::Betrayal), 0.1);
+    }
+
     #[test]
     fn test_config_to_toml() {
         let config = DirectorConfig::default();
@@ -370,6 +821,128 @@ mod tests {
         assert!(toml.contains("[commentary]"));
     }
 
+    #[test]
+    fn test_default_config_is_valid() {
+        assert!(DirectorConfig::default().validate().is_ok());
+        let parsed = DirectorConfig::from_str(&default_config_toml()).unwrap();
+        assert!(parsed.validate().is_ok());
+    }
+
+    #[test]
+    fn test_validate_reports_all_violations() {
+        let mut config = DirectorConfig::default();
+        config.focus.min_tension_severity = 1.5;
+        config.commentary.max_queue_size = 0;
+        config.commentary.base_display_duration_ticks = 0;
+        config.event_weights.base_scores.insert("betrayal".to_string(), -0.5);
+
+        let errors = config.validate().unwrap_err();
+        assert_eq!(errors.len(), 4);
+        assert!(errors.iter().any(|e| matches!(
+            e,
+            ConfigError::OutOfRange { field, value, .. }
+                if field == "focus.min_tension_severity" && *value == 1.5
+        )));
+        assert!(errors.iter().any(|e| matches!(
+            e,
+            ConfigError::NotPositive { field } if field == "commentary.max_queue_size"
+        )));
+        assert!(errors.iter().any(|e| matches!(
+            e,
+            ConfigError::NotPositive { field } if field == "commentary.base_display_duration_ticks"
+        )));
+        assert!(errors.iter().any(|e| matches!(
+            e,
+            ConfigError::Negative { field, .. } if field == "event_weights.base_scores.betrayal"
+        )));
+    }
+
+    #[test]
+    fn test_highlight_score_cannot_undercut_notability() {
+        let mut config = DirectorConfig::default();
+        config.focus.min_event_score = 0.5;
+        config.director.min_highlight_score = 0.4;
+
+        let errors = config.validate().unwrap_err();
+        assert_eq!(errors.len(), 1);
+        assert!(matches!(
+            &errors[0],
+            ConfigError::OutOfRange { field, min, .. }
+                if field == "director.min_highlight_score" && *min == 0.5
+        ));
+
+        config.director.min_highlight_score = 0.5;
+        assert!(config.validate().is_ok());
+    }
+
+    #[test]
+    fn test_from_file_rejects_invalid_config() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("director.toml");
+        std::fs::write(&path, r#"
+            [focus]
+            fatigue_multiplier = -0.5
+
+            [threads]
+            min_severity_for_thread = 2.0
+        "#).unwrap();
+
+        match DirectorConfig::from_file(&path) {
+            Err(ConfigError::Invalid(errors)) => {
+                assert_eq!(errors.len(), 2);
+                let message = ConfigError::Invalid(errors).to_string();
+                assert!(message.contains("focus.fatigue_multiplier"));
+                assert!(message.contains("threads.min_severity_for_thread"));
+            }
+            other => panic!("expected validation failure, got {:?}", other.map(|_| ())),
+        }
+    }
+
+    #[test]
+    fn test_validate_teasers_flags_unknown_tension_type() {
+        assert!(DirectorConfig::validate_teasers(&crate::commentary::default_templates()).is_ok());
+
+        let mut templates = crate::commentary::default_templates();
+        templates.tension_teasers[0].tension_type = "dragonattack".to_string();
+        let errors = DirectorConfig::validate_teasers(&templates).unwrap_err();
+        assert_eq!(errors.len(), 1);
+        assert!(matches!(
+            &errors[0],
+            ConfigError::UnknownTensionType { field, value }
+                if field == "tension_teasers[0].tension_type" && value == "dragonattack"
+        ));
+    }
+
+    #[test]
+    fn test_presets_exist_and_validate() {
+        for name in PRESET_NAMES {
+            let config = DirectorConfig::preset(name)
+                .unwrap_or_else(|| panic!("preset {} should exist", name));
+            assert!(config.validate().is_ok(), "preset {} should be valid", name);
+        }
+        assert!(DirectorConfig::preset("hollywood").is_none());
+    }
+
+    #[test]
+    fn test_presets_differ_in_hold_and_notability() {
+        let presets: Vec<DirectorConfig> = PRESET_NAMES
+            .iter()
+            .map(|name| DirectorConfig::preset(name).unwrap())
+            .collect();
+
+        for (i, a) in presets.iter().enumerate() {
+            for b in &presets[i + 1..] {
+                assert_ne!(a.focus.thread_fatigue_threshold_ticks, b.focus.thread_fatigue_threshold_ticks);
+                assert_ne!(a.focus.min_event_score, b.focus.min_event_score);
+            }
+        }
+
+        let documentary = DirectorConfig::preset("documentary").unwrap();
+        let fast_cut = DirectorConfig::preset("fast_cut").unwrap();
+        assert!(documentary.focus.thread_fatigue_threshold_ticks > fast_cut.focus.thread_fatigue_threshold_ticks);
+        assert!(fast_cut.focus.min_event_score > documentary.focus.min_event_score);
+    }
+
     #[test]
     fn test_default_config_toml_parses() {
         let toml = default_config_toml();
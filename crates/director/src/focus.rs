@@ -3,7 +3,10 @@
 //! Decides which narrative thread deserves camera attention based on
 //! tension severity, thread fatigue, and dramatic value.
 
-use sim_events::Tension;
+use std::collections::BTreeSet;
+
+use serde::{Deserialize, Serialize};
+use sim_events::{Event, EventType, Season, Tension, WorldSnapshot};
 
 use crate::config::FocusConfig;
 use crate::output::{
@@ -11,6 +14,15 @@ use crate::output::{
 };
 use crate::threads::{NarrativeThread, ScoredEvent};
 
+/// Establishing-shot state of a `FocusSelector`, for save/restore.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct FocusSelectorState {
+    /// Threads that have already had their establishing shot
+    pub established_threads: BTreeSet<String>,
+    /// Tick until which the current establishing shot holds
+    pub establishing_until: Option<u64>,
+}
+
 /// Selects camera focus based on tensions and narrative threads.
 #[derive(Debug, Clone)]
 pub struct FocusSelector {
@@ -20,6 +32,14 @@ pub struct FocusSelector {
     current_tick: u64,
     /// Sequence number for instruction IDs
     instruction_sequence: u32,
+    /// Threads that have already had their establishing shot
+    established_threads: BTreeSet<String>,
+    /// Tick until which the current establishing shot holds
+    establishing_until: Option<u64>,
+    /// Camera mode of the last instruction that actually cut
+    last_cut: Option<CameraMode>,
+    /// Top event score on the tick of the last cut
+    last_cut_score: f32,
 }
 
 impl FocusSelector {
@@ -29,6 +49,10 @@ impl FocusSelector {
             config,
             current_tick: 0,
             instruction_sequence: 0,
+            established_threads: BTreeSet::new(),
+            establishing_until: None,
+            last_cut: None,
+            last_cut_score: 0.0,
         }
     }
 
@@ -37,6 +61,25 @@ impl FocusSelector {
         Self::new(FocusConfig::default())
     }
 
+    /// Replaces the configuration, keeping the instruction ID sequence.
+    pub fn set_config(&mut self, config: FocusConfig) {
+        self.config = config;
+    }
+
+    /// Exports the establishing-shot state for save/restore.
+    pub fn export_state(&self) -> FocusSelectorState {
+        FocusSelectorState {
+            established_threads: self.established_threads.clone(),
+            establishing_until: self.establishing_until,
+        }
+    }
+
+    /// Replaces the establishing-shot state with a previously exported one.
+    pub fn restore_state(&mut self, state: FocusSelectorState) {
+        self.established_threads = state.established_threads;
+        self.establishing_until = state.establishing_until;
+    }
+
     /// Sets the current tick for instruction ID generation.
     pub fn set_current_tick(&mut self, tick: u64) {
         if tick != self.current_tick {
@@ -54,15 +97,74 @@ impl FocusSelector {
     ///    - If yes, continue with that focus
     /// 4. Otherwise, select highest severity tension that isn't fatigued
     /// 5. Generate appropriate CameraInstruction based on tension type
+    /// 6. If no event reaches `dominant_event_score`, follow the protagonist
+    ///    of the chosen tension's thread instead
+    /// 7. If that repeats the last cut and nothing outscores the event behind
+    ///    it, mark the instruction as a hold
+    /// 8. Size the shot's duration from its pacing, mode, and top event score
     pub fn select_focus(
         &mut self,
         tensions: &[Tension],
         threads: &[NarrativeThread],
         current_focus: Option<&CameraFocus>,
-        _scored_events: &[ScoredEvent],
+        scored_events: &[ScoredEvent],
+        timestamp: sim_events::SimTimestamp,
+    ) -> CameraInstruction {
+        let mut instruction =
+            self.choose_focus(tensions, threads, current_focus, scored_events, timestamp);
+
+        let top_score = scored_events.iter().map(|se| se.score).fold(0.0, f32::max);
+        let same_shot = current_focus == Some(&instruction.focus)
+            && self.last_cut.as_ref() == Some(&instruction.camera_mode);
+        if same_shot && top_score <= self.last_cut_score {
+            instruction.hold = true;
+            instruction.reason = format!("Holding: {}", instruction.reason);
+        } else {
+            self.last_cut = Some(instruction.camera_mode.clone());
+            self.last_cut_score = top_score;
+        }
+        instruction.duration_ticks =
+            self.shot_duration(instruction.pacing, &instruction.camera_mode, top_score);
+        instruction
+    }
+
+    /// How long a shot should hold, in ticks.
+    ///
+    /// Starts from the pacing's suggested hold, stretched for wide establishing
+    /// and overview shots and by the score of the event behind the shot, so
+    /// bigger moments linger. Cinematic paths keep their own length. The result
+    /// is clamped to `[min_shot_ticks, max_shot_ticks]`.
+    pub fn shot_duration(&self, pacing: PacingHint, camera_mode: &CameraMode, score: f32) -> u32 {
+        let hold = pacing.suggested_hold_ticks() as f32 * (1.0 + score.clamp(0.0, 1.0));
+        let ticks = match camera_mode {
+            CameraMode::Cinematic { duration_ticks, .. } => *duration_ticks as f32,
+            CameraMode::Establishing { .. } => hold * 1.5,
+            CameraMode::Overview { .. } => hold * 1.25,
+            _ => hold,
+        };
+        (ticks.round() as u32)
+            .max(self.config.min_shot_ticks)
+            .min(self.config.max_shot_ticks)
+    }
+
+    /// Picks the focus for this tick, without regard to what was shown before.
+    fn choose_focus(
+        &mut self,
+        tensions: &[Tension],
+        threads: &[NarrativeThread],
+        current_focus: Option<&CameraFocus>,
+        scored_events: &[ScoredEvent],
         timestamp: sim_events::SimTimestamp,
     ) -> CameraInstruction {
         self.set_current_tick(timestamp.tick);
+        let quiet = !scored_events
+            .iter()
+            .any(|se| se.score >= self.config.dominant_event_score);
+
+        // A dominant event spread across a crowd is about the place, not one person
+        if let Some(location_id) = self.dominant_event_location(scored_events) {
+            return self.focus_on_location(location_id, timestamp);
+        }
 
         // Filter to viable tensions (above severity threshold and active)
         let viable_tensions: Vec<_> = tensions
@@ -81,7 +183,8 @@ impl FocusSelector {
                 self.find_continuing_tension(&viable_tensions, focus, threads)
             {
                 if !self.is_fatigued(continuing_tension, threads) {
-                    return self.continue_focus(continuing_tension, timestamp);
+                    let instruction = self.continue_focus(continuing_tension, timestamp);
+                    return self.follow_protagonist(instruction, continuing_tension, threads, quiet);
                 }
             }
         }
@@ -93,7 +196,10 @@ impl FocusSelector {
             .max_by(|a, b| a.severity.partial_cmp(&b.severity).unwrap());
 
         match selected {
-            Some(tension) => self.focus_on_tension(tension, timestamp),
+            Some(tension) => {
+                let instruction = self.focus_on_tension(tension, timestamp);
+                self.follow_protagonist(instruction, tension, threads, quiet)
+            }
             None => {
                 // All tensions fatigued - fall back to highest severity anyway
                 // but mark it as a fatigue-induced choice
@@ -109,6 +215,62 @@ impl FocusSelector {
         }
     }
 
+    /// Emits an establishing shot on the first tick a new thread becomes active.
+    ///
+    /// Frames the thread's key locations at a wide zoom, valid for
+    /// `establishing_hold_ticks`. Each thread gets at most one establishing
+    /// shot; threads without known locations are skipped. When several threads
+    /// appear at once, the earliest created is framed.
+    pub fn establishing_shot(
+        &mut self,
+        threads: &[NarrativeThread],
+        timestamp: sim_events::SimTimestamp,
+    ) -> Option<CameraInstruction> {
+        self.set_current_tick(timestamp.tick);
+        let hold_ticks = self.config.establishing_hold_ticks;
+
+        let mut new_threads: Vec<&NarrativeThread> = threads
+            .iter()
+            .filter(|t| t.is_active() && !self.established_threads.contains(&t.thread_id))
+            .collect();
+        new_threads.sort_by(|a, b| a.thread_id.cmp(&b.thread_id));
+        for thread in &new_threads {
+            self.established_threads.insert(thread.thread_id.clone());
+        }
+
+        if hold_ticks == 0 {
+            return None;
+        }
+        let thread = new_threads.into_iter().find(|t| !t.key_locations.is_empty())?;
+        let zoom = if thread.key_locations.len() > 1 {
+            ZoomLevel::Regional
+        } else {
+            ZoomLevel::Wide
+        };
+
+        self.establishing_until = Some(timestamp.tick + hold_ticks);
+        let instruction_id = self.next_instruction_id();
+        let mut instruction = CameraInstruction::new(
+            instruction_id,
+            timestamp.clone(),
+            CameraMode::establishing(thread.key_locations.clone(), zoom),
+            CameraFocus::location(&thread.key_locations[0]),
+            format!("Establishing shot: {}", thread.summary),
+        )
+        .with_pacing(PacingHint::Slow)
+        .with_duration(u32::try_from(hold_ticks).unwrap_or(u32::MAX))
+        .with_valid_until(timestamp.advance(hold_ticks));
+        if let Some(tension_id) = thread.tension_ids.first() {
+            instruction = instruction.with_tension(tension_id);
+        }
+        Some(instruction)
+    }
+
+    /// Returns true while an establishing shot is still holding at `tick`.
+    pub fn is_establishing(&self, tick: u64) -> bool {
+        self.establishing_until.is_some_and(|until| tick < until)
+    }
+
     /// Finds a tension that matches the current focus and is still viable.
     fn find_continuing_tension<'a>(
         &self,
@@ -187,6 +349,72 @@ impl FocusSelector {
         .with_tension(&tension.tension_id)
     }
 
+    /// Location of the top dominant event, if its participants crowd one place.
+    ///
+    /// Affected agents carry no location of their own, so they count as being
+    /// where the event happened; a secondary actor elsewhere rules it out.
+    fn dominant_event_location<'a>(&self, scored_events: &[ScoredEvent<'a>]) -> Option<&'a str> {
+        if self.config.location_focus_min_agents == 0 {
+            return None;
+        }
+        let top = scored_events
+            .iter()
+            .filter(|se| se.score >= self.config.dominant_event_score)
+            .max_by(|a, b| a.score.partial_cmp(&b.score).unwrap_or(std::cmp::Ordering::Equal))?;
+
+        let actors = &top.event.actors;
+        let location = actors.primary.location.as_str();
+        if actors.secondary.as_ref().is_some_and(|s| s.location != location) {
+            return None;
+        }
+        let participants: BTreeSet<&str> = actors.all_agent_ids().into_iter().collect();
+        (participants.len() >= self.config.location_focus_min_agents).then_some(location)
+    }
+
+    /// Creates a camera instruction framing a location where a crowd event is unfolding.
+    fn focus_on_location(
+        &mut self,
+        location_id: &str,
+        timestamp: sim_events::SimTimestamp,
+    ) -> CameraInstruction {
+        let instruction_id = self.next_instruction_id();
+        CameraInstruction::new(
+            instruction_id,
+            timestamp,
+            CameraMode::frame_location(location_id, ZoomLevel::Wide),
+            CameraFocus::location(location_id),
+            format!("Crowd event at {}", location_id),
+        )
+        .with_pacing(PacingHint::Urgent)
+    }
+
+    /// On a quiet tick, points the camera at the protagonist of the tension's thread.
+    ///
+    /// With no single event demanding a cut, staying with one character gives
+    /// the thread a steady point of view instead of a rotating group shot.
+    fn follow_protagonist(
+        &self,
+        mut instruction: CameraInstruction,
+        tension: &Tension,
+        threads: &[NarrativeThread],
+        quiet: bool,
+    ) -> CameraInstruction {
+        if !quiet {
+            return instruction;
+        }
+        let protagonist = threads
+            .iter()
+            .find(|t| t.involves_tension(&tension.tension_id) && t.is_active())
+            .and_then(|t| t.protagonist());
+        if let Some(agent_id) = protagonist {
+            instruction.camera_mode =
+                CameraMode::follow_agent(agent_id, self.severity_to_zoom(tension.severity));
+            instruction.focus = CameraFocus::primary(agent_id);
+            instruction.reason = format!("{} (following {})", instruction.reason, agent_id);
+        }
+        instruction
+    }
+
     /// Creates a camera instruction to focus on a fatigued tension (fallback).
     fn focus_on_tension_with_fatigue(
         &mut self,
@@ -328,6 +556,38 @@ impl FocusSelector {
         }
     }
 
+    /// Picks the overall pacing for a tick from its event density and world state.
+    ///
+    /// A burst of conflict quickens the cuts. A quiet tick in winter, or while
+    /// grain is running short, slows the camera down for survival arcs.
+    pub fn tick_pacing(&self, events: &[Event], state: &WorldSnapshot) -> PacingHint {
+        let violent = events
+            .iter()
+            .filter(|e| matches!(e.event_type, EventType::Conflict | EventType::Death))
+            .count();
+        if self.config.conflict_burst_events > 0 && violent >= self.config.conflict_burst_events {
+            return PacingHint::Urgent;
+        }
+
+        let quiet = events.len() <= self.config.quiet_tick_max_events;
+        let winter = state.timestamp.date.season == Season::Winter;
+        if quiet && (winter || self.is_scarce(state)) {
+            PacingHint::Slow
+        } else {
+            PacingHint::Normal
+        }
+    }
+
+    /// Returns true if the world's grain would feed its agents for fewer than
+    /// `scarcity_grain_days` days.
+    fn is_scarce(&self, state: &WorldSnapshot) -> bool {
+        if state.agents.is_empty() {
+            return false;
+        }
+        let grain_days = state.world.global_resources.total_grain as f32 / state.agents.len() as f32;
+        grain_days < self.config.scarcity_grain_days
+    }
+
     /// Converts tension severity to a pacing hint.
     fn severity_to_pacing(&self, severity: f32) -> PacingHint {
         if severity >= 0.9 {
@@ -483,6 +743,82 @@ mod tests {
         assert_eq!(instruction.tension_id, Some("tens_low".to_string()));
     }
 
+    #[test]
+    fn test_quiet_tick_follows_top_thread_protagonist() {
+        let mut selector = FocusSelector::with_defaults();
+
+        let top = make_tension_with_agents("tens_top", 0.8, vec!["agent_a", "agent_b", "agent_c"]);
+        let minor = make_tension_with_agents("tens_minor", 0.4, vec!["agent_d"]);
+        let mut top_thread = make_thread_for_tension(&top, 0);
+        top_thread.record_involvement(["agent_a", "agent_b"]);
+        top_thread.record_involvement(["agent_b"]);
+        let mut minor_thread = NarrativeThread::from_tension(&minor, "thread_00002");
+        minor_thread.record_involvement(["agent_d", "agent_d"]);
+
+        let tensions = vec![top, minor];
+        let threads = vec![top_thread, minor_thread];
+
+        let instruction = selector.select_focus(&tensions, &threads, None, &[], test_timestamp(1000));
+
+        assert_eq!(instruction.tension_id, Some("tens_top".to_string()));
+        assert!(matches!(
+            instruction.camera_mode,
+            CameraMode::FollowAgent { ref agent_id, .. } if agent_id == "agent_b"
+        ));
+        assert_eq!(instruction.focus.agent_ids(), vec!["agent_b"]);
+    }
+
+    #[test]
+    fn test_crowd_event_at_one_location_focuses_location() {
+        use sim_events::{
+            ActorSet, ActorSnapshot, AffectedActor, EventContext, EventOutcome, EventSubtype,
+            GeneralOutcome, RitualSubtype,
+        };
+
+        let mut selector = FocusSelector::with_defaults();
+        let tensions = vec![make_tension("tens_high", 0.9, TensionStatus::Escalating)];
+
+        let disrupter = ActorSnapshot::new("agent_mira", "Mira", "thornwood", "laborer", "thornwood_hall");
+        let reader = ActorSnapshot::new("agent_corin", "Corin", "thornwood", "reader", "thornwood_hall");
+        let disrupted = Event::new(
+            "evt_ritual",
+            test_timestamp(1000),
+            EventType::Ritual,
+            EventSubtype::Ritual(RitualSubtype::ReadingDisrupted),
+            ActorSet::with_secondary(disrupter.clone(), reader).with_affected(vec![
+                AffectedActor::new("agent_bran", "Bran", "thornwood", "laborer"),
+                AffectedActor::new("agent_edda", "Edda", "thornwood", "laborer"),
+            ]),
+            EventContext::new("ritual_disrupted"),
+            EventOutcome::General(GeneralOutcome::default()),
+        );
+        let instruction = selector.select_focus(
+            &tensions,
+            &[],
+            None,
+            &[ScoredEvent::new(&disrupted, 0.9)],
+            test_timestamp(1000),
+        );
+        assert!(matches!(
+            instruction.focus,
+            CameraFocus::Location { ref location_id } if location_id == "thornwood_hall"
+        ));
+        assert!(instruction.focus.agent_ids().is_empty());
+
+        // Participants in different places keep the usual tension focus
+        let elsewhere = ActorSnapshot::new("agent_corin", "Corin", "thornwood", "reader", "thornwood_fields");
+        let mut scattered = disrupted.clone();
+        scattered.actors.secondary = Some(elsewhere);
+        let instruction = selector.select_focus(
+            &tensions,
+            &[],
+            None,
+            &[ScoredEvent::new(&scattered, 0.9)],
+            test_timestamp(1000),
+        );
+        assert_eq!(instruction.tension_id, Some("tens_high".to_string()));
+    }
+
     #[test]
     fn test_all_fatigued_falls_back_to_highest() {
         let mut selector = FocusSelector::new(FocusConfig {
@@ -620,6 +956,21 @@ mod tests {
         matches!(instruction.focus, CameraFocus::Conversation { .. });
     }
 
+    #[test]
+    fn test_grain_shortage_slows_quiet_tick() {
+        let selector = FocusSelector::with_defaults();
+        let mut state = WorldSnapshot::new("snap", test_timestamp(1000), "scheduled");
+        state.agents.push(sim_events::AgentSnapshot::new(
+            "agent_mira", "Mira", "thornwood", "scout", "eastern_bridge",
+        ));
+
+        state.world.global_resources.total_grain = 5;
+        assert_eq!(selector.tick_pacing(&[], &state), PacingHint::Slow);
+
+        state.world.global_resources.total_grain = 500;
+        assert_eq!(selector.tick_pacing(&[], &state), PacingHint::Normal);
+    }
+
     #[test]
     fn test_severity_to_pacing() {
         let selector = FocusSelector::with_defaults();
@@ -748,4 +1099,66 @@ mod tests {
         assert!(instruction.reason.contains("Continuing focus"));
         assert_eq!(instruction.tension_id, Some("tens_001".to_string()));
     }
+
+    #[test]
+    fn test_big_moment_holds_longer_than_quick_cut() {
+        let selector = FocusSelector::with_defaults();
+        let mode = CameraMode::follow_agent("agent_mira", ZoomLevel::Close);
+
+        let climax = selector.shot_duration(PacingHint::Climactic, &mode, 0.95);
+        let cut = selector.shot_duration(PacingHint::Climactic, &mode, 0.1);
+        assert!(climax > cut);
+        assert!(selector.shot_duration(PacingHint::Urgent, &mode, 0.1) < cut);
+
+        // Wide establishing shots linger longer than a cut to one agent
+        let establishing = CameraMode::establishing(vec!["eastern_bridge".to_string()], ZoomLevel::Wide);
+        assert!(selector.shot_duration(PacingHint::Urgent, &establishing, 0.1)
+            > selector.shot_duration(PacingHint::Urgent, &mode, 0.1));
+
+        // Never past the configured maximum
+        let longest = selector.shot_duration(PacingHint::Slow, &establishing, 1.0);
+        assert_eq!(longest, selector.config().max_shot_ticks);
+    }
+
+    #[test]
+    fn test_new_thread_triggers_single_establishing_shot() {
+        let mut selector = FocusSelector::with_defaults();
+        let mut tension = make_tension("tens_border", 0.6, TensionStatus::Escalating);
+        tension.add_location("thornwood_hall");
+        tension.add_location("eastern_bridge");
+        let threads = vec![make_thread_for_tension(&tension, 0)];
+
+        let shots: Vec<_> = (1000..1010)
+            .filter_map(|tick| selector.establishing_shot(&threads, test_timestamp(tick)))
+            .collect();
+
+        assert_eq!(shots.len(), 1);
+        let shot = &shots[0];
+        assert_eq!(shot.timestamp.tick, 1000);
+        assert_eq!(shot.valid_until.as_ref().map(|v| v.tick), Some(1100));
+        assert_eq!(shot.tension_id.as_deref(), Some("tens_border"));
+        match &shot.camera_mode {
+            CameraMode::Establishing { location_ids, zoom } => {
+                assert_eq!(location_ids, &["thornwood_hall", "eastern_bridge"]);
+                assert_eq!(*zoom, ZoomLevel::Regional);
+            }
+            other => panic!("expected establishing shot, got {:?}", other),
+        }
+        assert!(selector.is_establishing(1099));
+        assert!(!selector.is_establishing(1100));
+    }
+
+    #[test]
+    fn test_establishing_shot_disabled_with_zero_hold() {
+        let mut selector = FocusSelector::new(FocusConfig {
+            establishing_hold_ticks: 0,
+            ..FocusConfig::default()
+        });
+        let mut tension = make_tension("tens_border", 0.6, TensionStatus::Escalating);
+        tension.add_location("thornwood_hall");
+        let threads = vec![make_thread_for_tension(&tension, 0)];
+
+        assert!(selector.establishing_shot(&threads, test_timestamp(1000)).is_none());
+        assert!(!selector.is_establishing(1000));
+    }
 }
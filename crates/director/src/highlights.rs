@@ -0,0 +1,266 @@
+//! Highlight clip windows and reel compilation.
+//!
+//! Sizes clip windows around notable events and aggregates the per-tick
+//! highlight markers of a run into a "best of" reel.
+
+use serde::{Deserialize, Serialize};
+use sim_events::{CooperationSubtype, Event, EventSubtype, RitualSubtype};
+
+use crate::config::HighlightConfig;
+use crate::output::{HighlightMarker, HighlightType};
+
+/// Drama tags that bump a highlight one step up in importance.
+const UPGRADE_TAGS: &[&str] = &["succession_crisis", "winter_crisis"];
+
+/// Picks the highlight type for an event from its subtype and drama tags.
+pub fn highlight_type_for(event: &Event) -> HighlightType {
+    let base = match &event.subtype {
+        EventSubtype::Death(_) => HighlightType::Climax,
+        EventSubtype::Ritual(RitualSubtype::ReadingDisrupted) => HighlightType::Climax,
+        EventSubtype::Betrayal(_)
+        | EventSubtype::Faction(_)
+        | EventSubtype::Cooperation(CooperationSubtype::AllianceFormed) => HighlightType::TurningPoint,
+        _ => HighlightType::KeyMoment,
+    };
+
+    let upgrade = event
+        .drama_tags
+        .iter()
+        .any(|tag| UPGRADE_TAGS.contains(&tag.as_str()));
+    match (base, upgrade) {
+        (HighlightType::KeyMoment, true) => HighlightType::TurningPoint,
+        (HighlightType::TurningPoint, true) => HighlightType::Climax,
+        (highlight_type, _) => highlight_type,
+    }
+}
+
+/// Computes the `(clip_start, clip_end)` window around an event at `tick`.
+///
+/// Higher scores get longer pre- and post-roll; Climax highlights get extra
+/// post-roll. The start never underflows and never exceeds the end.
+pub fn clip_window(
+    config: &HighlightConfig,
+    tick: u64,
+    score: f32,
+    highlight_type: HighlightType,
+) -> (u64, u64) {
+    let scaled = (score.max(0.0) * config.ticks_per_score) as u64;
+    let pre_roll = config.base_pre_roll_ticks + scaled;
+    let mut post_roll = config.base_post_roll_ticks + scaled;
+    if highlight_type == HighlightType::Climax {
+        post_roll = (post_roll as f32 * config.climax_post_roll_multiplier.max(0.0)) as u64;
+    }
+
+    let clip_end = tick.saturating_add(post_roll);
+    let clip_start = tick.saturating_sub(pre_roll).min(clip_end);
+    (clip_start, clip_end)
+}
+
+/// A single clip in a highlight reel, possibly covering several markers.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ReelShot {
+    /// First tick of the clip
+    pub start_tick: u64,
+    /// Last tick of the clip
+    pub end_tick: u64,
+    /// Most important highlight type among the merged markers
+    pub highlight_type: HighlightType,
+    /// Events covered by this clip, in marker order
+    pub event_ids: Vec<String>,
+}
+
+impl ReelShot {
+    fn from_marker(marker: &HighlightMarker) -> Self {
+        let start = marker.suggested_clip_start.min(marker.suggested_clip_end);
+        let end = marker.suggested_clip_start.max(marker.suggested_clip_end);
+        Self {
+            start_tick: start,
+            end_tick: end,
+            highlight_type: marker.highlight_type,
+            event_ids: vec![marker.event_id.clone()],
+        }
+    }
+
+    /// Returns the clip length in ticks.
+    pub fn duration(&self) -> u64 {
+        self.end_tick - self.start_tick
+    }
+
+    /// Extends this clip to also cover `other`.
+    fn absorb(&mut self, other: ReelShot) {
+        self.end_tick = self.end_tick.max(other.end_tick);
+        if other.highlight_type.importance() > self.highlight_type.importance() {
+            self.highlight_type = other.highlight_type;
+        }
+        self.event_ids.extend(other.event_ids);
+    }
+}
+
+/// A non-overlapping, time-ordered shot list of a run's best moments.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct HighlightReel {
+    /// Shots in chronological order
+    pub shots: Vec<ReelShot>,
+}
+
+impl HighlightReel {
+    /// Compiles markers into a reel no longer than `max_duration_ticks`.
+    ///
+    /// Overlapping, abutting, and nested clip windows are merged first. The
+    /// merged shots are then taken in order of importance (earlier shots win
+    /// ties) while they fit the remaining budget, and returned in time order.
+    pub fn compile(markers: &[HighlightMarker], max_duration_ticks: u64) -> Self {
+        let mut candidates: Vec<ReelShot> = markers.iter().map(ReelShot::from_marker).collect();
+        candidates.sort_by_key(|shot| (shot.start_tick, shot.end_tick));
+
+        let mut merged: Vec<ReelShot> = Vec::new();
+        for shot in candidates {
+            match merged.last_mut() {
+                Some(last) if shot.start_tick <= last.end_tick => last.absorb(shot),
+                _ => merged.push(shot),
+            }
+        }
+
+        merged.sort_by(|a, b| {
+            b.highlight_type
+                .importance()
+                .cmp(&a.highlight_type.importance())
+                .then(a.start_tick.cmp(&b.start_tick))
+        });
+
+        let mut remaining = max_duration_ticks;
+        let mut shots: Vec<ReelShot> = Vec::new();
+        for shot in merged {
+            if shot.duration() <= remaining {
+                remaining -= shot.duration();
+                shots.push(shot);
+            }
+        }
+        shots.sort_by_key(|shot| shot.start_tick);
+
+        Self { shots }
+    }
+
+    /// Returns the combined length of all shots in ticks.
+    pub fn total_duration(&self) -> u64 {
+        self.shots.iter().map(ReelShot::duration).sum()
+    }
+
+    /// Returns true if the reel has no shots.
+    pub fn is_empty(&self) -> bool {
+        self.shots.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use sim_events::{
+        ActorSet, ActorSnapshot, ConflictSubtype, EventContext, EventOutcome, EventType,
+        FactionSubtype, GeneralOutcome, Season, SimTimestamp,
+    };
+
+    fn make_event(event_type: EventType, subtype: EventSubtype, tags: &[&str]) -> Event {
+        Event {
+            event_id: "evt_00001".to_string(),
+            timestamp: SimTimestamp::new(1000, 1, Season::Spring, 10),
+            event_type,
+            subtype,
+            actors: ActorSet::primary_only(ActorSnapshot::new(
+                "agent_mira", "Mira", "thornwood", "scout", "village_center",
+            )),
+            context: EventContext::new("test"),
+            outcome: EventOutcome::General(GeneralOutcome::default()),
+            drama_tags: tags.iter().map(|t| t.to_string()).collect(),
+            drama_score: 0.8,
+            connected_events: vec![],
+        }
+    }
+
+    #[test]
+    fn test_leadership_challenge_is_turning_point() {
+        let event = make_event(EventType::Faction, EventSubtype::Faction(FactionSubtype::ChallengeLeader), &[]);
+        assert_eq!(highlight_type_for(&event), HighlightType::TurningPoint);
+    }
+
+    #[test]
+    fn test_disrupted_reading_is_climax() {
+        let event = make_event(EventType::Ritual, EventSubtype::Ritual(RitualSubtype::ReadingDisrupted), &[]);
+        assert_eq!(highlight_type_for(&event), HighlightType::Climax);
+
+        let held = make_event(EventType::Ritual, EventSubtype::Ritual(RitualSubtype::ReadingHeld), &[]);
+        assert_eq!(highlight_type_for(&held), HighlightType::KeyMoment);
+    }
+
+    #[test]
+    fn test_alliance_formed_is_turning_point() {
+        let event = make_event(
+            EventType::Cooperation,
+            EventSubtype::Cooperation(CooperationSubtype::AllianceFormed),
+            &[],
+        );
+        assert_eq!(highlight_type_for(&event), HighlightType::TurningPoint);
+
+        let gift = make_event(EventType::Cooperation, EventSubtype::Cooperation(CooperationSubtype::Gift), &[]);
+        assert_eq!(highlight_type_for(&gift), HighlightType::KeyMoment);
+    }
+
+    #[test]
+    fn test_drama_tags_upgrade_highlight_type() {
+        let challenge = make_event(
+            EventType::Faction,
+            EventSubtype::Faction(FactionSubtype::ChallengeLeader),
+            &["succession_crisis"],
+        );
+        assert_eq!(highlight_type_for(&challenge), HighlightType::Climax);
+
+        let fight = make_event(
+            EventType::Conflict,
+            EventSubtype::Conflict(ConflictSubtype::Fight),
+            &["winter_crisis"],
+        );
+        assert_eq!(highlight_type_for(&fight), HighlightType::TurningPoint);
+    }
+
+    #[test]
+    fn test_overlapping_abutting_and_nested_windows_merge() {
+        let markers = vec![
+            HighlightMarker::new("evt_a", HighlightType::KeyMoment, 100, 200),
+            // Overlaps evt_a
+            HighlightMarker::new("evt_b", HighlightType::Climax, 150, 250),
+            // Abuts the merged window
+            HighlightMarker::new("evt_c", HighlightType::KeyMoment, 250, 300),
+            // Nested inside it
+            HighlightMarker::new("evt_d", HighlightType::TurningPoint, 160, 180),
+            // Separate clip
+            HighlightMarker::new("evt_e", HighlightType::TurningPoint, 500, 550),
+        ];
+
+        let reel = HighlightReel::compile(&markers, 10_000);
+
+        assert_eq!(reel.shots.len(), 2);
+        let first = &reel.shots[0];
+        assert_eq!((first.start_tick, first.end_tick), (100, 300));
+        assert_eq!(first.highlight_type, HighlightType::Climax);
+        assert_eq!(first.event_ids, vec!["evt_a", "evt_b", "evt_d", "evt_c"]);
+        assert_eq!((reel.shots[1].start_tick, reel.shots[1].end_tick), (500, 550));
+        assert_eq!(reel.total_duration(), 250);
+    }
+
+    #[test]
+    fn test_budget_keeps_most_important_shots_in_time_order() {
+        let markers = vec![
+            HighlightMarker::new("evt_key", HighlightType::KeyMoment, 100, 200),
+            HighlightMarker::new("evt_turn", HighlightType::TurningPoint, 400, 500),
+            HighlightMarker::new("evt_climax", HighlightType::Climax, 800, 900),
+        ];
+
+        let reel = HighlightReel::compile(&markers, 250);
+
+        let ids: Vec<&str> = reel.shots.iter().map(|s| s.event_ids[0].as_str()).collect();
+        assert_eq!(ids, vec!["evt_turn", "evt_climax"]);
+        assert!(reel.total_duration() <= 250);
+
+        assert!(HighlightReel::compile(&markers, 50).is_empty());
+    }
+}
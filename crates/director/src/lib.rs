@@ -20,10 +20,12 @@
 //! - [`scorer`]: Event prioritization with configurable weights
 //! - [`focus`]: Tension-based camera focus selection
 //! - [`commentary`]: Template-based text generation
+//! - [`highlights`]: Highlight clip windows and reel compilation
 
 pub mod commentary;
 pub mod config;
 pub mod focus;
+pub mod highlights;
 pub mod output;
 pub mod scorer;
 pub mod threads;
@@ -31,39 +33,55 @@ pub mod threads;
 // Re-export output types
 pub use output::{
     CameraEasing, CameraFocus, CameraInstruction, CameraMode, CameraWaypoint, CommentaryItem,
-    CommentaryType, DirectorOutput, HighlightMarker, HighlightType, OutputError, OutputReader,
-    OutputWriter, PacingHint, ZoomLevel, generate_commentary_id, generate_instruction_id,
+    CommentaryType, DirectorOutput, HighlightMarker, HighlightType, MemorySink, MultiWriter, OutputError,
+    OutputReader, OutputSink, OutputWriter, PacingHint, StreamSink, ZoomLevel, generate_commentary_id,
+    generate_instruction_id,
+    OUTPUT_SCHEMA_VERSION,
 };
 
+// Re-export highlight reel types
+pub use highlights::{clip_window, highlight_type_for, HighlightReel, ReelShot};
+
 // Re-export thread types
 pub use threads::{
-    generate_thread_id, NarrativeThread, ScoredEvent, ThreadStatus, ThreadTracker,
-    ThreadTrackerConfig,
+    generate_thread_id, NarrativeThread, OutlineEvent, ScoredEvent, ThreadOutline, ThreadStatus,
+    ThreadTracker, ThreadTrackerConfig,
 };
 
 // Re-export scorer types
-pub use scorer::{DirectorContext, EventScorer, EventWeights, ScorerError};
+pub use scorer::{novelty_key, DirectorContext, EventScorer, EventWeights, ScoreBreakdown, ScorerError};
 
 // Re-export config types
 pub use config::{
     default_config_toml, CommentaryConfig, ConfigError, DefaultCameraMode, DirectorConfig,
-    FocusConfig, GeneralConfig, TomlSerializeError,
+    FocusConfig, GeneralConfig, HighlightConfig, TomlSerializeError, Verbosity, PRESET_NAMES,
 };
 
 // Re-export focus types
-pub use focus::FocusSelector;
+pub use focus::{FocusSelector, FocusSelectorState};
 
 // Re-export commentary types
 pub use commentary::{
-    default_templates, default_templates_toml, BetrayalRecord, CommentaryGenerator,
-    CommentaryTemplates, IronyDetector, IronySituation, IronyTemplate, ReminderTemplate,
-    TeaserTemplate, TemplateError,
+    dedup_commentary, default_templates, default_templates_toml, remembered_events, role_display_name, role_with_article,
+    schedule_commentary, BetrayalRecord, CommentaryGenerator, CommentaryGeneratorState, CommentaryTemplates, GrudgeOrigin, IronyDetector,
+    IronyDetectorState, IronySituation, IronyTemplate, ReminderTemplate, TeaserTemplate, TemplateError, TemplateLine,
 };
 
-use std::collections::HashSet;
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeSet, VecDeque};
+use std::io::Read;
 use std::path::Path;
 
-use sim_events::{Event, EventType, Tension, WorldSnapshot};
+use sim_events::{
+    Event, EventReadError, EventReader, EventType, SimTimestamp, Tension, TensionType, WorldSnapshot, DAYS_PER_SEASON,
+    TICKS_PER_DAY,
+};
+
+/// How often (in simulation ticks) `run_from_files` prunes stale director state
+pub const CLEANUP_INTERVAL_TICKS: u64 = 1000;
+
+/// Age after which tracked betrayals are dropped during file-driven runs
+pub const MAX_BETRAYAL_AGE_TICKS: u64 = 50_000;
 
 /// Errors that can occur in Director operations.
 #[derive(Debug)]
@@ -74,6 +92,10 @@ pub enum DirectorError {
     Template(TemplateError),
     /// Error with scorer
     Scorer(ScorerError),
+    /// Error reading the event log
+    EventRead(EventReadError),
+    /// Error reading snapshots or writing output
+    Output(OutputError),
 }
 
 impl std::fmt::Display for DirectorError {
@@ -82,6 +104,8 @@ impl std::fmt::Display for DirectorError {
             DirectorError::Config(e) => write!(f, "Config error: {}", e),
             DirectorError::Template(e) => write!(f, "Template error: {}", e),
             DirectorError::Scorer(e) => write!(f, "Scorer error: {}", e),
+            DirectorError::EventRead(e) => write!(f, "Event log error: {}", e),
+            DirectorError::Output(e) => write!(f, "Output error: {}", e),
         }
     }
 }
@@ -92,6 +116,8 @@ impl std::error::Error for DirectorError {
             DirectorError::Config(e) => Some(e),
             DirectorError::Template(e) => Some(e),
             DirectorError::Scorer(e) => Some(e),
+            DirectorError::EventRead(e) => Some(e),
+            DirectorError::Output(e) => Some(e),
         }
     }
 }
@@ -114,6 +140,45 @@ impl From<ScorerError> for DirectorError {
     }
 }
 
+impl From<EventReadError> for DirectorError {
+    fn from(e: EventReadError) -> Self {
+        DirectorError::EventRead(e)
+    }
+}
+
+impl From<OutputError> for DirectorError {
+    fn from(e: OutputError) -> Self {
+        DirectorError::Output(e)
+    }
+}
+
+/// Serializable runtime state of a `Director`, for resuming a session.
+///
+/// Config and templates are not included; they are reloaded from their files
+/// and the state is restored on top with `Director::restore_state`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DirectorState {
+    /// Last processed simulation tick
+    pub current_tick: u64,
+    /// Narrative threads and their tension mapping
+    pub thread_tracker: ThreadTracker,
+    /// Tracked betrayals awaiting discovery
+    pub irony_detector: IronyDetector,
+    /// Agents the camera has recently featured
+    pub tracked_agents: BTreeSet<String>,
+    /// Current camera focus
+    pub current_focus: Option<CameraFocus>,
+    /// Recently scored events as (tick, novelty key), oldest first
+    #[serde(default)]
+    pub recent_events: VecDeque<(u64, String)>,
+    /// Teaser and caption cooldowns
+    #[serde(default)]
+    pub commentary: CommentaryGeneratorState,
+    /// Threads already introduced and any establishing shot still holding
+    #[serde(default)]
+    pub focus: FocusSelectorState,
+}
+
 /// The main Director AI that orchestrates drama detection and camera control.
 ///
 /// The Director watches raw events and active tensions, then decides what's worth
@@ -139,12 +204,20 @@ pub struct Director {
     irony_detector: IronyDetector,
     /// Current simulation tick
     current_tick: u64,
-    /// Threshold for event notability (events must score above this)
-    notability_threshold: f32,
     /// Currently tracked agents (for context)
-    tracked_agents: HashSet<String>,
+    tracked_agents: BTreeSet<String>,
     /// Current camera focus
     current_focus: Option<CameraFocus>,
+    /// Most recent snapshot passed to `process_tick`, carried forward between snapshots
+    last_snapshot: Option<WorldSnapshot>,
+    /// Tick of `last_snapshot`
+    snapshot_tick: Option<u64>,
+    /// Tick of the last automatic cleanup
+    last_auto_cleanup_tick: u64,
+    /// Tick at which each on-screen commentary slot comes free
+    commentary_slots: Vec<u64>,
+    /// Recently scored events as (tick, novelty key), oldest first
+    recent_events: VecDeque<(u64, String)>,
 }
 
 impl Director {
@@ -157,10 +230,10 @@ impl Director {
             default_templates(),
             config.commentary.clone(),
         );
-        let irony_detector = IronyDetector::new();
+        let irony_detector = IronyDetector::with_trust_threshold(config.commentary.irony_trust_threshold);
+        let commentary_slots = vec![0; config.commentary.max_simultaneous];
 
         Ok(Self {
-            notability_threshold: config.focus.min_event_score,
             config,
             scorer,
             focus_selector,
@@ -168,8 +241,13 @@ impl Director {
             commentary_generator,
             irony_detector,
             current_tick: 0,
-            tracked_agents: HashSet::new(),
+            tracked_agents: BTreeSet::new(),
             current_focus: None,
+            last_snapshot: None,
+            snapshot_tick: None,
+            last_auto_cleanup_tick: 0,
+            commentary_slots,
+            recent_events: VecDeque::new(),
         })
     }
 
@@ -184,6 +262,32 @@ impl Director {
         Self::new(DirectorConfig::default()).expect("Default config should always work")
     }
 
+    /// Re-reads the configuration file and applies it to a running director.
+    ///
+    /// Live state (narrative threads, tracked betrayals, current focus) is kept.
+    /// If the file can't be read or fails validation, the current config stays
+    /// in place and an error is returned.
+    pub fn reload_config(&mut self, path: &Path) -> Result<(), DirectorError> {
+        let config = DirectorConfig::from_file(path)?;
+        self.apply_config(config)
+    }
+
+    /// Validates and swaps in a new configuration, preserving live state.
+    pub fn apply_config(&mut self, config: DirectorConfig) -> Result<(), DirectorError> {
+        config.validate().map_err(ConfigError::Invalid)?;
+
+        self.scorer = EventScorer::new(config.event_weights.clone());
+        self.focus_selector.set_config(config.focus.clone());
+        self.thread_tracker.set_config(config.threads.clone());
+        self.commentary_generator.set_config(config.commentary.clone());
+        self.irony_detector.set_trust_threshold(config.commentary.irony_trust_threshold);
+        // Slots are interchangeable; when shrinking, keep the ones that free up first
+        self.commentary_slots.sort_unstable();
+        self.commentary_slots.resize(config.commentary.max_simultaneous, 0);
+        self.config = config;
+        Ok(())
+    }
+
     /// Processes a single tick of simulation data.
     ///
     /// This is the main entry point for the Director. It:
@@ -195,26 +299,68 @@ impl Director {
     /// 6. Selects camera focus
     /// 7. Generates commentary (captions + irony + teasers)
     /// 8. Marks highlights
-    /// 9. Updates current_tick
-    /// 10. Returns DirectorOutput
+    /// 9. Picks the tick's overall pacing
+    /// 10. Updates current_tick
+    /// 11. Cleans up old betrayals, if `auto_cleanup_interval_ticks` is set
+    /// 12. Returns DirectorOutput
+    ///
+    /// The snapshot is remembered so later snapshot-less ticks can be handled
+    /// by `process_tick_without_snapshot`.
     pub fn process_tick(
         &mut self,
         events: &[Event],
         tensions: &[Tension],
         state: &WorldSnapshot,
+    ) -> DirectorOutput {
+        self.last_snapshot = Some(state.clone());
+        self.snapshot_tick = Some(state.timestamp.tick);
+        self.process_with_state(events, tensions, state)
+    }
+
+    /// Processes a tick for which the simulation wrote no snapshot.
+    ///
+    /// The last known snapshot is carried forward (re-stamped with this tick's
+    /// timestamp). Its trust values may be outdated, so no irony is derived
+    /// from it and camera decisions are marked as made on stale state. Before
+    /// any snapshot has been seen an empty world is assumed.
+    pub fn process_tick_without_snapshot(
+        &mut self,
+        events: &[Event],
+        tensions: &[Tension],
+        timestamp: SimTimestamp,
+    ) -> DirectorOutput {
+        let mut state = self
+            .last_snapshot
+            .take()
+            .unwrap_or_else(|| WorldSnapshot::new("none", timestamp.clone(), "carried_forward"));
+        state.timestamp = timestamp;
+        let output = self.process_with_state(events, tensions, &state);
+        self.last_snapshot = Some(state);
+        output
+    }
+
+    /// Runs the director pipeline against a world state.
+    fn process_with_state(
+        &mut self,
+        events: &[Event],
+        tensions: &[Tension],
+        state: &WorldSnapshot,
     ) -> DirectorOutput {
         self.current_tick = state.timestamp.tick;
+        let stale = self.is_state_stale();
 
         // 1. Build context from current thread state
-        let context = self.build_context(tensions);
+        let context = self.build_context(tensions, state);
 
-        // 2. Score all events
+        // 2. Score all events, then remember them so repeats score lower
         let scored_events = self.scorer.score_batch(events, &context);
+        self.recent_events
+            .extend(events.iter().map(|e| (self.current_tick, novelty_key(e))));
 
         // 3. Filter to notable events
         let notable_events: Vec<ScoredEvent> = scored_events
             .into_iter()
-            .filter(|se| se.score >= self.notability_threshold)
+            .filter(|se| se.score >= self.config.focus.notability_threshold(se.event.event_type))
             .collect();
 
         // 4. Update thread tracker with notable events and tensions
@@ -235,17 +381,35 @@ impl Director {
             .into_iter()
             .cloned()
             .collect();
-        let camera_instruction = self.focus_selector.select_focus(
-            tensions,
-            &active_threads,
-            self.current_focus.as_ref(),
-            &notable_events,
-            state.timestamp.clone(),
-        );
+        // A new thread opens with an establishing shot; the cut to its subjects
+        // is scheduled for when the hold ends, and nothing else is cut in
+        // while the hold lasts.
+        let mut camera_script = Vec::new();
+        let establishing = self
+            .focus_selector
+            .establishing_shot(&active_threads, state.timestamp.clone());
+        let holding =
+            establishing.is_none() && self.focus_selector.is_establishing(self.current_tick);
+        if !holding {
+            let mut camera_instruction = self.focus_selector.select_focus(
+                tensions,
+                &active_threads,
+                self.current_focus.as_ref(),
+                &notable_events,
+                state.timestamp.clone(),
+            );
+            if let Some(shot) = establishing {
+                if let Some(until) = &shot.valid_until {
+                    camera_instruction.timestamp = until.clone();
+                }
+                camera_script.push(shot);
+            }
 
-        // Update tracked agents based on camera focus
-        self.update_tracked_agents(&camera_instruction);
-        self.current_focus = Some(camera_instruction.focus.clone());
+            // Update tracked agents based on camera focus
+            self.update_tracked_agents(&camera_instruction);
+            self.current_focus = Some(camera_instruction.focus.clone());
+            camera_script.push(camera_instruction);
+        }
 
         // 7. Generate commentary
         let mut commentary_queue = Vec::new();
@@ -253,51 +417,108 @@ impl Director {
         // Update generator tick
         self.commentary_generator.set_current_tick(self.current_tick);
 
-        // Generate captions for notable events
+        // Generate captions for notable events, weighing in who was involved.
+        // Silent verbosity keeps only the climaxes.
+        let verbosity = self.config.commentary.verbosity;
         for scored in &notable_events {
-            if let Some(caption) = self.commentary_generator.caption_event(&scored.event, state.timestamp.clone()) {
+            if verbosity.climax_only()
+                && highlights::highlight_type_for(scored.event) != HighlightType::Climax
+            {
+                continue;
+            }
+            let reputation = highest_reputation(scored.event, state);
+            if let Some(caption) = self.commentary_generator.caption_event_with_reputation(
+                scored.event,
+                state.timestamp.clone(),
+                reputation,
+            ) {
                 commentary_queue.push(caption);
             }
         }
 
-        // Detect and generate irony commentary
-        let irony_situations = self.irony_detector.detect_irony(state);
+        // Detect and generate irony commentary. Outdated trust could show irony
+        // that no longer holds (or miss that it ended), so wait for fresh state.
+        let irony_situations = if stale || !verbosity.allows_asides() {
+            Vec::new()
+        } else {
+            self.irony_detector
+                .detect_irony(state, events, &remembered_events(state, events))
+        };
         for situation in &irony_situations {
             if let Some(irony_commentary) = self.commentary_generator.generate_irony(situation, state.timestamp.clone()) {
                 commentary_queue.push(irony_commentary);
             }
         }
 
-        // Generate tension teasers
+        // Generate tension teasers, with the severity bar scaled by verbosity
+        let teaser_severity = self.config.focus.min_tension_severity * verbosity.threshold_multiplier();
         for tension in tensions {
-            if tension.is_active() && tension.severity >= self.config.focus.min_tension_severity {
-                if let Some(teaser) = self.commentary_generator.generate_teaser(tension, state.timestamp.clone()) {
+            if verbosity.allows_asides() && tension.is_active() && tension.severity >= teaser_severity {
+                let origin = grudge_origin(tension, state);
+                if let Some(teaser) = self.commentary_generator.generate_teaser_with_origin(
+                    tension,
+                    state.timestamp.clone(),
+                    origin.as_ref(),
+                ) {
                     commentary_queue.push(teaser);
                 }
             }
         }
 
-        // Sort commentary by priority and limit to max queue size
-        commentary_queue.sort_by(|a, b| b.priority.partial_cmp(&a.priority).unwrap());
+        // Drop duplicates (sorting by priority) before limiting to max queue size
+        dedup_commentary(&mut commentary_queue);
         commentary_queue.truncate(self.config.commentary.max_queue_size);
+        schedule_commentary(
+            &mut commentary_queue,
+            &mut self.commentary_slots,
+            self.current_tick,
+            self.config.commentary.max_commentary_delay_ticks,
+        );
 
         // 8. Mark highlights
         let highlights = self.mark_highlights(&notable_events, state.timestamp.clone());
 
-        // 9. Build output
+        if stale {
+            for instruction in &mut camera_script {
+                instruction.reason.push_str(" [stale-state]");
+            }
+        }
+
+        // 9. Pick overall pacing from event density and season
+        let pacing = self.focus_selector.tick_pacing(events, state);
+
+        // 10. Drop stale betrayals on the configured cadence
+        self.auto_cleanup();
+
+        // 11. Build output
         DirectorOutput {
+            schema_version: OUTPUT_SCHEMA_VERSION,
             generated_at_tick: self.current_tick,
-            camera_script: vec![camera_instruction],
+            camera_script,
             commentary_queue,
             active_threads,
             highlights,
+            pacing,
         }
     }
 
-    /// Builds scoring context from current state.
-    fn build_context(&self, tensions: &[Tension]) -> DirectorContext {
+    /// Builds scoring context from current state, forgetting events that have
+    /// aged out of the novelty window.
+    fn build_context(&mut self, tensions: &[Tension], state: &WorldSnapshot) -> DirectorContext {
         let mut context = DirectorContext::new();
 
+        let window = self.config.event_weights.novelty_window_ticks;
+        while self
+            .recent_events
+            .front()
+            .is_some_and(|(tick, _)| tick + window <= self.current_tick)
+        {
+            self.recent_events.pop_front();
+        }
+        for (_, key) in &self.recent_events {
+            *context.recent_event_counts.entry(key.clone()).or_insert(0) += 1;
+        }
+
         // Add tracked agents
         for agent_id in &self.tracked_agents {
             context.track_agent(agent_id);
@@ -307,11 +528,21 @@ impl Director {
         for tension in tensions {
             if tension.is_active() {
                 for trigger_event in &tension.trigger_events {
-                    context.add_tension_event(trigger_event);
+                    context.add_tension_trigger(trigger_event, tension.severity);
                 }
             }
         }
 
+        // Hungry agents act out of need, which colours how their actions read
+        for agent in &state.agents {
+            let desperation = match agent.needs.food_security.as_str() {
+                "desperate" => 1.0,
+                "stressed" => 0.5,
+                _ => continue,
+            };
+            context.set_desperation(&agent.agent_id, desperation);
+        }
+
         context
     }
 
@@ -323,22 +554,16 @@ impl Director {
     ) -> Vec<HighlightMarker> {
         notable_events
             .iter()
-            .filter(|se| se.score >= 0.7) // Only high-scoring events become highlights
+            .filter(|se| se.score >= self.config.director.min_highlight_score)
             .map(|se| {
-                // Map event types to appropriate highlight types
-                let highlight_type = match se.event.event_type {
-                    EventType::Betrayal => HighlightType::TurningPoint,
-                    EventType::Death => HighlightType::Climax,
-                    EventType::Conflict => HighlightType::KeyMoment,
-                    EventType::Faction => HighlightType::TurningPoint,
-                    EventType::Ritual => HighlightType::KeyMoment,
-                    _ => HighlightType::KeyMoment,
-                };
-
-                // Clip window: 50 ticks before to 50 ticks after the event
-                let tick = se.event.timestamp.tick;
-                let clip_start = tick.saturating_sub(50);
-                let clip_end = tick + 50;
+                let highlight_type = highlights::highlight_type_for(se.event);
+
+                let (clip_start, clip_end) = highlights::clip_window(
+                    &self.config.highlights,
+                    se.event.timestamp.tick,
+                    se.score,
+                    highlight_type,
+                );
 
                 HighlightMarker::new(
                     &se.event.event_id,
@@ -355,12 +580,90 @@ impl Director {
             .collect()
     }
 
+    /// Runs the director over a recorded simulation.
+    ///
+    /// Streams `events_path` (an `events.jsonl` log in tick order), groups the
+    /// events by tick and processes each group against the snapshot for that
+    /// tick (`snap_NNNNNN.json` or `snap_NNNNNN.json.gz` in `snapshots_dir`).
+    /// Ticks without a snapshot reuse the most recent one. Output is streamed
+    /// to `out_path` through an [`OutputWriter`]. Malformed event lines are
+    /// skipped with a warning.
+    ///
+    /// Returns the number of ticks processed.
+    pub fn run_from_files(
+        &mut self,
+        events_path: &Path,
+        snapshots_dir: &Path,
+        out_path: &Path,
+    ) -> Result<u64, DirectorError> {
+        let reader = EventReader::from_path(events_path).map_err(OutputError::Io)?;
+        let mut writer = OutputWriter::new(out_path)?;
+        let mut last_cleanup_tick = 0;
+        let mut total_events = 0u64;
+        let mut batch: Vec<Event> = Vec::new();
+
+        for result in reader {
+            let event = match result {
+                Ok(event) => event,
+                Err(e @ EventReadError::Parse { .. }) => {
+                    tracing::warn!(error = %e, "skipping malformed event");
+                    continue;
+                }
+                Err(e) => return Err(e.into()),
+            };
+
+            // A later tick closes out the batch gathered so far
+            if batch.first().is_some_and(|first| first.timestamp.tick != event.timestamp.tick) {
+                total_events += batch.len() as u64;
+                self.replay_tick(&mut batch, snapshots_dir, &mut writer, &mut last_cleanup_tick)?;
+            }
+            batch.push(event);
+        }
+        total_events += batch.len() as u64;
+        self.replay_tick(&mut batch, snapshots_dir, &mut writer, &mut last_cleanup_tick)?;
+
+        writer.flush()?;
+        writer.write_summary(total_events, 0)?;
+        Ok(writer.ticks_written())
+    }
+
+    /// Processes one tick's worth of replayed events and writes the output.
+    fn replay_tick(
+        &mut self,
+        batch: &mut Vec<Event>,
+        snapshots_dir: &Path,
+        writer: &mut OutputWriter,
+        last_cleanup_tick: &mut u64,
+    ) -> Result<(), DirectorError> {
+        let Some(first) = batch.first() else {
+            return Ok(());
+        };
+        let timestamp = first.timestamp.clone();
+        let output = match load_tick_snapshot(snapshots_dir, timestamp.tick)? {
+            Some(state) => self.process_tick(batch, &[], &state),
+            None => self.process_tick_without_snapshot(batch, &[], timestamp),
+        };
+        writer.write_tick(&output)?;
+        batch.clear();
+
+        if self.current_tick >= *last_cleanup_tick + CLEANUP_INTERVAL_TICKS {
+            self.cleanup(MAX_BETRAYAL_AGE_TICKS);
+            *last_cleanup_tick = self.current_tick;
+        }
+        Ok(())
+    }
+
     /// Updates tracked agents based on the camera instruction.
     fn update_tracked_agents(&mut self, instruction: &CameraInstruction) {
+        // A location shot names no agents; keep following the current cast
+        let agent_ids = instruction.focus.agent_ids();
+        if agent_ids.is_empty() {
+            return;
+        }
+
         // Clear old tracked agents and add new ones from camera focus
         self.tracked_agents.clear();
-
-        for agent_id in instruction.focus.agent_ids() {
+        for agent_id in agent_ids {
             self.tracked_agents.insert(agent_id.to_string());
         }
     }
@@ -375,6 +678,16 @@ impl Director {
         self.current_tick
     }
 
+    /// Returns the tick of the last snapshot the director was given, if any.
+    pub fn snapshot_tick(&self) -> Option<u64> {
+        self.snapshot_tick
+    }
+
+    /// Returns true if the current tick is running on a carried-forward snapshot.
+    pub fn is_state_stale(&self) -> bool {
+        self.snapshot_tick != Some(self.current_tick)
+    }
+
     /// Returns the number of active threads.
     pub fn active_thread_count(&self) -> usize {
         self.thread_tracker.active().len()
@@ -385,10 +698,109 @@ impl Director {
         self.irony_detector.betrayal_count()
     }
 
+    /// Returns the irony detector, e.g. to export its betrayal state.
+    pub fn irony_detector(&self) -> &IronyDetector {
+        &self.irony_detector
+    }
+
+    /// Captures the runtime state (threads, betrayals, focus) for later restore.
+    pub fn save_state(&self) -> DirectorState {
+        DirectorState {
+            current_tick: self.current_tick,
+            thread_tracker: self.thread_tracker.clone(),
+            irony_detector: self.irony_detector.clone(),
+            tracked_agents: self.tracked_agents.clone(),
+            current_focus: self.current_focus.clone(),
+            recent_events: self.recent_events.clone(),
+            commentary: self.commentary_generator.export_state(),
+            focus: self.focus_selector.export_state(),
+        }
+    }
+
+    /// Replaces the runtime state with a saved one, keeping the current config.
+    ///
+    /// No snapshot is carried over, so the next tick should be given one
+    /// through `process_tick`.
+    pub fn restore_state(&mut self, state: DirectorState) {
+        self.current_tick = state.current_tick;
+        self.last_auto_cleanup_tick = state.current_tick;
+        self.commentary_slots.fill(0);
+        self.thread_tracker = state.thread_tracker;
+        self.thread_tracker.set_config(self.config.threads.clone());
+        self.irony_detector = state.irony_detector;
+        self.irony_detector
+            .set_trust_threshold(self.config.commentary.irony_trust_threshold);
+        self.tracked_agents = state.tracked_agents;
+        self.current_focus = state.current_focus;
+        self.recent_events = state.recent_events;
+        self.commentary_generator.restore_state(state.commentary);
+        self.focus_selector.restore_state(state.focus);
+        self.last_snapshot = None;
+        self.snapshot_tick = None;
+    }
+
     /// Cleans up old data (betrayals, dormant threads, etc.)
     pub fn cleanup(&mut self, max_betrayal_age_ticks: u64) {
         self.irony_detector.cleanup(self.current_tick, max_betrayal_age_ticks);
     }
+
+    /// Cleans up old data, with the betrayal age given in seasons.
+    pub fn cleanup_seasons(&mut self, max_betrayal_age_seasons: u32) {
+        let ticks_per_season = TICKS_PER_DAY * DAYS_PER_SEASON as u64;
+        self.cleanup(max_betrayal_age_seasons as u64 * ticks_per_season);
+    }
+
+    /// Runs `cleanup_seasons` when the configured auto-cleanup interval has elapsed.
+    fn auto_cleanup(&mut self) {
+        let interval = self.config.director.auto_cleanup_interval_ticks;
+        if interval > 0 && self.current_tick >= self.last_auto_cleanup_tick + interval {
+            self.cleanup_seasons(self.config.director.betrayal_max_age_seasons);
+            self.last_auto_cleanup_tick = self.current_tick;
+        }
+    }
+}
+
+/// Highest reputation among an event's agents, if any of them is in the snapshot.
+fn highest_reputation(event: &Event, state: &WorldSnapshot) -> Option<f32> {
+    event
+        .all_agent_ids()
+        .into_iter()
+        .filter_map(|id| state.find_agent(id).map(|agent| agent.reputation))
+        .reduce(f32::max)
+}
+
+/// Origin of the strongest grudge behind a revenge arc, if the snapshot records one.
+fn grudge_origin(tension: &Tension, state: &WorldSnapshot) -> Option<GrudgeOrigin> {
+    if tension.tension_type != TensionType::RevengeArc {
+        return None;
+    }
+    let involved = |agent_id: &str| tension.key_agents.iter().any(|a| a.agent_id == agent_id);
+    state
+        .grudges
+        .iter()
+        .filter(|g| involved(&g.holder_id) && involved(&g.offender_id))
+        .filter_map(|g| GrudgeOrigin::from_snapshot(g, state).map(|origin| (g.severity, origin)))
+        .max_by(|(a, _), (b, _)| a.total_cmp(b))
+        .map(|(_, origin)| origin)
+}
+
+/// Loads the snapshot written for a tick, if the simulation wrote one.
+///
+/// Runs with `--compress-snapshots` write `snap_NNNNNN.json.gz`, which is
+/// gunzipped here.
+fn load_tick_snapshot(snapshots_dir: &Path, tick: u64) -> Result<Option<WorldSnapshot>, OutputError> {
+    let path = snapshots_dir.join(format!("snap_{:06}.json", tick));
+    let compressed = snapshots_dir.join(format!("snap_{:06}.json.gz", tick));
+    let json = if path.exists() {
+        std::fs::read_to_string(&path)?
+    } else if compressed.exists() {
+        let mut json = String::new();
+        flate2::read::GzDecoder::new(std::fs::File::open(&compressed)?).read_to_string(&mut json)?;
+        json
+    } else {
+        return Ok(None);
+    };
+    Ok(Some(serde_json::from_str(&json)?))
 }
 
 impl Default for Director {
@@ -400,9 +812,10 @@ impl Default for Director {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use std::collections::HashMap;
     use sim_events::{
-        ActorSet, ActorSnapshot, AffectedActor, BetrayalSubtype, EventContext, EventOutcome,
-        EventSubtype, GeneralOutcome, MovementSubtype, Season, SimTimestamp, TensionStatus,
+        ActorSet, ActorSnapshot, AffectedActor, BetrayalSubtype, ConflictSubtype, DeathSubtype,
+        EventContext, EventOutcome, EventSubtype, GeneralOutcome, MovementSubtype, Season, SimTimestamp, TensionStatus,
         TensionType, RelationshipSnapshot,
     };
 
@@ -499,7 +912,7 @@ mod tests {
         ));
 
         // Add relationship where Corin trusts Mira
-        let mut corin_rels = std::collections::HashMap::new();
+        let mut corin_rels = std::collections::BTreeMap::new();
         corin_rels.insert(
             "agent_mira".to_string(),
             RelationshipSnapshot::new(0.8, 0.6, 0.5),
@@ -523,6 +936,75 @@ mod tests {
         assert_eq!(director.current_tick(), 0);
     }
 
+    #[test]
+    fn test_reload_config_changes_notability() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("director.toml");
+        let mut director = Director::with_defaults();
+
+        let output = director.process_tick(&[make_betrayal_event(1000)], &[], &make_world_snapshot(1000));
+        assert!(!output.highlights.is_empty());
+
+        // Raise the bar so high nothing is notable
+        let mut config = DirectorConfig::default();
+        config.focus.min_event_score = 100.0;
+        config.director.min_highlight_score = 1.0;
+        config.commentary.irony_trust_threshold = 0.8;
+        config.commentary.max_simultaneous = 4;
+        std::fs::write(&path, config.to_toml().unwrap()).unwrap();
+        director.reload_config(&path).unwrap();
+
+        let output = director.process_tick(&[make_betrayal_event(1001)], &[], &make_world_snapshot(1001));
+        assert!(output.highlights.is_empty());
+        assert!(output.commentary_queue.iter().all(|c| c.commentary_type != CommentaryType::EventCaption));
+        // Live state survives the reload
+        assert_eq!(director.tracked_betrayal_count(), 2);
+        assert_eq!(director.config().focus.min_event_score, 100.0);
+        assert_eq!(director.irony_detector.export().trust_threshold, 0.8);
+        assert_eq!(director.commentary_slots.len(), 4);
+    }
+
+    #[test]
+    fn test_reload_rejects_invalid_config() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("director.toml");
+        let mut director = Director::with_defaults();
+
+        let mut config = DirectorConfig::default();
+        config.focus.fatigue_multiplier = 3.0;
+        std::fs::write(&path, config.to_toml().unwrap()).unwrap();
+        assert!(matches!(
+            director.reload_config(&path),
+            Err(DirectorError::Config(ConfigError::Invalid(_)))
+        ));
+
+        std::fs::write(&path, "not = [valid").unwrap();
+        assert!(director.reload_config(&path).is_err());
+
+        // Original config untouched
+        assert_eq!(director.config().focus.fatigue_multiplier, FocusConfig::default().fatigue_multiplier);
+    }
+
+    #[test]
+    fn test_load_tick_snapshot_reads_compressed() {
+        use std::io::Write;
+
+        let dir = tempfile::tempdir().unwrap();
+        let json = serde_json::to_string(&make_world_snapshot(20)).unwrap();
+        std::fs::write(dir.path().join("snap_000010.json"), &json).unwrap();
+        let mut encoder = flate2::write::GzEncoder::new(
+            std::fs::File::create(dir.path().join("snap_000020.json.gz")).unwrap(),
+            flate2::Compression::default(),
+        );
+        encoder.write_all(json.as_bytes()).unwrap();
+        encoder.finish().unwrap();
+
+        assert!(load_tick_snapshot(dir.path(), 10).unwrap().is_some());
+        let snapshot = load_tick_snapshot(dir.path(), 20).unwrap().unwrap();
+        assert_eq!(snapshot.timestamp.tick, 20);
+        assert!(load_tick_snapshot(dir.path(), 30).unwrap().is_none());
+    }
+
     #[test]
     fn test_process_tick_empty() {
         let mut director = Director::with_defaults();
@@ -551,6 +1033,82 @@ mod tests {
         assert!(!output.highlights.is_empty());
     }
 
+    #[test]
+    fn test_identical_events_yield_single_caption() {
+        let mut director = Director::with_defaults();
+        let state = make_world_snapshot(1000);
+        let events: Vec<Event> = (0..5)
+            .map(|i| {
+                let mut event = make_betrayal_event(1000);
+                event.event_id = format!("evt_dup_{}", i);
+                event
+            })
+            .collect();
+
+        let output = director.process_tick(&events, &[], &state);
+
+        let captions = output
+            .commentary_queue
+            .iter()
+            .filter(|c| c.commentary_type == CommentaryType::EventCaption)
+            .count();
+        assert!(captions <= 1);
+    }
+
+    #[test]
+    fn test_per_type_threshold_lets_betrayal_through() {
+        let mut config = DirectorConfig::default();
+        config.focus.min_event_score = 1.5;
+        config.director.min_highlight_score = 1.0;
+        let mut strict = Director::new(config.clone()).unwrap();
+
+        config.focus.event_type_thresholds.insert(EventType::Betrayal, 0.1);
+        let mut lenient = Director::new(config).unwrap();
+
+        let events = [make_betrayal_event(1000), make_movement_event(1000)];
+        let state = make_world_snapshot(1000);
+        let strict_output = strict.process_tick(&events, &[], &state);
+        let lenient_output = lenient.process_tick(&events, &[], &state);
+
+        let captions = |output: &DirectorOutput| {
+            output
+                .commentary_queue
+                .iter()
+                .filter(|c| c.commentary_type == CommentaryType::EventCaption)
+                .count()
+        };
+        // Below the global bar, but over the betrayal-specific one
+        assert_eq!(captions(&strict_output), 0);
+        assert_eq!(captions(&lenient_output), 1);
+    }
+
+    #[test]
+    fn test_winter_lull_paces_slower_than_summer_conflict() {
+        let mut winter_director = Director::with_defaults();
+        let mut winter = make_world_snapshot(1000);
+        winter.timestamp = SimTimestamp::new(1000, 1, Season::Winter, 10);
+        let winter_output = winter_director.process_tick(&[make_movement_event(1000)], &[], &winter);
+
+        let mut summer_director = Director::with_defaults();
+        let mut summer = make_world_snapshot(1000);
+        summer.timestamp = SimTimestamp::new(1000, 1, Season::Summer, 10);
+        summer.world.global_resources.total_grain = 10_000;
+        let fights: Vec<Event> = (0..4)
+            .map(|i| {
+                let mut event = make_betrayal_event(1000);
+                event.event_id = format!("evt_fight_{}", i);
+                event.event_type = EventType::Conflict;
+                event.subtype = EventSubtype::Conflict(ConflictSubtype::Fight);
+                event
+            })
+            .collect();
+        let summer_output = summer_director.process_tick(&fights, &[], &summer);
+
+        assert_eq!(winter_output.pacing, PacingHint::Slow);
+        assert_eq!(summer_output.pacing, PacingHint::Urgent);
+        assert!(winter_output.pacing.suggested_hold_ticks() > summer_output.pacing.suggested_hold_ticks());
+    }
+
     #[test]
     fn test_process_tick_filters_low_drama() {
         let mut director = Director::with_defaults();
@@ -577,6 +1135,110 @@ mod tests {
         // May generate tension teaser
     }
 
+    fn director_with_verbosity(verbosity: Verbosity) -> Director {
+        let mut config = DirectorConfig::default();
+        config.commentary.verbosity = verbosity;
+        Director::new(config).unwrap()
+    }
+
+    fn count_of(output: &DirectorOutput, commentary_type: CommentaryType) -> usize {
+        output
+            .commentary_queue
+            .iter()
+            .filter(|c| c.commentary_type == commentary_type)
+            .count()
+    }
+
+    #[test]
+    fn test_silent_verbosity_suppresses_teasers() {
+        let state = make_world_snapshot(1000);
+        let teasers = |verbosity: Verbosity| {
+            let output = director_with_verbosity(verbosity).process_tick(&[], &[make_tension()], &state);
+            count_of(&output, CommentaryType::TensionTeaser)
+        };
+
+        assert_eq!(teasers(Verbosity::Normal), 1);
+        assert_eq!(teasers(Verbosity::Silent), 0);
+
+        // A betrayal is a turning point, not a climax, so Silent leaves it uncaptioned
+        let mut director = director_with_verbosity(Verbosity::Silent);
+        let output = director.process_tick(&[make_betrayal_event(1000)], &[], &state);
+        assert!(output.commentary_queue.is_empty());
+    }
+
+    #[test]
+    fn test_verbose_captions_mid_drama_events() {
+        let state = make_world_snapshot(1000);
+        let captions = |verbosity: Verbosity, drama_score: f32| {
+            let mut event = make_betrayal_event(1000);
+            event.drama_score = drama_score;
+            let output = director_with_verbosity(verbosity).process_tick(&[event], &[], &state);
+            count_of(&output, CommentaryType::EventCaption)
+        };
+
+        assert_eq!(captions(Verbosity::Normal, 0.2), 0);
+        assert_eq!(captions(Verbosity::Verbose, 0.2), 1);
+
+        // Sparse raises the bar that Normal clears
+        assert_eq!(captions(Verbosity::Normal, 0.5), 1);
+        assert_eq!(captions(Verbosity::Sparse, 0.5), 0);
+    }
+
+    #[test]
+    fn test_revenge_teaser_names_the_offense() {
+        let mut state = make_world_snapshot(1000);
+        state.locations.push(sim_events::LocationSnapshot::new("eastern_bridge", "Eastern Bridge", "bridge"));
+        state.grudges.push(sim_events::GrudgeSnapshot {
+            holder_id: "agent_corin".to_string(),
+            offender_id: "agent_mira".to_string(),
+            origin_event: Some("evt_00999".to_string()),
+            location: Some("eastern_bridge".to_string()),
+            severity: 0.8,
+            formed_tick: 900,
+        });
+
+        let mut tension = Tension::new("tens_00002", TensionType::RevengeArc, 1000, "Corin wants payback");
+        tension.severity = 0.7;
+        tension.status = TensionStatus::Escalating;
+        tension.add_agent_inline("agent_corin", "avenger", "seething");
+        tension.add_agent_inline("agent_mira", "target", "unaware");
+
+        let output = Director::with_defaults().process_tick(&[], &[tension], &state);
+        let teaser = output
+            .commentary_queue
+            .iter()
+            .find(|c| c.commentary_type == CommentaryType::TensionTeaser)
+            .unwrap();
+        assert!(teaser.content.contains("Corin"));
+        assert!(teaser.content.contains("Mira"));
+        assert!(teaser.content.contains("Eastern Bridge"));
+    }
+
+    #[test]
+    fn test_unchanged_focus_holds_instead_of_recutting() {
+        let mut director = Director::with_defaults();
+        let tension = make_tension();
+
+        let script: Vec<CameraInstruction> = (1000..1003)
+            .flat_map(|tick| {
+                director
+                    .process_tick(&[], std::slice::from_ref(&tension), &make_world_snapshot(tick))
+                    .camera_script
+            })
+            .collect();
+
+        let holds: Vec<bool> = script.iter().map(|i| i.hold).collect();
+        assert_eq!(holds, vec![false, true, true]);
+        assert!(script.iter().all(|i| i.focus == script[0].focus));
+
+        // A more dramatic event on the same focus earns a fresh cut
+        let mut betrayal = make_betrayal_event(1003);
+        betrayal.actors.secondary = None;
+        betrayal.actors.affected.clear();
+        let output = director.process_tick(&[betrayal], std::slice::from_ref(&tension), &make_world_snapshot(1003));
+        assert!(!output.camera_script[0].hold);
+    }
+
     #[test]
     fn test_process_tick_creates_threads() {
         let mut director = Director::with_defaults();
@@ -589,6 +1251,40 @@ mod tests {
         assert!(director.active_thread_count() > 0);
     }
 
+    #[test]
+    fn test_new_thread_opens_with_one_establishing_shot() {
+        let mut director = Director::with_defaults();
+        let hold = director.config().focus.establishing_hold_ticks;
+        let mut tension = make_tension();
+        tension.add_location("thornwood_hall");
+
+        let mut establishing = 0;
+        let mut first_script = Vec::new();
+        for tick in 1000..1000 + hold + 10 {
+            let state = make_world_snapshot(tick);
+            let output = director.process_tick(&[], std::slice::from_ref(&tension), &state);
+            establishing += output
+                .camera_script
+                .iter()
+                .filter(|i| matches!(i.camera_mode, CameraMode::Establishing { .. }))
+                .count();
+            if tick == 1000 {
+                first_script = output.camera_script;
+            } else if tick < 1000 + hold {
+                // Nothing cuts in while the establishing shot holds
+                assert!(output.camera_script.is_empty());
+            } else {
+                assert_eq!(output.camera_script.len(), 1);
+            }
+        }
+
+        assert_eq!(establishing, 1);
+        // The cut to the thread's subjects is scheduled for the end of the hold
+        assert_eq!(first_script.len(), 2);
+        assert_eq!(first_script[1].timestamp.tick, 1000 + hold);
+        assert_eq!(first_script[1].tension_id.as_deref(), Some("tens_00001"));
+    }
+
     #[test]
     fn test_process_tick_tracks_betrayals() {
         let mut director = Director::with_defaults();
@@ -618,6 +1314,26 @@ mod tests {
         assert!(!irony_items.is_empty());
     }
 
+    #[test]
+    fn test_higher_irony_threshold_detects_less_irony() {
+        let state = make_world_snapshot(1000);
+        let irony_count = |threshold: f32| {
+            let mut config = DirectorConfig::default();
+            config.commentary.irony_trust_threshold = threshold;
+            let mut director = Director::new(config).unwrap();
+            director.process_tick(&[make_betrayal_event(1000)], &[], &state);
+            director
+                .irony_detector()
+                .clone()
+                .detect_irony(&state, &[], &HashMap::new())
+                .len()
+        };
+
+        // Corin's reliability in Mira is 0.8
+        assert_eq!(irony_count(0.5), 1);
+        assert_eq!(irony_count(0.9), 0);
+    }
+
     #[test]
     fn test_process_multiple_ticks() {
         let mut director = Director::with_defaults();
@@ -638,6 +1354,36 @@ mod tests {
         assert_eq!(director.tracked_betrayal_count(), 1);
     }
 
+    #[test]
+    fn test_tick_between_snapshots_reuses_prior_snapshot() {
+        let mut director = Director::with_defaults();
+        let state = make_world_snapshot(1000);
+        director.process_tick(&[make_movement_event(1000)], &[], &state);
+        assert!(!director.is_state_stale());
+
+        // Tick 1005 has events but no snapshot of its own
+        let output = director.process_tick_without_snapshot(
+            &[make_betrayal_event(1005)],
+            &[],
+            test_timestamp(1005),
+        );
+
+        assert_eq!(output.generated_at_tick, 1005);
+        assert_eq!(director.current_tick(), 1005);
+        assert_eq!(director.snapshot_tick(), Some(1000));
+        assert!(director.is_state_stale());
+        assert_eq!(director.tracked_betrayal_count(), 1);
+        // Irony waits for fresh trust values
+        assert!(output
+            .commentary_queue
+            .iter()
+            .all(|c| c.commentary_type != CommentaryType::DramaticIrony));
+        assert!(output
+            .camera_script
+            .iter()
+            .all(|c| c.reason.ends_with("[stale-state]")));
+    }
+
     #[test]
     fn test_director_cleanup() {
         let mut director = Director::with_defaults();
@@ -655,6 +1401,74 @@ mod tests {
         assert_eq!(director.tracked_betrayal_count(), 0);
     }
 
+    #[test]
+    fn test_cleanup_seasons_expires_only_old_betrayals() {
+        let ticks_per_season = TICKS_PER_DAY * DAYS_PER_SEASON as u64;
+        let mut director = Director::with_defaults();
+
+        let mut old = make_betrayal_event(1000);
+        old.event_id = "evt_old".to_string();
+        director.process_tick(&[old], &[], &make_world_snapshot(1000));
+        let fresh_tick = 1000 + ticks_per_season;
+        let mut fresh = make_betrayal_event(fresh_tick);
+        fresh.event_id = "evt_fresh".to_string();
+        director.process_tick(&[fresh], &[], &make_world_snapshot(fresh_tick));
+        assert_eq!(director.tracked_betrayal_count(), 2);
+
+        director.current_tick = fresh_tick + 10;
+        director.cleanup_seasons(1);
+
+        assert_eq!(director.tracked_betrayal_count(), 1);
+        assert_eq!(director.irony_detector().betrayals()[0].event_id, "evt_fresh");
+    }
+
+    #[test]
+    fn test_auto_cleanup_runs_on_configured_cadence() {
+        let ticks_per_season = TICKS_PER_DAY * DAYS_PER_SEASON as u64;
+        let mut config = DirectorConfig::default();
+        config.director.auto_cleanup_interval_ticks = 500;
+        config.director.betrayal_max_age_seasons = 1;
+        let mut director = Director::new(config).unwrap();
+
+        director.process_tick(&[make_betrayal_event(1000)], &[], &make_world_snapshot(1000));
+        assert_eq!(director.tracked_betrayal_count(), 1);
+
+        let later = 1000 + ticks_per_season + 1;
+        director.process_tick(&[], &[], &make_world_snapshot(later));
+        assert_eq!(director.tracked_betrayal_count(), 0);
+    }
+
+    #[test]
+    fn test_save_restore_state_round_trip() {
+        let mut director = Director::with_defaults();
+        let state = make_world_snapshot(1000);
+        director.process_tick(&[make_betrayal_event(1000)], &[make_tension()], &state);
+        assert!(director.active_thread_count() > 0);
+
+        let json = serde_json::to_string(&director.save_state()).unwrap();
+        let saved: DirectorState = serde_json::from_str(&json).unwrap();
+
+        let mut restored = Director::with_defaults();
+        restored.restore_state(saved);
+
+        assert_eq!(restored.current_tick(), director.current_tick());
+        assert_eq!(restored.active_thread_count(), director.active_thread_count());
+        assert_eq!(restored.tracked_betrayal_count(), director.tracked_betrayal_count());
+        assert_eq!(
+            restored.current_focus.as_ref().map(|f| f.agent_ids()),
+            director.current_focus.as_ref().map(|f| f.agent_ids())
+        );
+        assert!(restored.is_state_stale());
+
+        // Cooldowns and establishing shots carry over
+        let commentary = director.commentary_generator.export_state();
+        let focus = director.focus_selector.export_state();
+        assert!(!commentary.last_featured.is_empty());
+        assert!(!focus.established_threads.is_empty());
+        assert_eq!(restored.commentary_generator.export_state(), commentary);
+        assert_eq!(restored.focus_selector.export_state(), focus);
+    }
+
     #[test]
     fn test_commentary_queue_limited() {
         let mut director = Director::new(DirectorConfig {
@@ -693,13 +1507,123 @@ mod tests {
     }
 
     #[test]
-    fn test_build_context() {
+    fn test_lower_highlight_score_marks_more_highlights() {
+        let betrayal = make_betrayal_event(1000);
+        let mut conflict = make_betrayal_event(1000);
+        conflict.event_id = "evt_conflict".to_string();
+        conflict.event_type = EventType::Conflict;
+        conflict.subtype = EventSubtype::Conflict(ConflictSubtype::Fight);
+        let scored = vec![ScoredEvent::new(&betrayal, 0.9), ScoredEvent::new(&conflict, 0.5)];
+
+        let strict = Director::with_defaults();
+        assert_eq!(strict.mark_highlights(&scored, test_timestamp(1000)).len(), 1);
+
+        let mut config = DirectorConfig::default();
+        config.director.min_highlight_score = 0.4;
+        let lenient = Director::new(config).unwrap();
+        assert_eq!(lenient.mark_highlights(&scored, test_timestamp(1000)).len(), 2);
+    }
+
+    #[test]
+    fn test_respected_figure_death_outranks_unknown_death() {
+        fn death_caption(reputation: f32) -> CommentaryItem {
+            let mut director = Director::with_defaults();
+            let mut death = make_movement_event(1000);
+            death.event_type = EventType::Death;
+            death.subtype = EventSubtype::Death(DeathSubtype::Killed);
+            death.drama_score = 0.6;
+            let mut state = make_world_snapshot(1000);
+            state.agents[0].reputation = reputation;
+
+            director
+                .process_tick(&[death], &[], &state)
+                .commentary_queue
+                .into_iter()
+                .find(|item| item.commentary_type == CommentaryType::EventCaption)
+                .unwrap()
+        }
+
+        let respected = death_caption(0.9);
+        let obscure = death_caption(0.1);
+        assert!(respected.priority > obscure.priority);
+        assert!(respected.content.contains("giant") || respected.content.contains("mourn"));
+        assert!(!obscure.content.contains("giant") && !obscure.content.contains("mourn"));
+
+        // Agents missing from the snapshot are captioned like anyone else
+        let mut director = Director::with_defaults();
+        let mut stranger = make_movement_event(1000);
+        stranger.event_type = EventType::Death;
+        stranger.subtype = EventSubtype::Death(DeathSubtype::Killed);
+        stranger.drama_score = 0.6;
+        stranger.actors.primary.agent_id = "agent_unknown".to_string();
+        let output = director.process_tick(&[stranger], &[], &make_world_snapshot(1000));
+        let caption = output
+            .commentary_queue
+            .iter()
+            .find(|item| item.commentary_type == CommentaryType::EventCaption)
+            .unwrap();
+        assert!((caption.priority - obscure.priority).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_climax_clip_window_wider_than_key_moment() {
         let director = Director::with_defaults();
+        let mut death = make_betrayal_event(1000);
+        death.event_type = EventType::Death;
+        death.subtype = EventSubtype::Death(DeathSubtype::Killed);
+        let mut conflict = make_betrayal_event(1000);
+        conflict.event_id = "evt_conflict".to_string();
+        conflict.event_type = EventType::Conflict;
+        conflict.subtype = EventSubtype::Conflict(ConflictSubtype::Fight);
+
+        let scored = vec![ScoredEvent::new(&death, 0.95), ScoredEvent::new(&conflict, 0.7)];
+        let highlights = director.mark_highlights(&scored, test_timestamp(1000));
+
+        let width = |h: &HighlightMarker| h.suggested_clip_end - h.suggested_clip_start;
+        assert_eq!(highlights[0].highlight_type, HighlightType::Climax);
+        assert_eq!(highlights[1].highlight_type, HighlightType::KeyMoment);
+        assert!(width(&highlights[0]) > width(&highlights[1]));
+        // Climax post-roll outlasts its pre-roll
+        assert!(highlights[0].suggested_clip_end - 1000 > 1000 - highlights[0].suggested_clip_start);
+    }
+
+    #[test]
+    fn test_build_context() {
+        let mut director = Director::with_defaults();
         let tensions = vec![make_tension()];
 
-        let context = director.build_context(&tensions);
+        let context = director.build_context(&tensions, &make_world_snapshot(1000));
 
         // Context should have tension events
         assert!(context.is_tension_event("evt_00999"));
     }
+
+    #[test]
+    fn test_build_context_ordering_is_stable() {
+        let mut first = Director::with_defaults();
+        let mut second = Director::with_defaults();
+        for id in ["agent_voss", "agent_mira", "agent_corin"] {
+            first.tracked_agents.insert(id.to_string());
+        }
+        for id in ["agent_corin", "agent_voss", "agent_mira"] {
+            second.tracked_agents.insert(id.to_string());
+        }
+
+        let mut other = make_tension();
+        other.add_trigger_event("evt_00100");
+        let tensions = vec![make_tension(), other];
+
+        let state = make_world_snapshot(1000);
+        let a = first.build_context(&tensions, &state);
+        let b = second.build_context(&tensions, &state);
+
+        let a_agents: Vec<_> = a.tracked_agents.iter().collect();
+        let b_agents: Vec<_> = b.tracked_agents.iter().collect();
+        assert_eq!(a_agents, vec!["agent_corin", "agent_mira", "agent_voss"]);
+        assert_eq!(a_agents, b_agents);
+        assert_eq!(
+            a.active_tension_events.iter().collect::<Vec<_>>(),
+            b.active_tension_events.iter().collect::<Vec<_>>()
+        );
+    }
 }
@@ -7,8 +7,9 @@
 use serde::{Deserialize, Serialize};
 use sim_events::SimTimestamp;
 use std::fs::{self, File};
-use std::io::{BufWriter, Write};
+use std::io::{self, BufWriter, Write};
 use std::path::Path;
+use std::sync::{Arc, Mutex};
 
 use crate::threads::NarrativeThread;
 
@@ -28,11 +29,17 @@ pub struct CameraInstruction {
     pub focus: CameraFocus,
     /// Suggested pacing for transitions
     pub pacing: PacingHint,
+    /// How long the shot should hold before the next cut, in ticks
+    #[serde(default)]
+    pub duration_ticks: u32,
     /// Debug/logging reason for this instruction
     pub reason: String,
     /// Related tension ID if this instruction is tension-driven
     #[serde(skip_serializing_if = "Option::is_none")]
     pub tension_id: Option<String>,
+    /// Repeats the previous shot unchanged; viewers keep the current cut
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub hold: bool,
 }
 
 impl CameraInstruction {
@@ -51,8 +58,10 @@ impl CameraInstruction {
             camera_mode,
             focus,
             pacing: PacingHint::Normal,
+            duration_ticks: PacingHint::Normal.suggested_hold_ticks(),
             reason: reason.into(),
             tension_id: None,
+            hold: false,
         }
     }
 
@@ -68,6 +77,12 @@ impl CameraInstruction {
         self
     }
 
+    /// Sets the shot duration in ticks.
+    pub fn with_duration(mut self, duration_ticks: u32) -> Self {
+        self.duration_ticks = duration_ticks;
+        self
+    }
+
     /// Sets the related tension ID.
     pub fn with_tension(mut self, tension_id: impl Into<String>) -> Self {
         self.tension_id = Some(tension_id.into());
@@ -76,7 +91,7 @@ impl CameraInstruction {
 }
 
 /// Camera behavior mode.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all = "snake_case", tag = "type")]
 pub enum CameraMode {
     /// Follow a single agent
@@ -103,6 +118,11 @@ pub enum CameraMode {
     Overview {
         region: Option<String>,
     },
+    /// Establishing shot framing a thread's locations before cutting to its subjects
+    Establishing {
+        location_ids: Vec<String>,
+        zoom: ZoomLevel,
+    },
 }
 
 impl CameraMode {
@@ -131,10 +151,15 @@ impl CameraMode {
     pub fn overview(region: Option<String>) -> Self {
         Self::Overview { region }
     }
+
+    /// Creates an Establishing mode.
+    pub fn establishing(location_ids: Vec<String>, zoom: ZoomLevel) -> Self {
+        Self::Establishing { location_ids, zoom }
+    }
 }
 
 /// What the camera should focus on.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all = "snake_case", tag = "type")]
 pub enum CameraFocus {
     /// Focus on a single agent or location
@@ -240,7 +265,7 @@ pub enum ZoomLevel {
 }
 
 /// A waypoint for cinematic camera paths.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct CameraWaypoint {
     /// Target location or agent ID
     pub target: String,
@@ -295,6 +320,9 @@ pub struct CommentaryItem {
     pub timestamp: SimTimestamp,
     /// How long to show (in ticks)
     pub display_duration_ticks: u32,
+    /// Ticks after `timestamp` to hold the item back, so overlays don't pile up
+    #[serde(default)]
+    pub start_offset_ticks: u64,
     /// Type of commentary
     pub commentary_type: CommentaryType,
     /// The actual text content
@@ -321,6 +349,7 @@ impl CommentaryItem {
             item_id: item_id.into(),
             timestamp,
             display_duration_ticks: 100,
+            start_offset_ticks: 0,
             commentary_type,
             content: content.into(),
             priority: 0.5,
@@ -426,9 +455,30 @@ pub enum HighlightType {
     Foreshadowing,
 }
 
+impl HighlightType {
+    /// Ranking used when trimming a highlight reel (higher is more important).
+    pub fn importance(self) -> u8 {
+        match self {
+            HighlightType::Climax => 4,
+            HighlightType::TurningPoint => 3,
+            HighlightType::Resolution => 2,
+            HighlightType::KeyMoment => 1,
+            HighlightType::Foreshadowing => 0,
+        }
+    }
+}
+
+/// Current `DirectorOutput` schema version.
+///
+/// Version 1 predates the `schema_version` field; files without it are read
+/// as version 1 and migrated.
+pub const OUTPUT_SCHEMA_VERSION: u32 = 2;
+
 /// Complete output from the Director for a processing tick.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct DirectorOutput {
+    /// Schema version this output was written with
+    pub schema_version: u32,
     /// Tick when this output was generated
     pub generated_at_tick: u64,
     /// Camera instructions for this tick
@@ -439,17 +489,22 @@ pub struct DirectorOutput {
     pub active_threads: Vec<NarrativeThread>,
     /// Highlighted moments for later summarization
     pub highlights: Vec<HighlightMarker>,
+    /// Overall pacing for this tick, for modulating shot length
+    #[serde(default)]
+    pub pacing: PacingHint,
 }
 
 impl DirectorOutput {
     /// Creates a new empty DirectorOutput.
     pub fn new(tick: u64) -> Self {
         Self {
+            schema_version: OUTPUT_SCHEMA_VERSION,
             generated_at_tick: tick,
             camera_script: Vec::new(),
             commentary_queue: Vec::new(),
             active_threads: Vec::new(),
             highlights: Vec::new(),
+            pacing: PacingHint::Normal,
         }
     }
 
@@ -478,33 +533,25 @@ impl DirectorOutput {
         !self.commentary_queue.is_empty()
     }
 
-    /// Writes the camera script to a JSON file.
+    /// Writes the camera script to a JSON file, atomically.
     pub fn write_camera_script(&self, path: &Path) -> Result<(), OutputError> {
-        let file = File::create(path).map_err(OutputError::Io)?;
-        let writer = BufWriter::new(file);
-        serde_json::to_writer_pretty(writer, &self.camera_script).map_err(OutputError::Json)?;
-        Ok(())
+        write_json_atomic(path, &self.camera_script)
     }
 
-    /// Writes the commentary queue to a JSON file.
+    /// Writes the commentary queue to a JSON file, atomically.
     pub fn write_commentary(&self, path: &Path) -> Result<(), OutputError> {
-        let file = File::create(path).map_err(OutputError::Io)?;
-        let writer = BufWriter::new(file);
-        serde_json::to_writer_pretty(writer, &self.commentary_queue).map_err(OutputError::Json)?;
-        Ok(())
+        write_json_atomic(path, &self.commentary_queue)
     }
 
-    /// Writes the highlights to a JSON file.
+    /// Writes the highlights to a JSON file, atomically.
     pub fn write_highlights(&self, path: &Path) -> Result<(), OutputError> {
-        let file = File::create(path).map_err(OutputError::Io)?;
-        let writer = BufWriter::new(file);
-        serde_json::to_writer_pretty(writer, &self.highlights).map_err(OutputError::Json)?;
-        Ok(())
+        write_json_atomic(path, &self.highlights)
     }
 
     /// Writes all output files to a directory.
     ///
-    /// Creates the directory if it doesn't exist. Writes:
+    /// Creates the directory if it doesn't exist. Each file is replaced
+    /// atomically, so readers see either the old or the new content. Writes:
     /// - `camera_script.json` - Camera instructions
     /// - `commentary.json` - Commentary queue
     /// - `highlights.json` - Highlight markers
@@ -527,6 +574,63 @@ impl DirectorOutput {
     pub fn to_json_compact(&self) -> Result<String, OutputError> {
         serde_json::to_string(self).map_err(OutputError::Json)
     }
+
+    /// Deserializes output written by any supported schema version.
+    ///
+    /// Older versions are migrated to the current struct. Versions newer than
+    /// [`OUTPUT_SCHEMA_VERSION`] are rejected with `OutputError::UnsupportedVersion`.
+    pub fn from_json(json: &str) -> Result<Self, OutputError> {
+        let mut value: serde_json::Value = serde_json::from_str(json)?;
+        migrate_output(&mut value)?;
+        serde_json::from_value(value).map_err(OutputError::Json)
+    }
+}
+
+/// Writes pretty JSON to a temp file beside `path`, then renames it into place.
+///
+/// Readers never see a partially written file: `path` holds either its old
+/// content or the complete new content. The temp file is in the same
+/// directory, so the rename never crosses filesystems.
+fn write_json_atomic<T: Serialize + ?Sized>(path: &Path, value: &T) -> Result<(), OutputError> {
+    let file_name = path.file_name().and_then(|n| n.to_str()).unwrap_or("output");
+    let temp_path = path.with_file_name(format!(".{}.{}.tmp", file_name, std::process::id()));
+
+    let result = (|| {
+        let mut writer = BufWriter::new(File::create(&temp_path)?);
+        serde_json::to_writer_pretty(&mut writer, value)?;
+        let file = writer.into_inner().map_err(|e| e.into_error())?;
+        file.sync_all()?;
+        fs::rename(&temp_path, path)?;
+        Ok(())
+    })();
+
+    if result.is_err() {
+        let _ = fs::remove_file(&temp_path);
+    }
+    result
+}
+
+/// Upgrades a serialized `DirectorOutput` in place to the current schema.
+fn migrate_output(value: &mut serde_json::Value) -> Result<(), OutputError> {
+    let Some(object) = value.as_object_mut() else {
+        // Let deserialization report the shape error
+        return Ok(());
+    };
+
+    let version = object
+        .get("schema_version")
+        .and_then(|v| v.as_u64())
+        .unwrap_or(1) as u32;
+    if version > OUTPUT_SCHEMA_VERSION {
+        return Err(OutputError::UnsupportedVersion {
+            found: version,
+            supported: OUTPUT_SCHEMA_VERSION,
+        });
+    }
+
+    // v1 only lacks `schema_version`; every other field was always written
+    object.insert("schema_version".to_string(), OUTPUT_SCHEMA_VERSION.into());
+    Ok(())
 }
 
 /// Generates a camera instruction ID.
@@ -546,6 +650,8 @@ pub enum OutputError {
     Io(std::io::Error),
     /// JSON serialization error
     Json(serde_json::Error),
+    /// Output was written by a newer schema than this reader understands
+    UnsupportedVersion { found: u32, supported: u32 },
 }
 
 impl std::fmt::Display for OutputError {
@@ -553,6 +659,11 @@ impl std::fmt::Display for OutputError {
         match self {
             OutputError::Io(e) => write!(f, "I/O error: {}", e),
             OutputError::Json(e) => write!(f, "JSON error: {}", e),
+            OutputError::UnsupportedVersion { found, supported } => write!(
+                f,
+                "output schema version {} is newer than supported version {}",
+                found, supported
+            ),
         }
     }
 }
@@ -562,6 +673,7 @@ impl std::error::Error for OutputError {
         match self {
             OutputError::Io(e) => Some(e),
             OutputError::Json(e) => Some(e),
+            OutputError::UnsupportedVersion { .. } => None,
         }
     }
 }
@@ -624,12 +736,22 @@ impl OutputWriter {
         })
     }
 
+    /// Writes a complete output as one JSON file, atomically.
+    ///
+    /// For the non-streaming path: a reader polling `path` sees either the
+    /// previous output or this one, never a truncated file.
+    pub fn write_atomic(path: &Path, output: &DirectorOutput) -> Result<(), OutputError> {
+        write_json_atomic(path, output)
+    }
+
     /// Writes a tick's output to all files.
     ///
     /// Each file gets one JSON object per line (JSON Lines format).
     pub fn write_tick(&mut self, output: &DirectorOutput) -> Result<(), OutputError> {
-        // Write camera script (array of instructions as single line)
-        let camera_json = serde_json::to_string(&output.camera_script)?;
+        // Write camera script (array of instructions as single line), leaving out
+        // holds since they don't cut; the full output still records them
+        let cuts: Vec<&CameraInstruction> = output.camera_script.iter().filter(|i| !i.hold).collect();
+        let camera_json = serde_json::to_string(&cuts)?;
         writeln!(self.camera_writer, "{}", camera_json)?;
 
         // Write commentary (array of items as single line)
@@ -675,14 +797,170 @@ impl OutputWriter {
             }
         });
 
-        let summary_path = self.output_dir.join("summary.json");
-        let file = File::create(summary_path)?;
-        let writer = BufWriter::new(file);
-        serde_json::to_writer_pretty(writer, &summary)?;
+        write_json_atomic(&self.output_dir.join("summary.json"), &summary)
+    }
+}
+
+/// A destination for per-tick director output.
+pub trait OutputSink {
+    /// Writes one tick's output.
+    fn write_tick(&mut self, output: &DirectorOutput) -> Result<(), OutputError>;
+
+    /// Flushes anything buffered.
+    fn flush(&mut self) -> Result<(), OutputError>;
+}
+
+impl OutputSink for OutputWriter {
+    fn write_tick(&mut self, output: &DirectorOutput) -> Result<(), OutputError> {
+        OutputWriter::write_tick(self, output)
+    }
+
+    fn flush(&mut self) -> Result<(), OutputError> {
+        OutputWriter::flush(self)
+    }
+}
+
+/// Writes each tick's full output as one JSON line to any writer, such as stdout.
+#[derive(Debug)]
+pub struct StreamSink<W: Write> {
+    writer: W,
+}
+
+impl<W: Write> StreamSink<W> {
+    /// Creates a sink over the given writer.
+    pub fn new(writer: W) -> Self {
+        Self { writer }
+    }
+
+    /// Returns the underlying writer.
+    pub fn into_inner(self) -> W {
+        self.writer
+    }
+}
+
+impl StreamSink<io::Stdout> {
+    /// Creates a sink writing to standard output.
+    pub fn stdout() -> Self {
+        Self::new(io::stdout())
+    }
+}
+
+impl<W: Write> OutputSink for StreamSink<W> {
+    fn write_tick(&mut self, output: &DirectorOutput) -> Result<(), OutputError> {
+        let json = serde_json::to_string(output)?;
+        writeln!(self.writer, "{}", json)?;
+        Ok(())
+    }
+
+    fn flush(&mut self) -> Result<(), OutputError> {
+        self.writer.flush()?;
         Ok(())
     }
 }
 
+/// Keeps every output in memory. Clones share the same buffer, so a clone
+/// handed to a `MultiWriter` can be read back through the original.
+#[derive(Debug, Clone, Default)]
+pub struct MemorySink {
+    outputs: Arc<Mutex<Vec<DirectorOutput>>>,
+}
+
+impl MemorySink {
+    /// Creates an empty in-memory sink.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns a copy of every output received so far.
+    pub fn outputs(&self) -> Vec<DirectorOutput> {
+        self.outputs.lock().unwrap_or_else(|e| e.into_inner()).clone()
+    }
+}
+
+impl OutputSink for MemorySink {
+    fn write_tick(&mut self, output: &DirectorOutput) -> Result<(), OutputError> {
+        self.outputs
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .push(output.clone());
+        Ok(())
+    }
+
+    fn flush(&mut self) -> Result<(), OutputError> {
+        Ok(())
+    }
+}
+
+/// Fans each tick's output out to several sinks.
+///
+/// Sinks are isolated from each other: one failing doesn't stop the rest
+/// from receiving the output, and a sink that failed may succeed next tick.
+#[derive(Default)]
+pub struct MultiWriter {
+    sinks: Vec<Box<dyn OutputSink>>,
+}
+
+impl MultiWriter {
+    /// Creates a writer with no sinks.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a sink.
+    pub fn with_sink(mut self, sink: impl OutputSink + 'static) -> Self {
+        self.sinks.push(Box::new(sink));
+        self
+    }
+
+    /// Adds a sink.
+    pub fn add_sink(&mut self, sink: impl OutputSink + 'static) {
+        self.sinks.push(Box::new(sink));
+    }
+
+    /// Number of sinks.
+    pub fn len(&self) -> usize {
+        self.sinks.len()
+    }
+
+    /// Returns true if there are no sinks.
+    pub fn is_empty(&self) -> bool {
+        self.sinks.is_empty()
+    }
+
+    /// Writes a tick's output to every sink.
+    ///
+    /// Returns the failures as (sink index, error), after every sink has
+    /// been tried.
+    pub fn write_tick(&mut self, output: &DirectorOutput) -> Result<(), Vec<(usize, OutputError)>> {
+        Self::collect_errors(self.sinks.iter_mut().map(|sink| sink.write_tick(output)))
+    }
+
+    /// Flushes every sink, returning the failures as (sink index, error).
+    pub fn flush(&mut self) -> Result<(), Vec<(usize, OutputError)>> {
+        Self::collect_errors(self.sinks.iter_mut().map(|sink| sink.flush()))
+    }
+
+    fn collect_errors(
+        results: impl Iterator<Item = Result<(), OutputError>>,
+    ) -> Result<(), Vec<(usize, OutputError)>> {
+        let errors: Vec<(usize, OutputError)> = results
+            .enumerate()
+            .filter_map(|(i, result)| result.err().map(|e| (i, e)))
+            .collect();
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(errors)
+        }
+    }
+}
+
+impl std::fmt::Debug for MultiWriter {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("MultiWriter").field("sinks", &self.sinks.len()).finish()
+    }
+}
+
 /// Wrapper for reading DirectorOutput from JSON Lines files.
 #[derive(Debug)]
 pub struct OutputReader {
@@ -703,15 +981,14 @@ impl OutputReader {
         Self::new(&output_dir.join("full_output.jsonl"))
     }
 
-    /// Reads all outputs from the file.
+    /// Reads all outputs from the file, migrating older schema versions.
     pub fn read_all(&self) -> Result<Vec<DirectorOutput>, OutputError> {
         let content = fs::read_to_string(&self.path)?;
         let mut outputs = Vec::new();
 
         for line in content.lines() {
             if !line.trim().is_empty() {
-                let output: DirectorOutput = serde_json::from_str(line)?;
-                outputs.push(output);
+                outputs.push(DirectorOutput::from_json(line)?);
             }
         }
 
@@ -724,8 +1001,7 @@ impl OutputReader {
 
         for (i, line) in content.lines().enumerate() {
             if i == tick_index && !line.trim().is_empty() {
-                let output: DirectorOutput = serde_json::from_str(line)?;
-                return Ok(Some(output));
+                return Ok(Some(DirectorOutput::from_json(line)?));
             }
         }
 
@@ -1051,6 +1327,50 @@ mod tests {
         assert!(dir.path().join("highlights.json").exists());
     }
 
+    #[test]
+    fn test_atomic_write_is_never_seen_half_written() {
+        use std::sync::atomic::{AtomicBool, Ordering};
+
+        let dir = tempdir().unwrap();
+        let path = dir.path().join("camera_script.json");
+        let mut output = make_test_output();
+        // Big enough that a plain write would take several syscalls
+        for _ in 0..200 {
+            output.add_commentary(output.commentary_queue[0].clone());
+        }
+        OutputWriter::write_atomic(&path, &output).unwrap();
+
+        let done = Arc::new(AtomicBool::new(false));
+        let reader = {
+            let (path, done) = (path.clone(), Arc::clone(&done));
+            std::thread::spawn(move || {
+                let mut reads = 0;
+                loop {
+                    let content = fs::read_to_string(&path).unwrap();
+                    DirectorOutput::from_json(&content).expect("reader saw a partial file");
+                    reads += 1;
+                    if done.load(Ordering::Relaxed) {
+                        return reads;
+                    }
+                }
+            })
+        };
+
+        for tick in 0..100 {
+            output.generated_at_tick = tick;
+            OutputWriter::write_atomic(&path, &output).unwrap();
+        }
+        done.store(true, Ordering::Relaxed);
+        assert!(reader.join().unwrap() > 0);
+
+        // The temp file is gone once renamed into place
+        let leftovers: Vec<_> = fs::read_dir(dir.path())
+            .unwrap()
+            .map(|e| e.unwrap().file_name())
+            .collect();
+        assert_eq!(leftovers, vec![std::ffi::OsString::from("camera_script.json")]);
+    }
+
     #[test]
     fn test_output_writer_creation() {
         let dir = tempdir().unwrap();
@@ -1114,6 +1434,50 @@ mod tests {
         assert!(content.contains("camera_script.jsonl"));
     }
 
+    #[test]
+    fn test_failing_sink_does_not_starve_the_others() {
+        /// A sink whose disk is always full
+        struct BrokenPipe;
+        impl Write for BrokenPipe {
+            fn write(&mut self, _: &[u8]) -> io::Result<usize> {
+                Err(io::Error::other("disk full"))
+            }
+            fn flush(&mut self) -> io::Result<()> {
+                Err(io::Error::other("disk full"))
+            }
+        }
+
+        let memory = MemorySink::new();
+        let mut writer = MultiWriter::new()
+            .with_sink(StreamSink::new(BrokenPipe))
+            .with_sink(memory.clone());
+
+        for tick in [1000, 1001, 1002] {
+            let errors = writer.write_tick(&DirectorOutput::new(tick)).unwrap_err();
+            assert_eq!(errors.len(), 1);
+            assert_eq!(errors[0].0, 0);
+        }
+        assert!(writer.flush().is_err());
+
+        let received: Vec<u64> = memory.outputs().iter().map(|o| o.generated_at_tick).collect();
+        assert_eq!(received, vec![1000, 1001, 1002]);
+    }
+
+    #[test]
+    fn test_stream_sink_writes_json_lines() {
+        let mut sink = StreamSink::new(Vec::new());
+        sink.write_tick(&make_test_output()).unwrap();
+        sink.write_tick(&DirectorOutput::new(2000)).unwrap();
+
+        let written = String::from_utf8(sink.into_inner()).unwrap();
+        let outputs: Vec<DirectorOutput> = written
+            .lines()
+            .map(|line| DirectorOutput::from_json(line).unwrap())
+            .collect();
+        assert_eq!(outputs.len(), 2);
+        assert_eq!(outputs[1].generated_at_tick, 2000);
+    }
+
     #[test]
     fn test_output_reader_read_all() {
         let dir = tempdir().unwrap();
@@ -1170,6 +1534,40 @@ mod tests {
         assert!(tick2.is_none());
     }
 
+    #[test]
+    fn test_reader_migrates_v1_output() {
+        let dir = tempdir().unwrap();
+        let path = dir.path().join("full_output.jsonl");
+        // v1: no schema_version
+        let v1 = r#"{"generated_at_tick":1000,"camera_script":[],"commentary_queue":[],"active_threads":[],"highlights":[]}"#;
+        std::fs::write(&path, format!("{}\n", v1)).unwrap();
+
+        let outputs = OutputReader::new(&path).read_all().unwrap();
+
+        assert_eq!(outputs.len(), 1);
+        assert_eq!(outputs[0].schema_version, OUTPUT_SCHEMA_VERSION);
+        assert_eq!(outputs[0].generated_at_tick, 1000);
+        assert!(outputs[0].active_threads.is_empty());
+        assert!(outputs[0].highlights.is_empty());
+    }
+
+    #[test]
+    fn test_reader_rejects_newer_schema() {
+        let json = format!(
+            r#"{{"schema_version":{},"generated_at_tick":1,"camera_script":[],"commentary_queue":[],"active_threads":[],"highlights":[]}}"#,
+            OUTPUT_SCHEMA_VERSION + 1
+        );
+
+        let err = DirectorOutput::from_json(&json).unwrap_err();
+
+        assert!(matches!(
+            err,
+            OutputError::UnsupportedVersion { found, supported }
+                if found == OUTPUT_SCHEMA_VERSION + 1 && supported == OUTPUT_SCHEMA_VERSION
+        ));
+        assert!(err.to_string().contains("newer than supported"));
+    }
+
     #[test]
     fn test_output_error_display() {
         let io_err = OutputError::Io(std::io::Error::new(
@@ -3,15 +3,90 @@
 //! Scores events by dramatic interest for prioritization.
 
 use serde::{Deserialize, Serialize};
-use sim_events::{Event, EventType};
-use std::collections::{HashMap, HashSet};
+use sim_events::{Event, EventSubtype, EventType, ResourceSubtype};
+use std::collections::{BTreeMap, BTreeSet, HashMap};
 use std::path::Path;
 
 use crate::threads::ScoredEvent;
 
+/// Default additive bonus for events involving a tracked agent.
+pub const DEFAULT_NARRATIVE_CONTINUITY_BONUS: f32 = 0.1;
+
+/// Default additive bonus for a tension trigger event, scaled by the tension's severity.
+pub const DEFAULT_TENSION_SEVERITY_BONUS: f32 = 0.3;
+
+/// Default additive bonus for a conflict won by the agent who was challenged.
+pub const DEFAULT_UPSET_VICTORY_BONUS: f32 = 0.15;
+
+/// Default additive bonus for a theft by a desperate agent, scaled by how desperate.
+pub const DEFAULT_DESPERATE_THEFT_BONUS: f32 = 0.2;
+
+/// Default score multiplier applied once per recent repeat of the same kind of event.
+pub const DEFAULT_NOVELTY_DECAY: f32 = 0.75;
+
+/// Default lowest novelty factor, so routine events never vanish entirely.
+pub const DEFAULT_NOVELTY_FLOOR: f32 = 0.25;
+
+/// Default ticks an event counts as recent for novelty (one season).
+pub const DEFAULT_NOVELTY_WINDOW_TICKS: u64 = 3000;
+
+fn default_narrative_continuity_bonus() -> f32 {
+    DEFAULT_NARRATIVE_CONTINUITY_BONUS
+}
+
+fn default_tension_severity_bonus() -> f32 {
+    DEFAULT_TENSION_SEVERITY_BONUS
+}
+
+fn default_upset_victory_bonus() -> f32 {
+    DEFAULT_UPSET_VICTORY_BONUS
+}
+
+fn default_desperate_theft_bonus() -> f32 {
+    DEFAULT_DESPERATE_THEFT_BONUS
+}
+
+fn default_novelty_decay() -> f32 {
+    DEFAULT_NOVELTY_DECAY
+}
+
+fn default_novelty_floor() -> f32 {
+    DEFAULT_NOVELTY_FLOOR
+}
+
+fn default_novelty_window_ticks() -> u64 {
+    DEFAULT_NOVELTY_WINDOW_TICKS
+}
+
 /// Weights for scoring events.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct EventWeights {
+    /// Additive bonus when an event involves an agent the director is already
+    /// tracking, so the camera sticks with a consistent cast
+    #[serde(default = "default_narrative_continuity_bonus")]
+    pub narrative_continuity_bonus: f32,
+    /// Additive bonus, multiplied by tension severity, when an event is one of
+    /// an active tension's trigger events
+    #[serde(default = "default_tension_severity_bonus")]
+    pub tension_severity_bonus: f32,
+    /// Additive bonus when a conflict's structured outcome names the
+    /// challenged (secondary) agent as the winner
+    #[serde(default = "default_upset_victory_bonus")]
+    pub upset_victory_bonus: f32,
+    /// Additive bonus, multiplied by the thief's desperation, when a theft is
+    /// driven by hunger rather than greed
+    #[serde(default = "default_desperate_theft_bonus")]
+    pub desperate_theft_bonus: f32,
+    /// Score multiplier per recent event of the same type, subtype and
+    /// primary faction; 1.0 disables the novelty penalty
+    #[serde(default = "default_novelty_decay")]
+    pub novelty_decay: f32,
+    /// Lowest the novelty multiplier can fall
+    #[serde(default = "default_novelty_floor")]
+    pub novelty_floor: f32,
+    /// How long an event counts toward later repeats
+    #[serde(default = "default_novelty_window_ticks")]
+    pub novelty_window_ticks: u64,
     /// Base scores by event type name
     #[serde(default)]
     pub base_scores: HashMap<String, f32>,
@@ -51,6 +126,13 @@ impl Default for EventWeights {
         drama_tag_scores.insert("death".to_string(), 0.1);
 
         Self {
+            narrative_continuity_bonus: DEFAULT_NARRATIVE_CONTINUITY_BONUS,
+            tension_severity_bonus: DEFAULT_TENSION_SEVERITY_BONUS,
+            upset_victory_bonus: DEFAULT_UPSET_VICTORY_BONUS,
+            desperate_theft_bonus: DEFAULT_DESPERATE_THEFT_BONUS,
+            novelty_decay: DEFAULT_NOVELTY_DECAY,
+            novelty_floor: DEFAULT_NOVELTY_FLOOR,
+            novelty_window_ticks: DEFAULT_NOVELTY_WINDOW_TICKS,
             base_scores,
             subtype_modifiers: HashMap::new(),
             drama_tag_scores,
@@ -74,17 +156,29 @@ impl EventWeights {
     pub fn drama_tag_score(&self, tag: &str) -> f32 {
         self.drama_tag_scores.get(tag).copied().unwrap_or(0.0)
     }
+
+    /// Score multiplier for an event seen `repeats` times recently.
+    pub fn novelty_factor(&self, repeats: u32) -> f32 {
+        let repeats = i32::try_from(repeats).unwrap_or(i32::MAX);
+        self.novelty_decay.powi(repeats).max(self.novelty_floor).min(1.0)
+    }
 }
 
 /// Context for scoring events relative to current director state.
 #[derive(Debug, Clone, Default)]
 pub struct DirectorContext {
     /// Agents currently being tracked (e.g., following with camera)
-    pub tracked_agents: HashSet<String>,
+    pub tracked_agents: BTreeSet<String>,
     /// Event IDs that are part of active tensions
-    pub active_tension_events: HashSet<String>,
+    pub active_tension_events: BTreeSet<String>,
+    /// Highest severity of the active tensions each trigger event belongs to
+    pub tension_event_severity: BTreeMap<String, f32>,
     /// Current agent being followed (if any)
     pub current_focus: Option<String>,
+    /// How desperate each hungry agent is (0.0 to 1.0), from snapshot needs
+    pub agent_desperation: BTreeMap<String, f32>,
+    /// Recent event counts keyed by `novelty_key`
+    pub recent_event_counts: BTreeMap<String, u32>,
 }
 
 impl DirectorContext {
@@ -110,6 +204,38 @@ impl DirectorContext {
         self.active_tension_events.insert(event_id.into());
     }
 
+    /// Adds a trigger event of an active tension with that tension's severity.
+    ///
+    /// An event shared by several tensions keeps the highest severity.
+    pub fn add_tension_trigger(&mut self, event_id: impl Into<String>, severity: f32) {
+        let event_id = event_id.into();
+        let entry = self
+            .tension_event_severity
+            .entry(event_id.clone())
+            .or_insert(severity);
+        *entry = entry.max(severity);
+        self.active_tension_events.insert(event_id);
+    }
+
+    /// Records how desperate an agent is, clamped to 0.0 to 1.0.
+    pub fn set_desperation(&mut self, agent_id: impl Into<String>, desperation: f32) {
+        self.agent_desperation
+            .insert(agent_id.into(), desperation.clamp(0.0, 1.0));
+    }
+
+    /// Counts an event toward the repeats of its kind.
+    pub fn record_recent_event(&mut self, event: &Event) {
+        *self.recent_event_counts.entry(novelty_key(event)).or_insert(0) += 1;
+    }
+
+    /// How many recent events were of the same kind as this one.
+    pub fn recent_repeats(&self, event: &Event) -> u32 {
+        self.recent_event_counts
+            .get(&novelty_key(event))
+            .copied()
+            .unwrap_or(0)
+    }
+
     /// Sets the current focus agent.
     pub fn set_focus(&mut self, agent_id: impl Into<String>) {
         self.current_focus = Some(agent_id.into());
@@ -124,6 +250,64 @@ impl DirectorContext {
     pub fn is_tension_event(&self, event_id: &str) -> bool {
         self.active_tension_events.contains(event_id)
     }
+
+    /// How desperate an agent is; agents with no recorded needs are not.
+    pub fn desperation(&self, agent_id: &str) -> f32 {
+        self.agent_desperation.get(agent_id).copied().unwrap_or(0.0)
+    }
+
+    /// Severity of the most serious active tension an event triggered, if known.
+    pub fn tension_severity(&self, event_id: &str) -> Option<f32> {
+        self.tension_event_severity.get(event_id).copied()
+    }
+}
+
+/// Per-term contributions to an event's score, from `EventScorer::explain`.
+///
+/// Multiplicative boosts are reported as the amount they added, so the
+/// terms always sum to `total`.
+#[derive(Debug, Clone, Default, PartialEq, Serialize)]
+pub struct ScoreBreakdown {
+    /// Base score for the event type
+    pub base: f32,
+    /// Change from the subtype modifier
+    pub subtype: f32,
+    /// Sum of drama tag scores
+    pub drama_tags: f32,
+    /// Bonus for the challenged side winning a conflict
+    pub upset_victory: f32,
+    /// Bonus for a theft driven by desperation
+    pub motive: f32,
+    /// Tracked-agent boost plus the narrative continuity bonus
+    pub continuity: f32,
+    /// Change from the event being part of an active tension
+    pub tension_linkage: f32,
+    /// Bonus scaled by the linked tension's severity
+    pub tension_severity: f32,
+    /// Amount removed because the event is a repeat (zero or negative)
+    pub novelty: f32,
+    /// Amount removed by the score cap (zero or negative)
+    pub cap: f32,
+    /// Final score, as returned by `EventScorer::score`
+    pub total: f32,
+}
+
+impl ScoreBreakdown {
+    /// Named terms in the order they are applied, excluding the total.
+    pub fn terms(&self) -> [(&'static str, f32); 10] {
+        [
+            ("base", self.base),
+            ("subtype", self.subtype),
+            ("drama_tags", self.drama_tags),
+            ("upset_victory", self.upset_victory),
+            ("motive", self.motive),
+            ("continuity", self.continuity),
+            ("tension_linkage", self.tension_linkage),
+            ("tension_severity", self.tension_severity),
+            ("novelty", self.novelty),
+            ("cap", self.cap),
+        ]
+    }
 }
 
 /// Scores events for dramatic interest.
@@ -182,13 +366,24 @@ impl EventScorer {
             score += self.weights.drama_tag_score(tag);
         }
 
-        // Boost if involves tracked agents
+        // The challenged side turning the tables makes for a better scene
+        let defender = event.actors.secondary.as_ref().map(|s| s.agent_id.as_str());
+        if defender.is_some() && event.conflict_winner() == defender {
+            score += self.weights.upset_victory_bonus;
+        }
+
+        // Stealing to survive is a harder story than stealing from plenty
+        score += self.motive_bonus(event, context);
+
+        // Boost if involves tracked agents, plus a flat narrative continuity
+        // bonus so even low-scoring events keep the current cast on screen
         let involves_tracked = event
             .all_agent_ids()
             .iter()
             .any(|id| context.is_tracked(id));
         if involves_tracked {
             score *= self.tracked_agent_boost;
+            score += self.weights.narrative_continuity_bonus;
         }
 
         // Boost if event is part of active tension
@@ -196,10 +391,87 @@ impl EventScorer {
             score *= self.tension_event_boost;
         }
 
+        // The more serious the situation an event belongs to, the more it matters
+        if let Some(severity) = context.tension_severity(&event.event_id) {
+            score += self.weights.tension_severity_bonus * severity;
+        }
+
+        // The hundredth routine patrol is less interesting than the first
+        score *= self.weights.novelty_factor(context.recent_repeats(event));
+
         // Cap at 1.0 but allow natural scores to accumulate
         score.min(1.5)
     }
 
+    /// Breaks an event's score into its contributing terms, for weight tuning.
+    ///
+    /// Mirrors `score` step by step; kept separate so scoring itself does no
+    /// bookkeeping.
+    pub fn explain(&self, event: &Event, context: &DirectorContext) -> ScoreBreakdown {
+        let mut breakdown = ScoreBreakdown::default();
+
+        let mut score = self.weights.base_score(&event.event_type);
+        breakdown.base = score;
+
+        let subtype_str = subtype_to_string(&event.subtype);
+        if let Some(modifier) = self.weights.subtype_modifier(&subtype_str) {
+            breakdown.subtype = score * modifier - score;
+            score *= modifier;
+        }
+
+        for tag in &event.drama_tags {
+            breakdown.drama_tags += self.weights.drama_tag_score(tag);
+        }
+        score += breakdown.drama_tags;
+
+        let defender = event.actors.secondary.as_ref().map(|s| s.agent_id.as_str());
+        if defender.is_some() && event.conflict_winner() == defender {
+            breakdown.upset_victory = self.weights.upset_victory_bonus;
+            score += breakdown.upset_victory;
+        }
+
+        breakdown.motive = self.motive_bonus(event, context);
+        score += breakdown.motive;
+
+        let involves_tracked = event
+            .all_agent_ids()
+            .iter()
+            .any(|id| context.is_tracked(id));
+        if involves_tracked {
+            let boosted = score * self.tracked_agent_boost + self.weights.narrative_continuity_bonus;
+            breakdown.continuity = boosted - score;
+            score = boosted;
+        }
+
+        if context.is_tension_event(&event.event_id) {
+            breakdown.tension_linkage = score * self.tension_event_boost - score;
+            score *= self.tension_event_boost;
+        }
+
+        if let Some(severity) = context.tension_severity(&event.event_id) {
+            breakdown.tension_severity = self.weights.tension_severity_bonus * severity;
+            score += breakdown.tension_severity;
+        }
+
+        let novelty = self.weights.novelty_factor(context.recent_repeats(event));
+        breakdown.novelty = score * novelty - score;
+        score *= novelty;
+
+        breakdown.total = score.min(1.5);
+        breakdown.cap = breakdown.total - score;
+        breakdown
+    }
+
+    /// Bonus for a theft, scaled by how desperate the thief is.
+    fn motive_bonus(&self, event: &Event, context: &DirectorContext) -> f32 {
+        match event.subtype {
+            EventSubtype::Resource(ResourceSubtype::Steal) => {
+                self.weights.desperate_theft_bonus * context.desperation(&event.actors.primary.agent_id)
+            }
+            _ => 0.0,
+        }
+    }
+
     /// Scores a batch of events.
     pub fn score_batch<'a>(
         &self,
@@ -251,6 +523,17 @@ impl std::error::Error for ScorerError {
     }
 }
 
+/// Groups events that read as "the same thing again": type, subtype and
+/// the primary actor's faction.
+pub fn novelty_key(event: &Event) -> String {
+    format!(
+        "{}.{}.{}",
+        event_type_to_string(&event.event_type),
+        subtype_to_string(&event.subtype),
+        event.actors.primary.faction
+    )
+}
+
 /// Converts an EventType to its lowercase string representation.
 fn event_type_to_string(event_type: &EventType) -> String {
     match event_type {
@@ -294,7 +577,7 @@ mod tests {
     use super::*;
     use sim_events::{
         ActorSet, ActorSnapshot, EventContext, EventOutcome, GeneralOutcome, MovementSubtype,
-        BetrayalSubtype, EventSubtype, Season, SimTimestamp,
+        BetrayalSubtype, ConflictOutcome, ConflictSubtype, EventSubtype, Season, SimTimestamp,
     };
 
     fn make_movement_event(id: &str, agent_id: &str) -> Event {
@@ -393,7 +676,35 @@ mod tests {
         let boosted_score = scorer.score(&event, &context);
 
         assert!(boosted_score > base_score, "Tracked agent should boost score");
-        assert!((boosted_score / base_score - 1.5).abs() < 0.01, "Boost should be 1.5x");
+        let expected = base_score * 1.5 + DEFAULT_NARRATIVE_CONTINUITY_BONUS;
+        assert!((boosted_score - expected).abs() < 0.01, "Boost should be 1.5x plus continuity bonus");
+    }
+
+    #[test]
+    fn test_tracked_agent_outscores_stranger() {
+        let scorer = EventScorer::default();
+        let mut context = DirectorContext::new();
+        context.track_agent("agent_mira");
+
+        // Identical events, differing only in who is involved
+        let tracked = make_movement_event("evt_1", "agent_mira");
+        let stranger = make_movement_event("evt_2", "agent_stranger");
+
+        let tracked_score = scorer.score(&tracked, &context);
+        let stranger_score = scorer.score(&stranger, &context);
+        assert!(
+            tracked_score > stranger_score,
+            "tracked {} should outscore stranger {}",
+            tracked_score,
+            stranger_score
+        );
+
+        // The continuity bonus alone is enough, even without the multiplier
+        let flat = EventScorer::default().with_tracked_boost(1.0);
+        let movement_tracked = make_movement_event("evt_3", "agent_mira");
+        let movement_stranger = make_movement_event("evt_4", "agent_stranger");
+        let gap = flat.score(&movement_tracked, &context) - flat.score(&movement_stranger, &context);
+        assert!((gap - DEFAULT_NARRATIVE_CONTINUITY_BONUS).abs() < 0.001);
     }
 
     #[test]
@@ -412,6 +723,132 @@ mod tests {
         assert!((boosted_score / base_score - 2.0).abs() < 0.01, "Boost should be 2.0x");
     }
 
+    #[test]
+    fn test_tension_severity_bonus() {
+        let scorer = EventScorer::default();
+        let mut context = DirectorContext::new();
+        context.add_tension_trigger("evt_linked", 0.8);
+        context.add_tension_trigger("evt_minor", 0.2);
+
+        let linked = make_movement_event("evt_linked", "agent_1");
+        let minor = make_movement_event("evt_minor", "agent_2");
+        let unrelated = make_movement_event("evt_unrelated", "agent_3");
+
+        let linked_score = scorer.score(&linked, &context);
+        let unrelated_score = scorer.score(&unrelated, &context);
+        let expected = unrelated_score * 2.0 + DEFAULT_TENSION_SEVERITY_BONUS * 0.8;
+        assert!(linked_score > unrelated_score);
+        assert!((linked_score - expected).abs() < 0.001);
+        // A more severe tension lifts its events further
+        assert!(linked_score > scorer.score(&minor, &context));
+    }
+
+    #[test]
+    fn test_desperate_theft_outscores_greedy_theft() {
+        let scorer = EventScorer::default();
+        let mut context = DirectorContext::new();
+        context.set_desperation("agent_hungry", 1.0);
+
+        let theft = |id: &str, agent_id: &str| {
+            let mut event = make_movement_event(id, agent_id);
+            event.event_type = EventType::Resource;
+            event.subtype = EventSubtype::Resource(ResourceSubtype::Steal);
+            event
+        };
+        let desperate = scorer.explain(&theft("evt_1", "agent_hungry"), &context);
+        let greedy = scorer.explain(&theft("evt_2", "agent_fed"), &context);
+
+        assert!((desperate.motive - DEFAULT_DESPERATE_THEFT_BONUS).abs() < 1e-5);
+        assert_eq!(greedy.motive, 0.0);
+        assert!(desperate.total > greedy.total);
+
+        // Hunger only changes how thefts read
+        let walk = make_movement_event("evt_3", "agent_hungry");
+        assert_eq!(scorer.explain(&walk, &context).motive, 0.0);
+    }
+
+    #[test]
+    fn test_fifth_identical_patrol_scores_well_below_first() {
+        let scorer = EventScorer::default();
+        let mut context = DirectorContext::new();
+        let patrol = make_movement_event("evt_patrol", "agent_1");
+
+        let first = scorer.score(&patrol, &context);
+        for _ in 0..4 {
+            context.record_recent_event(&patrol);
+        }
+        let fifth = scorer.score(&patrol, &context);
+        assert!(fifth < first * 0.5, "first {} fifth {}", first, fifth);
+        // Never below the floor
+        assert!(fifth >= first * DEFAULT_NOVELTY_FLOOR - 1e-6);
+
+        // A betrayal after all those patrols still pops
+        let betrayal = make_betrayal_event("evt_betrayal", "agent_1");
+        assert_eq!(context.recent_repeats(&betrayal), 0);
+        assert_eq!(scorer.score(&betrayal, &context), scorer.score(&betrayal, &DirectorContext::new()));
+    }
+
+    #[test]
+    fn test_explain_terms_sum_to_score() {
+        let scorer = EventScorer::default();
+        let mut context = DirectorContext::new();
+        context.track_agent("agent_1");
+        context.add_tension_trigger("evt_linked", 0.6);
+
+        let events = [
+            make_movement_event("evt_plain", "agent_2"),
+            make_movement_event("evt_linked", "agent_1"),
+            make_betrayal_event("evt_betrayal", "agent_1"),
+        ];
+        for event in &events {
+            let breakdown = scorer.explain(event, &context);
+            let sum: f32 = breakdown.terms().iter().map(|(_, value)| value).sum();
+            assert!((breakdown.total - scorer.score(event, &context)).abs() < 1e-5);
+            assert!((sum - breakdown.total).abs() < 1e-5, "{:?}", breakdown);
+        }
+
+        // The betrayal piles up enough to hit the cap
+        let capped = scorer.explain(&events[2], &context);
+        assert!(capped.cap < 0.0);
+        assert!(capped.continuity > 0.0);
+    }
+
+    #[test]
+    fn test_upset_victory_reads_structured_winner() {
+        let scorer = EventScorer::default();
+        let context = DirectorContext::new();
+        let fight = |winner: &str| {
+            let mut event = make_movement_event("evt_fight", "agent_1");
+            event.actors.secondary = Some(ActorSnapshot::new("agent_2", "Rival", "faction", "scout", "loc"));
+            event.event_type = EventType::Conflict;
+            event.subtype = EventSubtype::Conflict(ConflictSubtype::Fight);
+            event.outcome = EventOutcome::Conflict(ConflictOutcome {
+                winner_id: Some(winner.to_string()),
+                lethal: false,
+                relationship_changes: vec![],
+                description: None,
+            });
+            event
+        };
+
+        let aggressor_wins = scorer.score(&fight("agent_1"), &context);
+        let defender_wins = scorer.score(&fight("agent_2"), &context);
+
+        assert!((defender_wins - aggressor_wins - DEFAULT_UPSET_VICTORY_BONUS).abs() < 0.001);
+    }
+
+    #[test]
+    fn test_tension_trigger_keeps_highest_severity() {
+        let mut context = DirectorContext::new();
+        context.add_tension_trigger("evt_shared", 0.4);
+        context.add_tension_trigger("evt_shared", 0.9);
+        context.add_tension_trigger("evt_shared", 0.6);
+
+        assert!(context.is_tension_event("evt_shared"));
+        assert_eq!(context.tension_severity("evt_shared"), Some(0.9));
+        assert_eq!(context.tension_severity("evt_other"), None);
+    }
+
     #[test]
     fn test_scorer_drama_tags_additive() {
         let scorer = EventScorer::default();
@@ -466,8 +903,8 @@ mod tests {
         let event = make_movement_event("evt_1", "agent_1");
         let score = scorer.score(&event, &context);
 
-        // Base movement (0.1) * tracked boost (2.0) = 0.2
-        assert!((score - 0.2).abs() < 0.01);
+        // Base movement (0.1) * tracked boost (2.0) + continuity bonus (0.1) = 0.3
+        assert!((score - 0.3).abs() < 0.01);
     }
 
     #[test]
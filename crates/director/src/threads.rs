@@ -4,7 +4,7 @@
 
 use serde::{Deserialize, Serialize};
 use sim_events::{Event, Tension, TensionStatus};
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 
 /// Status of a narrative thread's lifecycle.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, Default)]
@@ -53,9 +53,18 @@ pub struct NarrativeThread {
     /// Key agents in this thread
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub key_agents: Vec<String>,
+    /// Locations where this thread plays out
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub key_locations: Vec<String>,
     /// Key event IDs in this thread
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub key_events: Vec<String>,
+    /// Tick of each key event, parallel to `key_events`
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub key_event_ticks: Vec<u64>,
+    /// Number of thread events each agent took part in
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub agent_involvement: BTreeMap<String, u32>,
     /// Type of narrative thread (e.g., "betrayal_arc", "succession")
     pub thread_type: String,
     /// Human-readable summary
@@ -68,6 +77,12 @@ pub struct NarrativeThread {
     /// Last tick when this thread was actively shown
     #[serde(skip_serializing_if = "Option::is_none")]
     pub last_shown_tick: Option<u64>,
+    /// Ticks left until the likeliest predicted outcome, for overlay countdowns
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub predicted_outcome_in_ticks: Option<u64>,
+    /// The predicted outcome's estimated tick has passed without it happening
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub prediction_overdue: bool,
 }
 
 impl NarrativeThread {
@@ -85,12 +100,17 @@ impl NarrativeThread {
             status: ThreadStatus::Developing,
             tension_ids: Vec::new(),
             key_agents: Vec::new(),
+            key_locations: Vec::new(),
             key_events: Vec::new(),
+            key_event_ticks: Vec::new(),
+            agent_involvement: BTreeMap::new(),
             thread_type: thread_type.into(),
             summary: summary.into(),
             hook: String::new(),
             screen_time_ticks: 0,
             last_shown_tick: None,
+            predicted_outcome_in_ticks: None,
+            prediction_overdue: false,
         }
     }
 
@@ -106,10 +126,14 @@ impl NarrativeThread {
         for agent in &tension.key_agents {
             thread.add_agent(&agent.agent_id);
         }
+        for location in &tension.key_locations {
+            thread.add_location(location);
+        }
         if !tension.narrative_hooks.is_empty() {
             thread.hook = tension.narrative_hooks[0].clone();
         }
         thread.status = ThreadStatus::from_tension_status(tension.status);
+        thread.update_prediction(tension, tension.last_updated_tick);
         thread
     }
 
@@ -129,9 +153,38 @@ impl NarrativeThread {
         }
     }
 
-    /// Adds a key event to this thread.
+    /// Adds a key location to this thread.
+    pub fn add_location(&mut self, location_id: impl Into<String>) {
+        let id = location_id.into();
+        if !self.key_locations.contains(&id) {
+            self.key_locations.push(id);
+        }
+    }
+
+    /// Adds a key event to this thread, stamped with the last activity tick.
     pub fn add_event(&mut self, event_id: impl Into<String>) {
         self.key_events.push(event_id.into());
+        self.key_event_ticks.push(self.last_updated_tick);
+    }
+
+    /// Counts an event's participants toward their involvement in this thread.
+    pub fn record_involvement<'a>(&mut self, agent_ids: impl IntoIterator<Item = &'a str>) {
+        for agent_id in agent_ids {
+            *self.agent_involvement.entry(agent_id.to_string()).or_insert(0) += 1;
+        }
+    }
+
+    /// The most central key agent, by number of thread events they took part in.
+    ///
+    /// Ties go to whoever joined the thread first, so the agents the thread
+    /// was founded on keep the point of view until someone clearly overtakes them.
+    pub fn protagonist(&self) -> Option<&str> {
+        // max_by_key keeps the last maximum, so walk backwards to favour early agents
+        self.key_agents
+            .iter()
+            .rev()
+            .max_by_key(|agent_id| self.agent_involvement.get(*agent_id).copied().unwrap_or(0))
+            .map(String::as_str)
     }
 
     /// Sets the hook.
@@ -171,10 +224,31 @@ impl NarrativeThread {
         self.status = ThreadStatus::from_tension_status(tension.status);
         self.last_updated_tick = current_tick;
 
-        // Add any new agents from the tension
+        // Add any new agents and locations from the tension
         for agent in &tension.key_agents {
             self.add_agent(&agent.agent_id);
         }
+        for location in &tension.key_locations {
+            self.add_location(location);
+        }
+        self.update_prediction(tension, current_tick);
+    }
+
+    /// Recomputes the countdown to the tension's likeliest timed outcome.
+    ///
+    /// Estimates are relative to the tension's last update, so the countdown
+    /// keeps running between re-estimates. Once the estimated tick has passed
+    /// the countdown stays at zero and the prediction is flagged overdue.
+    pub fn update_prediction(&mut self, tension: &Tension, current_tick: u64) {
+        let due_tick = tension
+            .predicted_outcomes
+            .iter()
+            .filter_map(|o| o.estimated_ticks_until.map(|ticks| (o.probability, ticks)))
+            .max_by(|(a, _), (b, _)| a.total_cmp(b))
+            .map(|(_, ticks)| tension.last_updated_tick + ticks);
+
+        self.predicted_outcome_in_ticks = due_tick.map(|due| due.saturating_sub(current_tick));
+        self.prediction_overdue = due_tick.is_some_and(|due| current_tick > due);
     }
 }
 
@@ -217,18 +291,25 @@ impl Default for ThreadTrackerConfig {
 }
 
 /// Manages a collection of narrative threads.
-#[derive(Debug, Clone)]
+///
+/// Serialization captures the threads but not the config, which is
+/// reloaded from the director's config file and reapplied with `set_config`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ThreadTracker {
     /// Active threads indexed by thread ID
     threads: HashMap<String, NarrativeThread>,
     /// Maps tension IDs to thread IDs
     tension_to_thread: HashMap<String, String>,
     /// Configuration
+    #[serde(skip)]
     config: ThreadTrackerConfig,
     /// Next thread sequence number
     next_sequence: u64,
     /// Current tick for tracking
     current_tick: u64,
+    /// Threads pruned from tracking, kept for the story outline
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    retired: Vec<NarrativeThread>,
 }
 
 impl ThreadTracker {
@@ -245,9 +326,15 @@ impl ThreadTracker {
             config,
             next_sequence: 1,
             current_tick: 0,
+            retired: Vec::new(),
         }
     }
 
+    /// Replaces the configuration, keeping all existing threads.
+    pub fn set_config(&mut self, config: ThreadTrackerConfig) {
+        self.config = config;
+    }
+
     /// Updates threads based on new events and tensions.
     ///
     /// This method:
@@ -311,8 +398,9 @@ impl ThreadTracker {
                 .any(|id| thread.involves_agent(id));
 
             if involves_thread_agent {
-                thread.add_event(&event.event_id);
                 thread.touch(event.timestamp.tick);
+                thread.add_event(&event.event_id);
+                thread.record_involvement(event.all_agent_ids());
 
                 // Add any new agents from the event
                 for agent_id in event.all_agent_ids() {
@@ -356,12 +444,13 @@ impl ThreadTracker {
         }
     }
 
-    /// Removes a thread and its tension mappings.
+    /// Stops tracking a thread, retiring it to the outline.
     fn remove_thread(&mut self, thread_id: &str) {
         if let Some(thread) = self.threads.remove(thread_id) {
             for tension_id in &thread.tension_ids {
                 self.tension_to_thread.remove(tension_id);
             }
+            self.retired.push(thread);
         }
     }
 
@@ -423,6 +512,104 @@ impl ThreadTracker {
     pub fn set_current_tick(&mut self, tick: u64) {
         self.current_tick = tick;
     }
+
+    /// Outlines every thread seen so far, including dormant, concluded and
+    /// pruned ones, in the order they began.
+    pub fn export_outline(&self) -> Vec<ThreadOutline> {
+        let mut outlines: Vec<ThreadOutline> = self
+            .threads
+            .values()
+            .chain(&self.retired)
+            .map(ThreadOutline::from_thread)
+            .collect();
+        outlines.sort_by(|a, b| {
+            a.start_tick
+                .cmp(&b.start_tick)
+                .then_with(|| a.thread_id.cmp(&b.thread_id))
+        });
+        outlines
+    }
+}
+
+/// A key event in a thread outline.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct OutlineEvent {
+    pub event_id: String,
+    pub tick: u64,
+}
+
+/// How a narrative thread developed, for reading after a run.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ThreadOutline {
+    pub thread_id: String,
+    /// The thread's hook, or its type if it has none
+    pub title: String,
+    pub summary: String,
+    pub status: ThreadStatus,
+    pub key_agents: Vec<String>,
+    pub start_tick: u64,
+    pub end_tick: u64,
+    /// Key events in the order they happened
+    pub events: Vec<OutlineEvent>,
+}
+
+impl ThreadOutline {
+    /// Builds the outline of a single thread.
+    pub fn from_thread(thread: &NarrativeThread) -> Self {
+        let mut events: Vec<OutlineEvent> = thread
+            .key_events
+            .iter()
+            .enumerate()
+            .map(|(i, event_id)| OutlineEvent {
+                event_id: event_id.clone(),
+                tick: thread
+                    .key_event_ticks
+                    .get(i)
+                    .copied()
+                    .unwrap_or(thread.created_at_tick),
+            })
+            .collect();
+        // Stable, so events from the same tick keep the order they arrived in
+        events.sort_by_key(|event| event.tick);
+
+        let title = if thread.hook.is_empty() {
+            thread.thread_type.replace('_', " ")
+        } else {
+            thread.hook.clone()
+        };
+
+        Self {
+            thread_id: thread.thread_id.clone(),
+            title,
+            summary: thread.summary.clone(),
+            status: thread.status,
+            key_agents: thread.key_agents.clone(),
+            start_tick: thread.created_at_tick,
+            end_tick: events
+                .last()
+                .map_or(thread.last_updated_tick, |event| event.tick.max(thread.last_updated_tick)),
+            events,
+        }
+    }
+
+    /// Renders the outline as a Markdown section.
+    pub fn to_markdown(&self) -> String {
+        let mut md = format!("## {}\n\n{}\n\n", self.title, self.summary);
+        md.push_str(&format!(
+            "- Status: {:?}\n- Ticks: {}-{}\n",
+            self.status, self.start_tick, self.end_tick
+        ));
+        if !self.key_agents.is_empty() {
+            md.push_str(&format!("- Key agents: {}\n", self.key_agents.join(", ")));
+        }
+        if !self.events.is_empty() {
+            md.push('\n');
+            for (i, event) in self.events.iter().enumerate() {
+                md.push_str(&format!("{}. `{}` (tick {})\n", i + 1, event.event_id, event.tick));
+            }
+        }
+        md
+    }
 }
 
 impl Default for ThreadTracker {
@@ -663,6 +850,66 @@ mod tests {
         assert!(thread.key_events.contains(&"evt_00001".to_string()));
     }
 
+    #[test]
+    fn test_escalating_prediction_counts_down() {
+        use sim_events::PredictedOutcome;
+
+        let mut tracker = ThreadTracker::new();
+        let mut tension = make_test_tension("tens_001", 0.6, TensionStatus::Escalating);
+        tension.last_updated_tick = 1000;
+        tension.add_predicted_outcome(PredictedOutcome::new("stays_loyal", 0.3, "low").with_estimated_ticks(900));
+        tension.add_predicted_outcome(PredictedOutcome::new("defects", 0.7, "high").with_estimated_ticks(500));
+        tracker.update(&[], std::slice::from_ref(&tension));
+
+        let countdown = |tracker: &ThreadTracker| {
+            tracker.get_thread_for_tension("tens_001").unwrap().predicted_outcome_in_ticks.unwrap()
+        };
+        // The likelier outcome sets the countdown
+        let mut previous = countdown(&tracker);
+        assert_eq!(previous, 500);
+
+        // Each tick the tension escalates and the simulation re-estimates sooner
+        for tick in [1010, 1020, 1030] {
+            tension.last_updated_tick = tick;
+            tension.severity += 0.05;
+            tension.predicted_outcomes[1].estimated_ticks_until = Some(1500 - tick - 20);
+            tracker.update(&[], std::slice::from_ref(&tension));
+            let current = countdown(&tracker);
+            assert!(current < previous, "{} !< {}", current, previous);
+            previous = current;
+        }
+
+        // Past the estimate the countdown holds at zero and is flagged
+        let thread = tracker.get_thread_mut("thread_00001").unwrap();
+        thread.update_prediction(&tension, 2000);
+        assert_eq!(thread.predicted_outcome_in_ticks, Some(0));
+        assert!(thread.prediction_overdue);
+    }
+
+    #[test]
+    fn test_thread_protagonist_by_involvement() {
+        let mut tracker = ThreadTracker::new();
+        let mut tension = make_test_tension("tens_00001", 0.5, TensionStatus::Escalating);
+        tension.add_agent_inline("agent_corin", "target", "unaware");
+        tracker.update(&[], &[tension]);
+
+        // Tied at zero: the first key agent leads
+        let thread = tracker.get_thread_for_tension("tens_00001").unwrap();
+        assert_eq!(thread.protagonist(), Some("agent_mira"));
+
+        // Tied at one each: still the first key agent
+        let corin_event = make_test_event("evt_00001", 1500, "agent_corin");
+        let mira_event = make_test_event("evt_00002", 1510, "agent_mira");
+        tracker.update(&[ScoredEvent::new(&corin_event, 0.5), ScoredEvent::new(&mira_event, 0.5)], &[]);
+        let thread = tracker.get_thread_for_tension("tens_00001").unwrap();
+        assert_eq!(thread.protagonist(), Some("agent_mira"));
+
+        let second_corin_event = make_test_event("evt_00003", 1520, "agent_corin");
+        tracker.update(&[ScoredEvent::new(&second_corin_event, 0.5)], &[]);
+        let thread = tracker.get_thread_for_tension("tens_00001").unwrap();
+        assert_eq!(thread.protagonist(), Some("agent_corin"));
+    }
+
     #[test]
     fn test_thread_tracker_active_threads() {
         let mut tracker = ThreadTracker::new();
@@ -736,6 +983,36 @@ mod tests {
         assert_eq!(thread.status, ThreadStatus::Dormant);
     }
 
+    #[test]
+    fn test_outline_lists_thread_events_in_order() {
+        let mut tracker = ThreadTracker::new();
+        let tension = make_test_tension("tens_00001", 0.5, TensionStatus::Escalating);
+        tracker.update(&[], &[tension]);
+
+        let first = make_test_event("evt_00001", 1500, "agent_mira");
+        let late = make_test_event("evt_00003", 2400, "agent_mira");
+        let middle = make_test_event("evt_00002", 1900, "agent_mira");
+        tracker.update(&[ScoredEvent::new(&first, 0.5)], &[]);
+        tracker.update(&[ScoredEvent::new(&late, 0.5), ScoredEvent::new(&middle, 0.5)], &[]);
+        let thread_id = tracker.get_thread_for_tension("tens_00001").unwrap().thread_id.clone();
+        tracker.mark_concluded(&thread_id);
+
+        let outlines = tracker.export_outline();
+        assert_eq!(outlines.len(), 1);
+        let outline = &outlines[0];
+        assert_eq!(outline.status, ThreadStatus::Concluded);
+        assert_eq!(outline.summary, "Test tension");
+        assert_eq!(outline.start_tick, 1000);
+        assert_eq!(outline.end_tick, 2400);
+        let ids: Vec<&str> = outline.events.iter().map(|e| e.event_id.as_str()).collect();
+        assert_eq!(ids, vec!["evt_00001", "evt_00002", "evt_00003"]);
+
+        let md = outline.to_markdown();
+        assert!(md.contains("1. `evt_00001` (tick 1500)"));
+        assert!(md.contains("3. `evt_00003` (tick 2400)"));
+        assert!(serde_json::to_string(&outlines).unwrap().contains("\"start_tick\":1000"));
+    }
+
     #[test]
     fn test_thread_tracker_max_threads() {
         let mut tracker = ThreadTracker::with_config(ThreadTrackerConfig {
@@ -4,9 +4,13 @@
 //! works correctly end-to-end.
 
 use director::{
-    CommentaryType, Director, DirectorConfig, DirectorOutput, OutputWriter, PacingHint,
+    CameraMode, CommentaryType, Director, DirectorConfig, DirectorOutput, OutputReader,
+    OutputWriter, PacingHint,
+};
+use sim_events::{
+    create_movement_event, ActorSnapshot, Event, Season, SimTimestamp, Tension, TensionStatus,
+    TensionType, WorldSnapshot,
 };
-use sim_events::{Event, Season, SimTimestamp, Tension, TensionStatus, TensionType, WorldSnapshot};
 use std::fs;
 use tempfile::tempdir;
 
@@ -110,9 +114,14 @@ fn test_betrayal_gets_focus() {
 
     let output = director.process_tick(&events, &tensions, &state);
 
-    // The camera should focus on the betrayal tension (highest severity at 0.85)
+    // The camera should focus on the betrayal tension (highest severity at 0.85),
+    // after the establishing shot that opens its thread
     assert!(!output.camera_script.is_empty());
-    let instruction = &output.camera_script[0];
+    let instruction = output
+        .camera_script
+        .iter()
+        .find(|i| !matches!(i.camera_mode, CameraMode::Establishing { .. }))
+        .expect("Expected a subject instruction");
 
     // Should have a tension ID and focus on betrayal-related agents
     assert!(
@@ -496,3 +505,60 @@ fn test_golden_output() {
         );
     }
 }
+
+/// Test the file-driven director run over a small recorded simulation.
+#[test]
+fn test_run_from_files() {
+    let dir = tempdir().expect("Failed to create temp dir");
+    let snapshots_dir = dir.path().join("snapshots");
+    fs::create_dir_all(&snapshots_dir).expect("Failed to create snapshots dir");
+
+    // Only tick 10 has a snapshot; ticks 12 and 15 must reuse it
+    let mut snapshot = load_sample_state();
+    snapshot.timestamp = SimTimestamp::new(10, 1, Season::Spring, 1);
+    fs::write(
+        snapshots_dir.join("snap_000010.json"),
+        serde_json::to_string(&snapshot).unwrap(),
+    )
+    .expect("Failed to write snapshot");
+
+    let movement = |id: &str, tick: u64| {
+        create_movement_event(
+            id,
+            SimTimestamp::new(tick, 1, Season::Spring, 1),
+            ActorSnapshot::new("agent_mira", "Mira", "thornwood", "scout", "thornwood_village"),
+            "patrol",
+            "eastern_woods",
+        )
+        .to_jsonl()
+        .unwrap()
+    };
+    let log = [
+        movement("evt_00000001", 5),
+        movement("evt_00000002", 10),
+        movement("evt_00000003", 10),
+        movement("evt_00000004", 12),
+        "{not an event".to_string(),
+        movement("evt_00000005", 15),
+    ]
+    .join("\n");
+    let events_path = dir.path().join("events.jsonl");
+    fs::write(&events_path, log).expect("Failed to write events");
+
+    let out_dir = dir.path().join("director");
+    let mut director = Director::with_defaults();
+    let ticks = director
+        .run_from_files(&events_path, &snapshots_dir, &out_dir)
+        .expect("Replay should succeed");
+
+    assert_eq!(ticks, 4);
+    assert_eq!(director.current_tick(), 15);
+
+    let outputs = OutputReader::from_dir(&out_dir).read_all().expect("Failed to read output");
+    let output_ticks: Vec<u64> = outputs.iter().map(|o| o.generated_at_tick).collect();
+    assert_eq!(output_ticks, vec![5, 10, 12, 15]);
+
+    let summary: serde_json::Value =
+        serde_json::from_str(&fs::read_to_string(out_dir.join("summary.json")).unwrap()).unwrap();
+    assert_eq!(summary["total_events_processed"], 5);
+}
@@ -0,0 +1,128 @@
+//! Action generation benchmark
+//!
+//! Compares running the `generate_*` systems one after another on a single
+//! thread against letting Bevy run them in parallel now that each writes to
+//! its own action buffer, at 500 agents.
+//!
+//! Run with `cargo bench -p sim-core --bench action_generation`.
+
+use std::time::{Duration, Instant};
+
+use bevy_ecs::prelude::*;
+use bevy_ecs::schedule::ExecutorKind;
+use rand::rngs::SmallRng;
+use rand::{Rng, SeedableRng};
+
+use sim_core::components::agent::{AgentId, AgentName, Goals, Needs, Role, Traits};
+use sim_core::components::faction::{Faction, FactionMembership, FactionRegistry};
+use sim_core::components::social::{MemoryBank, Relationship, RelationshipGraph, Trust};
+use sim_core::components::world::{Position, WorldState};
+use sim_core::systems::{
+    generate_communication_actions, generate_conflict_actions, generate_faction_actions,
+    generate_social_actions, AgentsByLocation, PendingActions, TerritorialFriction,
+};
+
+const AGENTS: usize = 500;
+const LOCATIONS: usize = 20;
+const TICKS: usize = 200;
+/// Relationships each agent holds toward others
+const RELATIONSHIPS_PER_AGENT: usize = 10;
+
+fn setup_world() -> World {
+    let mut rng = SmallRng::seed_from_u64(42);
+    let mut world = World::new();
+    world.insert_resource(WorldState::new());
+    world.insert_resource(MemoryBank::new());
+    world.insert_resource(PendingActions::new());
+    world.insert_resource(TerritorialFriction::new());
+
+    let mut registry = FactionRegistry::new();
+    let mut thornwood = Faction::new("thornwood", "Thornwood", "loc_0");
+    thornwood.leader = Some("agent_0000".to_string());
+    registry.register(thornwood);
+    let mut ironmere = Faction::new("ironmere", "Ironmere", "loc_1");
+    ironmere.leader = Some("agent_0001".to_string());
+    registry.register(ironmere);
+    world.insert_resource(registry);
+
+    let mut by_location = AgentsByLocation::new();
+    let mut graph = RelationshipGraph::new();
+    for i in 0..AGENTS {
+        let id = format!("agent_{:04}", i);
+        let location = format!("loc_{}", i % LOCATIONS);
+        let faction = if i % 2 == 0 { "thornwood" } else { "ironmere" };
+        let role = if i < 2 { Role::Leader } else { Role::Laborer };
+
+        for _ in 0..RELATIONSHIPS_PER_AGENT {
+            let other = format!("agent_{:04}", rng.gen_range(0..AGENTS));
+            let trust = Trust::new(
+                rng.gen_range(-1.0..1.0),
+                rng.gen_range(-1.0..1.0),
+                rng.gen_range(-1.0..1.0),
+            );
+            graph.set(&id, Relationship::new(other).with_trust(trust));
+        }
+        by_location.add(&location, &id);
+        world.spawn((
+            AgentId(id.clone()),
+            AgentName(id),
+            Position::new(location),
+            FactionMembership::new(faction, role),
+            Needs::default(),
+            Traits {
+                boldness: rng.gen(),
+                ambition: rng.gen(),
+                sociability: rng.gen(),
+                ..Traits::default()
+            },
+            Goals::new(),
+        ));
+    }
+    world.insert_resource(by_location);
+    world.insert_resource(graph);
+    world
+}
+
+fn run(label: &str, parallel: bool) -> Duration {
+    let mut world = setup_world();
+    let mut schedule = Schedule::default();
+    let systems = (
+        generate_communication_actions,
+        generate_social_actions,
+        generate_faction_actions,
+        generate_conflict_actions,
+    );
+    if parallel {
+        schedule.set_executor_kind(ExecutorKind::MultiThreaded);
+        schedule.add_systems(systems);
+    } else {
+        schedule.set_executor_kind(ExecutorKind::SingleThreaded);
+        schedule.add_systems(systems.chain());
+    }
+    schedule.run(&mut world);
+
+    let start = Instant::now();
+    for _ in 0..TICKS {
+        world.resource_mut::<PendingActions>().clear();
+        schedule.run(&mut world);
+    }
+    let elapsed = start.elapsed();
+    println!(
+        "{:<12} {} agents x {} ticks: {:>10.3?} ({:.3?}/tick)",
+        label,
+        AGENTS,
+        TICKS,
+        elapsed,
+        elapsed / TICKS as u32
+    );
+    elapsed
+}
+
+fn main() {
+    let sequential = run("sequential", false);
+    let parallel = run("parallel", true);
+    println!(
+        "speedup: {:.2}x",
+        sequential.as_secs_f64() / parallel.as_secs_f64().max(f64::EPSILON)
+    );
+}
@@ -0,0 +1,101 @@
+//! Location index benchmark
+//!
+//! Compares rebuilding `AgentsByLocation` from scratch every tick against the
+//! incremental index at 500 agents, with a small fraction moving each tick.
+//!
+//! Run with `cargo bench -p sim-core --bench location_index`.
+
+use std::time::{Duration, Instant};
+
+use bevy_ecs::prelude::*;
+use rand::rngs::SmallRng;
+use rand::{Rng, SeedableRng};
+
+use sim_core::components::agent::AgentId;
+use sim_core::components::world::Position;
+use sim_core::systems::{build_location_index, AgentsByLocation};
+
+const AGENTS: usize = 500;
+const LOCATIONS: usize = 20;
+const TICKS: usize = 1000;
+/// Fraction of agents that move each tick
+const MOVE_RATE: f64 = 0.05;
+
+/// The previous behavior: clear and re-add every agent each tick
+fn rebuild_location_index(
+    mut agents_by_location: ResMut<AgentsByLocation>,
+    query: Query<(&AgentId, &Position)>,
+) {
+    agents_by_location.clear();
+    for (agent_id, position) in query.iter() {
+        agents_by_location.add(&position.location_id, &agent_id.0);
+    }
+}
+
+fn setup_world() -> World {
+    let mut world = World::new();
+    world.insert_resource(AgentsByLocation::new());
+    for i in 0..AGENTS {
+        world.spawn((
+            AgentId(format!("agent_{:04}", i)),
+            Position::new(format!("loc_{}", i % LOCATIONS)),
+        ));
+    }
+    world
+}
+
+/// Moves a random subset of agents, reporting moves to the index if asked
+fn move_agents(world: &mut World, rng: &mut SmallRng, report_moves: bool) {
+    let mut query = world.query::<(&AgentId, &mut Position)>();
+    let mut moves = Vec::new();
+    for (agent_id, mut position) in query.iter_mut(world) {
+        if rng.gen_bool(MOVE_RATE) {
+            let to = format!("loc_{}", rng.gen_range(0..LOCATIONS));
+            moves.push((agent_id.0.clone(), position.location_id.clone(), to.clone()));
+            position.location_id = to;
+        }
+    }
+    if report_moves {
+        let mut index = world.resource_mut::<AgentsByLocation>();
+        for (agent_id, from, to) in moves {
+            index.moved(&agent_id, &from, &to);
+        }
+    }
+}
+
+fn run(label: &str, incremental: bool) -> Duration {
+    let mut world = setup_world();
+    let mut rng = SmallRng::seed_from_u64(42);
+    let mut schedule = Schedule::default();
+    if incremental {
+        schedule.add_systems(build_location_index);
+    } else {
+        schedule.add_systems(rebuild_location_index);
+    }
+    schedule.run(&mut world);
+
+    let start = Instant::now();
+    for _ in 0..TICKS {
+        move_agents(&mut world, &mut rng, incremental);
+        schedule.run(&mut world);
+    }
+    let elapsed = start.elapsed();
+    println!(
+        "{:<12} {} agents x {} ticks: {:>10.3?} ({:.3?}/tick)",
+        label,
+        AGENTS,
+        TICKS,
+        elapsed,
+        elapsed / TICKS as u32
+    );
+    elapsed
+}
+
+fn main() {
+    let full = run("full", false);
+    let incremental = run("incremental", true);
+    println!(
+        "speedup: {:.2}x",
+        full.as_secs_f64() / incremental.as_secs_f64().max(f64::EPSILON)
+    );
+}
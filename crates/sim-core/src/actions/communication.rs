@@ -15,6 +15,8 @@ pub enum CommunicationType {
     Lie,
     /// Confess a secret
     Confess,
+    /// Woo a member of another faction toward the actor's faction
+    Recruit,
 }
 
 /// Whether targeting an individual or a group
@@ -117,6 +119,22 @@ impl CommunicationAction {
         }
     }
 
+    /// Create a recruit action
+    pub fn recruit(
+        actor_id: impl Into<String>,
+        target_id: impl Into<String>,
+    ) -> Self {
+        Self {
+            actor_id: actor_id.into(),
+            communication_type: CommunicationType::Recruit,
+            target_mode: TargetMode::Individual, // Recruitment is a private pitch
+            target_id: target_id.into(),
+            memory_id: None,
+            subject_id: None,
+            content: None,
+        }
+    }
+
     /// Check if this is individual communication
     pub fn is_individual(&self) -> bool {
         self.target_mode == TargetMode::Individual
@@ -207,6 +225,12 @@ pub mod communication_weights {
     /// Bonus for negative memory about third party
     pub const NEGATIVE_GOSSIP_BONUS: f32 = 0.2;
 
+    /// Bonus for a secret the speaker was meant to keep
+    pub const SECRET_GOSSIP_BONUS: f32 = 0.5;
+
+    /// Bonus for a memory about someone the listener has a relationship with
+    pub const LISTENER_RELATION_GOSSIP_BONUS: f32 = 0.3;
+
     /// Bonus for same faction listener
     pub const SAME_FACTION_BONUS: f32 = 0.2;
 
@@ -219,11 +243,53 @@ pub mod communication_weights {
     /// Fidelity reduction for group communication (noisier)
     pub const GROUP_FIDELITY_MULTIPLIER: f32 = 0.9;
 
+    /// Further fidelity decay per recipient beyond the first in a group
+    pub const GROUP_FIDELITY_DECAY: f32 = 0.95;
+
+    /// Group fidelity multiplier never drops below this, however big the crowd
+    pub const MIN_GROUP_FIDELITY_MULTIPLIER: f32 = 0.4;
+
     /// Fidelity reduction per hop in source chain
     pub const SECONDHAND_FIDELITY_MULTIPLIER: f32 = 0.7;
 
     /// Emotional weight reduction for secondhand
     pub const SECONDHAND_EMOTIONAL_MULTIPLIER: f32 = 0.5;
+
+    /// Base weight for recruiting a member of another faction
+    pub const RECRUIT_BASE: f32 = 0.05;
+
+    /// Intoxication level at which agents start letting secrets slip
+    pub const DRUNK_CONFESS_THRESHOLD: f32 = 0.4;
+
+    /// Base weight for a drunken confession
+    pub const DRUNK_CONFESS_BASE: f32 = 0.2;
+
+    /// Confession weight added per point of intoxication
+    pub const DRUNK_CONFESS_INTOX_MULT: f32 = 1.0;
+
+    /// Recruiters need at least this much sociability
+    pub const RECRUIT_MIN_SOCIABILITY: f32 = 0.6;
+
+    /// Recruit bonus per point of sociability
+    pub const RECRUIT_SOCIABILITY_MULT: f32 = 0.2;
+
+    /// Recruit bonus when the target is isolated in their faction
+    pub const RECRUIT_ISOLATED_BONUS: f32 = 0.1;
+
+    /// Alignment the target gains toward the recruiter
+    pub const RECRUIT_ALIGNMENT_GAIN: f32 = 0.25;
+
+    /// Reliability the target gains toward the recruiter
+    pub const RECRUIT_RELIABILITY_GAIN: f32 = 0.1;
+
+    /// Alignment the target loses toward their own leader
+    pub const RECRUIT_LEADER_DOUBT: f32 = 0.15;
+
+    /// Targets at or above this loyalty refuse the recruiter outright
+    pub const REFUSE_BRIBE_LOYALTY_THRESHOLD: f32 = 0.7;
+
+    /// Alignment the refusing target loses toward the recruiter
+    pub const REFUSE_BRIBE_ALIGNMENT_LOSS: f32 = 0.1;
 }
 
 /// Result of a communication action
@@ -11,6 +11,8 @@ pub enum ConflictActionType {
     Argue,
     /// Physical fight - causes harm, high relationship damage
     Fight,
+    /// Formal duel between mutual rivals - single decisive winner, possibly lethal
+    Duel,
     /// Sabotage target's resources or reputation
     Sabotage,
     /// Kill target (high risk, requires extreme conditions)
@@ -60,6 +62,21 @@ impl ConflictAction {
         }
     }
 
+    /// Create a duel challenge
+    pub fn duel(
+        actor_id: impl Into<String>,
+        target_id: impl Into<String>,
+        reason: Option<String>,
+    ) -> Self {
+        Self {
+            actor_id: actor_id.into(),
+            action_type: ConflictActionType::Duel,
+            target_id: target_id.into(),
+            reason,
+            related_goal: None,
+        }
+    }
+
     /// Create a sabotage action
     pub fn sabotage(
         actor_id: impl Into<String>,
@@ -119,6 +136,17 @@ pub mod conflict_weights {
     /// Fight penalty for low boldness
     pub const FIGHT_LOW_BOLDNESS_PENALTY: f32 = 0.1;
 
+    /// Base weight for duel challenge (rare - requires mutual hostility)
+    pub const DUEL_BASE: f32 = 0.03;
+    /// Duel bonus for revenge goal
+    pub const DUEL_REVENGE_BONUS: f32 = 0.15;
+    /// Duel bonus based on boldness trait
+    pub const DUEL_BOLDNESS_MULT: f32 = 0.1;
+    /// Both duelists need at least this boldness
+    pub const DUEL_MIN_BOLDNESS: f32 = 0.6;
+    /// Both duelists must distrust each other at least this much
+    pub const DUEL_MUTUAL_DISTRUST: f32 = -0.3;
+
     /// Base weight for sabotage action (low - sneaky action)
     pub const SABOTAGE_BASE: f32 = 0.03;
     /// Sabotage bonus for revenge goal
@@ -143,14 +171,30 @@ pub mod conflict_weights {
     pub const ARGUE_RELATIONSHIP_DAMAGE: f32 = 0.05;
     /// Relationship damage from fight
     pub const FIGHT_RELATIONSHIP_DAMAGE: f32 = 0.2;
+    /// Relationship damage from duel (applied in both directions)
+    pub const DUEL_RELATIONSHIP_DAMAGE: f32 = 0.35;
+    /// Chance that the loser of a duel is killed
+    pub const DUEL_LETHAL_CHANCE: f32 = 0.25;
     /// Relationship damage from sabotage (if discovered)
     pub const SABOTAGE_RELATIONSHIP_DAMAGE: f32 = 0.3;
     /// Detection chance for sabotage
     pub const SABOTAGE_DETECTION_CHANCE: f32 = 0.4;
+    /// Fraction of the target faction's grain destroyed by undetected sabotage
+    pub const SABOTAGE_GRAIN_DESTROYED: f32 = 0.1;
+    /// Fraction of the target faction's iron destroyed by undetected sabotage
+    pub const SABOTAGE_IRON_DESTROYED: f32 = 0.1;
     /// Fight resolution chance (argument resolves conflict)
     pub const ARGUE_RESOLUTION_CHANCE: f32 = 0.3;
     /// Fight success rate modifier based on capability
     pub const FIGHT_CAPABILITY_MODIFIER: f32 = 0.3;
+    /// Multiplier on all conflict weights against members of an allied faction
+    pub const ALLIED_HOSTILITY_MULT: f32 = 0.3;
+    /// Multiplier on all conflict weights against a rival faction contesting the same location
+    pub const TERRITORIAL_FRICTION_MULT: f32 = 1.5;
+    /// Bystanders at or above this loyalty step in when a faction-mate is attacked
+    pub const DEFEND_ALLY_LOYALTY_THRESHOLD: f32 = 0.7;
+    /// Reliability the defended agent gains toward their defender
+    pub const DEFEND_ALLY_RELIABILITY_GAIN: f32 = 0.15;
 }
 
 #[cfg(test)]
@@ -176,6 +220,13 @@ mod tests {
         assert!(action.reason.is_none());
     }
 
+    #[test]
+    fn test_duel_action() {
+        let action = ConflictAction::duel("agent_001", "agent_002", Some("honor".to_string()));
+        assert_eq!(action.action_type, ConflictActionType::Duel);
+        assert_eq!(action.target_id, "agent_002");
+    }
+
     #[test]
     fn test_sabotage_action() {
         let action = ConflictAction::sabotage(
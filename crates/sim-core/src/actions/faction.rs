@@ -95,6 +95,8 @@ pub mod faction_weights {
     pub const DEFECT_LEADER_DISTRUST_BONUS: f32 = 0.15;
     /// Defect bonus for having cross-faction contacts
     pub const DEFECT_EXTERNAL_CONTACT_BONUS: f32 = 0.1;
+    /// Trust toward a member of another faction needed to count as a contact
+    pub const EXTERNAL_CONTACT_TRUST_THRESHOLD: f32 = 0.1;
     /// Defect penalty for high loyalty
     pub const DEFECT_HIGH_LOYALTY_PENALTY: f32 = 0.2;
 
@@ -104,6 +106,10 @@ pub mod faction_weights {
     pub const EXILE_DISTRUST_BONUS: f32 = 0.2;
     /// Exile bonus if target has betrayed faction
     pub const EXILE_BETRAYAL_BONUS: f32 = 0.3;
+    /// Trust toward a member must be below this to consider exiling them
+    pub const EXILE_TRUST_THRESHOLD: f32 = -0.2;
+    /// Members with loyalty below this are exile candidates
+    pub const EXILE_LOW_LOYALTY_THRESHOLD: f32 = 0.4;
 
     /// Base weight for challenge leader action (very low - dramatic action)
     pub const CHALLENGE_BASE: f32 = 0.01;
@@ -129,6 +135,13 @@ pub mod faction_weights {
     pub const CHALLENGE_MIN_SUPPORTERS: usize = 2;
     /// Trust threshold considered "weak" leadership
     pub const WEAK_LEADER_TRUST_THRESHOLD: f32 = 0.2;
+
+    /// How much the challenger's ambition adds to their claim when a challenge resolves
+    pub const CHALLENGE_AMBITION_CLAIM_WEIGHT: f32 = 0.3;
+    /// Alignment lost with each faction mate after a failed challenge
+    pub const CHALLENGE_FAILED_MEMBER_PENALTY: f32 = 0.1;
+    /// Reliability the incumbent loses in a challenger who failed to unseat them
+    pub const CHALLENGE_FAILED_LEADER_PENALTY: f32 = 0.4;
 }
 
 #[cfg(test)]
@@ -145,6 +145,8 @@ pub mod resource_weights {
     pub const TRADE_TRUST_BONUS: f32 = 0.1;
     /// Trade bonus for cross-faction relations
     pub const TRADE_CROSS_FACTION_BONUS: f32 = 0.05;
+    /// Additional trade bonus when the other agent's faction is an ally
+    pub const TRADE_ALLIED_BONUS: f32 = 0.1;
 
     /// Base weight for steal action
     pub const STEAL_BASE: f32 = 0.05;
@@ -164,12 +166,23 @@ pub mod resource_weights {
     /// Hoard penalty for high loyalty
     pub const HOARD_HIGH_LOYALTY_PENALTY: f32 = 0.1;
 
+    /// How far ahead factions forecast their grain (two seasons)
+    pub const FORECAST_HORIZON_TICKS: u64 = 600;
+    /// Work bonus when the faction is projected to run out of grain
+    pub const WORK_FORECAST_SHORTFALL_BONUS: f32 = 0.2;
+    /// Trade bonus when the faction is projected to run out of grain
+    pub const TRADE_FORECAST_SHORTFALL_BONUS: f32 = 0.1;
+    /// Hoard bonus when the faction is projected to run out of grain
+    pub const HOARD_FORECAST_SHORTFALL_BONUS: f32 = 0.1;
+
     /// Base yield from work (resource units)
     pub const WORK_BASE_YIELD: u32 = 5;
     /// Detection chance base for stealing
     pub const STEAL_DETECTION_BASE: f32 = 0.3;
     /// Detection bonus from victim's perception
     pub const STEAL_DETECTION_VIGILANCE: f32 = 0.2;
+    /// Emotional weight of the victim's memory of a detected theft
+    pub const THEFT_MEMORY_WEIGHT: f32 = 0.7;
 }
 
 #[cfg(test)]
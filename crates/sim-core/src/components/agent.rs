@@ -332,3 +332,31 @@ impl Intoxication {
         (base_honesty + self.honesty_modifier).clamp(0.0, 1.0)
     }
 }
+
+/// Tracks how long an agent has gone without adequate food
+#[derive(Component, Debug, Clone, Default, Serialize, Deserialize)]
+pub struct StarvationClock {
+    /// Consecutive ticks spent in the Desperate food security state
+    pub desperate_ticks: u64,
+}
+
+impl StarvationClock {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Advance the clock by one tick of desperation
+    pub fn tick(&mut self) {
+        self.desperate_ticks += 1;
+    }
+
+    /// Reset the clock after the agent has eaten
+    pub fn reset(&mut self) {
+        self.desperate_ticks = 0;
+    }
+
+    /// Check if the agent has starved past the given threshold
+    pub fn has_starved(&self, threshold: u64) -> bool {
+        self.desperate_ticks >= threshold
+    }
+}
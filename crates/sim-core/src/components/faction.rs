@@ -4,9 +4,15 @@
 
 use bevy_ecs::prelude::*;
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
+use std::collections::{BTreeMap, BTreeSet, HashMap};
 
-use super::agent::Role;
+use super::agent::{Role, StatusLevel};
+use super::world::SeasonSchedule;
+use crate::systems::consumption::consumption_constants::GRAIN_PER_AGENT_PER_DAY;
+
+/// Grain each member is expected to bring in per day at a 1.0 production
+/// modifier, used when forecasting stocks
+pub const EXPECTED_GRAIN_YIELD_PER_MEMBER_PER_DAY: f32 = 1.2;
 
 /// Component: An agent's membership in a faction
 #[derive(Component, Debug, Clone, Serialize, Deserialize)]
@@ -44,8 +50,27 @@ impl FactionMembership {
             Role::Leader | Role::Reader | Role::CouncilMember
         )
     }
+
+    /// Leaders and council members may exile other members
+    pub fn can_exile(&self) -> bool {
+        matches!(self.role, Role::Leader | Role::CouncilMember)
+    }
+
+    /// Strip faction membership, leaving the agent factionless
+    pub fn exile(&mut self) {
+        self.faction_id = EXILED_FACTION_ID.to_string();
+        self.role = Role::Newcomer;
+        self.status_level = StatusLevel::Exile as u8;
+    }
+
+    pub fn is_exiled(&self) -> bool {
+        self.faction_id == EXILED_FACTION_ID
+    }
 }
 
+/// Faction ID given to agents who have been exiled (not a registered faction)
+pub const EXILED_FACTION_ID: &str = "exiled";
+
 /// Unique identifier for a faction
 #[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub struct FactionId(pub String);
@@ -151,6 +176,23 @@ impl Faction {
     pub fn controls_location(&self, location_id: &str) -> bool {
         self.territory.contains(&location_id.to_string())
     }
+
+    /// Estimate grain stock `ticks_ahead` ticks from now
+    ///
+    /// Members eat `GRAIN_PER_AGENT_PER_DAY` and produce
+    /// `EXPECTED_GRAIN_YIELD_PER_MEMBER_PER_DAY` scaled by each season's
+    /// production modifier. A negative result is the size of the shortfall.
+    pub fn project_grain(&self, ticks_ahead: u64, schedule: &SeasonSchedule) -> i32 {
+        let members = self.member_count.max(1) as f32;
+        let mut stock = self.resources.grain as f32;
+        for (season, ticks) in schedule.spans(ticks_ahead) {
+            let days = ticks as f32 / schedule.ticks_per_day.max(1) as f32;
+            let produced = members * EXPECTED_GRAIN_YIELD_PER_MEMBER_PER_DAY * season.production_modifier();
+            let consumed = members * GRAIN_PER_AGENT_PER_DAY;
+            stock += (produced - consumed) * days;
+        }
+        stock.round() as i32
+    }
 }
 
 /// An entry in a faction's archive
@@ -307,8 +349,10 @@ impl Archive {
 /// Resource: Registry of all factions
 #[derive(Resource, Debug, Default)]
 pub struct FactionRegistry {
-    factions: HashMap<String, Faction>,
-    archives: HashMap<String, Archive>,
+    factions: BTreeMap<String, Faction>,
+    archives: BTreeMap<String, Archive>,
+    /// Maps faction_id -> allied faction IDs (stored symmetrically)
+    alliances: BTreeMap<String, BTreeSet<String>>,
 }
 
 impl FactionRegistry {
@@ -358,12 +402,52 @@ impl FactionRegistry {
         self.factions.keys().collect()
     }
 
+    /// Estimate a faction's grain stock `ticks_ahead` ticks from now
+    pub fn project_grain(&self, faction_id: &str, ticks_ahead: u64, schedule: &SeasonSchedule) -> Option<i32> {
+        self.get(faction_id).map(|f| f.project_grain(ticks_ahead, schedule))
+    }
+
     /// Find which faction controls a location
     pub fn faction_controlling(&self, location_id: &str) -> Option<&Faction> {
         self.factions
             .values()
             .find(|f| f.controls_location(location_id))
     }
+
+    /// Form an alliance between two factions. Returns false if already allied.
+    pub fn form_alliance(&mut self, faction_a: &str, faction_b: &str) -> bool {
+        if faction_a == faction_b || self.are_allied(faction_a, faction_b) {
+            return false;
+        }
+        self.alliances.entry(faction_a.to_string()).or_default().insert(faction_b.to_string());
+        self.alliances.entry(faction_b.to_string()).or_default().insert(faction_a.to_string());
+        true
+    }
+
+    /// Dissolve an alliance between two factions
+    pub fn break_alliance(&mut self, faction_a: &str, faction_b: &str) {
+        if let Some(allies) = self.alliances.get_mut(faction_a) {
+            allies.remove(faction_b);
+        }
+        if let Some(allies) = self.alliances.get_mut(faction_b) {
+            allies.remove(faction_a);
+        }
+    }
+
+    /// Check whether two factions are allied
+    pub fn are_allied(&self, faction_a: &str, faction_b: &str) -> bool {
+        self.alliances
+            .get(faction_a)
+            .is_some_and(|allies| allies.contains(faction_b))
+    }
+
+    /// Get all allies of a faction
+    pub fn allies_of(&self, faction_id: &str) -> Vec<&String> {
+        self.alliances
+            .get(faction_id)
+            .map(|allies| allies.iter().collect())
+            .unwrap_or_default()
+    }
 }
 
 /// Tick of next ritual for each faction
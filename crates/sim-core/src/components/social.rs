@@ -3,8 +3,9 @@
 //! Components for relationships, memories, and trust.
 
 use bevy_ecs::prelude::*;
+use rand::Rng;
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 
 /// Multi-dimensional trust model
 /// Each dimension is -1.0 to 1.0 (negative = distrust, positive = trust)
@@ -96,6 +97,18 @@ impl Trust {
         self.alignment = (self.alignment + gain * 0.5).clamp(-1.0, 1.0);
     }
 
+    /// Pull damaged reliability and alignment back toward neutral.
+    ///
+    /// Recovery is a fraction `rate` of the remaining damage, capped at
+    /// `max_recovery` per call, so it never crosses zero into positive trust.
+    pub fn apply_forgiveness(&mut self, rate: f32, max_recovery: f32) {
+        for value in [&mut self.reliability, &mut self.alignment] {
+            if *value < 0.0 {
+                *value += (-*value * rate).min(max_recovery);
+            }
+        }
+    }
+
     /// Apply broken promise penalty (reliability hit)
     pub fn apply_broken_promise(&mut self) {
         self.reliability -= 0.2; // Larger than positive gain (asymmetric)
@@ -134,6 +147,9 @@ pub struct Relationship {
     pub last_interaction_tick: u64,
     /// Count of significant memories about this agent
     pub memory_count: u32,
+    /// The target has died
+    #[serde(default)]
+    pub target_deceased: bool,
 }
 
 impl Relationship {
@@ -143,6 +159,7 @@ impl Relationship {
             trust: Trust::default(),
             last_interaction_tick: 0,
             memory_count: 0,
+            target_deceased: false,
         }
     }
 
@@ -184,6 +201,9 @@ pub struct Memory {
     pub valence: MemoryValence,
     /// Is this a secret (should not be shared openly)?
     pub is_secret: bool,
+    /// Independent retellings that have confirmed this memory
+    #[serde(default)]
+    pub corroborations: u32,
 }
 
 /// Emotional valence of a memory
@@ -194,6 +214,21 @@ pub enum MemoryValence {
     Negative,
 }
 
+/// Retellings shorter than this many hops are passed on faithfully
+pub const DISTORTION_MIN_CHAIN: usize = 3;
+
+/// Scales lost fidelity into the per-retelling chance of distortion
+pub const DISTORTION_SCALE: f32 = 0.5;
+
+/// Fidelity a retold memory never drops below, however long its chain
+pub const MIN_RUMOR_FIDELITY: f32 = 0.15;
+
+/// Share of the remaining doubt an independent confirmation removes
+pub const CORROBORATION_FIDELITY_BOOST: f32 = 0.5;
+
+/// Hedges a rumor picks up as its content drifts
+const DISTORTION_HEDGES: [&str; 3] = ["they say ", "word is ", "rumor has it "];
+
 impl Memory {
     /// Create a firsthand memory
     pub fn firsthand(
@@ -216,6 +251,7 @@ impl Memory {
             tick_created: tick,
             valence,
             is_secret: false,
+            corroborations: 0,
         }
     }
 
@@ -234,13 +270,76 @@ impl Memory {
             event_id: original.event_id.clone(),
             subject: original.subject.clone(),
             content: original.content.clone(),
-            fidelity: original.fidelity * 0.7, // Degrades with each hop
+            fidelity: (original.fidelity * 0.7).max(MIN_RUMOR_FIDELITY), // Degrades with each hop
             source_chain,
             emotional_weight: original.emotional_weight * 0.5, // Diminished impact
             tick_created: tick,
             valence: original.valence,
             is_secret: original.is_secret,
+            corroborations: original.corroborations,
+        }
+    }
+
+    /// Lose extra fidelity in the telling, e.g. to a crowd.
+    ///
+    /// Like any retelling, this never drops below `MIN_RUMOR_FIDELITY`.
+    pub fn degrade(&mut self, multiplier: f32) {
+        self.fidelity = (self.fidelity * multiplier).max(MIN_RUMOR_FIDELITY);
+    }
+
+    /// Returns true if both memories are of the same event and no teller
+    /// appears in both source chains.
+    pub fn is_independent_of(&self, other: &Memory) -> bool {
+        self.event_id.is_some()
+            && self.event_id == other.event_id
+            && !self
+                .source_chain
+                .iter()
+                .any(|s| other.source_chain.iter().any(|o| o.agent_id == s.agent_id))
+    }
+
+    /// Fold in an independent account of the same event.
+    ///
+    /// The rumor is treated as confirmed: fidelity rises to the better of the
+    /// two accounts, then recovers `CORROBORATION_FIDELITY_BOOST` of what is
+    /// still missing.
+    pub fn corroborate(&mut self, other: &Memory) {
+        let best = self.fidelity.max(other.fidelity);
+        self.fidelity = best + (1.0 - best).max(0.0) * CORROBORATION_FIDELITY_BOOST;
+        self.emotional_weight = self.emotional_weight.max(other.emotional_weight);
+        self.corroborations += 1;
+    }
+
+    /// Possibly distort a retold memory, modeling the telephone game.
+    ///
+    /// Once the source chain reaches `DISTORTION_MIN_CHAIN`, each retelling has
+    /// a chance proportional to lost fidelity to either flip the valence or
+    /// mangle the content. Corroborated memories are harder to distort.
+    /// Returns true if the memory changed.
+    pub fn distort<R: Rng + ?Sized>(&mut self, rng: &mut R) -> bool {
+        if self.source_chain.len() < DISTORTION_MIN_CHAIN {
+            return false;
         }
+
+        let chance = (1.0 - self.fidelity).clamp(0.0, 1.0) * DISTORTION_SCALE
+            / (1 + self.corroborations) as f32;
+        if rng.gen::<f32>() >= chance {
+            return false;
+        }
+
+        let already_hedged = DISTORTION_HEDGES.iter().any(|h| self.content.starts_with(h));
+        if already_hedged || rng.gen_bool(0.5) {
+            self.valence = match self.valence {
+                MemoryValence::Positive => MemoryValence::Negative,
+                MemoryValence::Negative => MemoryValence::Positive,
+                MemoryValence::Neutral if rng.gen_bool(0.5) => MemoryValence::Positive,
+                MemoryValence::Neutral => MemoryValence::Negative,
+            };
+        } else {
+            let hedge = DISTORTION_HEDGES[rng.gen_range(0..DISTORTION_HEDGES.len())];
+            self.content = format!("{}{}", hedge, self.content);
+        }
+        true
     }
 
     /// Create a memory from archive reading
@@ -262,6 +361,7 @@ impl Memory {
             tick_created: tick,
             valence,
             is_secret: false,
+            corroborations: 0,
         }
     }
 
@@ -283,13 +383,31 @@ impl Memory {
     pub fn is_significant(&self) -> bool {
         self.fidelity > 0.1 || self.emotional_weight > 0.1
     }
+
+    /// How strongly this memory resists eviction from a full memory bank
+    pub fn retention_score(&self) -> f32 {
+        let score = self.emotional_weight * self.fidelity;
+        if self.is_secret {
+            score * SECRET_RETENTION_MULTIPLIER
+        } else {
+            score
+        }
+    }
+}
+
+/// Reputation of an agent nobody has an opinion of
+pub const NEUTRAL_REPUTATION: f32 = 0.0;
+
+/// Map a rater's own standing from [-1, 1] to a [0, 1] weight on their opinion
+fn rater_weight(rater_standing: Option<f32>) -> f32 {
+    (1.0 + rater_standing.unwrap_or(NEUTRAL_REPUTATION)) / 2.0
 }
 
 /// Resource: Graph of all relationships between agents
 #[derive(Resource, Debug, Default)]
 pub struct RelationshipGraph {
     /// Maps (from_agent_id, to_agent_id) -> Relationship
-    relationships: HashMap<(String, String), Relationship>,
+    relationships: BTreeMap<(String, String), Relationship>,
 }
 
 impl RelationshipGraph {
@@ -333,12 +451,95 @@ impl RelationshipGraph {
             .collect()
     }
 
+    /// Unweighted average of how everyone else rates each agent, for every
+    /// agent anyone has an opinion of. One pass over all relationships.
+    fn raw_reputations(&self) -> BTreeMap<&str, f32> {
+        let mut totals: BTreeMap<&str, (f32, u32)> = BTreeMap::new();
+        for ((from, to), rel) in &self.relationships {
+            if from != to {
+                let entry = totals.entry(to.as_str()).or_default();
+                entry.0 += rel.trust.overall();
+                entry.1 += 1;
+            }
+        }
+        totals
+            .into_iter()
+            .map(|(id, (sum, count))| (id, sum / count as f32))
+            .collect()
+    }
+
+    /// Every rated agent's overall standing, computed in a single pass.
+    ///
+    /// Use this instead of calling `reputation` per agent when scoring a
+    /// whole population. Agents nobody rates are absent.
+    pub fn reputations(&self) -> BTreeMap<String, f32> {
+        let raw = self.raw_reputations();
+        let mut weighted: BTreeMap<&str, (f32, f32)> = BTreeMap::new();
+        for ((from, to), rel) in &self.relationships {
+            if from == to {
+                continue;
+            }
+            let weight = rater_weight(raw.get(from.as_str()).copied());
+            let entry = weighted.entry(to.as_str()).or_default();
+            entry.0 += rel.trust.overall() * weight;
+            entry.1 += weight;
+        }
+
+        weighted
+            .into_iter()
+            .map(|(id, (weighted_sum, total_weight))| {
+                let score = if total_weight <= f32::EPSILON {
+                    // Only pariahs have an opinion: fall back to the plain average
+                    raw[id]
+                } else {
+                    weighted_sum / total_weight
+                };
+                (id.to_string(), score)
+            })
+            .collect()
+    }
+
+    /// An agent's overall standing: the average of how others rate them,
+    /// weighted by each rater's own standing so that opinions from isolated
+    /// or distrusted agents count for less.
+    ///
+    /// Returns `NEUTRAL_REPUTATION` if nobody has an opinion of the agent.
+    pub fn reputation(&self, agent_id: &str) -> f32 {
+        let raw = self.raw_reputations();
+        let mut weighted_sum = 0.0;
+        let mut total_weight = 0.0;
+
+        for (rater, rel) in self.trusted_by(agent_id) {
+            if rater == agent_id {
+                continue;
+            }
+            let weight = rater_weight(raw.get(rater.as_str()).copied());
+            weighted_sum += rel.trust.overall() * weight;
+            total_weight += weight;
+        }
+
+        if total_weight <= f32::EPSILON {
+            // Only pariahs have an opinion: fall back to the plain average
+            return raw.get(agent_id).copied().unwrap_or(NEUTRAL_REPUTATION);
+        }
+        weighted_sum / total_weight
+    }
+
     /// Check if relationship exists
     pub fn has_relationship(&self, from: &str, to: &str) -> bool {
         self.relationships
             .contains_key(&(from.to_string(), to.to_string()))
     }
 
+    /// Mark every relationship with an agent as being with someone deceased
+    pub fn mark_deceased(&mut self, agent_id: &str) {
+        for ((_, to), rel) in self.relationships.iter_mut() {
+            if to == agent_id {
+                rel.target_deceased = true;
+            }
+        }
+    }
+
     /// Create or get relationship (ensures it exists)
     pub fn ensure_relationship(&mut self, from: &str, to: &str) -> &mut Relationship {
         let key = (from.to_string(), to.to_string());
@@ -409,13 +610,31 @@ impl RelationshipGraph {
     }
 }
 
+/// Default number of memories an agent keeps before the weakest are evicted
+pub const DEFAULT_MAX_MEMORIES_PER_AGENT: usize = 200;
+
+/// Secrets are weighted this much more heavily when choosing what to forget
+const SECRET_RETENTION_MULTIPLIER: f32 = 2.0;
+
 /// Resource: Bank of all memories for all agents
-#[derive(Resource, Debug, Default)]
+#[derive(Resource, Debug)]
 pub struct MemoryBank {
     /// Maps agent_id -> list of memories
     memories: HashMap<String, Vec<Memory>>,
     /// Counter for generating unique memory IDs
     next_memory_id: u64,
+    /// Per-agent cap; adding beyond it evicts the least significant memory
+    max_memories_per_agent: usize,
+}
+
+impl Default for MemoryBank {
+    fn default() -> Self {
+        Self {
+            memories: HashMap::new(),
+            next_memory_id: 0,
+            max_memories_per_agent: DEFAULT_MAX_MEMORIES_PER_AGENT,
+        }
+    }
 }
 
 impl MemoryBank {
@@ -423,6 +642,19 @@ impl MemoryBank {
         Self::default()
     }
 
+    /// Create a memory bank with a custom per-agent cap (at least 1)
+    pub fn with_max_memories(max_memories_per_agent: usize) -> Self {
+        Self {
+            max_memories_per_agent: max_memories_per_agent.max(1),
+            ..Self::default()
+        }
+    }
+
+    /// Get the per-agent memory cap
+    pub fn max_memories_per_agent(&self) -> usize {
+        self.max_memories_per_agent
+    }
+
     /// Generate a unique memory ID
     pub fn generate_id(&mut self) -> String {
         let id = format!("mem_{:08}", self.next_memory_id);
@@ -430,12 +662,43 @@ impl MemoryBank {
         id
     }
 
-    /// Add a memory for an agent
+    /// Add a memory for an agent, evicting the least significant if over the cap
     pub fn add_memory(&mut self, agent_id: impl Into<String>, memory: Memory) {
-        self.memories
-            .entry(agent_id.into())
-            .or_default()
-            .push(memory);
+        let memories = self.memories.entry(agent_id.into()).or_default();
+        memories.push(memory);
+
+        while memories.len() > self.max_memories_per_agent {
+            // Strict comparison so the oldest memory loses ties
+            let mut weakest = 0;
+            for i in 1..memories.len() {
+                if memories[i].retention_score() < memories[weakest].retention_score() {
+                    weakest = i;
+                }
+            }
+            memories.remove(weakest);
+        }
+    }
+
+    /// Receive a retold memory, corroborating an existing memory of the same
+    /// event when the two accounts came by independent routes.
+    ///
+    /// An account that passed through the listener themself is their own story
+    /// coming back, not a confirmation. Returns true if the memory was stored
+    /// as new rather than folded into an existing one.
+    pub fn receive_memory(&mut self, agent_id: &str, memory: Memory) -> bool {
+        let echo = memory.source_chain.iter().any(|s| s.agent_id == agent_id);
+        if !echo {
+            let existing = self
+                .memories
+                .get_mut(agent_id)
+                .and_then(|mems| mems.iter_mut().find(|m| m.is_independent_of(&memory)));
+            if let Some(existing) = existing {
+                existing.corroborate(&memory);
+                return false;
+            }
+        }
+        self.add_memory(agent_id.to_string(), memory);
+        true
     }
 
     /// Get all memories for an agent
@@ -492,3 +755,164 @@ impl MemoryBank {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::rngs::SmallRng;
+    use rand::SeedableRng;
+
+    fn source(i: usize) -> MemorySource {
+        MemorySource {
+            agent_id: format!("agent_{:03}", i),
+            agent_name: format!("Teller {}", i),
+        }
+    }
+
+    fn rate(graph: &mut RelationshipGraph, from: &str, to: &str, value: f32) {
+        graph.set(from, Relationship::new(to).with_trust(Trust::new(value, value, value)));
+    }
+
+    #[test]
+    fn test_reputation_well_liked_vs_distrusted() {
+        let mut graph = RelationshipGraph::new();
+        for rater in ["a", "b", "c"] {
+            rate(&mut graph, rater, "hero", 0.7);
+            rate(&mut graph, rater, "villain", -0.6);
+        }
+        // Raters think reasonably well of each other
+        rate(&mut graph, "a", "b", 0.3);
+        rate(&mut graph, "b", "c", 0.3);
+        rate(&mut graph, "c", "a", 0.3);
+
+        assert!(graph.reputation("hero") > 0.5);
+        assert!(graph.reputation("villain") < -0.5);
+        assert_eq!(graph.reputation("stranger"), NEUTRAL_REPUTATION);
+    }
+
+    #[test]
+    fn test_reputations_match_per_agent_reputation() {
+        let mut graph = RelationshipGraph::new();
+        rate(&mut graph, "respected", "subject", 0.6);
+        rate(&mut graph, "pariah", "subject", -0.8);
+        rate(&mut graph, "other", "respected", 0.8);
+        rate(&mut graph, "other", "pariah", -0.8);
+        rate(&mut graph, "subject", "pariah", -1.0);
+
+        let all = graph.reputations();
+        for agent in ["subject", "respected", "pariah"] {
+            assert!((all[agent] - graph.reputation(agent)).abs() < 1e-6);
+        }
+        assert!(!all.contains_key("other"));
+    }
+
+    #[test]
+    fn test_reputation_discounts_distrusted_raters() {
+        let mut graph = RelationshipGraph::new();
+        rate(&mut graph, "respected", "subject", 0.6);
+        rate(&mut graph, "pariah", "subject", -0.8);
+        rate(&mut graph, "other", "respected", 0.8);
+        rate(&mut graph, "other", "pariah", -0.8);
+
+        // A plain average would be -0.1; the pariah's opinion counts for little
+        assert!(graph.reputation("subject") > 0.3);
+    }
+
+    #[test]
+    fn test_short_chain_is_not_distorted() {
+        let mut rng = SmallRng::seed_from_u64(42);
+        let original = Memory::firsthand(
+            "mem_0", "evt_0", "agent_999", "stole grain", 0.8, 0, MemoryValence::Negative,
+        );
+        let mut retold = Memory::secondhand("mem_1", &original, source(1), 1);
+        for _ in 0..100 {
+            assert!(!retold.distort(&mut rng));
+        }
+        assert_eq!(retold.valence, MemoryValence::Negative);
+        assert_eq!(retold.content, "stole grain");
+    }
+
+    #[test]
+    fn test_long_chain_eventually_distorts() {
+        let mut rng = SmallRng::seed_from_u64(42);
+        let original = Memory::firsthand(
+            "mem_0", "evt_0", "agent_999", "stole grain", 0.8, 0, MemoryValence::Negative,
+        );
+
+        let mut current = original.clone();
+        let mut drifted = false;
+        for hop in 1..=20 {
+            let mut next = Memory::secondhand(format!("mem_{}", hop), &current, source(hop), hop as u64);
+            if next.distort(&mut rng) {
+                drifted |= next.valence != original.valence || next.content != original.content;
+            }
+            current = next;
+        }
+
+        assert!(drifted);
+    }
+
+    #[test]
+    fn test_two_independent_sources_outweigh_one_long_chain() {
+        let original = Memory::firsthand(
+            "mem_0", "evt_0", "agent_999", "stole grain", 0.8, 0, MemoryValence::Negative,
+        );
+        let retell = |tellers: &[usize]| {
+            tellers.iter().fold(original.clone(), |current, &i| {
+                Memory::secondhand(format!("mem_{}", i), &current, source(i), i as u64)
+            })
+        };
+
+        // One long chain through four tellers
+        let mut single = MemoryBank::new();
+        assert!(single.receive_memory("listener", retell(&[1, 2, 3, 4])));
+        let single_fidelity = single.get_memories("listener").unwrap()[0].fidelity;
+
+        // The same story reaching the listener by two separate routes
+        let mut confirmed = MemoryBank::new();
+        assert!(confirmed.receive_memory("listener", retell(&[1, 2])));
+        assert!(!confirmed.receive_memory("listener", retell(&[3, 4])));
+        let memories = confirmed.get_memories("listener").unwrap();
+        assert_eq!(memories.len(), 1);
+        assert_eq!(memories[0].corroborations, 1);
+        assert!(memories[0].fidelity > single_fidelity);
+        assert!(memories[0].fidelity > retell(&[1, 2]).fidelity);
+
+        // Hearing the same chain again confirms nothing
+        assert!(confirmed.receive_memory("listener", retell(&[1, 5])));
+        assert_eq!(confirmed.get_memories("listener").unwrap()[0].corroborations, 1);
+    }
+
+    #[test]
+    fn test_retold_fidelity_has_a_floor() {
+        let mut current = Memory::firsthand(
+            "mem_0", "evt_0", "agent_999", "stole grain", 0.8, 0, MemoryValence::Negative,
+        );
+        for hop in 1..=30 {
+            current = Memory::secondhand(format!("mem_{}", hop), &current, source(hop), hop as u64);
+        }
+        assert_eq!(current.fidelity, MIN_RUMOR_FIDELITY);
+    }
+
+    #[test]
+    fn test_memory_cap_keeps_most_significant() {
+        let mut bank = MemoryBank::with_max_memories(3);
+        let weights = [0.1, 0.9, 0.5, 0.3, 0.8, 0.2];
+        for (i, weight) in weights.iter().enumerate() {
+            let mut memory = Memory::firsthand(
+                format!("mem_{}", i), "evt_0", "agent_999", "gossip", *weight, i as u64, MemoryValence::Neutral,
+            );
+            // A weak secret still outranks a middling rumor
+            memory.is_secret = i == 3;
+            bank.add_memory("agent_001", memory);
+        }
+
+        let kept: Vec<&str> = bank
+            .get_memories("agent_001")
+            .unwrap()
+            .iter()
+            .map(|m| m.memory_id.as_str())
+            .collect();
+        assert_eq!(kept, vec!["mem_1", "mem_3", "mem_4"]);
+    }
+}
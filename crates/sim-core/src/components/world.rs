@@ -4,7 +4,7 @@
 
 use bevy_ecs::prelude::*;
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
+use std::collections::BTreeMap;
 
 /// Component: An agent's current position in the world
 #[derive(Component, Debug, Clone, Serialize, Deserialize)]
@@ -402,6 +402,11 @@ impl Location {
         self.properties.contains(property)
     }
 
+    /// Whether beer can be brewed here (halls and villages have the vats)
+    pub fn is_brewery(&self) -> bool {
+        self.benefits.production_types.contains(&ProductionType::Brew)
+    }
+
     pub fn is_adjacent_to(&self, other_id: &str) -> bool {
         self.adjacent.contains(&other_id.to_string())
     }
@@ -410,7 +415,7 @@ impl Location {
 /// Resource: Registry of all locations in the world
 #[derive(Resource, Debug, Default)]
 pub struct LocationRegistry {
-    locations: HashMap<String, Location>,
+    locations: BTreeMap<String, Location>,
 }
 
 impl LocationRegistry {
@@ -598,6 +603,16 @@ impl Season {
         }
     }
 
+    /// Work drive modifier: stock up before winter, ease off once it sets in
+    pub fn work_modifier(&self) -> f32 {
+        match self {
+            Season::Spring => 1.0,
+            Season::Summer => 1.0,
+            Season::Autumn => 1.3,
+            Season::Winter => 0.6,
+        }
+    }
+
     /// Is this a harsh season?
     pub fn is_harsh(&self) -> bool {
         matches!(self, Season::Winter)
@@ -656,6 +671,39 @@ impl Default for TimeConfig {
     }
 }
 
+/// Maps upcoming ticks onto seasons, for forecasting
+#[derive(Debug, Clone, Copy)]
+pub struct SeasonSchedule {
+    /// Season the forecast starts in
+    pub current_season: Season,
+    /// Ticks remaining in the current season
+    pub ticks_left_in_season: u64,
+    /// Ticks in a full season
+    pub ticks_per_season: u64,
+    /// Ticks per day
+    pub ticks_per_day: u64,
+}
+
+impl SeasonSchedule {
+    /// Splits the next `ticks_ahead` ticks into consecutive (season, ticks) spans
+    ///
+    /// Horizons longer than a year wrap around through the seasons again.
+    pub fn spans(&self, ticks_ahead: u64) -> Vec<(Season, u64)> {
+        let mut spans = Vec::new();
+        let mut season = self.current_season;
+        let mut span = self.ticks_left_in_season.max(1);
+        let mut remaining = ticks_ahead;
+        while remaining > 0 {
+            let ticks = span.min(remaining);
+            spans.push((season, ticks));
+            remaining -= ticks;
+            season = season.next();
+            span = self.ticks_per_season.max(1);
+        }
+        spans
+    }
+}
+
 /// Resource: Current state of the world
 #[derive(Resource, Debug)]
 pub struct WorldState {
@@ -743,4 +791,15 @@ impl WorldState {
     pub fn is_winter(&self) -> bool {
         self.current_season == Season::Winter
     }
+
+    /// Season schedule starting from the current tick
+    pub fn season_schedule(&self) -> SeasonSchedule {
+        let ticks_per_season = self.time_config.ticks_per_day * self.time_config.days_per_season as u64;
+        SeasonSchedule {
+            current_season: self.current_season,
+            ticks_left_in_season: ticks_per_season - self.current_tick % ticks_per_season,
+            ticks_per_season,
+            ticks_per_day: self.time_config.ticks_per_day,
+        }
+    }
 }
@@ -0,0 +1,150 @@
+//! Run Control
+//!
+//! Lets an external driver (the viz) pause, resume, single-step, and throttle
+//! the main simulation loop by writing line commands to stdin:
+//! `pause`, `resume`, `step`, and `rate <ticks per second>` (0 = unthrottled).
+
+use std::io::BufRead;
+use std::sync::mpsc::{channel, Receiver};
+use std::time::Duration;
+
+/// A command read from the control channel
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ControlCommand {
+    Pause,
+    Resume,
+    Step,
+    Rate(f64),
+}
+
+impl ControlCommand {
+    /// Parse a command line, ignoring surrounding whitespace and case
+    pub fn parse(line: &str) -> Option<Self> {
+        let mut parts = line.split_whitespace();
+        let command = match parts.next()?.to_ascii_lowercase().as_str() {
+            "pause" => Self::Pause,
+            "resume" => Self::Resume,
+            "step" => Self::Step,
+            "rate" => Self::Rate(parts.next()?.parse().ok().filter(|r: &f64| *r >= 0.0)?),
+            _ => return None,
+        };
+        Some(command)
+    }
+}
+
+/// Pause/step/rate state consulted before each tick
+#[derive(Debug, Clone, PartialEq)]
+pub struct RunControl {
+    /// Whether the loop is paused
+    pub paused: bool,
+    /// Ticks allowed to run while paused
+    pub pending_steps: u64,
+    /// Target ticks per second (0 = as fast as possible)
+    pub ticks_per_second: f64,
+}
+
+impl RunControl {
+    pub fn new(paused: bool, ticks_per_second: f64) -> Self {
+        Self {
+            paused,
+            pending_steps: 0,
+            ticks_per_second: ticks_per_second.max(0.0),
+        }
+    }
+
+    /// Apply a control command
+    pub fn apply(&mut self, command: ControlCommand) {
+        match command {
+            ControlCommand::Pause => {
+                self.paused = true;
+                self.pending_steps = 0;
+            }
+            ControlCommand::Resume => {
+                self.paused = false;
+                self.pending_steps = 0;
+            }
+            ControlCommand::Step => {
+                if self.paused {
+                    self.pending_steps += 1;
+                }
+            }
+            ControlCommand::Rate(rate) => self.ticks_per_second = rate.max(0.0),
+        }
+    }
+
+    /// Check whether the next tick may run, consuming a step if paused
+    pub fn take_tick(&mut self) -> bool {
+        if !self.paused {
+            return true;
+        }
+        if self.pending_steps > 0 {
+            self.pending_steps -= 1;
+            return true;
+        }
+        false
+    }
+
+    /// Minimum wall-clock time per tick, if throttled
+    pub fn tick_interval(&self) -> Option<Duration> {
+        (self.ticks_per_second > 0.0).then(|| Duration::from_secs_f64(1.0 / self.ticks_per_second))
+    }
+}
+
+/// Read control commands from stdin on a background thread
+pub fn spawn_stdin_listener() -> Receiver<ControlCommand> {
+    let (tx, rx) = channel();
+    std::thread::spawn(move || {
+        let stdin = std::io::stdin();
+        for line in stdin.lock().lines().map_while(Result::ok) {
+            match ControlCommand::parse(&line) {
+                Some(command) => {
+                    if tx.send(command).is_err() {
+                        break;
+                    }
+                }
+                None => eprintln!("Warning: Unknown control command '{}'", line.trim()),
+            }
+        }
+    });
+    rx
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_commands() {
+        assert_eq!(ControlCommand::parse("pause"), Some(ControlCommand::Pause));
+        assert_eq!(ControlCommand::parse(" STEP \n"), Some(ControlCommand::Step));
+        assert_eq!(ControlCommand::parse("rate 2.5"), Some(ControlCommand::Rate(2.5)));
+        assert_eq!(ControlCommand::parse("rate -1"), None);
+        assert_eq!(ControlCommand::parse("rate"), None);
+        assert_eq!(ControlCommand::parse("jump"), None);
+    }
+
+    #[test]
+    fn test_step_runs_exactly_one_tick_while_paused() {
+        let mut control = RunControl::new(true, 0.0);
+        assert!(!control.take_tick());
+
+        control.apply(ControlCommand::Step);
+        assert!(control.take_tick());
+        assert!(!control.take_tick());
+
+        control.apply(ControlCommand::Resume);
+        assert!(control.take_tick());
+        assert!(control.take_tick());
+
+        // Steps mean nothing while running
+        control.apply(ControlCommand::Step);
+        control.apply(ControlCommand::Pause);
+        assert!(!control.take_tick());
+    }
+
+    #[test]
+    fn test_tick_interval() {
+        assert_eq!(RunControl::new(false, 0.0).tick_interval(), None);
+        assert_eq!(RunControl::new(false, 4.0).tick_interval(), Some(Duration::from_millis(250)));
+    }
+}
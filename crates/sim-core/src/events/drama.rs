@@ -192,6 +192,7 @@ fn get_base_score(event: &Event) -> f32 {
             FactionSubtype::Join => 0.3,
             FactionSubtype::Promotion => 0.4,
             FactionSubtype::Demotion => 0.35,
+            FactionSubtype::GoalResolved => 0.2,
         },
         EventSubtype::Conflict(c) => match c {
             ConflictSubtype::Argument => base_scores::CONFLICT_ARGUMENT,
@@ -223,6 +224,7 @@ fn get_base_score(event: &Event) -> f32 {
             LoyaltySubtype::SacrificeForFaction => 0.6,
             LoyaltySubtype::RefuseBribe => 0.35,
             LoyaltySubtype::ReportSuspicion => 0.3,
+            LoyaltySubtype::SwearVengeance => 0.6,
         },
         EventSubtype::Death(d) => match d {
             DeathSubtype::Natural => 0.5,
@@ -1,13 +1,19 @@
 //! Event Logger
 //!
 //! Append-only JSONL event logging.
+//!
+//! Every tick's events are appended to `events.jsonl` before `TickEvents` is
+//! cleared, so the Director can consume the full stream.
 
 use bevy_ecs::prelude::*;
 use std::fs::{File, OpenOptions};
 use std::io::{BufWriter, Write};
 use std::path::Path;
 
-use super::types::Event;
+use super::types::{Event, EventType};
+
+/// Number of logged events between automatic flushes
+pub const FLUSH_INTERVAL_EVENTS: u64 = 500;
 
 /// Resource for logging events to a JSONL file
 #[derive(Resource)]
@@ -15,6 +21,10 @@ pub struct EventLogger {
     writer: Option<BufWriter<File>>,
     event_count: u64,
     next_event_id: u64,
+    /// Movement events below this drama score are not written
+    min_movement_drama: f32,
+    /// Events skipped by the movement filter
+    skipped_count: u64,
 }
 
 impl EventLogger {
@@ -30,15 +40,25 @@ impl EventLogger {
             writer: Some(BufWriter::new(file)),
             event_count: 0,
             next_event_id: 1,
+            min_movement_drama: 0.0,
+            skipped_count: 0,
         })
     }
 
+    /// Skip movement events scoring below the given drama threshold
+    pub fn with_min_movement_drama(mut self, threshold: f32) -> Self {
+        self.min_movement_drama = threshold;
+        self
+    }
+
     /// Create a logger that discards events (for testing)
     pub fn null() -> Self {
         Self {
             writer: None,
             event_count: 0,
             next_event_id: 1,
+            min_movement_drama: 0.0,
+            skipped_count: 0,
         }
     }
 
@@ -54,13 +74,29 @@ impl EventLogger {
         self.event_count
     }
 
+    /// Get the number of events skipped by the movement drama filter
+    pub fn skipped_count(&self) -> u64 {
+        self.skipped_count
+    }
+
     /// Log an event to the file
+    ///
+    /// Low-drama movement events are dropped to keep the log small.
+    /// The buffer is flushed every `FLUSH_INTERVAL_EVENTS` events.
     pub fn log(&mut self, event: &Event) -> std::io::Result<()> {
+        if event.event_type == EventType::Movement && event.drama_score < self.min_movement_drama {
+            self.skipped_count += 1;
+            return Ok(());
+        }
+
         self.event_count += 1;
         if let Some(ref mut writer) = self.writer {
             let json = serde_json::to_string(event)?;
             writeln!(writer, "{}", json)?;
         }
+        if self.event_count.is_multiple_of(FLUSH_INTERVAL_EVENTS) {
+            self.flush()?;
+        }
         Ok(())
     }
 
@@ -168,6 +204,51 @@ mod tests {
         fs::remove_file(test_path).ok();
     }
 
+    #[test]
+    fn test_logged_events_are_readable_lines() {
+        let test_path = "output/test_events_many.jsonl";
+        fs::create_dir_all("output").unwrap();
+        let mut logger = EventLogger::new(test_path).unwrap();
+
+        let events: Vec<Event> = (0..25)
+            .map(|i| {
+                let actor = ActorSnapshot::new("agent_1", "Test", "faction", "role", "loc");
+                create_movement_event(logger.next_id(), i, "day_1", actor, "test", "loc2")
+            })
+            .collect();
+        logger.log_batch(&events).unwrap();
+        logger.flush().unwrap();
+
+        let file = File::open(test_path).unwrap();
+        let lines: Vec<String> = std::io::BufReader::new(file)
+            .lines()
+            .map(|l| l.unwrap())
+            .collect();
+        assert_eq!(lines.len(), 25);
+        for (line, event) in lines.iter().zip(&events) {
+            let parsed: Event = serde_json::from_str(line).unwrap();
+            assert_eq!(parsed.event_id, event.event_id);
+        }
+
+        fs::remove_file(test_path).ok();
+    }
+
+    #[test]
+    fn test_low_drama_movement_skipped() {
+        let mut logger = EventLogger::null().with_min_movement_drama(0.2);
+
+        let actor = ActorSnapshot::new("agent_1", "Test", "faction", "role", "loc");
+        let quiet = create_movement_event("evt_1", 1, "day_1", actor.clone(), "test", "loc2")
+            .with_drama(0.1, Vec::new());
+        let flight = create_movement_event("evt_2", 1, "day_1", actor, "fleeing", "loc3")
+            .with_drama(0.4, Vec::new());
+
+        logger.log(&quiet).unwrap();
+        logger.log(&flight).unwrap();
+        assert_eq!(logger.event_count(), 1);
+        assert_eq!(logger.skipped_count(), 1);
+    }
+
     #[test]
     fn test_null_logger() {
         let mut logger = EventLogger::null();
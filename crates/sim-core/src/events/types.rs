@@ -22,6 +22,18 @@ pub enum EventType {
     Birth,
 }
 
+impl EventType {
+    /// Parse an event type from its snake_case name
+    pub fn from_name(name: &str) -> Option<Self> {
+        serde_json::from_value(serde_json::Value::String(name.to_string())).ok()
+    }
+
+    /// Checks if the given snake_case subtype is valid for this event type
+    pub fn is_valid_subtype(&self, subtype: &str) -> bool {
+        EventSubtype::parse(self, subtype).is_some()
+    }
+}
+
 /// Movement event subtypes
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(rename_all = "snake_case")]
@@ -63,6 +75,7 @@ pub enum LoyaltySubtype {
     SacrificeForFaction,
     RefuseBribe,
     ReportSuspicion,
+    SwearVengeance,
 }
 
 /// Conflict event subtypes
@@ -98,6 +111,7 @@ pub enum FactionSubtype {
     Demotion,
     ChallengeLeader,
     SupportLeader,
+    GoalResolved,
 }
 
 /// Archive event subtypes
@@ -171,6 +185,30 @@ pub enum EventSubtype {
     Birth(BirthSubtype),
 }
 
+impl EventSubtype {
+    /// Parse a snake_case subtype name belonging to the given event type
+    pub fn parse(event_type: &EventType, subtype: &str) -> Option<Self> {
+        fn named<T: serde::de::DeserializeOwned>(name: &str) -> Option<T> {
+            serde_json::from_value(serde_json::Value::String(name.to_string())).ok()
+        }
+
+        match event_type {
+            EventType::Movement => named(subtype).map(EventSubtype::Movement),
+            EventType::Communication => named(subtype).map(EventSubtype::Communication),
+            EventType::Betrayal => named(subtype).map(EventSubtype::Betrayal),
+            EventType::Loyalty => named(subtype).map(EventSubtype::Loyalty),
+            EventType::Conflict => named(subtype).map(EventSubtype::Conflict),
+            EventType::Cooperation => named(subtype).map(EventSubtype::Cooperation),
+            EventType::Faction => named(subtype).map(EventSubtype::Faction),
+            EventType::Archive => named(subtype).map(EventSubtype::Archive),
+            EventType::Ritual => named(subtype).map(EventSubtype::Ritual),
+            EventType::Resource => named(subtype).map(EventSubtype::Resource),
+            EventType::Death => named(subtype).map(EventSubtype::Death),
+            EventType::Birth => named(subtype).map(EventSubtype::Birth),
+        }
+    }
+}
+
 /// Timestamp for events
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct EventTimestamp {
@@ -294,10 +332,14 @@ impl EventContext {
 }
 
 /// Flexible outcome data for events
+///
+/// Untagged, so variants are tried in order: ones with required fields must
+/// come before those that would accept any object.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(untagged)]
 pub enum EventOutcome {
     Movement(MovementOutcome),
+    Conflict(ConflictOutcome),
     Communication(CommunicationOutcome),
     Relationship(RelationshipOutcome),
     Archive(ArchiveOutcome),
@@ -320,6 +362,21 @@ pub struct ArchiveOutcome {
     pub is_authentic: bool,
 }
 
+/// Conflict event outcome
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ConflictOutcome {
+    /// Agent who came out on top, if the conflict was decided
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub winner_id: Option<String>,
+    /// Whether someone died
+    pub lethal: bool,
+    /// Trust changes between the combatants
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub relationship_changes: Vec<RelationshipChange>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+}
+
 /// Movement event outcome
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct MovementOutcome {
@@ -523,4 +580,17 @@ mod tests {
         assert_eq!(event.drama_score, 0.5);
         assert_eq!(event.drama_tags.len(), 1);
     }
+
+    #[test]
+    fn test_subtype_validation() {
+        assert_eq!(EventType::from_name("betrayal"), Some(EventType::Betrayal));
+        assert_eq!(EventType::from_name("treason"), None);
+
+        assert!(EventType::Betrayal.is_valid_subtype("defection"));
+        assert!(!EventType::Betrayal.is_valid_subtype("travel"));
+        assert_eq!(
+            EventSubtype::parse(&EventType::Resource, "trade"),
+            Some(EventSubtype::Resource(ResourceSubtype::Trade))
+        );
+    }
 }
@@ -9,15 +9,16 @@ use std::fs;
 use std::path::Path;
 
 use crate::components::agent::{
-    AgentId, AgentName, FoodSecurity, Goals, GoalType, Needs, Role, SocialBelonging, Traits,
+    AgentId, AgentName, Alive, FoodSecurity, Goals, GoalType, Needs, Role, SocialBelonging, Traits,
 };
 use crate::components::faction::{FactionMembership, FactionRegistry};
 use crate::components::social::RelationshipGraph;
 use crate::components::world::{Position, WorldState};
 use crate::events::types::{
-    ActorSnapshot, Event, EventActors, EventContext, EventOutcome, EventSubtype, EventTimestamp,
-    EventType, GeneralOutcome,
+    ActorSnapshot, DeathSubtype, Event, EventActors, EventContext, EventOutcome, EventSubtype,
+    EventTimestamp, EventType, GeneralOutcome,
 };
+use crate::output::tension::{Tension, TensionStream, TensionType};
 use crate::systems::action::TickEvents;
 
 /// Directory to watch for intervention files
@@ -71,6 +72,28 @@ pub enum InterventionType {
         target: Option<String>,
         priority: Option<f32>,
     },
+    /// Kill an agent outright
+    KillAgent {
+        agent_id: String,
+        cause: String, // "executed", "killed", "natural"
+    },
+    /// Inject a hand-crafted event into the event stream
+    TriggerEvent {
+        event_type: String,
+        subtype: String,
+        primary_agent: String,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        secondary_agent: Option<String>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        drama_score: Option<f32>,
+    },
+    /// Push a hand-crafted tension into the tension stream
+    InjectTension {
+        tension_type: String,
+        severity: f32,
+        key_agents: Vec<String>,
+        summary: String,
+    },
 }
 
 /// Modification to agent traits
@@ -128,9 +151,21 @@ pub struct Intervention {
     pub reason: Option<String>,
     /// The actual intervention to apply
     pub intervention: InterventionType,
+    /// Tick at which to apply this intervention (immediately if absent)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub apply_at_tick: Option<u64>,
+}
+
+impl Intervention {
+    /// Check if this intervention should be applied at the given tick
+    pub fn is_due(&self, current_tick: u64) -> bool {
+        self.apply_at_tick.is_none_or(|tick| tick <= current_tick)
+    }
 }
 
 /// Resource for tracking pending interventions
+///
+/// Future-dated interventions stay staged here until their tick arrives.
 #[derive(Resource, Default)]
 pub struct PendingInterventions {
     pub interventions: Vec<(String, Intervention)>, // (filename, intervention)
@@ -142,12 +177,17 @@ impl PendingInterventions {
             interventions: Vec::new(),
         }
     }
+
+    /// Check if an intervention file is already staged
+    pub fn is_staged(&self, filename: &str) -> bool {
+        self.interventions.iter().any(|(f, _)| f == filename)
+    }
 }
 
 /// System to scan for and load intervention files
+///
+/// Files already staged from a previous scan are skipped.
 pub fn scan_interventions(mut pending: ResMut<PendingInterventions>) {
-    // Clear any leftover from previous tick
-    pending.interventions.clear();
 
     let interventions_path = Path::new(INTERVENTIONS_DIR);
 
@@ -169,14 +209,18 @@ pub fn scan_interventions(mut pending: ResMut<PendingInterventions>) {
         let path = entry.path();
         if path.extension().and_then(|s| s.to_str()) == Some("json") {
             match fs::read_to_string(&path) {
-                Ok(content) => match serde_json::from_str::<Intervention>(&content) {
-                    Ok(intervention) => {
+                Ok(content) => match parse_intervention_file(&content) {
+                    Ok(interventions) => {
                         let filename = path
                             .file_name()
                             .and_then(|s| s.to_str())
                             .unwrap_or("unknown")
                             .to_string();
-                        pending.interventions.push((filename, intervention));
+                        if !pending.is_staged(&filename) {
+                            for intervention in interventions {
+                                pending.interventions.push((filename.clone(), intervention));
+                            }
+                        }
                     }
                     Err(e) => {
                         eprintln!(
@@ -196,6 +240,22 @@ pub fn scan_interventions(mut pending: ResMut<PendingInterventions>) {
     }
 }
 
+/// Agent components that interventions read and modify
+type InterventionAgents<'w, 's> = Query<
+    'w,
+    's,
+    (
+        &'static AgentId,
+        &'static AgentName,
+        &'static mut Traits,
+        &'static mut Needs,
+        &'static mut Goals,
+        &'static mut Position,
+        &'static mut FactionMembership,
+        &'static mut Alive,
+    ),
+>;
+
 /// System to apply pending interventions
 pub fn apply_interventions(
     world_state: Res<WorldState>,
@@ -203,35 +263,191 @@ pub fn apply_interventions(
     mut tick_events: ResMut<TickEvents>,
     mut faction_registry: ResMut<FactionRegistry>,
     mut relationship_graph: ResMut<RelationshipGraph>,
-    mut agents: Query<(
-        &AgentId,
-        &AgentName,
-        &mut Traits,
-        &mut Needs,
-        &mut Goals,
-        &mut Position,
-        &mut FactionMembership,
-    )>,
+    mut tension_stream: ResMut<TensionStream>,
+    mut agents: InterventionAgents,
 ) {
-    let interventions: Vec<_> = pending.interventions.drain(..).collect();
-
-    for (filename, intervention) in interventions {
-        let success = apply_single_intervention(
-            &intervention,
-            &world_state,
-            &mut tick_events,
-            &mut faction_registry,
-            &mut relationship_graph,
-            &mut agents,
-        );
+    // Group staged interventions by source file, preserving order within each file
+    let mut files: Vec<(String, Vec<Intervention>)> = Vec::new();
+    for (filename, intervention) in pending.interventions.drain(..) {
+        match files.iter_mut().find(|(f, _)| *f == filename) {
+            Some((_, batch)) => batch.push(intervention),
+            None => files.push((filename, vec![intervention])),
+        }
+    }
+
+    let current_tick = world_state.current_tick;
+    for (filename, batch) in files {
+        // A file only applies once every intervention in it is due; keep the rest staged
+        if !batch.iter().all(|intervention| intervention.is_due(current_tick)) {
+            pending
+                .interventions
+                .extend(batch.into_iter().map(|i| (filename.clone(), i)));
+            continue;
+        }
 
-        if success {
-            // Delete the intervention file
-            let filepath = Path::new(INTERVENTIONS_DIR).join(&filename);
-            if let Err(e) = fs::remove_file(&filepath) {
-                eprintln!("Warning: Could not delete intervention file {:?}: {}", filepath, e);
+        // Validate the whole file up front so a bad element leaves it untouched
+        let mut killed = Vec::new();
+        let rejected = batch.iter().enumerate().find_map(|(index, intervention)| {
+            validate_intervention(intervention, &faction_registry, &agents, &mut killed)
+                .err()
+                .map(|reason| (index, reason))
+        });
+        if let Some((index, reason)) = rejected {
+            eprintln!(
+                "Warning: Intervention file {} rejected at element {} ({}): {}",
+                filename, index, batch[index].id, reason
+            );
+            continue;
+        }
+
+        for intervention in &batch {
+            if !apply_single_intervention(
+                intervention,
+                &world_state,
+                &mut tick_events,
+                &mut faction_registry,
+                &mut relationship_graph,
+                &mut tension_stream,
+                &mut agents,
+            ) {
+                eprintln!(
+                    "Warning: Validated intervention {} from {} failed to apply",
+                    intervention.id, filename
+                );
             }
         }
+
+        // Delete the intervention file
+        let filepath = Path::new(INTERVENTIONS_DIR).join(&filename);
+        if let Err(e) = fs::remove_file(&filepath) {
+            eprintln!("Warning: Could not delete intervention file {:?}: {}", filepath, e);
+        }
+    }
+}
+
+/// Parse the contents of an intervention file
+///
+/// Accepts a single intervention object, a top-level array of interventions,
+/// or an object of the form `{ "batch": [...] }`.
+pub fn parse_intervention_file(content: &str) -> Result<Vec<Intervention>, serde_json::Error> {
+    let value: serde_json::Value = serde_json::from_str(content)?;
+    match value {
+        serde_json::Value::Array(_) => serde_json::from_value(value),
+        serde_json::Value::Object(mut map) if map.contains_key("batch") => {
+            serde_json::from_value(map.remove("batch").unwrap_or_default())
+        }
+        _ => serde_json::from_value(value).map(|intervention| vec![intervention]),
+    }
+}
+
+/// Check that an intervention can be applied to the current world without
+/// changing anything
+///
+/// `killed` collects agents killed by earlier elements of the same file so a
+/// batch can't kill the same agent twice.
+fn validate_intervention(
+    intervention: &Intervention,
+    faction_registry: &FactionRegistry,
+    agents: &InterventionAgents,
+    killed: &mut Vec<String>,
+) -> Result<(), String> {
+    let require_agent = |agent_id: &str| {
+        if agents.iter().any(|(id, ..)| id.0 == agent_id) {
+            Ok(())
+        } else {
+            Err(format!("agent {} not found", agent_id))
+        }
+    };
+
+    match &intervention.intervention {
+        InterventionType::ModifyAgent { agent_id, .. }
+        | InterventionType::MoveAgent { agent_id, .. }
+        | InterventionType::ChangeFaction { agent_id, .. } => require_agent(agent_id),
+        InterventionType::ModifyFaction { faction_id, .. } => {
+            if faction_registry.get(faction_id).is_some() {
+                Ok(())
+            } else {
+                Err(format!("faction {} not found", faction_id))
+            }
+        }
+        InterventionType::ModifyRelationship { .. } => Ok(()),
+        InterventionType::AddGoal {
+            agent_id,
+            goal_type,
+            ..
+        } => {
+            parse_goal_type(goal_type).ok_or_else(|| format!("unknown goal type {}", goal_type))?;
+            require_agent(agent_id)
+        }
+        InterventionType::KillAgent { agent_id, cause } => {
+            parse_death_cause(cause).ok_or_else(|| format!("unknown death cause {}", cause))?;
+            let alive = agents
+                .iter()
+                .find(|(id, ..)| id.0 == *agent_id)
+                .map(|(.., alive)| alive.is_alive())
+                .ok_or_else(|| format!("agent {} not found", agent_id))?;
+            if !alive || killed.contains(agent_id) {
+                return Err(format!("agent {} is already dead", agent_id));
+            }
+            killed.push(agent_id.clone());
+            Ok(())
+        }
+        InterventionType::TriggerEvent {
+            event_type,
+            subtype,
+            primary_agent,
+            secondary_agent,
+            ..
+        } => {
+            let parsed_type = EventType::from_name(event_type)
+                .ok_or_else(|| format!("unknown event type {}", event_type))?;
+            if !parsed_type.is_valid_subtype(subtype)
+                || EventSubtype::parse(&parsed_type, subtype).is_none()
+            {
+                return Err(format!(
+                    "subtype {} is not valid for event type {}",
+                    subtype, event_type
+                ));
+            }
+            require_agent(primary_agent)?;
+            secondary_agent.as_deref().map_or(Ok(()), require_agent)
+        }
+        InterventionType::InjectTension {
+            tension_type,
+            key_agents,
+            ..
+        } => {
+            TensionType::from_name(tension_type)
+                .ok_or_else(|| format!("unknown tension type {}", tension_type))?;
+            if key_agents.is_empty() {
+                return Err("injected tension has no key agents".to_string());
+            }
+            key_agents.iter().try_for_each(|agent_id| require_agent(agent_id))
+        }
+    }
+}
+
+/// Parse a goal type name used in intervention files
+fn parse_goal_type(name: &str) -> Option<GoalType> {
+    match name {
+        "revenge" => Some(GoalType::Revenge),
+        "challenge_leader" => Some(GoalType::ChallengeLeader),
+        "support_leader" => Some(GoalType::SupportLeader),
+        "rise_in_status" => Some(GoalType::RiseInStatus),
+        "survive" => Some(GoalType::Survive),
+        "survive_winter" => Some(GoalType::SurviveWinter),
+        "protect" => Some(GoalType::Protect),
+        _ => None,
+    }
+}
+
+/// Parse a KillAgent cause into a death subtype
+fn parse_death_cause(cause: &str) -> Option<DeathSubtype> {
+    match cause {
+        "executed" => Some(DeathSubtype::Executed),
+        "killed" => Some(DeathSubtype::Killed),
+        "natural" => Some(DeathSubtype::Natural),
+        _ => None,
     }
 }
 
@@ -242,15 +458,8 @@ fn apply_single_intervention(
     tick_events: &mut TickEvents,
     faction_registry: &mut FactionRegistry,
     relationship_graph: &mut RelationshipGraph,
-    agents: &mut Query<(
-        &AgentId,
-        &AgentName,
-        &mut Traits,
-        &mut Needs,
-        &mut Goals,
-        &mut Position,
-        &mut FactionMembership,
-    )>,
+    tension_stream: &mut TensionStream,
+    agents: &mut InterventionAgents,
 ) -> bool {
     match &intervention.intervention {
         InterventionType::ModifyAgent {
@@ -259,7 +468,7 @@ fn apply_single_intervention(
             needs,
             goals,
         } => {
-            for (id, name, mut agent_traits, mut agent_needs, mut agent_goals, _, membership) in
+            for (id, name, mut agent_traits, mut agent_needs, mut agent_goals, _, membership, _) in
                 agents.iter_mut()
             {
                 if &id.0 == agent_id {
@@ -306,15 +515,8 @@ fn apply_single_intervention(
                     // Apply goal modifications
                     if let Some(goal_list) = goals {
                         for g in goal_list {
-                            let goal_type = match g.goal_type.as_str() {
-                                "revenge" => GoalType::Revenge,
-                                "challenge_leader" => GoalType::ChallengeLeader,
-                                "support_leader" => GoalType::SupportLeader,
-                                "rise_in_status" => GoalType::RiseInStatus,
-                                "survive" => GoalType::Survive,
-                                "survive_winter" => GoalType::SurviveWinter,
-                                "protect" => GoalType::Protect,
-                                _ => continue,
+                            let Some(goal_type) = parse_goal_type(&g.goal_type) else {
+                                continue;
                             };
                             let mut goal = crate::components::agent::Goal::new(goal_type, g.priority);
                             goal.target = g.target.clone();
@@ -421,7 +623,7 @@ fn apply_single_intervention(
             agent_id,
             location_id,
         } => {
-            for (id, name, _, _, _, mut position, membership) in agents.iter_mut() {
+            for (id, name, _, _, _, mut position, membership, _) in agents.iter_mut() {
                 if &id.0 == agent_id {
                     position.location_id = location_id.clone();
 
@@ -449,7 +651,7 @@ fn apply_single_intervention(
             new_faction_id,
             new_role,
         } => {
-            for (id, name, _, _, _, _, mut membership) in agents.iter_mut() {
+            for (id, name, _, _, _, _, mut membership, _) in agents.iter_mut() {
                 if &id.0 == agent_id {
                     let old_faction = membership.faction_id.clone();
                     membership.faction_id = new_faction_id.clone();
@@ -501,20 +703,11 @@ fn apply_single_intervention(
             target,
             priority,
         } => {
-            for (id, name, _, _, mut goals, _, membership) in agents.iter_mut() {
+            for (id, name, _, _, mut goals, _, membership, _) in agents.iter_mut() {
                 if &id.0 == agent_id {
-                    let gt = match goal_type.as_str() {
-                        "revenge" => GoalType::Revenge,
-                        "challenge_leader" => GoalType::ChallengeLeader,
-                        "support_leader" => GoalType::SupportLeader,
-                        "rise_in_status" => GoalType::RiseInStatus,
-                        "survive" => GoalType::Survive,
-                        "survive_winter" => GoalType::SurviveWinter,
-                        "protect" => GoalType::Protect,
-                        _ => {
-                            eprintln!("Warning: Unknown goal type {}", goal_type);
-                            return false;
-                        }
+                    let Some(gt) = parse_goal_type(goal_type) else {
+                        eprintln!("Warning: Unknown goal type {}", goal_type);
+                        return false;
                     };
 
                     let mut goal = crate::components::agent::Goal::new(gt, priority.unwrap_or(0.5));
@@ -542,6 +735,264 @@ fn apply_single_intervention(
             );
             false
         }
+
+        InterventionType::KillAgent { agent_id, cause } => {
+            let Some(subtype) = parse_death_cause(cause) else {
+                eprintln!("Warning: Unknown death cause {}", cause);
+                return false;
+            };
+
+            for (id, name, _, _, _, position, membership, mut alive) in agents.iter_mut() {
+                if &id.0 == agent_id {
+                    if !alive.is_alive() {
+                        eprintln!("Warning: Agent {} is already dead", agent_id);
+                        return false;
+                    }
+                    alive.0 = false;
+
+                    let event = create_death_event(
+                        tick_events,
+                        world_state,
+                        intervention,
+                        subtype,
+                        DeathTarget {
+                            agent_id,
+                            agent_name: &name.0,
+                            membership: &membership,
+                            location: &position.location_id,
+                        },
+                    );
+                    tick_events.push(event);
+
+                    return true;
+                }
+            }
+            eprintln!(
+                "Warning: Agent {} not found for kill intervention",
+                agent_id
+            );
+            false
+        }
+
+        InterventionType::TriggerEvent {
+            event_type,
+            subtype,
+            primary_agent,
+            secondary_agent,
+            drama_score,
+        } => {
+            let Some(parsed_type) = EventType::from_name(event_type) else {
+                eprintln!("Warning: Unknown event type {}", event_type);
+                return false;
+            };
+            if !parsed_type.is_valid_subtype(subtype) {
+                eprintln!(
+                    "Warning: Subtype {} is not valid for event type {}",
+                    subtype, event_type
+                );
+                return false;
+            }
+            let Some(parsed_subtype) = EventSubtype::parse(&parsed_type, subtype) else {
+                return false;
+            };
+
+            let Some(primary) = find_actor_snapshot(agents, primary_agent) else {
+                eprintln!(
+                    "Warning: Agent {} not found for trigger event intervention",
+                    primary_agent
+                );
+                return false;
+            };
+            let secondary = match secondary_agent {
+                Some(secondary_id) => match find_actor_snapshot(agents, secondary_id) {
+                    Some(snapshot) => Some(snapshot),
+                    None => {
+                        eprintln!(
+                            "Warning: Agent {} not found for trigger event intervention",
+                            secondary_id
+                        );
+                        return false;
+                    }
+                },
+                None => None,
+            };
+
+            let event = Event {
+                event_id: tick_events.generate_id(),
+                timestamp: EventTimestamp {
+                    tick: world_state.current_tick,
+                    date: world_state.formatted_date(),
+                },
+                event_type: parsed_type,
+                subtype: parsed_subtype,
+                actors: EventActors {
+                    primary,
+                    secondary,
+                    affected: None,
+                },
+                context: EventContext {
+                    trigger: format!("intervention:{}", intervention.id),
+                    preconditions: Vec::new(),
+                    location_description: None,
+                },
+                outcome: EventOutcome::General(GeneralOutcome {
+                    description: intervention.reason.clone(),
+                    state_changes: Vec::new(),
+                }),
+                drama_tags: vec!["intervention".to_string()],
+                drama_score: drama_score.unwrap_or(0.5).clamp(0.0, 1.0),
+                connected_events: Vec::new(),
+            };
+            tick_events.push(event);
+
+            true
+        }
+
+        InterventionType::InjectTension {
+            tension_type,
+            severity,
+            key_agents,
+            summary,
+        } => {
+            let Some(parsed_type) = TensionType::from_name(tension_type) else {
+                eprintln!("Warning: Unknown tension type {}", tension_type);
+                return false;
+            };
+            if key_agents.is_empty() {
+                eprintln!("Warning: Injected tension {} has no key agents", intervention.id);
+                return false;
+            }
+
+            let mut actors = Vec::new();
+            for agent_id in key_agents {
+                match find_actor_snapshot(agents, agent_id) {
+                    Some(snapshot) => actors.push(snapshot),
+                    None => {
+                        eprintln!(
+                            "Warning: Agent {} not found for inject tension intervention",
+                            agent_id
+                        );
+                        return false;
+                    }
+                }
+            }
+
+            // Log the injection; the tension points back at it as its trigger
+            let event = create_intervention_event(
+                tick_events,
+                world_state,
+                &intervention.id,
+                &format!("Injected tension: {}", summary),
+                intervention.reason.as_deref(),
+                &actors[0].agent_id,
+                &actors[0].name,
+                &actors[0].faction,
+            );
+
+            let current_tick = world_state.current_tick;
+            let mut tension = Tension::new(
+                tension_stream.generate_id(),
+                parsed_type,
+                current_tick,
+                summary.clone(),
+            );
+            for actor in &actors {
+                tension.add_agent(&actor.agent_id, "scripted", "stable");
+                if !tension.key_locations.contains(&actor.location) {
+                    tension.add_location(&actor.location);
+                }
+            }
+            tension.add_trigger_event(&event.event_id);
+            tension.update_severity(*severity, current_tick);
+            tension_stream.upsert(tension);
+            tick_events.push(event);
+
+            true
+        }
+    }
+}
+
+/// Build an actor snapshot for a live agent
+fn find_actor_snapshot(
+    agents: &InterventionAgents,
+    agent_id: &str,
+) -> Option<ActorSnapshot> {
+    agents
+        .iter()
+        .find(|(id, ..)| id.0 == agent_id)
+        .map(|(id, name, _, _, _, position, membership, _)| ActorSnapshot {
+            agent_id: id.0.clone(),
+            name: name.0.clone(),
+            faction: membership.faction_id.clone(),
+            role: format!("{:?}", membership.role).to_lowercase(),
+            location: position.location_id.clone(),
+        })
+}
+
+/// The agent a KillAgent intervention is applied to
+struct DeathTarget<'a> {
+    agent_id: &'a str,
+    agent_name: &'a str,
+    membership: &'a FactionMembership,
+    location: &'a str,
+}
+
+/// Create a death event for an agent killed by intervention
+fn create_death_event(
+    tick_events: &mut TickEvents,
+    world_state: &WorldState,
+    intervention: &Intervention,
+    subtype: DeathSubtype,
+    target: DeathTarget,
+) -> Event {
+    let event_id = tick_events.generate_id();
+    let timestamp = EventTimestamp {
+        tick: world_state.current_tick,
+        date: world_state.formatted_date(),
+    };
+
+    let actor = ActorSnapshot {
+        agent_id: target.agent_id.to_string(),
+        name: target.agent_name.to_string(),
+        faction: target.membership.faction_id.clone(),
+        role: format!("{:?}", target.membership.role).to_lowercase(),
+        location: target.location.to_string(),
+    };
+
+    let drama_score = match subtype {
+        DeathSubtype::Natural => 0.5,
+        DeathSubtype::Killed => 0.9,
+        DeathSubtype::Executed => 0.85,
+        DeathSubtype::Sacrifice => 0.95,
+    };
+
+    let description = match &intervention.reason {
+        Some(r) => format!("{} died (Reason: {})", target.agent_name, r),
+        None => format!("{} died", target.agent_name),
+    };
+
+    Event {
+        event_id,
+        timestamp,
+        event_type: EventType::Death,
+        subtype: EventSubtype::Death(subtype),
+        actors: EventActors {
+            primary: actor,
+            secondary: None,
+            affected: None,
+        },
+        context: EventContext {
+            trigger: format!("intervention:{}", intervention.id),
+            preconditions: Vec::new(),
+            location_description: None,
+        },
+        outcome: EventOutcome::General(GeneralOutcome {
+            description: Some(description),
+            state_changes: vec!["alive: false".to_string()],
+        }),
+        drama_tags: vec!["intervention".to_string(), "death".to_string()],
+        drama_score,
+        connected_events: Vec::new(),
     }
 }
 
@@ -704,4 +1155,469 @@ mod tests {
             _ => panic!("Wrong intervention type"),
         }
     }
+
+    #[test]
+    fn test_kill_agent_parsing() {
+        let json = r#"{
+            "id": "kill_001",
+            "reason": "Assassination scenario",
+            "intervention": {
+                "type": "kill_agent",
+                "agent_id": "agent_victim",
+                "cause": "killed"
+            }
+        }"#;
+
+        let intervention: Intervention = serde_json::from_str(json).unwrap();
+
+        match intervention.intervention {
+            InterventionType::KillAgent { agent_id, cause } => {
+                assert_eq!(agent_id, "agent_victim");
+                assert_eq!(cause, "killed");
+            }
+            _ => panic!("Wrong intervention type"),
+        }
+    }
+
+    #[test]
+    fn test_killed_agent_stops_acting() {
+        use crate::systems::action::{Action, PendingActions, SelectedActions, WeightedAction};
+        use crate::systems::action::{select_actions, SelectionConfig};
+        use crate::SimRng;
+        use rand::rngs::SmallRng;
+        use rand::SeedableRng;
+
+        let mut world = World::new();
+        world.insert_resource(WorldState::new());
+        world.insert_resource(TickEvents::new());
+        world.insert_resource(FactionRegistry::new());
+        world.insert_resource(RelationshipGraph::new());
+        world.insert_resource(TensionStream::new());
+        world.insert_resource(SimRng(SmallRng::seed_from_u64(42)));
+        world.insert_resource(SelectedActions::new());
+        world.insert_resource(SelectionConfig::new());
+
+        let entity = world
+            .spawn((
+                AgentId("agent_victim".to_string()),
+                AgentName("Victim".to_string()),
+                Traits::default(),
+                Needs::default(),
+                Goals::new(),
+                Position::new("hq"),
+                FactionMembership::new("thornwood", Role::Laborer),
+                Alive::new(),
+            ))
+            .id();
+
+        let mut pending = PendingInterventions::new();
+        pending.interventions.push((
+            "kill_001.json".to_string(),
+            Intervention {
+                id: "kill_001".to_string(),
+                reason: None,
+                intervention: InterventionType::KillAgent {
+                    agent_id: "agent_victim".to_string(),
+                    cause: "executed".to_string(),
+                },
+                apply_at_tick: None,
+            },
+        ));
+        world.insert_resource(pending);
+
+        let mut schedule = Schedule::default();
+        schedule.add_systems(apply_interventions);
+        schedule.run(&mut world);
+
+        assert!(!world.get::<Alive>(entity).unwrap().is_alive());
+        let events = &world.resource::<TickEvents>().events;
+        assert_eq!(events.len(), 1);
+        assert_eq!(events[0].event_type, EventType::Death);
+        assert_eq!(events[0].subtype, EventSubtype::Death(DeathSubtype::Executed));
+
+        // Any action generated for the dead agent is never selected
+        let mut actions = PendingActions::new();
+        actions.add("agent_victim", WeightedAction::new(Action::Idle, 1.0, "idle"));
+        world.insert_resource(actions);
+
+        let mut schedule = Schedule::default();
+        schedule.add_systems(select_actions);
+        schedule.run(&mut world);
+
+        assert!(world.resource::<SelectedActions>().get("agent_victim").is_none());
+    }
+
+    #[test]
+    fn test_trigger_event_parsing() {
+        let json = r#"{
+            "id": "event_001",
+            "reason": "Director test",
+            "intervention": {
+                "type": "trigger_event",
+                "event_type": "betrayal",
+                "subtype": "secret_shared_with_enemy",
+                "primary_agent": "agent_traitor",
+                "secondary_agent": "agent_handler",
+                "drama_score": 0.9
+            }
+        }"#;
+
+        let intervention: Intervention = serde_json::from_str(json).unwrap();
+
+        match intervention.intervention {
+            InterventionType::TriggerEvent {
+                event_type,
+                subtype,
+                primary_agent,
+                secondary_agent,
+                drama_score,
+            } => {
+                assert_eq!(event_type, "betrayal");
+                assert_eq!(subtype, "secret_shared_with_enemy");
+                assert_eq!(primary_agent, "agent_traitor");
+                assert_eq!(secondary_agent, Some("agent_handler".to_string()));
+                assert_eq!(drama_score, Some(0.9));
+            }
+            _ => panic!("Wrong intervention type"),
+        }
+    }
+
+    #[test]
+    fn test_trigger_event_application() {
+        use crate::events::types::BetrayalSubtype;
+
+        let mut world = World::new();
+        world.insert_resource(WorldState::new());
+        world.insert_resource(TickEvents::new());
+        world.insert_resource(FactionRegistry::new());
+        world.insert_resource(RelationshipGraph::new());
+        world.insert_resource(TensionStream::new());
+
+        for (id, faction) in [("agent_traitor", "thornwood"), ("agent_handler", "ironmere")] {
+            world.spawn((
+                AgentId(id.to_string()),
+                AgentName(id.to_string()),
+                Traits::default(),
+                Needs::default(),
+                Goals::new(),
+                Position::new("crossroads"),
+                FactionMembership::new(faction, Role::Laborer),
+                Alive::new(),
+            ));
+        }
+
+        let trigger = |id: &str, subtype: &str| Intervention {
+            id: id.to_string(),
+            reason: None,
+            intervention: InterventionType::TriggerEvent {
+                event_type: "betrayal".to_string(),
+                subtype: subtype.to_string(),
+                primary_agent: "agent_traitor".to_string(),
+                secondary_agent: Some("agent_handler".to_string()),
+                drama_score: Some(0.9),
+            },
+            apply_at_tick: None,
+        };
+
+        let mut pending = PendingInterventions::new();
+        pending.interventions.push(("valid.json".to_string(), trigger("valid", "secret_shared_with_enemy")));
+        // Travel is not a betrayal subtype and must be rejected
+        pending.interventions.push(("invalid.json".to_string(), trigger("invalid", "travel")));
+        world.insert_resource(pending);
+
+        let mut schedule = Schedule::default();
+        schedule.add_systems(apply_interventions);
+        schedule.run(&mut world);
+
+        let events = &world.resource::<TickEvents>().events;
+        assert_eq!(events.len(), 1);
+        let event = &events[0];
+        assert_eq!(event.event_type, EventType::Betrayal);
+        assert_eq!(
+            event.subtype,
+            EventSubtype::Betrayal(BetrayalSubtype::SecretSharedWithEnemy)
+        );
+        assert_eq!(event.actors.primary.faction, "thornwood");
+        assert_eq!(event.actors.secondary.as_ref().unwrap().faction, "ironmere");
+        assert_eq!(event.drama_score, 0.9);
+    }
+
+    #[test]
+    fn test_scheduled_intervention_waits_for_tick() {
+        let mut world = World::new();
+        world.insert_resource(WorldState::new());
+        world.insert_resource(TickEvents::new());
+        let mut registry = FactionRegistry::new();
+        registry.register(crate::components::faction::Faction::new("thornwood", "Thornwood", "hq"));
+        world.insert_resource(registry);
+        world.insert_resource(RelationshipGraph::new());
+        world.insert_resource(TensionStream::new());
+
+        let json = r#"{
+            "id": "scheduled_001",
+            "apply_at_tick": 500,
+            "intervention": {
+                "type": "modify_faction",
+                "faction_id": "thornwood",
+                "resources": { "grain": 100 }
+            }
+        }"#;
+        let intervention: Intervention = serde_json::from_str(json).unwrap();
+        assert_eq!(intervention.apply_at_tick, Some(500));
+
+        let mut pending = PendingInterventions::new();
+        pending.interventions.push(("scheduled_001.json".to_string(), intervention));
+        world.insert_resource(pending);
+
+        let mut schedule = Schedule::default();
+        schedule.add_systems(apply_interventions);
+
+        let grain = |world: &World| {
+            world.resource::<FactionRegistry>().get("thornwood").unwrap().resources.grain
+        };
+
+        // Too early: nothing happens, intervention stays queued
+        world.resource_mut::<WorldState>().set_tick(100);
+        schedule.run(&mut world);
+        assert_eq!(grain(&world), 0);
+        assert!(world.resource::<TickEvents>().is_empty());
+        assert!(world.resource::<PendingInterventions>().is_staged("scheduled_001.json"));
+
+        // At the target tick it applies
+        world.resource_mut::<WorldState>().set_tick(500);
+        schedule.run(&mut world);
+        assert_eq!(grain(&world), 100);
+        assert_eq!(world.resource::<TickEvents>().len(), 1);
+        assert!(world.resource::<PendingInterventions>().interventions.is_empty());
+
+        // And only once
+        world.resource_mut::<WorldState>().set_tick(501);
+        schedule.run(&mut world);
+        assert_eq!(grain(&world), 100);
+        assert_eq!(world.resource::<TickEvents>().len(), 1);
+    }
+
+    #[test]
+    fn test_batch_parsing() {
+        let elements = r#"[
+            { "id": "batch_1", "intervention": { "type": "move_agent", "agent_id": "a", "location_id": "bridge" } },
+            { "id": "batch_2", "intervention": { "type": "add_goal", "agent_id": "a", "goal_type": "revenge", "target": "b" } },
+            { "id": "batch_3", "intervention": { "type": "kill_agent", "agent_id": "b", "cause": "killed" } }
+        ]"#;
+
+        let from_array = parse_intervention_file(elements).unwrap();
+        let from_object = parse_intervention_file(&format!(r#"{{ "batch": {} }}"#, elements)).unwrap();
+
+        for batch in [from_array, from_object] {
+            let ids: Vec<_> = batch.iter().map(|i| i.id.as_str()).collect();
+            assert_eq!(ids, vec!["batch_1", "batch_2", "batch_3"]);
+            assert!(matches!(batch[2].intervention, InterventionType::KillAgent { .. }));
+        }
+
+        // A plain object is still a single intervention
+        let single = parse_intervention_file(
+            r#"{ "id": "solo", "intervention": { "type": "move_agent", "agent_id": "a", "location_id": "bridge" } }"#,
+        )
+        .unwrap();
+        assert_eq!(single.len(), 1);
+    }
+
+    #[test]
+    fn test_batch_applies_in_order() {
+        let mut world = World::new();
+        world.insert_resource(WorldState::new());
+        world.insert_resource(TickEvents::new());
+        world.insert_resource(FactionRegistry::new());
+        world.insert_resource(RelationshipGraph::new());
+        world.insert_resource(TensionStream::new());
+
+        let entity = world
+            .spawn((
+                AgentId("agent_001".to_string()),
+                AgentName("Wanderer".to_string()),
+                Traits::default(),
+                Needs::default(),
+                Goals::new(),
+                Position::new("hq"),
+                FactionMembership::new("thornwood", Role::Laborer),
+                Alive::new(),
+            ))
+            .id();
+
+        let batch = parse_intervention_file(
+            r#"{ "batch": [
+                { "id": "step_1", "intervention": { "type": "move_agent", "agent_id": "agent_001", "location_id": "fields" } },
+                { "id": "step_2", "intervention": { "type": "move_agent", "agent_id": "agent_001", "location_id": "bridge" } },
+                { "id": "step_3", "intervention": { "type": "move_agent", "agent_id": "agent_001", "location_id": "market" } }
+            ] }"#,
+        )
+        .unwrap();
+
+        let mut pending = PendingInterventions::new();
+        for intervention in batch {
+            pending.interventions.push(("journey.json".to_string(), intervention));
+        }
+        world.insert_resource(pending);
+
+        let mut schedule = Schedule::default();
+        schedule.add_systems(apply_interventions);
+        schedule.run(&mut world);
+
+        assert_eq!(world.get::<Position>(entity).unwrap().location_id, "market");
+        let triggers: Vec<_> = world
+            .resource::<TickEvents>()
+            .events
+            .iter()
+            .map(|e| e.context.trigger.clone())
+            .collect();
+        assert_eq!(
+            triggers,
+            vec!["intervention:step_1", "intervention:step_2", "intervention:step_3"]
+        );
+    }
+
+    #[test]
+    fn test_invalid_batch_applies_nothing() {
+        let mut world = World::new();
+        world.insert_resource(WorldState::new());
+        world.insert_resource(TickEvents::new());
+        world.insert_resource(FactionRegistry::new());
+        world.insert_resource(RelationshipGraph::new());
+        world.insert_resource(TensionStream::new());
+
+        let entity = world
+            .spawn((
+                AgentId("agent_001".to_string()),
+                AgentName("Wanderer".to_string()),
+                Traits::default(),
+                Needs::default(),
+                Goals::new(),
+                Position::new("hq"),
+                FactionMembership::new("thornwood", Role::Laborer),
+                Alive::new(),
+            ))
+            .id();
+
+        // The second element names an agent that doesn't exist
+        let batch = parse_intervention_file(
+            r#"{ "batch": [
+                { "id": "step_1", "intervention": { "type": "move_agent", "agent_id": "agent_001", "location_id": "fields" } },
+                { "id": "step_2", "intervention": { "type": "kill_agent", "agent_id": "agent_404", "cause": "killed" } }
+            ] }"#,
+        )
+        .unwrap();
+
+        let mut pending = PendingInterventions::new();
+        for intervention in batch {
+            pending.interventions.push(("broken.json".to_string(), intervention));
+        }
+        world.insert_resource(pending);
+
+        let mut schedule = Schedule::default();
+        schedule.add_systems(apply_interventions);
+        schedule.run(&mut world);
+
+        assert_eq!(world.get::<Position>(entity).unwrap().location_id, "hq");
+        assert!(world.resource::<TickEvents>().events.is_empty());
+    }
+
+    #[test]
+    fn test_inject_tension_parsing() {
+        let json = r#"{
+            "id": "tension_001",
+            "reason": "Force the succession storyline",
+            "intervention": {
+                "type": "inject_tension",
+                "tension_type": "succession_crisis",
+                "severity": 0.75,
+                "key_agents": ["agent_heir", "agent_rival"],
+                "summary": "Two claimants circle the empty seat"
+            }
+        }"#;
+
+        let intervention: Intervention = serde_json::from_str(json).unwrap();
+
+        match intervention.intervention {
+            InterventionType::InjectTension {
+                tension_type,
+                severity,
+                key_agents,
+                summary,
+            } => {
+                assert_eq!(tension_type, "succession_crisis");
+                assert_eq!(severity, 0.75);
+                assert_eq!(key_agents, vec!["agent_heir", "agent_rival"]);
+                assert_eq!(summary, "Two claimants circle the empty seat");
+            }
+            _ => panic!("Wrong intervention type"),
+        }
+        assert_eq!(
+            TensionType::from_name("succession_crisis"),
+            Some(TensionType::SuccessionCrisis)
+        );
+        assert_eq!(TensionType::from_name("civil_war"), None);
+    }
+
+    #[test]
+    fn test_inject_tension_application() {
+        let mut world = World::new();
+        world.insert_resource(WorldState::new());
+        world.insert_resource(TickEvents::new());
+        world.insert_resource(FactionRegistry::new());
+        world.insert_resource(RelationshipGraph::new());
+        world.insert_resource(TensionStream::new());
+
+        for (id, location) in [("agent_heir", "thornwood_hall"), ("agent_rival", "market")] {
+            world.spawn((
+                AgentId(id.to_string()),
+                AgentName(id.to_string()),
+                Traits::default(),
+                Needs::default(),
+                Goals::new(),
+                Position::new(location),
+                FactionMembership::new("thornwood", Role::CouncilMember),
+                Alive::new(),
+            ));
+        }
+
+        let inject = |id: &str, tension_type: &str| Intervention {
+            id: id.to_string(),
+            reason: None,
+            intervention: InterventionType::InjectTension {
+                tension_type: tension_type.to_string(),
+                severity: 0.85,
+                key_agents: vec!["agent_heir".to_string(), "agent_rival".to_string()],
+                summary: "Two claimants circle the empty seat".to_string(),
+            },
+            apply_at_tick: None,
+        };
+
+        let mut pending = PendingInterventions::new();
+        pending.interventions.push(("valid.json".to_string(), inject("valid", "succession_crisis")));
+        // Unknown tension types are rejected
+        pending.interventions.push(("invalid.json".to_string(), inject("invalid", "civil_war")));
+        world.insert_resource(pending);
+
+        let mut schedule = Schedule::default();
+        schedule.add_systems(apply_interventions);
+        schedule.run(&mut world);
+
+        let stream = world.resource::<TensionStream>();
+        assert_eq!(stream.active_count(), 1);
+        let tension = stream.most_severe().unwrap();
+        assert_eq!(tension.tension_type, TensionType::SuccessionCrisis);
+        assert_eq!(tension.severity, 0.85);
+        assert_eq!(tension.key_agents.len(), 2);
+        assert_eq!(tension.key_locations, vec!["thornwood_hall", "market"]);
+
+        // The intervention event is logged and linked as the tension's trigger
+        let events = &world.resource::<TickEvents>().events;
+        assert_eq!(events.len(), 1);
+        assert_eq!(tension.trigger_events, vec![events[0].event_id.clone()]);
+
+        // And the tension is part of the serialized stream the director reads
+        let json = stream.to_json();
+        assert!(json.contains(&tension.tension_id));
+        assert!(json.contains("Two claimants circle the empty seat"));
+    }
 }
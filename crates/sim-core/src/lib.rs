@@ -13,6 +13,7 @@ pub mod output;
 pub mod setup;
 pub mod interventions;
 pub mod config;
+pub mod control;
 
 pub use components::*;
 
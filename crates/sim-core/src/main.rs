@@ -19,25 +19,27 @@ use sim_core::setup;
 
 use systems::{
     AgentsByLocation, InteractionTracker, RitualAttendance, SeasonTracker, TrustEventQueue,
+    GrudgeLedger,
     ConsumptionTracker,
     build_location_index, update_perception,
     update_food_security, update_social_belonging, decay_interaction_counts,
     decay_memories, cleanup_memories,
     apply_daily_consumption, enforce_storage_caps, apply_seasonal_spoilage, decay_intoxication,
+    apply_starvation,
     process_trust_events, decay_grudges,
-    execute_rituals,
-    detect_tensions, output_tensions,
-    PendingActions, SelectedActions, TickEvents,
-    generate_movement_actions, generate_patrol_actions, generate_communication_actions, generate_archive_actions,
-    generate_resource_actions, generate_social_actions, generate_faction_actions, generate_conflict_actions,
-    generate_beer_actions,
-    apply_trait_weights, add_noise_to_weights, select_actions,
+    execute_rituals, handle_succession, resolve_goals, cascade_deaths, DeathRecord, form_alliances,
+    detect_tensions, output_tensions, record_conflicts, ConflictHistory,
+    detect_territorial_friction, TerritorialFriction,
+    NoiseConfig, PendingActions, SelectedActions, SelectionConfig, TickEvents,
+    action_generation_schedule, run_action_generation,
+    apply_trait_weights, apply_intoxication_weights, add_noise_to_weights, select_actions,
     execute_movement_actions, execute_communication_actions, execute_archive_actions,
     execute_resource_actions, execute_social_actions, execute_faction_actions, execute_conflict_actions,
     execute_beer_actions,
 };
 
 use sim_core::interventions::{PendingInterventions, scan_interventions, apply_interventions};
+use sim_core::control::{spawn_stdin_listener, RunControl};
 
 pub use components::*;
 
@@ -58,6 +60,10 @@ struct Args {
     #[arg(long, default_value_t = 100)]
     snapshot_interval: u64,
 
+    /// Write snapshots as gzipped JSON (`.json.gz`) to save disk on long runs
+    #[arg(long)]
+    compress_snapshots: bool,
+
     /// Interval between faction rituals (in ticks)
     #[arg(long, default_value_t = 500)]
     ritual_interval: u64,
@@ -73,6 +79,38 @@ struct Args {
     /// Starting tick when resuming from snapshot (auto-detected if not specified)
     #[arg(long)]
     start_tick: Option<u64>,
+
+    /// Path of the JSONL event log
+    #[arg(long, default_value = "output/events.jsonl")]
+    event_log: PathBuf,
+
+    /// Movement events below this drama score are left out of the event log
+    #[arg(long, default_value_t = 0.0)]
+    event_log_min_movement_drama: f32,
+
+    /// Accept pause/resume/step/rate commands on stdin and report every tick
+    #[arg(long)]
+    control_stdin: bool,
+
+    /// Start paused (only meaningful with --control-stdin)
+    #[arg(long)]
+    start_paused: bool,
+
+    /// Maximum ticks per second (0 = unthrottled)
+    #[arg(long, default_value_t = 0.0)]
+    tick_rate: f64,
+
+    /// Random noise mixed into action weights (0 = purely weight-driven selection)
+    #[arg(long, default_value_t = 0.2)]
+    noise_scale: f32,
+
+    /// Softmax temperature for action selection (omit to pick in proportion to weight)
+    #[arg(long)]
+    selection_temperature: Option<f32>,
+
+    /// Memories each agent keeps before the least significant are evicted
+    #[arg(long, default_value_t = social::DEFAULT_MAX_MEMORIES_PER_AGENT)]
+    max_memories_per_agent: usize,
 }
 
 /// Global simulation state resource
@@ -95,6 +133,7 @@ fn main() {
     println!("Ticks: {}", args.ticks);
     println!("Snapshot interval: {}", args.snapshot_interval);
     println!("Ritual interval: {}", args.ritual_interval);
+    println!("Event log: {}", args.event_log.display());
     if let Some(ref path) = args.from_snapshot {
         println!("Resuming from: {:?}", path);
     }
@@ -140,7 +179,7 @@ fn main() {
 
     // Initialize social resources
     world.insert_resource(social::RelationshipGraph::new());
-    world.insert_resource(social::MemoryBank::new());
+    world.insert_resource(social::MemoryBank::with_max_memories(args.max_memories_per_agent));
 
     // Initialize perception and needs resources
     world.insert_resource(AgentsByLocation::new());
@@ -152,20 +191,29 @@ fn main() {
     // Initialize action resources
     world.insert_resource(PendingActions::new());
     world.insert_resource(SelectedActions::new());
+    world.insert_resource(NoiseConfig::with_scale(args.noise_scale));
+    world.insert_resource(SelectionConfig {
+        temperature: args.selection_temperature.map(|t| t.max(0.0)),
+    });
     world.insert_resource(TickEvents::new());
 
     // Initialize trust resources
     world.insert_resource(TrustEventQueue::new());
+    world.insert_resource(GrudgeLedger::new());
+    world.insert_resource(DeathRecord::new());
 
     // Initialize tension stream for Director AI
     world.insert_resource(output::TensionStream::new());
+    world.insert_resource(ConflictHistory::new());
+    world.insert_resource(TerritorialFriction::new());
 
     // Initialize intervention system
     world.insert_resource(PendingInterventions::new());
 
     // Initialize event logger
-    let event_logger = events::EventLogger::new("output/events.jsonl")
-        .expect("Failed to create event logger");
+    let event_logger = events::EventLogger::new(&args.event_log)
+        .expect("Failed to create event logger")
+        .with_min_movement_drama(args.event_log_min_movement_drama);
     world.insert_resource(event_logger);
 
     // Spawn agents
@@ -208,7 +256,7 @@ fn main() {
         // Generate initial snapshot
         println!("Generating initial snapshot...");
         let initial_snapshot = output::generate_snapshot(&mut world, "simulation_start");
-        if let Err(e) = output::write_snapshot_to_dir(&initial_snapshot) {
+        if let Err(e) = output::write_snapshot_to_dir(&initial_snapshot, args.compress_snapshots) {
             eprintln!("  Warning: Could not write initial snapshot: {}", e);
         }
         if let Err(e) = output::write_current_state(&initial_snapshot) {
@@ -221,6 +269,12 @@ fn main() {
 
     // Create the schedule
     let mut schedule = Schedule::default();
+    // Several systems share SimRng without an explicit ordering; the
+    // multi-threaded executor would run them in arbitrary order and break
+    // seed reproducibility. Action generators don't touch SimRng and run in
+    // their own multi-threaded schedule instead.
+    schedule.set_executor_kind(bevy_ecs::schedule::ExecutorKind::SingleThreaded);
+    world.add_schedule(action_generation_schedule());
 
     // Add systems to the schedule
     // Intervention systems run first to apply any pending modifications
@@ -240,59 +294,62 @@ fn main() {
         apply_daily_consumption,
         enforce_storage_caps,
         apply_seasonal_spoilage,
-    ).after(update_perception));
+    ).chain().after(update_perception));
 
     // Needs systems run after consumption
     schedule.add_systems((
         update_food_security,
         update_social_belonging,
         decay_interaction_counts,
-    ).after(apply_daily_consumption));
+    ).chain().after(apply_seasonal_spoilage));
 
     // Intoxication decay runs with needs
     schedule.add_systems(
         decay_intoxication.after(update_food_security)
     );
 
+    // Starvation runs once food security is known for this tick
+    schedule.add_systems(
+        apply_starvation.after(decay_interaction_counts)
+    );
+
+    // Territorial friction is read from where everyone stands this tick
+    schedule.add_systems(
+        detect_territorial_friction.after(build_location_index).after(apply_starvation)
+    );
+
     // Memory systems run after needs (decay is per-season, cleanup is periodic)
     schedule.add_systems((
         decay_memories,
         cleanup_memories,
-    ).after(decay_interaction_counts));
+    ).chain().after(decay_interaction_counts));
 
     // Action systems run after memory
-    // 1. Generate possible actions
-    // 2. Apply trait-based weight modifiers
+    // 1. Generate possible actions (in parallel, see ActionGeneration)
+    // 2. Apply trait-based and intoxication weight modifiers
     // 3. Add noise for variety
     // 4. Select action probabilistically
     // 5. Execute selected actions
-    schedule.add_systems((
-        generate_movement_actions,
-        generate_patrol_actions,
-        generate_communication_actions,
-        generate_archive_actions,
-        generate_resource_actions,
-        generate_social_actions,
-        generate_faction_actions,
-        generate_conflict_actions,
-        generate_beer_actions,
-    ).after(cleanup_memories));
+    // Generation takes the whole world, so it waits for every system that
+    // updates state earlier in the tick.
+    schedule.add_systems(
+        run_action_generation
+            .after(cleanup_memories)
+            .after(detect_territorial_friction)
+            .after(decay_intoxication)
+            .after(apply_starvation)
+    );
+
+    schedule.add_systems(
+        apply_trait_weights.after(run_action_generation)
+    );
 
     schedule.add_systems(
-        apply_trait_weights
-            .after(generate_movement_actions)
-            .after(generate_patrol_actions)
-            .after(generate_communication_actions)
-            .after(generate_archive_actions)
-            .after(generate_resource_actions)
-            .after(generate_social_actions)
-            .after(generate_faction_actions)
-            .after(generate_conflict_actions)
-            .after(generate_beer_actions)
+        apply_intoxication_weights.after(apply_trait_weights)
     );
 
     schedule.add_systems(
-        add_noise_to_weights.after(apply_trait_weights)
+        add_noise_to_weights.after(apply_intoxication_weights)
     );
 
     schedule.add_systems(
@@ -309,14 +366,15 @@ fn main() {
         execute_faction_actions,
         execute_conflict_actions,
         execute_beer_actions,
-    ).after(select_actions));
+    ).chain().after(select_actions));
 
     // Trust systems run after action execution
     // Process trust events generated by actions, then decay grudges
     schedule.add_systems((
         process_trust_events,
         decay_grudges,
-    ).after(execute_communication_actions)
+    ).chain()
+     .after(execute_communication_actions)
      .after(execute_movement_actions)
      .after(execute_archive_actions)
      .after(execute_resource_actions)
@@ -327,14 +385,38 @@ fn main() {
 
     // Ritual system runs after trust (rituals are periodic faction-wide events)
     schedule.add_systems(
-        execute_rituals.after(process_trust_events)
+        execute_rituals.after(decay_grudges)
+    );
+
+    // Succession fills any leadership vacancy left by deaths or defections
+    schedule.add_systems(
+        handle_succession.after(execute_rituals).after(apply_starvation)
+    );
+
+    // Deaths ripple out: goals aimed at the dead are dropped and allies seek revenge
+    schedule.add_systems(
+        cascade_deaths.after(handle_succession)
+    );
+
+    // Goals already achieved (dead rivals, won challenges) are cleared
+    schedule.add_systems(
+        resolve_goals.after(cascade_deaths)
+    );
+
+    // Alliances form once leadership is settled for the tick
+    schedule.add_systems(
+        form_alliances.after(resolve_goals)
     );
 
     // Tension detection runs after rituals (detect dramatic patterns)
+    // Conflicts are recorded every tick; detection only samples periodically
+    schedule.add_systems(
+        record_conflicts.after(form_alliances)
+    );
     schedule.add_systems((
         detect_tensions,
         output_tensions,
-    ).after(execute_rituals));
+    ).chain().after(record_conflicts));
 
     println!();
     println!("Starting simulation...");
@@ -343,8 +425,24 @@ fn main() {
     // Calculate end tick (start_tick + ticks)
     let end_tick = start_tick + args.ticks;
 
+    let mut run_control = RunControl::new(args.control_stdin && args.start_paused, args.tick_rate);
+    let control_rx = args.control_stdin.then(spawn_stdin_listener);
+
     // Main simulation loop
     for tick in start_tick..end_tick {
+        // Wait while paused, then throttle to the requested tick rate
+        let tick_started = loop {
+            if let Some(rx) = &control_rx {
+                for command in rx.try_iter() {
+                    run_control.apply(command);
+                }
+            }
+            if run_control.take_tick() {
+                break std::time::Instant::now();
+            }
+            std::thread::sleep(std::time::Duration::from_millis(10));
+        };
+
         // Update current tick (set both to same value to avoid off-by-one)
         world.resource_mut::<SimulationState>().current_tick = tick;
         world.resource_mut::<world::WorldState>().set_tick(tick);
@@ -427,7 +525,7 @@ fn main() {
         };
         if should_snapshot {
             let snapshot = output::generate_snapshot(&mut world, "periodic");
-            if let Err(e) = output::write_snapshot_to_dir(&snapshot) {
+            if let Err(e) = output::write_snapshot_to_dir(&snapshot, args.compress_snapshots) {
                 eprintln!("Warning: Could not write snapshot at tick {}: {}", tick, e);
             }
             if let Err(e) = output::write_current_state(&snapshot) {
@@ -436,6 +534,18 @@ fn main() {
             world.resource_mut::<output::SnapshotGenerator>().mark_snapshot(tick);
         }
 
+        // Report every tick to a controlling process
+        if control_rx.is_some() {
+            println!("tick={} max={}", tick, end_tick);
+        }
+
+        if let Some(interval) = run_control.tick_interval() {
+            let elapsed = tick_started.elapsed();
+            if elapsed < interval {
+                std::thread::sleep(interval - elapsed);
+            }
+        }
+
         // Print progress every 100 ticks
         if tick > 0 && tick % 100 == 0 {
             let world_state = world.resource::<world::WorldState>();
@@ -455,7 +565,7 @@ fn main() {
 
     // Generate final snapshot
     let final_snapshot = output::generate_snapshot(&mut world, "simulation_end");
-    if let Err(e) = output::write_snapshot_to_dir(&final_snapshot) {
+    if let Err(e) = output::write_snapshot_to_dir(&final_snapshot, args.compress_snapshots) {
         eprintln!("Warning: Could not write final snapshot: {}", e);
     }
     if let Err(e) = output::write_current_state(&final_snapshot) {
@@ -3,7 +3,7 @@
 //! Serialization structs for world snapshots and state output.
 
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
+use std::collections::BTreeMap;
 
 /// Timestamp for snapshots
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -43,8 +43,8 @@ pub struct FactionSnapshot {
     pub reader: Option<String>,
     pub archive_entry_count: usize,
     pub cohesion_score: f32,
-    #[serde(skip_serializing_if = "HashMap::is_empty")]
-    pub external_reputation: HashMap<String, f32>,
+    #[serde(skip_serializing_if = "BTreeMap::is_empty")]
+    pub external_reputation: BTreeMap<String, f32>,
 }
 
 /// Faction resources
@@ -68,6 +68,20 @@ pub struct TraitsSnapshot {
     pub group_preference: f32,
 }
 
+impl Default for TraitsSnapshot {
+    fn default() -> Self {
+        Self {
+            boldness: 0.5,
+            loyalty_weight: 0.5,
+            grudge_persistence: 0.5,
+            ambition: 0.5,
+            honesty: 0.5,
+            sociability: 0.5,
+            group_preference: 0.5,
+        }
+    }
+}
+
 /// Agent status snapshot
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct StatusSnapshot {
@@ -86,6 +100,15 @@ pub struct NeedsSnapshot {
     pub social_belonging: String,
 }
 
+impl Default for NeedsSnapshot {
+    fn default() -> Self {
+        Self {
+            food_security: "secure".to_string(),
+            social_belonging: "integrated".to_string(),
+        }
+    }
+}
+
 /// Agent goal snapshot
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct GoalSnapshot {
@@ -104,11 +127,18 @@ pub struct AgentSnapshot {
     pub faction: String,
     pub role: String,
     pub location: String,
+    #[serde(default)]
     pub traits: TraitsSnapshot,
     pub status: StatusSnapshot,
+    #[serde(default)]
     pub needs: NeedsSnapshot,
-    #[serde(skip_serializing_if = "Vec::is_empty")]
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub goals: Vec<GoalSnapshot>,
+    /// Overall standing, weighted average of how others trust this agent
+    pub reputation: f32,
+    /// IDs of the events this agent holds a memory of, firsthand or heard
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub remembered_events: Vec<String>,
 }
 
 /// Relationship snapshot
@@ -132,6 +162,9 @@ pub struct LocationSnapshot {
     pub agents_present: Vec<String>,
     pub resources: LocationResourcesSnapshot,
     pub properties: Vec<String>,
+    /// Whether beer can be brewed here
+    #[serde(default)]
+    pub is_brewery: bool,
 }
 
 /// Location resources
@@ -189,9 +222,9 @@ pub struct SocialNetworkSnapshot {
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct ComputedMetrics {
     #[serde(default)]
-    pub faction_power_balance: HashMap<String, f32>,
-    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
-    pub war_probability_30_days: HashMap<String, f32>,
+    pub faction_power_balance: BTreeMap<String, f32>,
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub war_probability_30_days: BTreeMap<String, f32>,
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub agents_at_defection_risk: Vec<String>,
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
@@ -200,6 +233,19 @@ pub struct ComputedMetrics {
     pub social_network: SocialNetworkSnapshot,
 }
 
+/// A grudge one agent holds against another, and where it started
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GrudgeSnapshot {
+    pub holder_id: String,
+    pub offender_id: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub origin_event: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub location: Option<String>,
+    pub severity: f32,
+    pub formed_tick: u64,
+}
+
 /// Complete world snapshot
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct WorldSnapshot {
@@ -209,9 +255,11 @@ pub struct WorldSnapshot {
     pub world: WorldStateSnapshot,
     pub factions: Vec<FactionSnapshot>,
     pub agents: Vec<AgentSnapshot>,
-    pub relationships: HashMap<String, HashMap<String, RelationshipSnapshot>>,
+    pub relationships: BTreeMap<String, BTreeMap<String, RelationshipSnapshot>>,
     pub locations: Vec<LocationSnapshot>,
     pub computed_metrics: ComputedMetrics,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub grudges: Vec<GrudgeSnapshot>,
 }
 
 impl WorldSnapshot {
@@ -235,9 +283,10 @@ impl WorldSnapshot {
             },
             factions: Vec::new(),
             agents: Vec::new(),
-            relationships: HashMap::new(),
+            relationships: BTreeMap::new(),
             locations: Vec::new(),
             computed_metrics: ComputedMetrics::default(),
+            grudges: Vec::new(),
         }
     }
 }
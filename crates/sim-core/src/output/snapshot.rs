@@ -3,14 +3,20 @@
 //! System for generating world snapshots at regular intervals.
 
 use bevy_ecs::prelude::*;
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 use std::fs;
+use std::io::{Read, Write};
 use std::path::Path;
 
+use flate2::read::GzDecoder;
+use flate2::write::GzEncoder;
+use flate2::Compression;
+
 use crate::components::agent::{AgentId, AgentName, Alive, Goals, Needs, Traits};
 use crate::components::faction::{FactionMembership, FactionRegistry};
-use crate::components::social::RelationshipGraph;
+use crate::components::social::{MemoryBank, RelationshipGraph, NEUTRAL_REPUTATION};
 use crate::components::world::{LocationRegistry, Position, WorldState};
+use crate::systems::trust::{Grudge, GrudgeLedger};
 
 use super::schemas::*;
 
@@ -101,7 +107,7 @@ pub fn generate_snapshot(world: &mut World, triggered_by: &str) -> WorldSnapshot
             reader: faction.reader.clone(),
             archive_entry_count: archive_count,
             cohesion_score: 0.8, // Placeholder - compute from relationships
-            external_reputation: HashMap::new(), // Placeholder
+            external_reputation: BTreeMap::new(), // Placeholder
         });
     }
 
@@ -174,6 +180,8 @@ pub fn generate_snapshot(world: &mut World, triggered_by: &str) -> WorldSnapshot
                     social_belonging: format!("{:?}", needs.social_belonging).to_lowercase(),
                 },
                 goals: goals_snapshot,
+                reputation: 0.0, // Computed below
+                remembered_events: Vec::new(), // Computed below
             });
         }
     }
@@ -188,7 +196,7 @@ pub fn generate_snapshot(world: &mut World, triggered_by: &str) -> WorldSnapshot
         let agent_relationships = relationship_graph.relationships_for(&agent.agent_id);
 
         if !agent_relationships.is_empty() {
-            let mut rel_map: HashMap<String, RelationshipSnapshot> = HashMap::new();
+            let mut rel_map: BTreeMap<String, RelationshipSnapshot> = BTreeMap::new();
 
             for rel in agent_relationships {
                 // Count trusts
@@ -211,8 +219,13 @@ pub fn generate_snapshot(world: &mut World, triggered_by: &str) -> WorldSnapshot
         }
     }
 
-    // Update agent trust counts
+    // Update agent trust counts and reputation
+    let reputations = relationship_graph.reputations();
     for agent in &mut snapshot.agents {
+        agent.reputation = reputations
+            .get(&agent.agent_id)
+            .copied()
+            .unwrap_or(NEUTRAL_REPUTATION);
         if let Some(&(trusted_by, trusts)) = trust_counts.get(&agent.agent_id) {
             agent.status.trusted_by_count = trusted_by;
             agent.status.trusts_count = trusts;
@@ -220,6 +233,21 @@ pub fn generate_snapshot(world: &mut World, triggered_by: &str) -> WorldSnapshot
         }
     }
 
+    // Record which events each agent remembers, so readers can tell who knows what
+    if let Some(memory_bank) = world.get_resource::<MemoryBank>() {
+        for agent in &mut snapshot.agents {
+            let mut remembered: Vec<String> = memory_bank
+                .get_memories(&agent.agent_id)
+                .into_iter()
+                .flatten()
+                .filter_map(|m| m.event_id.clone())
+                .collect();
+            remembered.sort();
+            remembered.dedup();
+            agent.remembered_events = remembered;
+        }
+    }
+
     // Collect location data
     let location_registry = world.resource::<LocationRegistry>();
 
@@ -262,9 +290,25 @@ pub fn generate_snapshot(world: &mut World, triggered_by: &str) -> WorldSnapshot
             agents_present,
             resources,
             properties: location.properties.iter().map(|p| format!("{:?}", p).to_lowercase()).collect(),
+            is_brewery: location.is_brewery(),
         });
     }
 
+    // Grudges and their origins
+    if let Some(ledger) = world.get_resource::<GrudgeLedger>() {
+        snapshot.grudges = ledger
+            .all()
+            .map(|grudge| GrudgeSnapshot {
+                holder_id: grudge.holder_id.clone(),
+                offender_id: grudge.offender_id.clone(),
+                origin_event: grudge.origin_event.clone(),
+                location: grudge.location.clone(),
+                severity: grudge.severity,
+                formed_tick: grudge.formed_tick,
+            })
+            .collect();
+    }
+
     // Compute metrics
     snapshot.computed_metrics = compute_metrics(&snapshot);
 
@@ -348,10 +392,29 @@ pub fn write_snapshot(snapshot: &WorldSnapshot, path: impl AsRef<Path>) -> std::
     Ok(())
 }
 
+/// Write snapshot to file as gzipped JSON
+pub fn write_snapshot_compressed(
+    snapshot: &WorldSnapshot,
+    path: impl AsRef<Path>,
+) -> std::io::Result<()> {
+    let json = serde_json::to_vec(snapshot)?;
+    let mut encoder = GzEncoder::new(fs::File::create(path)?, Compression::default());
+    encoder.write_all(&json)?;
+    encoder.finish()?;
+    Ok(())
+}
+
 /// Write snapshot to snapshots directory
-pub fn write_snapshot_to_dir(snapshot: &WorldSnapshot) -> std::io::Result<()> {
-    let path = format!("output/snapshots/snap_{:06}.json", snapshot.timestamp.tick);
-    write_snapshot(snapshot, path)
+///
+/// Plain `.json` by default; with `compress` the snapshot is gzipped to
+/// `.json.gz`, which `load_snapshot` and the visualizer read transparently.
+pub fn write_snapshot_to_dir(snapshot: &WorldSnapshot, compress: bool) -> std::io::Result<()> {
+    let tick = snapshot.timestamp.tick;
+    if compress {
+        write_snapshot_compressed(snapshot, format!("output/snapshots/snap_{:06}.json.gz", tick))
+    } else {
+        write_snapshot(snapshot, format!("output/snapshots/snap_{:06}.json", tick))
+    }
 }
 
 /// Write current state (overwrites each time)
@@ -359,9 +422,16 @@ pub fn write_current_state(snapshot: &WorldSnapshot) -> std::io::Result<()> {
     write_snapshot(snapshot, "output/current_state.json")
 }
 
-/// Load a snapshot from file
+/// Load a snapshot from file, decompressing it if the path ends in `.gz`
 pub fn load_snapshot(path: impl AsRef<Path>) -> std::io::Result<WorldSnapshot> {
-    let json = fs::read_to_string(path)?;
+    let path = path.as_ref();
+    let json = if path.extension().is_some_and(|e| e == "gz") {
+        let mut json = String::new();
+        GzDecoder::new(fs::File::open(path)?).read_to_string(&mut json)?;
+        json
+    } else {
+        fs::read_to_string(path)?
+    };
     let snapshot: WorldSnapshot = serde_json::from_str(&json)
         .map_err(|e| std::io::Error::new(std::io::ErrorKind::InvalidData, e))?;
     Ok(snapshot)
@@ -523,12 +593,27 @@ pub fn restore_from_snapshot(world: &mut World, snapshot: &WorldSnapshot) -> u64
                     },
                     last_interaction_tick: rel_snap.last_interaction_tick,
                     memory_count: rel_snap.memory_count,
+                    target_deceased: false,
                 };
                 relationship_graph.set(agent_id, relationship);
             }
         }
     }
 
+    // Restore grudges
+    if let Some(mut ledger) = world.get_resource_mut::<GrudgeLedger>() {
+        for grudge_snap in &snapshot.grudges {
+            ledger.record(Grudge {
+                holder_id: grudge_snap.holder_id.clone(),
+                offender_id: grudge_snap.offender_id.clone(),
+                origin_event: grudge_snap.origin_event.clone(),
+                location: grudge_snap.location.clone(),
+                severity: grudge_snap.severity,
+                formed_tick: grudge_snap.formed_tick,
+            });
+        }
+    }
+
     tick
 }
 
@@ -549,6 +634,86 @@ mod tests {
         assert_eq!(parsed.snapshot_id, "snap_000001");
     }
 
+    #[test]
+    fn test_compressed_snapshot_round_trip() {
+        let mut snapshot = WorldSnapshot::new("snap_000250", 250, "year_1.summer.day_5", "periodic");
+        snapshot.factions.push(FactionSnapshot {
+            faction_id: "thornwood".to_string(),
+            name: "Thornwood".to_string(),
+            territory: vec!["thornwood_hall".to_string()],
+            headquarters: "thornwood_hall".to_string(),
+            resources: FactionResourcesSnapshot {
+                grain: 120,
+                iron: 40,
+                salt: 15,
+                beer: 8,
+            },
+            member_count: 12,
+            leader: Some("agent_0001".to_string()),
+            reader: None,
+            archive_entry_count: 3,
+            cohesion_score: 0.65,
+            external_reputation: BTreeMap::from([("ironmere".to_string(), -0.25)]),
+        });
+
+        let dir = std::env::temp_dir()
+            .join(format!("emergent_sim_snapshot_gz_{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("snap_000250.json.gz");
+        write_snapshot_compressed(&snapshot, &path).unwrap();
+
+        // Really compressed: gzip magic bytes, not JSON
+        let bytes = fs::read(&path).unwrap();
+        assert_eq!(&bytes[..2], &[0x1f, 0x8b]);
+
+        let loaded = load_snapshot(&path).unwrap();
+        assert_eq!(
+            serde_json::to_value(&loaded).unwrap(),
+            serde_json::to_value(&snapshot).unwrap()
+        );
+        let _ = fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn test_snapshot_captures_needs_and_traits() {
+        use crate::components::agent::{FoodSecurity, Role, SocialBelonging};
+
+        let mut world = World::new();
+        world.insert_resource(WorldState::new());
+        world.insert_resource(SnapshotGenerator::new(100));
+        world.insert_resource(FactionRegistry::new());
+        world.insert_resource(RelationshipGraph::new());
+        world.insert_resource(LocationRegistry::new());
+        world.spawn((
+            AgentId("agent_0001".to_string()),
+            AgentName("Mira".to_string()),
+            Alive::new(),
+            FactionMembership::new("thornwood", Role::Laborer),
+            Position::new("thornwood_hall"),
+            Traits { ambition: 0.9, ..Traits::default() },
+            Needs {
+                food_security: FoodSecurity::Desperate,
+                social_belonging: SocialBelonging::Isolated,
+            },
+            Goals::new(),
+        ));
+
+        let snapshot = generate_snapshot(&mut world, "test");
+        let agent = &snapshot.agents[0];
+        assert_eq!(agent.needs.food_security, "desperate");
+        assert_eq!(agent.needs.social_belonging, "isolated");
+        assert_eq!(agent.traits.ambition, 0.9);
+
+        // Snapshots written before needs and traits were recorded still load
+        let mut json = serde_json::to_value(&snapshot).unwrap();
+        let agent_json = json["agents"][0].as_object_mut().unwrap();
+        agent_json.remove("needs");
+        agent_json.remove("traits");
+        let parsed: WorldSnapshot = serde_json::from_value(json).unwrap();
+        assert_eq!(parsed.agents[0].needs.food_security, "secure");
+        assert_eq!(parsed.agents[0].traits.ambition, 0.5);
+    }
+
     #[test]
     fn test_faction_power_calculation() {
         let faction = FactionSnapshot {
@@ -567,7 +732,7 @@ mod tests {
             reader: None,
             archive_entry_count: 0,
             cohesion_score: 0.8,
-            external_reputation: HashMap::new(),
+            external_reputation: BTreeMap::new(),
         };
 
         let power = faction_power(&faction);
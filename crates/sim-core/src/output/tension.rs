@@ -5,33 +5,9 @@
 
 use bevy_ecs::prelude::*;
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
+use std::collections::BTreeMap;
 
-/// Type of tension detected in the simulation
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
-#[serde(rename_all = "snake_case")]
-pub enum TensionType {
-    /// Trust eroding toward defection/sabotage
-    BrewingBetrayal,
-    /// Leadership contested or uncertain
-    SuccessionCrisis,
-    /// Scarcity driving competition
-    ResourceConflict,
-    /// Cross-faction relationship forming
-    ForbiddenAlliance,
-    /// Agent pursuing payback
-    RevengeArc,
-    /// Agent gaining influence rapidly
-    RisingPower,
-    /// Internal faction divisions deepening
-    FactionFracture,
-    /// Outside pressure forcing response
-    ExternalThreat,
-    /// Hidden information about to surface
-    SecretExposed,
-    /// Upcoming ritual at risk
-    RitualDisruption,
-}
+pub use sim_events::TensionType;
 
 /// Status of a tension
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
@@ -193,12 +169,130 @@ impl Tension {
     pub fn is_resolved(&self) -> bool {
         self.status == TensionStatus::Resolved
     }
+
+    /// Merge an overlapping tension of the same type into this one.
+    /// Delegates to `sim_events::Tension::try_merge`.
+    pub fn try_merge(&self, other: &Tension) -> Option<Tension> {
+        sim_events::Tension::from(self)
+            .try_merge(&sim_events::Tension::from(other))
+            .map(Tension::from)
+    }
+}
+
+impl From<TensionStatus> for sim_events::TensionStatus {
+    fn from(status: TensionStatus) -> Self {
+        match status {
+            TensionStatus::Developing => sim_events::TensionStatus::Emerging,
+            TensionStatus::Escalating => sim_events::TensionStatus::Escalating,
+            TensionStatus::Critical => sim_events::TensionStatus::Critical,
+            TensionStatus::DeEscalating => sim_events::TensionStatus::Resolving,
+            TensionStatus::Resolved => sim_events::TensionStatus::Resolved,
+        }
+    }
+}
+
+impl From<sim_events::TensionStatus> for TensionStatus {
+    fn from(status: sim_events::TensionStatus) -> Self {
+        match status {
+            sim_events::TensionStatus::Emerging | sim_events::TensionStatus::Dormant => {
+                TensionStatus::Developing
+            }
+            sim_events::TensionStatus::Escalating => TensionStatus::Escalating,
+            sim_events::TensionStatus::Critical | sim_events::TensionStatus::Climax => {
+                TensionStatus::Critical
+            }
+            sim_events::TensionStatus::Resolving => TensionStatus::DeEscalating,
+            sim_events::TensionStatus::Resolved => TensionStatus::Resolved,
+        }
+    }
+}
+
+impl From<&Tension> for sim_events::Tension {
+    fn from(tension: &Tension) -> Self {
+        sim_events::Tension {
+            tension_id: tension.tension_id.clone(),
+            detected_at_tick: tension.detected_at_tick,
+            last_updated_tick: tension.last_updated_tick,
+            status: tension.status.into(),
+            tension_type: tension.tension_type,
+            severity: tension.severity,
+            confidence: tension.confidence,
+            summary: tension.summary.clone(),
+            key_agents: tension
+                .key_agents
+                .iter()
+                .map(|a| sim_events::TensionAgent::new(&a.agent_id, &a.role_in_tension, &a.trajectory))
+                .collect(),
+            key_locations: tension.key_locations.clone(),
+            trigger_events: tension.trigger_events.clone(),
+            predicted_outcomes: tension
+                .predicted_outcomes
+                .iter()
+                .map(|o| sim_events::PredictedOutcome {
+                    outcome: o.outcome.clone(),
+                    probability: o.probability,
+                    impact: o.impact.clone(),
+                    estimated_ticks_until: o.estimated_ticks_until,
+                })
+                .collect(),
+            narrative_hooks: tension.narrative_hooks.clone(),
+            recommended_camera_focus: tension.recommended_camera_focus.as_ref().map(|f| {
+                sim_events::CameraRecommendation::new(&f.primary)
+                    .with_secondary(f.secondary.clone())
+                    .with_locations(f.locations_of_interest.clone())
+            }),
+            connected_tensions: tension.connected_tensions.clone(),
+        }
+    }
+}
+
+impl From<sim_events::Tension> for Tension {
+    fn from(tension: sim_events::Tension) -> Self {
+        Tension {
+            tension_id: tension.tension_id,
+            detected_at_tick: tension.detected_at_tick,
+            last_updated_tick: tension.last_updated_tick,
+            status: tension.status.into(),
+            tension_type: tension.tension_type,
+            severity: tension.severity,
+            confidence: tension.confidence,
+            summary: tension.summary,
+            key_agents: tension
+                .key_agents
+                .into_iter()
+                .map(|a| TensionAgent {
+                    agent_id: a.agent_id,
+                    role_in_tension: a.role_in_tension,
+                    trajectory: a.trajectory,
+                })
+                .collect(),
+            key_locations: tension.key_locations,
+            trigger_events: tension.trigger_events,
+            predicted_outcomes: tension
+                .predicted_outcomes
+                .into_iter()
+                .map(|o| PredictedOutcome {
+                    outcome: o.outcome,
+                    probability: o.probability,
+                    impact: o.impact,
+                    estimated_ticks_until: o.estimated_ticks_until,
+                })
+                .collect(),
+            narrative_hooks: tension.narrative_hooks,
+            recommended_camera_focus: tension.recommended_camera_focus.map(|f| CameraFocus {
+                primary: f.primary.unwrap_or_default(),
+                secondary: f.secondary,
+                locations_of_interest: f.locations_of_interest,
+            }),
+            connected_tensions: tension.connected_tensions,
+        }
+    }
 }
 
 /// Resource holding all active tensions
 #[derive(Resource, Debug, Default, Serialize, Deserialize)]
 pub struct TensionStream {
-    tensions: HashMap<String, Tension>,
+    tensions: BTreeMap<String, Tension>,
     next_tension_id: u64,
 }
 
@@ -272,6 +366,28 @@ impl TensionStream {
         serde_json::to_string_pretty(&active).unwrap_or_else(|_| "[]".to_string())
     }
 
+    /// Fold overlapping tensions of the same type into one, keeping the
+    /// lowest ID. Returns the number of tensions absorbed.
+    pub fn merge_overlapping(&mut self) -> usize {
+        let ids: Vec<String> = self.tensions.keys().cloned().collect();
+        let mut absorbed = 0;
+
+        for (i, id) in ids.iter().enumerate() {
+            for other_id in &ids[i + 1..] {
+                let (Some(base), Some(other)) = (self.tensions.get(id), self.tensions.get(other_id)) else {
+                    continue;
+                };
+                if let Some(merged) = base.try_merge(other) {
+                    self.tensions.insert(id.clone(), merged);
+                    self.tensions.remove(other_id);
+                    absorbed += 1;
+                }
+            }
+        }
+
+        absorbed
+    }
+
     /// Find existing tension involving specific agents
     pub fn find_tension_with_agents(&self, agent_ids: &[&str]) -> Option<&Tension> {
         self.tensions.values().find(|t| {
@@ -324,6 +440,40 @@ mod tests {
         assert_eq!(most_severe.tension_type, TensionType::BrewingBetrayal);
     }
 
+    #[test]
+    fn test_merge_overlapping() {
+        let mut stream = TensionStream::new();
+
+        let mut a = Tension::new("betrayal_mira_vs_corin", TensionType::BrewingBetrayal, 100, "Mira wavers");
+        a.add_agent("mira", "potential_traitor", "uncertain");
+        a.add_agent("corin", "leader", "unaware");
+        a.add_trigger_event("evt_1");
+        a.severity = 0.4;
+
+        let mut b = Tension::new("betrayal_voss_vs_corin", TensionType::BrewingBetrayal, 50, "Voss wavers");
+        b.add_agent("voss", "potential_traitor", "uncertain");
+        b.add_agent("corin", "leader", "unaware");
+        b.add_trigger_event("evt_2");
+        b.severity = 0.6;
+
+        let mut c = Tension::new("revenge_mira", TensionType::RevengeArc, 100, "Mira hunts");
+        c.add_agent("mira", "avenger", "hunting");
+
+        stream.upsert(a);
+        stream.upsert(b);
+        stream.upsert(c);
+
+        assert_eq!(stream.merge_overlapping(), 1);
+        assert_eq!(stream.active_count(), 2);
+
+        let merged = stream.get("betrayal_mira_vs_corin").unwrap();
+        assert_eq!(merged.key_agents.len(), 3);
+        assert_eq!(merged.trigger_events.len(), 2);
+        assert_eq!(merged.severity, 0.6);
+        assert_eq!(merged.detected_at_tick, 50);
+        assert!(stream.get("revenge_mira").is_some());
+    }
+
     #[test]
     fn test_tension_serialization() {
         let mut tension = Tension::new("tens_00001", TensionType::ForbiddenAlliance, 500, "Cross-faction friendship");
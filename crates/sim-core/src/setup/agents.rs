@@ -7,7 +7,8 @@ use rand::rngs::SmallRng;
 use rand::Rng;
 
 use crate::components::agent::{
-    Agent, AgentId, AgentName, Alive, FoodSecurity, Goals, Intoxication, Needs, Role, SocialBelonging, Traits,
+    Agent, AgentId, AgentName, Alive, FoodSecurity, Goals, Intoxication, Needs, Role, SocialBelonging,
+    StarvationClock, Traits,
 };
 use crate::components::faction::FactionMembership;
 use crate::components::social::{Memory, MemoryBank, MemoryValence, Relationship, RelationshipGraph, Trust};
@@ -163,6 +164,7 @@ pub fn spawn_faction_agents(
             Alive::new(),
             VisibleAgents::new(),
             Intoxication::new(),
+            StarvationClock::new(),
         )).id();
 
         spawned_entities.push(entity);
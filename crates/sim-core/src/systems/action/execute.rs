@@ -8,9 +8,9 @@ use rand::Rng;
 use crate::actions::movement::{MoveAction, MovementType};
 use crate::actions::communication::{CommunicationAction, CommunicationType, TargetMode, communication_weights};
 use crate::actions::archive::{ArchiveAction, ArchiveActionType};
-use crate::actions::resource::{ResourceAction, ResourceActionType};
+use crate::actions::resource::{ResourceAction, ResourceActionType, ResourceType, resource_weights};
 use crate::actions::social::{SocialAction, SocialActionType, social_weights};
-use crate::actions::faction::{FactionAction, FactionActionType};
+use crate::actions::faction::{FactionAction, FactionActionType, faction_weights};
 use crate::actions::conflict::{ConflictAction, ConflictActionType, conflict_weights};
 use crate::actions::beer::{BeerAction, BeerActionType, beer_weights};
 use crate::components::agent::{AgentId, AgentName, Alive, Goals, GoalType, Intoxication, Needs, Role, SocialBelonging, Traits};
@@ -22,11 +22,14 @@ use crate::events::types::{
     EventSubtype, MovementSubtype, MovementOutcome, CommunicationSubtype,
     CommunicationOutcome as EventCommunicationOutcome, MemorySharedInfo, RecipientStateChange,
     ArchiveSubtype, ArchiveOutcome, ResourceSubtype, CooperationSubtype, FactionSubtype,
-    ConflictSubtype, GeneralOutcome, RelationshipOutcome, RelationshipChange,
+    ConflictSubtype, ConflictOutcome, GeneralOutcome, RelationshipOutcome, RelationshipChange,
+    AffectedActor, LoyaltySubtype,
 };
-use crate::components::faction::{FactionMembership, FactionRegistry, ArchiveEntry};
+use crate::components::faction::{FactionMembership, FactionRegistry, FactionResources, ArchiveEntry};
 use crate::systems::memory::calculate_secondhand_trust_impact;
 use crate::systems::perception::AgentsByLocation;
+use crate::systems::succession::{challenge_support, install_leader, step_down};
+use crate::systems::trust::{create_trust_event, TrustEventQueue, TrustEventType};
 use crate::SimRng;
 
 use super::generate::Action;
@@ -70,6 +73,7 @@ impl TickEvents {
 /// System to execute movement actions
 pub fn execute_movement_actions(
     world_state: Res<WorldState>,
+    mut agents_by_location: ResMut<AgentsByLocation>,
     mut selected_actions: ResMut<SelectedActions>,
     mut tick_events: ResMut<TickEvents>,
     mut query: Query<(Entity, &AgentId, &mut Position, &crate::components::faction::FactionMembership, &crate::components::agent::AgentName)>,
@@ -84,8 +88,9 @@ pub fn execute_movement_actions(
                 let old_location = position.location_id.clone();
                 let new_location = move_action.destination.clone();
 
-                // Update position
+                // Update position and the location index
                 position.location_id = new_location.clone();
+                agents_by_location.moved(&agent_id.0, &old_location, &new_location);
 
                 // Generate movement event
                 let event = create_movement_event(
@@ -197,24 +202,30 @@ fn create_movement_event(
 
 /// System to execute communication actions
 pub fn execute_communication_actions(
+    mut rng: ResMut<SimRng>,
     world_state: Res<WorldState>,
+    faction_registry: Res<FactionRegistry>,
     agents_by_location: Res<AgentsByLocation>,
     mut memory_bank: ResMut<MemoryBank>,
     mut relationship_graph: ResMut<RelationshipGraph>,
     mut selected_actions: ResMut<SelectedActions>,
     mut tick_events: ResMut<TickEvents>,
-    query: Query<(&AgentId, &AgentName, &Position, &crate::components::faction::FactionMembership)>,
+    query: Query<(&AgentId, &AgentName, &Position, &crate::components::faction::FactionMembership, Option<&Traits>)>,
 ) {
     // Build lookup map for agent info
     let agent_info: std::collections::HashMap<String, (&AgentName, &Position, &crate::components::faction::FactionMembership)> = query
         .iter()
-        .map(|(id, name, pos, mem)| (id.0.clone(), (name, pos, mem)))
+        .map(|(id, name, pos, mem, _)| (id.0.clone(), (name, pos, mem)))
+        .collect();
+    let loyalty: std::collections::HashMap<String, f32> = query
+        .iter()
+        .map(|(id, _, _, _, traits)| (id.0.clone(), traits.map(|t| t.loyalty_weight).unwrap_or(Traits::default().loyalty_weight)))
         .collect();
 
     // Collect communication actions to process
     let mut comm_actions: Vec<(String, CommunicationAction)> = Vec::new();
 
-    for (agent_id, _, _, _) in query.iter() {
+    for (agent_id, _, _, _, _) in query.iter() {
         if let Some(action) = selected_actions.actions.get(&agent_id.0) {
             if let Action::Communicate(comm_action) = action {
                 comm_actions.push((agent_id.0.clone(), comm_action.clone()));
@@ -238,6 +249,7 @@ pub fn execute_communication_actions(
             CommunicationType::ShareMemory => {
                 if let Some(memory) = shared_memory {
                     execute_share_memory(
+                        &mut rng.0,
                         &world_state,
                         &agents_by_location,
                         &mut memory_bank,
@@ -258,6 +270,7 @@ pub fn execute_communication_actions(
                 // For now, treat same as share memory
                 if let Some(memory) = shared_memory {
                     execute_share_memory(
+                        &mut rng.0,
                         &world_state,
                         &agents_by_location,
                         &mut memory_bank,
@@ -273,6 +286,23 @@ pub fn execute_communication_actions(
                     );
                 }
             }
+            CommunicationType::Recruit => {
+                execute_recruit(
+                    &world_state,
+                    &faction_registry,
+                    &mut relationship_graph,
+                    &mut tick_events,
+                    &agent_info,
+                    RecruitAttempt {
+                        actor_id: &actor_id,
+                        actor_name,
+                        actor_pos,
+                        actor_membership,
+                        target_id: &comm_action.target_id,
+                        target_loyalty: loyalty.get(&comm_action.target_id).copied().unwrap_or(0.0),
+                    },
+                );
+            }
             CommunicationType::Lie | CommunicationType::Confess => {
                 // These require more complex handling - placeholder for now
             }
@@ -282,6 +312,7 @@ pub fn execute_communication_actions(
 
 /// Execute a share memory action
 fn execute_share_memory(
+    rng: &mut impl Rng,
     world_state: &WorldState,
     agents_by_location: &AgentsByLocation,
     memory_bank: &mut MemoryBank,
@@ -313,7 +344,7 @@ fn execute_share_memory(
 
     // Calculate fidelity for secondhand memory
     let fidelity_multiplier = if comm_action.target_mode == TargetMode::Group {
-        communication_weights::GROUP_FIDELITY_MULTIPLIER
+        group_fidelity_multiplier(target_ids.len())
     } else {
         1.0
     };
@@ -344,10 +375,16 @@ fn execute_share_memory(
         );
 
         // Apply group fidelity multiplier
-        new_memory.fidelity *= fidelity_multiplier;
+        new_memory.degrade(fidelity_multiplier);
+
+        // Long retelling chains drift from the original
+        new_memory.distort(rng);
+        let heard_valence = new_memory.valence;
 
-        memory_bank.add_memory(target_id, new_memory);
-        memories_created.push(new_memory_id);
+        // A second, independent telling confirms what the listener already heard
+        if memory_bank.receive_memory(target_id, new_memory) {
+            memories_created.push(new_memory_id);
+        }
         recipients.push(target_id.clone());
 
         // Update trust if memory is about a third party
@@ -360,7 +397,7 @@ fn execute_share_memory(
 
             // Calculate trust impact toward the subject
             let trust_delta = calculate_secondhand_trust_impact(
-                memory.valence,
+                heard_valence,
                 source_trust,
                 memory.fidelity,
             );
@@ -400,6 +437,152 @@ fn execute_share_memory(
     }
 }
 
+/// A recruiter and the agent they are trying to win over
+struct RecruitAttempt<'a> {
+    actor_id: &'a str,
+    actor_name: &'a AgentName,
+    actor_pos: &'a Position,
+    actor_membership: &'a crate::components::faction::FactionMembership,
+    target_id: &'a str,
+    target_loyalty: f32,
+}
+
+/// Execute a recruit action: the target warms to the recruiter and cools on
+/// their own leader, which feeds later defection weights
+fn execute_recruit(
+    world_state: &WorldState,
+    faction_registry: &FactionRegistry,
+    relationship_graph: &mut RelationshipGraph,
+    tick_events: &mut TickEvents,
+    agent_info: &std::collections::HashMap<String, (&AgentName, &Position, &crate::components::faction::FactionMembership)>,
+    attempt: RecruitAttempt,
+) {
+    let RecruitAttempt {
+        actor_id,
+        actor_name,
+        actor_pos,
+        actor_membership,
+        target_id,
+        target_loyalty,
+    } = attempt;
+    let Some((target_name, target_pos, target_membership)) = agent_info.get(target_id) else {
+        return;
+    };
+    if target_pos.location_id != actor_pos.location_id
+        || target_membership.faction_id == actor_membership.faction_id
+    {
+        return;
+    }
+
+    let actor = ActorSnapshot {
+        agent_id: actor_id.to_string(),
+        name: actor_name.0.clone(),
+        faction: actor_membership.faction_id.clone(),
+        role: format!("{:?}", actor_membership.role).to_lowercase(),
+        location: actor_pos.location_id.clone(),
+    };
+    let target = ActorSnapshot {
+        agent_id: target_id.to_string(),
+        name: target_name.0.clone(),
+        faction: target_membership.faction_id.clone(),
+        role: format!("{:?}", target_membership.role).to_lowercase(),
+        location: target_pos.location_id.clone(),
+    };
+
+    // A loyal target turns the offer down and thinks less of whoever made it
+    if target_loyalty >= communication_weights::REFUSE_BRIBE_LOYALTY_THRESHOLD {
+        let rel = relationship_graph.ensure_relationship(target_id, actor_id);
+        let old_alignment = rel.trust.alignment;
+        rel.trust.update_alignment(-communication_weights::REFUSE_BRIBE_ALIGNMENT_LOSS);
+        rel.last_interaction_tick = world_state.current_tick;
+        let relationship_changes = vec![RelationshipChange {
+            from: target_id.to_string(),
+            to: actor_id.to_string(),
+            dimension: "alignment".to_string(),
+            old_value: old_alignment,
+            new_value: rel.trust.alignment,
+        }];
+        let event = create_loyalty_event(
+            tick_events,
+            world_state,
+            LoyaltySubtype::RefuseBribe,
+            target,
+            actor,
+            relationship_changes,
+        );
+        tick_events.push(event);
+        return;
+    }
+
+    let mut relationship_changes = Vec::new();
+
+    let rel = relationship_graph.ensure_relationship(target_id, actor_id);
+    let old_trust = rel.trust.overall();
+    rel.trust.update_alignment(communication_weights::RECRUIT_ALIGNMENT_GAIN);
+    rel.trust.update_reliability(communication_weights::RECRUIT_RELIABILITY_GAIN);
+    rel.last_interaction_tick = world_state.current_tick;
+    relationship_changes.push(RelationshipChange {
+        from: target_id.to_string(),
+        to: actor_id.to_string(),
+        dimension: "overall".to_string(),
+        old_value: old_trust,
+        new_value: rel.trust.overall(),
+    });
+
+    let leader_id = faction_registry
+        .get(&target_membership.faction_id)
+        .and_then(|f| f.leader.clone())
+        .filter(|leader| leader != target_id);
+    if let Some(leader_id) = leader_id {
+        let rel = relationship_graph.ensure_relationship(target_id, &leader_id);
+        let old_alignment = rel.trust.alignment;
+        rel.trust.update_alignment(-communication_weights::RECRUIT_LEADER_DOUBT);
+        relationship_changes.push(RelationshipChange {
+            from: target_id.to_string(),
+            to: leader_id,
+            dimension: "alignment".to_string(),
+            old_value: old_alignment,
+            new_value: rel.trust.alignment,
+        });
+    }
+
+    let event = Event {
+        event_id: tick_events.generate_id(),
+        timestamp: EventTimestamp {
+            tick: world_state.current_tick,
+            date: world_state.formatted_date(),
+        },
+        event_type: EventType::Communication,
+        subtype: EventSubtype::Communication(CommunicationSubtype::Recruit),
+        actors: EventActors {
+            primary: actor,
+            secondary: Some(target),
+            affected: None,
+        },
+        context: EventContext {
+            trigger: "recruitment".to_string(),
+            preconditions: vec!["target_poorly_integrated".to_string()],
+            location_description: Some(format!("at {}", actor_pos.location_id)),
+        },
+        outcome: EventOutcome::Relationship(RelationshipOutcome {
+            relationship_changes,
+            state_changes: Vec::new(),
+        }),
+        drama_tags: vec!["forbidden_alliance".to_string(), "cross_faction".to_string()],
+        drama_score: 0.5,
+        connected_events: Vec::new(),
+    };
+    tick_events.push(event);
+}
+
+/// Fidelity multiplier for telling a group; bigger audiences lose more detail
+fn group_fidelity_multiplier(recipient_count: usize) -> f32 {
+    let extra_recipients = recipient_count.saturating_sub(1) as i32;
+    (communication_weights::GROUP_FIDELITY_MULTIPLIER
+        * communication_weights::GROUP_FIDELITY_DECAY.powi(extra_recipients))
+    .max(communication_weights::MIN_GROUP_FIDELITY_MULTIPLIER)
+}
+
 /// Create a communication event
 fn create_communication_event(
     tick_events: &mut TickEvents,
@@ -447,6 +630,7 @@ fn create_communication_event(
         CommunicationType::SpreadRumor => CommunicationSubtype::SpreadRumor,
         CommunicationType::Lie => CommunicationSubtype::Lie,
         CommunicationType::Confess => CommunicationSubtype::Confess,
+        CommunicationType::Recruit => CommunicationSubtype::Recruit,
     };
 
     let trigger = match comm_action.communication_type {
@@ -454,6 +638,7 @@ fn create_communication_event(
         CommunicationType::SpreadRumor => "spreading_rumor",
         CommunicationType::Lie => "deception",
         CommunicationType::Confess => "confession",
+        CommunicationType::Recruit => "recruitment",
     };
 
     let source_chain: Vec<String> = memory.source_chain
@@ -779,12 +964,22 @@ fn create_archive_event(
 
 /// System to execute resource actions
 pub fn execute_resource_actions(
+    mut rng: ResMut<SimRng>,
     world_state: Res<WorldState>,
     mut faction_registry: ResMut<FactionRegistry>,
+    mut memory_bank: ResMut<MemoryBank>,
     mut selected_actions: ResMut<SelectedActions>,
     mut tick_events: ResMut<TickEvents>,
     query: Query<(&AgentId, &AgentName, &Position, &FactionMembership)>,
 ) {
+    // Victim lookup for thefts: name, location, faction
+    let agent_info: std::collections::HashMap<String, (String, String, String)> = query
+        .iter()
+        .map(|(id, name, pos, mem)| {
+            (id.0.clone(), (name.0.clone(), pos.location_id.clone(), mem.faction_id.clone()))
+        })
+        .collect();
+
     // Collect resource actions
     let mut resource_actions: Vec<(String, ResourceAction, String, String, String)> = Vec::new();
 
@@ -843,7 +1038,39 @@ pub fn execute_resource_actions(
                 tick_events.push(event);
             }
             ResourceActionType::Steal => {
-                let event = create_resource_event(
+                let Some(victim_id) = action.target_id.clone() else {
+                    continue;
+                };
+                let Some((victim_name, victim_location, victim_faction)) = agent_info.get(&victim_id).cloned() else {
+                    continue;
+                };
+                let resource_type = action.resource_type.unwrap_or(ResourceType::Grain);
+
+                // Take the goods from the victim's faction stores
+                let stolen = faction_registry
+                    .get_mut(&victim_faction)
+                    .and_then(|faction| stolen_stock_mut(&mut faction.resources, &resource_type))
+                    .map(|stock| {
+                        let taken = action.amount.min(*stock);
+                        *stock -= taken;
+                        taken
+                    })
+                    .unwrap_or(0);
+                if let Some(stock) = faction_registry
+                    .get_mut(&actor_faction)
+                    .and_then(|faction| stolen_stock_mut(&mut faction.resources, &resource_type))
+                {
+                    *stock += stolen;
+                }
+
+                // Victims nearby are more likely to notice
+                let mut detection_chance = resource_weights::STEAL_DETECTION_BASE;
+                if victim_location == location {
+                    detection_chance += resource_weights::STEAL_DETECTION_VIGILANCE;
+                }
+                let detected = rng.0.gen::<f32>() < detection_chance;
+
+                let mut event = create_resource_event(
                     &mut tick_events,
                     &world_state,
                     &actor_id,
@@ -851,9 +1078,43 @@ pub fn execute_resource_actions(
                     &actor_faction,
                     &location,
                     ResourceSubtype::Steal,
-                    action.amount,
-                    action.target_id.as_deref(),
+                    stolen,
+                    Some(&victim_id),
                 );
+                event.actors.secondary = Some(ActorSnapshot {
+                    agent_id: victim_id.clone(),
+                    name: victim_name.clone(),
+                    faction: victim_faction.clone(),
+                    role: "victim".to_string(),
+                    location: victim_location,
+                });
+
+                if detected {
+                    // The victim remembers who robbed them; the memory can spread as gossip
+                    let memory_id = memory_bank.generate_id();
+                    memory_bank.add_memory(
+                        &victim_id,
+                        Memory::firsthand(
+                            memory_id,
+                            event.event_id.clone(),
+                            actor_id.clone(),
+                            format!(
+                                "{} stole {} {} from {}",
+                                actor_name,
+                                stolen,
+                                resource_name(&resource_type),
+                                victim_faction
+                            ),
+                            resource_weights::THEFT_MEMORY_WEIGHT,
+                            world_state.current_tick,
+                            MemoryValence::Negative,
+                        ),
+                    );
+                    event.drama_tags.push("theft_detected".to_string());
+                    event.drama_score = (event.drama_score + 0.1).min(1.0);
+                } else {
+                    event.drama_tags.push("theft_hidden".to_string());
+                }
                 tick_events.push(event);
             }
             ResourceActionType::Hoard => {
@@ -913,6 +1174,29 @@ pub fn execute_resource_actions(
     }
 }
 
+/// Faction stockpile a stolen resource type is taken from and added to
+fn stolen_stock_mut<'a>(resources: &'a mut FactionResources, resource_type: &ResourceType) -> Option<&'a mut u32> {
+    match resource_type {
+        ResourceType::Grain | ResourceType::Food => Some(&mut resources.grain),
+        ResourceType::Iron => Some(&mut resources.iron),
+        ResourceType::Salt => Some(&mut resources.salt),
+        // Water and wood aren't stockpiled by factions
+        ResourceType::Water | ResourceType::Wood => None,
+    }
+}
+
+/// Lowercase display name for a resource type
+fn resource_name(resource_type: &ResourceType) -> &'static str {
+    match resource_type {
+        ResourceType::Grain => "grain",
+        ResourceType::Iron => "iron",
+        ResourceType::Salt => "salt",
+        ResourceType::Water => "water",
+        ResourceType::Wood => "wood",
+        ResourceType::Food => "food",
+    }
+}
+
 /// Create a resource event
 fn create_resource_event(
     tick_events: &mut TickEvents,
@@ -979,6 +1263,7 @@ pub fn execute_social_actions(
     mut relationship_graph: ResMut<RelationshipGraph>,
     mut selected_actions: ResMut<SelectedActions>,
     mut tick_events: ResMut<TickEvents>,
+    mut trust_events: ResMut<TrustEventQueue>,
     query: Query<(&AgentId, &AgentName, &Position, &FactionMembership)>,
 ) {
     // Build agent info map
@@ -1050,6 +1335,14 @@ pub fn execute_social_actions(
                     new_trust,
                 );
                 tick_events.push(event);
+
+                // The recipient warms to the giver
+                trust_events.push(create_trust_event(
+                    &action.target_id,
+                    &actor_id,
+                    TrustEventType::PositiveInteraction,
+                    None,
+                ));
             }
             SocialActionType::Gift => {
                 let rel = relationship_graph.ensure_relationship(&actor_id, &action.target_id);
@@ -1072,6 +1365,14 @@ pub fn execute_social_actions(
                     new_trust,
                 );
                 tick_events.push(event);
+
+                // The recipient warms to the giver
+                trust_events.push(create_trust_event(
+                    &action.target_id,
+                    &actor_id,
+                    TrustEventType::PositiveInteraction,
+                    None,
+                ));
             }
             SocialActionType::Ostracize => {
                 // Damage relationship
@@ -1177,14 +1478,16 @@ fn create_social_event(
 pub fn execute_faction_actions(
     world_state: Res<WorldState>,
     mut faction_registry: ResMut<FactionRegistry>,
+    mut relationship_graph: ResMut<RelationshipGraph>,
     mut selected_actions: ResMut<SelectedActions>,
     mut tick_events: ResMut<TickEvents>,
-    mut query: Query<(&AgentId, &AgentName, &Position, &mut FactionMembership)>,
+    mut query: Query<(&AgentId, &AgentName, &Position, &mut FactionMembership, &mut Needs)>,
+    traits_query: Query<(&AgentId, &Traits)>,
 ) {
     // Collect faction actions
     let mut faction_actions: Vec<(String, FactionAction, String, String, String)> = Vec::new();
 
-    for (agent_id, name, pos, membership) in query.iter() {
+    for (agent_id, name, pos, membership, _) in query.iter() {
         if let Some(action) = selected_actions.actions.get(&agent_id.0) {
             if let Action::Faction(faction_action) = action {
                 faction_actions.push((
@@ -1215,7 +1518,41 @@ pub fn execute_faction_actions(
                 tick_events.push(event);
             }
             FactionActionType::Exile => {
-                let event = create_faction_event(
+                // Authority may have changed since the action was generated
+                let actor_can_exile = query
+                    .iter()
+                    .any(|(id, _, _, membership, _)| id.0 == actor_id && membership.can_exile());
+                if !actor_can_exile {
+                    continue;
+                }
+
+                let Some((target_id, target_name, _, mut target_membership, mut target_needs)) = query
+                    .iter_mut()
+                    .find(|(id, _, _, _, _)| id.0 == action.target_id)
+                else {
+                    continue;
+                };
+                if target_membership.faction_id != actor_faction || target_membership.is_leader() {
+                    continue;
+                }
+
+                let exiled = AffectedActor {
+                    agent_id: target_id.0.clone(),
+                    name: target_name.0.clone(),
+                    faction: actor_faction.clone(),
+                    role: format!("{:?}", target_membership.role).to_lowercase(),
+                    relationship_to_primary: None,
+                    attended: None,
+                    reason: Some("exiled".to_string()),
+                };
+                target_membership.exile();
+                target_needs.social_belonging = SocialBelonging::Isolated;
+
+                if let Some(faction) = faction_registry.get_mut(&actor_faction) {
+                    faction.member_count = faction.member_count.saturating_sub(1);
+                }
+
+                let mut event = create_faction_event(
                     &mut tick_events,
                     &world_state,
                     &actor_id,
@@ -1225,10 +1562,22 @@ pub fn execute_faction_actions(
                     FactionSubtype::Exile,
                     Some(&action.target_id),
                 );
+                event.actors.affected = Some(vec![exiled]);
                 tick_events.push(event);
             }
             FactionActionType::ChallengeLeader => {
-                let event = create_faction_event(
+                let incumbent = faction_registry
+                    .get(&actor_faction)
+                    .and_then(|f| f.leader.clone())
+                    .filter(|leader| leader != &actor_id)
+                    .and_then(|leader| {
+                        query
+                            .iter()
+                            .find(|(id, _, _, m, _)| id.0 == leader && m.faction_id == actor_faction)
+                            .map(|(_, name, _, _, _)| (leader, name.0.clone()))
+                    });
+
+                let mut event = create_faction_event(
                     &mut tick_events,
                     &world_state,
                     &actor_id,
@@ -1236,9 +1585,90 @@ pub fn execute_faction_actions(
                     &actor_faction,
                     &location,
                     FactionSubtype::ChallengeLeader,
-                    None,
+                    incumbent.as_ref().map(|(id, _)| id.as_str()),
                 );
-                tick_events.push(event);
+
+                // With no sitting leader there is nobody to unseat; succession fills the seat
+                let Some((incumbent_id, incumbent_name)) = incumbent else {
+                    tick_events.push(event);
+                    continue;
+                };
+
+                let members: Vec<String> = query
+                    .iter()
+                    .filter(|(_, _, _, m, _)| m.faction_id == actor_faction)
+                    .map(|(id, _, _, _, _)| id.0.clone())
+                    .collect();
+                let ambition = traits_query
+                    .iter()
+                    .find(|(id, _)| id.0 == actor_id)
+                    .map_or(0.5, |(_, traits)| traits.ambition);
+                let (challenger_support, incumbent_support) =
+                    challenge_support(&relationship_graph, &members, &actor_id, &incumbent_id, ambition);
+
+                event.actors.secondary = Some(ActorSnapshot {
+                    agent_id: incumbent_id.clone(),
+                    name: incumbent_name.clone(),
+                    faction: actor_faction.clone(),
+                    role: "leader".to_string(),
+                    location: location.clone(),
+                });
+
+                if challenger_support > incumbent_support {
+                    for (id, _, _, mut membership, _) in query.iter_mut() {
+                        if id.0 == actor_id {
+                            install_leader(&mut faction_registry, &actor_faction, &actor_id, &mut membership);
+                        } else if id.0 == incumbent_id {
+                            step_down(&mut membership);
+                        }
+                    }
+
+                    event.drama_tags.push("challenge_succeeded".to_string());
+                    tick_events.push(event);
+
+                    let mut promotion = create_faction_event(
+                        &mut tick_events,
+                        &world_state,
+                        &actor_id,
+                        &actor_name,
+                        &actor_faction,
+                        &location,
+                        FactionSubtype::Promotion,
+                        Some(&incumbent_id),
+                    );
+                    promotion.actors.primary.role = "leader".to_string();
+                    promotion.drama_tags.push("leadership_change".to_string());
+                    tick_events.push(promotion);
+
+                    let mut demotion = create_faction_event(
+                        &mut tick_events,
+                        &world_state,
+                        &incumbent_id,
+                        &incumbent_name,
+                        &actor_faction,
+                        &location,
+                        FactionSubtype::Demotion,
+                        Some(&actor_id),
+                    );
+                    demotion.actors.primary.role = "councilmember".to_string();
+                    demotion.drama_tags.push("leadership_change".to_string());
+                    tick_events.push(demotion);
+                } else {
+                    // A failed challenger is marked as disloyal by the faction and the leader
+                    for member in members.iter().filter(|m| **m != actor_id && **m != incumbent_id) {
+                        relationship_graph
+                            .ensure_relationship(member, &actor_id)
+                            .trust
+                            .update_alignment(-faction_weights::CHALLENGE_FAILED_MEMBER_PENALTY);
+                    }
+                    relationship_graph
+                        .ensure_relationship(&incumbent_id, &actor_id)
+                        .trust
+                        .update_reliability(-faction_weights::CHALLENGE_FAILED_LEADER_PENALTY);
+
+                    event.drama_tags.push("challenge_failed".to_string());
+                    tick_events.push(event);
+                }
             }
             FactionActionType::SupportLeader => {
                 let event = create_faction_event(
@@ -1320,14 +1750,24 @@ pub fn execute_conflict_actions(
     mut rng: ResMut<SimRng>,
     world_state: Res<WorldState>,
     mut relationship_graph: ResMut<RelationshipGraph>,
+    mut faction_registry: ResMut<FactionRegistry>,
     mut selected_actions: ResMut<SelectedActions>,
     mut tick_events: ResMut<TickEvents>,
+    mut trust_events: ResMut<TrustEventQueue>,
     query: Query<(&AgentId, &AgentName, &Position, &FactionMembership, &Traits)>,
+    mut alive_query: Query<(&AgentId, &mut Alive)>,
 ) {
+    // Duelists already engaged this tick (a mutual challenge resolves once)
+    let mut dueled: std::collections::BTreeSet<String> = std::collections::BTreeSet::new();
+
     // Build agent info map
     let agent_info: std::collections::HashMap<String, (&AgentName, &FactionMembership, &Traits)> =
         query.iter().map(|(id, name, _, mem, traits)| (id.0.clone(), (name, mem, traits))).collect();
 
+    // Where everyone stands, so loyal bystanders can step into a fight
+    let agent_locations: std::collections::HashMap<String, &str> =
+        query.iter().map(|(id, _, pos, _, _)| (id.0.clone(), pos.location_id.as_str())).collect();
+
     // Collect conflict actions
     let mut conflict_actions: Vec<(String, ConflictAction, String, String, String, f32)> = Vec::new();
 
@@ -1353,7 +1793,15 @@ pub fn execute_conflict_actions(
             ConflictActionType::Argue => {
                 // Damage relationship
                 let rel = relationship_graph.ensure_relationship(&actor_id, &action.target_id);
+                let old_trust = rel.trust.overall();
                 rel.trust.update_alignment(-conflict_weights::ARGUE_RELATIONSHIP_DAMAGE);
+                let relationship_changes = vec![RelationshipChange {
+                    from: actor_id.clone(),
+                    to: action.target_id.clone(),
+                    dimension: "overall".to_string(),
+                    old_value: old_trust,
+                    new_value: rel.trust.overall(),
+                }];
 
                 // Check for resolution
                 let resolved = rng.0.gen::<f32>() < conflict_weights::ARGUE_RESOLUTION_CHANCE;
@@ -1370,14 +1818,23 @@ pub fn execute_conflict_actions(
                     target_info.map(|(n, _, _)| n.0.as_str()),
                     resolved,
                     false,
+                    relationship_changes,
                 );
                 tick_events.push(event);
             }
             ConflictActionType::Fight => {
                 // Heavy relationship damage
                 let rel = relationship_graph.ensure_relationship(&actor_id, &action.target_id);
+                let old_trust = rel.trust.overall();
                 rel.trust.update_reliability(-conflict_weights::FIGHT_RELATIONSHIP_DAMAGE);
                 rel.trust.update_alignment(-conflict_weights::FIGHT_RELATIONSHIP_DAMAGE);
+                let relationship_changes = vec![RelationshipChange {
+                    from: actor_id.clone(),
+                    to: action.target_id.clone(),
+                    dimension: "overall".to_string(),
+                    old_value: old_trust,
+                    new_value: rel.trust.overall(),
+                }];
 
                 // Determine winner based on capability/boldness
                 let target_capability = target_info.map(|(_, _, t)| t.boldness).unwrap_or(0.5);
@@ -1397,20 +1854,199 @@ pub fn execute_conflict_actions(
                     target_info.map(|(n, _, _)| n.0.as_str()),
                     false,
                     actor_wins,
+                    relationship_changes,
+                );
+                let fight_event_id = event.event_id.clone();
+                tick_events.push(event);
+
+                // The most loyal faction-mate of the target on hand steps in
+                let Some((_, target_membership, _)) = target_info else {
+                    continue;
+                };
+                let defender = agent_info
+                    .iter()
+                    .filter(|(id, (_, membership, traits))| {
+                        **id != actor_id
+                            && **id != action.target_id
+                            && membership.faction_id == target_membership.faction_id
+                            && membership.faction_id != actor_faction
+                            && traits.loyalty_weight >= conflict_weights::DEFEND_ALLY_LOYALTY_THRESHOLD
+                            && agent_locations.get(*id).copied() == Some(location.as_str())
+                    })
+                    .max_by(|(a_id, (_, _, a)), (b_id, (_, _, b))| {
+                        a.loyalty_weight.total_cmp(&b.loyalty_weight).then_with(|| b_id.cmp(a_id))
+                    });
+                if let Some((defender_id, (defender_name, defender_membership, _))) = defender {
+                    let rel = relationship_graph.ensure_relationship(&action.target_id, defender_id);
+                    let old_reliability = rel.trust.reliability;
+                    rel.trust.update_reliability(conflict_weights::DEFEND_ALLY_RELIABILITY_GAIN);
+                    rel.last_interaction_tick = world_state.current_tick;
+                    let relationship_changes = vec![RelationshipChange {
+                        from: action.target_id.clone(),
+                        to: defender_id.clone(),
+                        dimension: "reliability".to_string(),
+                        old_value: old_reliability,
+                        new_value: rel.trust.reliability,
+                    }];
+
+                    let mut event = create_loyalty_event(
+                        &mut tick_events,
+                        &world_state,
+                        LoyaltySubtype::DefendAlly,
+                        ActorSnapshot {
+                            agent_id: defender_id.clone(),
+                            name: defender_name.0.clone(),
+                            faction: defender_membership.faction_id.clone(),
+                            role: format!("{:?}", defender_membership.role).to_lowercase(),
+                            location: location.clone(),
+                        },
+                        ActorSnapshot {
+                            agent_id: action.target_id.clone(),
+                            name: target_info.map(|(n, _, _)| n.0.clone()).unwrap_or_default(),
+                            faction: target_membership.faction_id.clone(),
+                            role: format!("{:?}", target_membership.role).to_lowercase(),
+                            location: location.clone(),
+                        },
+                        relationship_changes,
+                    );
+                    event.actors.affected = Some(vec![AffectedActor {
+                        agent_id: actor_id.clone(),
+                        name: actor_name.clone(),
+                        faction: actor_faction.clone(),
+                        role: "attacker".to_string(),
+                        relationship_to_primary: Some("enemy".to_string()),
+                        attended: None,
+                        reason: Some("confronted".to_string()),
+                    }]);
+                    event.connected_events.push(fight_event_id);
+                    tick_events.push(event);
+                }
+            }
+            ConflictActionType::Duel => {
+                if dueled.contains(&actor_id) || dueled.contains(&action.target_id) {
+                    continue;
+                }
+                dueled.insert(actor_id.clone());
+                dueled.insert(action.target_id.clone());
+
+                // Decisive: exactly one winner, decided by boldness
+                let target_boldness = target_info.map(|(_, _, t)| t.boldness).unwrap_or(0.5);
+                let actor_advantage = actor_boldness - target_boldness;
+                let win_chance = 0.5 + actor_advantage * conflict_weights::FIGHT_CAPABILITY_MODIFIER;
+                let actor_wins = rng.0.gen::<f32>() < win_chance;
+                let lethal = rng.0.gen::<f32>() < conflict_weights::DUEL_LETHAL_CHANCE;
+
+                // Both sides come away with far less trust than after a brawl
+                let mut relationship_changes = Vec::new();
+                for (from, to) in [(&actor_id, &action.target_id), (&action.target_id, &actor_id)] {
+                    let rel = relationship_graph.ensure_relationship(from, to);
+                    let old_trust = rel.trust.overall();
+                    rel.trust.update_reliability(-conflict_weights::DUEL_RELATIONSHIP_DAMAGE);
+                    rel.trust.update_alignment(-conflict_weights::DUEL_RELATIONSHIP_DAMAGE);
+                    relationship_changes.push(RelationshipChange {
+                        from: from.clone(),
+                        to: to.clone(),
+                        dimension: "overall".to_string(),
+                        old_value: old_trust,
+                        new_value: rel.trust.overall(),
+                    });
+                }
+
+                let (loser_id, loser_name) = if actor_wins {
+                    (action.target_id.clone(), target_info.map(|(n, _, _)| n.0.clone()).unwrap_or_default())
+                } else {
+                    (actor_id.clone(), actor_name.clone())
+                };
+                let loser_faction = if actor_wins {
+                    target_info.map(|(_, m, _)| m.faction_id.clone()).unwrap_or_default()
+                } else {
+                    actor_faction.clone()
+                };
+
+                if lethal {
+                    if let Some((_, mut alive)) = alive_query.iter_mut().find(|(id, _)| id.0 == loser_id) {
+                        alive.0 = false;
+                    }
+                }
+
+                let mut event = create_conflict_event(
+                    &mut tick_events,
+                    &world_state,
+                    &actor_id,
+                    &actor_name,
+                    &actor_faction,
+                    &location,
+                    ConflictSubtype::Duel,
+                    &action.target_id,
+                    target_info.map(|(n, _, _)| n.0.as_str()),
+                    true,
+                    actor_wins,
+                    relationship_changes,
                 );
+                // A duel always has a loser; its outcome drives the drama, not the challenger's success
+                event.drama_score = if lethal { 0.9 } else { 0.8 };
+                if let EventOutcome::Conflict(outcome) = &mut event.outcome {
+                    outcome.lethal = lethal;
+                    outcome.description = Some(if lethal {
+                        format!("{} was slain in the duel", loser_name)
+                    } else {
+                        format!("{} was defeated in the duel", loser_name)
+                    });
+                }
+                if lethal {
+                    event.drama_tags.push("death".to_string());
+                }
+                event.actors.affected = Some(vec![AffectedActor {
+                    agent_id: loser_id,
+                    name: loser_name,
+                    faction: loser_faction,
+                    role: "duel_loser".to_string(),
+                    relationship_to_primary: Some("rival".to_string()),
+                    attended: None,
+                    reason: Some(if lethal { "killed" } else { "defeated" }.to_string()),
+                }]);
                 tick_events.push(event);
             }
             ConflictActionType::Sabotage => {
                 // Check if detected
                 let detected = rng.0.gen::<f32>() < conflict_weights::SABOTAGE_DETECTION_CHANCE;
+                let target_faction = target_info.map(|(_, m, _)| m.faction_id.clone());
 
+                let mut destroyed = (0, 0);
+                let mut relationship_changes = Vec::new();
                 if detected {
-                    // Heavy relationship damage if caught
-                    let rel = relationship_graph.ensure_relationship(&actor_id, &action.target_id);
+                    // The victim catches the saboteur: heavy damage and a likely grudge
+                    let rel = relationship_graph.ensure_relationship(&action.target_id, &actor_id);
+                    let old_trust = rel.trust.overall();
                     rel.trust.update_reliability(-conflict_weights::SABOTAGE_RELATIONSHIP_DAMAGE);
+                    rel.last_interaction_tick = world_state.current_tick;
+                    relationship_changes.push(RelationshipChange {
+                        from: action.target_id.clone(),
+                        to: actor_id.clone(),
+                        dimension: "overall".to_string(),
+                        old_value: old_trust,
+                        new_value: rel.trust.overall(),
+                    });
+                    trust_events.push(create_trust_event(
+                        &action.target_id,
+                        &actor_id,
+                        TrustEventType::BetrayedBy,
+                        None,
+                    ));
+                } else if let Some(faction) = target_faction
+                    .as_deref()
+                    .and_then(|id| faction_registry.get_mut(id))
+                {
+                    // Undetected sabotage ruins part of the target faction's stores
+                    let resources = &mut faction.resources;
+                    let grain = (resources.grain as f32 * conflict_weights::SABOTAGE_GRAIN_DESTROYED).ceil() as u32;
+                    let iron = (resources.iron as f32 * conflict_weights::SABOTAGE_IRON_DESTROYED).ceil() as u32;
+                    resources.grain -= grain.min(resources.grain);
+                    resources.iron -= iron.min(resources.iron);
+                    destroyed = (grain, iron);
                 }
 
-                let event = create_conflict_event(
+                let mut event = create_conflict_event(
                     &mut tick_events,
                     &world_state,
                     &actor_id,
@@ -1422,7 +2058,32 @@ pub fn execute_conflict_actions(
                     target_info.map(|(n, _, _)| n.0.as_str()),
                     !detected,
                     !detected,
+                    relationship_changes,
                 );
+                if detected {
+                    event.drama_tags.push("sabotage_detected".to_string());
+                    event.drama_score = (event.drama_score + 0.1).min(1.0);
+                    if let EventOutcome::Conflict(outcome) = &mut event.outcome {
+                        outcome.description = Some(format!(
+                            "{} was caught sabotaging {}",
+                            actor_name,
+                            target_info.map(|(n, _, _)| n.0.as_str()).unwrap_or("unknown")
+                        ));
+                    }
+                } else {
+                    event.drama_tags.push("sabotage_undetected".to_string());
+                    if destroyed != (0, 0) {
+                        event.drama_tags.push("resource_conflict".to_string());
+                    }
+                    if let EventOutcome::Conflict(outcome) = &mut event.outcome {
+                        outcome.description = Some(format!(
+                            "{} grain and {} iron of {} were ruined",
+                            destroyed.0,
+                            destroyed.1,
+                            target_faction.as_deref().unwrap_or("unknown")
+                        ));
+                    }
+                }
                 tick_events.push(event);
             }
             ConflictActionType::Assassinate => {
@@ -1440,6 +2101,7 @@ pub fn execute_conflict_actions(
                     target_info.map(|(n, _, _)| n.0.as_str()),
                     false,
                     true, // Assassination attempt
+                    Vec::new(),
                 );
                 tick_events.push(event);
             }
@@ -1460,6 +2122,7 @@ fn create_conflict_event(
     target_name: Option<&str>,
     resolved: bool,
     actor_success: bool,
+    relationship_changes: Vec<RelationshipChange>,
 ) -> Event {
     let event_id = tick_events.generate_id();
     let timestamp = EventTimestamp {
@@ -1486,13 +2149,21 @@ fn create_conflict_event(
     let (trigger, base_drama, mut drama_tags) = match subtype {
         ConflictSubtype::Argument => ("heated_dispute", 0.3, vec!["conflict".to_string()]),
         ConflictSubtype::Fight => ("physical_altercation", 0.6, vec!["violence".to_string()]),
+        ConflictSubtype::Duel => ("formal_challenge", 0.8, vec!["duel".to_string(), "violence".to_string()]),
         ConflictSubtype::Raid => ("sabotage_attempt", 0.5, vec!["sabotage".to_string()]),
         ConflictSubtype::Assassination => ("murder_attempt", 0.95, vec!["assassination".to_string(), "death".to_string()]),
-        _ => ("conflict", 0.4, vec!["conflict".to_string()]),
     };
 
     let drama_score = if actor_success { base_drama } else { base_drama * 0.8 };
 
+    // Fights and duels always end with someone on top; other conflicts only
+    // have a winner when the actor succeeded
+    let winner_id = match subtype {
+        ConflictSubtype::Fight | ConflictSubtype::Duel if !actor_success => Some(target_id.to_string()),
+        _ if actor_success => Some(actor_id.to_string()),
+        _ => None,
+    };
+
     Event {
         event_id,
         timestamp,
@@ -1508,12 +2179,65 @@ fn create_conflict_event(
             preconditions: Vec::new(),
             location_description: Some(format!("at {}", location)),
         },
-        outcome: EventOutcome::General(GeneralOutcome {
+        outcome: EventOutcome::Conflict(ConflictOutcome {
+            winner_id,
+            lethal: false,
+            relationship_changes,
             description: Some(if actor_success {
                 "Actor prevailed".to_string()
             } else {
                 "Conflict unresolved".to_string()
             }),
+        }),
+        drama_tags,
+        drama_score,
+        connected_events: Vec::new(),
+    }
+}
+
+/// Create a loyalty event where `primary` stands by `secondary` or their faction
+fn create_loyalty_event(
+    tick_events: &mut TickEvents,
+    world_state: &WorldState,
+    subtype: LoyaltySubtype,
+    primary: ActorSnapshot,
+    secondary: ActorSnapshot,
+    relationship_changes: Vec<RelationshipChange>,
+) -> Event {
+    let (trigger, drama_score, drama_tags) = match subtype {
+        LoyaltySubtype::DefendAlly => (
+            "ally_attacked",
+            0.5,
+            vec!["loyalty".to_string(), "defend_ally".to_string(), "violence".to_string()],
+        ),
+        LoyaltySubtype::RefuseBribe => (
+            "recruitment_refused",
+            0.45,
+            vec!["loyalty".to_string(), "refused_bribe".to_string(), "cross_faction".to_string()],
+        ),
+        _ => ("loyalty", 0.35, vec!["loyalty".to_string()]),
+    };
+
+    Event {
+        event_id: tick_events.generate_id(),
+        timestamp: EventTimestamp {
+            tick: world_state.current_tick,
+            date: world_state.formatted_date(),
+        },
+        event_type: EventType::Loyalty,
+        subtype: EventSubtype::Loyalty(subtype),
+        context: EventContext {
+            trigger: trigger.to_string(),
+            preconditions: Vec::new(),
+            location_description: Some(format!("at {}", primary.location)),
+        },
+        actors: EventActors {
+            primary,
+            secondary: Some(secondary),
+            affected: None,
+        },
+        outcome: EventOutcome::Relationship(RelationshipOutcome {
+            relationship_changes,
             state_changes: Vec::new(),
         }),
         drama_tags,
@@ -1529,6 +2253,7 @@ pub fn execute_beer_actions(
     mut relationship_graph: ResMut<RelationshipGraph>,
     selected_actions: Res<SelectedActions>,
     mut tick_events: ResMut<TickEvents>,
+    mut trust_events: ResMut<TrustEventQueue>,
     mut query: Query<(
         &AgentId,
         &AgentName,
@@ -1624,6 +2349,12 @@ pub fn execute_beer_actions(
                             let rel = relationship_graph.ensure_relationship(&actor_id, target_id);
                             rel.trust.update_reliability(beer_weights::SHARE_TRUST_GAIN);
                             rel.last_interaction_tick = world_state.current_tick;
+                            trust_events.push(create_trust_event(
+                                target_id,
+                                &actor_id,
+                                TrustEventType::PositiveInteraction,
+                                None,
+                            ));
 
                             // Apply intoxication to target
                             for (id, _, _, _, mut intox, _) in query.iter_mut() {
@@ -1736,4 +2467,636 @@ mod tests {
         assert_ne!(id1, id2);
         assert!(id1.starts_with("evt_"));
     }
+
+    #[test]
+    fn test_exile_removes_membership() {
+        use crate::components::faction::{Faction, EXILED_FACTION_ID};
+
+        let mut world = World::new();
+        world.insert_resource(WorldState::new());
+        world.insert_resource(TickEvents::new());
+        let mut registry = FactionRegistry::new();
+        let mut faction = Faction::new("thornwood", "Thornwood", "hall");
+        faction.leader = Some("leader".to_string());
+        faction.member_count = 2;
+        registry.register(faction);
+        world.insert_resource(registry);
+        world.insert_resource(RelationshipGraph::new());
+
+        let mut selected = SelectedActions::new();
+        selected.set("leader", Action::Faction(FactionAction::exile("leader", "schemer")));
+        world.insert_resource(selected);
+
+        world.spawn((
+            AgentId("leader".to_string()),
+            AgentName("Leader".to_string()),
+            Position::new("hall"),
+            FactionMembership::new("thornwood", Role::Leader),
+            Needs::default(),
+        ));
+        let schemer = world
+            .spawn((
+                AgentId("schemer".to_string()),
+                AgentName("Schemer".to_string()),
+                Position::new("hall"),
+                FactionMembership::new("thornwood", Role::Laborer),
+                Needs::default(),
+            ))
+            .id();
+
+        let mut schedule = Schedule::default();
+        schedule.add_systems(execute_faction_actions);
+        schedule.run(&mut world);
+
+        let membership = world.get::<FactionMembership>(schemer).unwrap();
+        assert_eq!(membership.faction_id, EXILED_FACTION_ID);
+        assert!(membership.is_exiled());
+        assert_eq!(
+            world.get::<Needs>(schemer).unwrap().social_belonging,
+            SocialBelonging::Isolated
+        );
+        assert_eq!(world.resource::<FactionRegistry>().get("thornwood").unwrap().member_count, 1);
+
+        let events = &world.resource::<TickEvents>().events;
+        assert_eq!(events.len(), 1);
+        assert_eq!(events[0].subtype, EventSubtype::Faction(FactionSubtype::Exile));
+        let affected = events[0].actors.affected.as_ref().unwrap();
+        assert_eq!(affected[0].agent_id, "schemer");
+    }
+
+    fn run_challenge(follower_trust_in_challenger: f32) -> (World, Entity, Entity) {
+        use crate::components::faction::Faction;
+        use crate::components::social::{Relationship, Trust};
+
+        let mut world = World::new();
+        world.insert_resource(WorldState::new());
+        world.insert_resource(TickEvents::new());
+        let mut registry = FactionRegistry::new();
+        let mut faction = Faction::new("thornwood", "Thornwood", "hall");
+        faction.leader = Some("leader".to_string());
+        registry.register(faction);
+        world.insert_resource(registry);
+
+        let mut graph = RelationshipGraph::new();
+        for follower in ["follower_a", "follower_b"] {
+            graph.set(follower, Relationship::new("leader").with_trust(Trust::new(0.2, 0.2, 0.2)));
+            graph.set(
+                follower,
+                Relationship::new("challenger").with_trust(Trust::new(
+                    follower_trust_in_challenger,
+                    follower_trust_in_challenger,
+                    follower_trust_in_challenger,
+                )),
+            );
+        }
+        world.insert_resource(graph);
+
+        let mut selected = SelectedActions::new();
+        selected.set(
+            "challenger",
+            Action::Faction(FactionAction::challenge_leader("challenger", "thornwood")),
+        );
+        world.insert_resource(selected);
+
+        let spawn = |world: &mut World, id: &str, role: Role| {
+            world
+                .spawn((
+                    AgentId(id.to_string()),
+                    AgentName(id.to_string()),
+                    Position::new("hall"),
+                    FactionMembership::new("thornwood", role),
+                    Needs::default(),
+                    Traits { ambition: 0.5, ..Traits::default() },
+                ))
+                .id()
+        };
+        let leader = spawn(&mut world, "leader", Role::Leader);
+        let challenger = spawn(&mut world, "challenger", Role::CouncilMember);
+        spawn(&mut world, "follower_a", Role::Laborer);
+        spawn(&mut world, "follower_b", Role::Laborer);
+
+        let mut schedule = Schedule::default();
+        schedule.add_systems(execute_faction_actions);
+        schedule.run(&mut world);
+
+        (world, leader, challenger)
+    }
+
+    #[test]
+    fn test_backed_challenger_takes_leadership() {
+        let (world, leader, challenger) = run_challenge(0.5);
+
+        assert_eq!(world.get::<FactionMembership>(challenger).unwrap().role, Role::Leader);
+        assert_eq!(world.get::<FactionMembership>(leader).unwrap().role, Role::CouncilMember);
+        let registry = world.resource::<FactionRegistry>();
+        assert_eq!(registry.get("thornwood").unwrap().leader.as_deref(), Some("challenger"));
+
+        let events = &world.resource::<TickEvents>().events;
+        let subtypes: Vec<_> = events.iter().map(|e| e.subtype.clone()).collect();
+        assert_eq!(
+            subtypes,
+            vec![
+                EventSubtype::Faction(FactionSubtype::ChallengeLeader),
+                EventSubtype::Faction(FactionSubtype::Promotion),
+                EventSubtype::Faction(FactionSubtype::Demotion),
+            ]
+        );
+        assert!(events[0].drama_tags.contains(&"challenge_succeeded".to_string()));
+        assert_eq!(events[2].actors.primary.agent_id, "leader");
+    }
+
+    #[test]
+    fn test_failed_challenge_costs_trust() {
+        let (world, leader, challenger) = run_challenge(-0.3);
+
+        assert_eq!(world.get::<FactionMembership>(leader).unwrap().role, Role::Leader);
+        assert_eq!(world.get::<FactionMembership>(challenger).unwrap().role, Role::CouncilMember);
+        let registry = world.resource::<FactionRegistry>();
+        assert_eq!(registry.get("thornwood").unwrap().leader.as_deref(), Some("leader"));
+
+        let events = &world.resource::<TickEvents>().events;
+        assert_eq!(events.len(), 1);
+        assert!(events[0].drama_tags.contains(&"challenge_failed".to_string()));
+
+        let graph = world.resource::<RelationshipGraph>();
+        let follower_view = graph.get("follower_a", "challenger").unwrap();
+        assert!(follower_view.trust.alignment < -0.3);
+        let leader_view = graph.get("leader", "challenger").unwrap();
+        assert!(leader_view.trust.reliability <= -faction_weights::CHALLENGE_FAILED_LEADER_PENALTY);
+    }
+
+    #[test]
+    fn test_mutual_duel_resolves_once_with_single_loser() {
+        use rand::rngs::SmallRng;
+        use rand::SeedableRng;
+
+        let mut world = World::new();
+        world.insert_resource(SimRng(SmallRng::seed_from_u64(7)));
+        world.insert_resource(WorldState::new());
+        world.insert_resource(RelationshipGraph::new());
+        world.insert_resource(TickEvents::new());
+        world.insert_resource(TrustEventQueue::new());
+        world.insert_resource(FactionRegistry::new());
+
+        // Both rivals challenge each other in the same tick
+        let mut selected = SelectedActions::new();
+        selected.set("rival_a", Action::Conflict(ConflictAction::duel("rival_a", "rival_b", None)));
+        selected.set("rival_b", Action::Conflict(ConflictAction::duel("rival_b", "rival_a", None)));
+        world.insert_resource(selected);
+
+        let mut entities = Vec::new();
+        for id in ["rival_a", "rival_b"] {
+            entities.push(
+                world
+                    .spawn((
+                        AgentId(id.to_string()),
+                        AgentName(id.to_string()),
+                        Position::new("square"),
+                        FactionMembership::new("thornwood", Role::Laborer),
+                        Traits { boldness: 0.8, ..Traits::default() },
+                        Alive::new(),
+                    ))
+                    .id(),
+            );
+        }
+
+        let mut schedule = Schedule::default();
+        schedule.add_systems(execute_conflict_actions);
+        schedule.run(&mut world);
+
+        let events = world.resource::<TickEvents>().events.clone();
+        assert_eq!(events.len(), 1);
+        assert_eq!(events[0].subtype, EventSubtype::Conflict(ConflictSubtype::Duel));
+        assert!(events[0].drama_score >= 0.8);
+
+        let loser = &events[0].actors.affected.as_ref().unwrap()[0];
+        let killed = loser.reason.as_deref() == Some("killed");
+        for (entity, id) in entities.iter().zip(["rival_a", "rival_b"]) {
+            let alive = world.get::<Alive>(*entity).unwrap().is_alive();
+            assert_eq!(alive, !(killed && loser.agent_id == id));
+        }
+
+        // Damage is mutual and heavier than a fight's
+        let graph = world.resource::<RelationshipGraph>();
+        for (from, to) in [("rival_a", "rival_b"), ("rival_b", "rival_a")] {
+            let reliability = graph.get(from, to).unwrap().trust.reliability;
+            assert!(reliability < -conflict_weights::FIGHT_RELATIONSHIP_DAMAGE);
+        }
+    }
+
+    #[test]
+    fn test_loyal_ally_defends_faction_mate_in_fight() {
+        use rand::rngs::SmallRng;
+        use rand::SeedableRng;
+
+        let mut world = World::new();
+        world.insert_resource(SimRng(SmallRng::seed_from_u64(7)));
+        world.insert_resource(WorldState::new());
+        world.insert_resource(RelationshipGraph::new());
+        world.insert_resource(TickEvents::new());
+        world.insert_resource(TrustEventQueue::new());
+        world.insert_resource(FactionRegistry::new());
+
+        let mut selected = SelectedActions::new();
+        selected.set("brute", Action::Conflict(ConflictAction::fight("brute", "victim", None)));
+        world.insert_resource(selected);
+
+        let agents = [
+            ("brute", "ironmere", "square", 0.5),
+            ("victim", "thornwood", "square", 0.5),
+            ("stalwart", "thornwood", "square", 0.9),
+            ("fairweather", "thornwood", "square", 0.3),
+            ("faraway", "thornwood", "mill", 0.95),
+        ];
+        for (id, faction, location, loyalty) in agents {
+            world.spawn((
+                AgentId(id.to_string()),
+                AgentName(id.to_string()),
+                Position::new(location),
+                FactionMembership::new(faction, Role::Laborer),
+                Traits { loyalty_weight: loyalty, ..Traits::default() },
+            ));
+        }
+
+        let mut schedule = Schedule::default();
+        schedule.add_systems(execute_conflict_actions);
+        schedule.run(&mut world);
+
+        let events = world.resource::<TickEvents>().events.clone();
+        assert_eq!(events.len(), 2);
+        let defend = &events[1];
+        assert_eq!(defend.event_type, EventType::Loyalty);
+        assert_eq!(defend.subtype, EventSubtype::Loyalty(LoyaltySubtype::DefendAlly));
+        assert_eq!(defend.actors.primary.agent_id, "stalwart");
+        assert_eq!(defend.actors.secondary.as_ref().unwrap().agent_id, "victim");
+        assert_eq!(defend.actors.affected.as_ref().unwrap()[0].agent_id, "brute");
+        assert!(defend.drama_tags.contains(&"defend_ally".to_string()));
+        assert_eq!(defend.connected_events, vec![events[0].event_id.clone()]);
+
+        let graph = world.resource::<RelationshipGraph>();
+        assert!(graph.get("victim", "stalwart").unwrap().trust.reliability > 0.0);
+    }
+
+    /// Runs one theft of 30 grain from `victim` by `thief` with the given seed
+    /// and returns the world afterwards.
+    fn run_theft(seed: u64) -> World {
+        use crate::components::faction::{Faction, FactionResources};
+        use rand::rngs::SmallRng;
+        use rand::SeedableRng;
+
+        let mut world = World::new();
+        world.insert_resource(SimRng(SmallRng::seed_from_u64(seed)));
+        world.insert_resource(WorldState::new());
+        world.insert_resource(MemoryBank::new());
+        world.insert_resource(TickEvents::new());
+        let mut registry = FactionRegistry::new();
+        let mut ironmere = Faction::new("ironmere", "Ironmere", "keep");
+        ironmere.resources = FactionResources::new(100, 20, 5);
+        registry.register(ironmere);
+        let mut thornwood = Faction::new("thornwood", "Thornwood", "hall");
+        thornwood.resources = FactionResources::new(10, 0, 0);
+        registry.register(thornwood);
+        world.insert_resource(registry);
+
+        let mut selected = SelectedActions::new();
+        selected.set(
+            "thief",
+            Action::Resource(ResourceAction::steal("thief", "victim", ResourceType::Grain, 30)),
+        );
+        world.insert_resource(selected);
+
+        for (id, faction) in [("thief", "thornwood"), ("victim", "ironmere")] {
+            world.spawn((
+                AgentId(id.to_string()),
+                AgentName(id.to_string()),
+                Position::new("market"),
+                FactionMembership::new(faction, Role::Laborer),
+            ));
+        }
+
+        let mut schedule = Schedule::default();
+        schedule.add_systems(execute_resource_actions);
+        schedule.run(&mut world);
+        world
+    }
+
+    fn theft_detected(world: &World) -> bool {
+        world.resource::<TickEvents>().events[0]
+            .drama_tags
+            .contains(&"theft_detected".to_string())
+    }
+
+    #[test]
+    fn test_theft_transfers_grain_between_factions() {
+        for world in (0..10).map(run_theft) {
+            let registry = world.resource::<FactionRegistry>();
+            assert_eq!(registry.get("ironmere").unwrap().resources.grain, 70);
+            assert_eq!(registry.get("thornwood").unwrap().resources.grain, 40);
+            assert_eq!(registry.get("ironmere").unwrap().resources.iron, 20);
+
+            let event = &world.resource::<TickEvents>().events[0];
+            assert_eq!(event.actors.secondary.as_ref().unwrap().agent_id, "victim");
+        }
+    }
+
+    #[test]
+    fn test_detected_theft_leaves_victim_memory() {
+        let detected = (0..50)
+            .map(run_theft)
+            .find(theft_detected)
+            .expect("some seed should get the theft detected");
+        let memories = detected.resource::<MemoryBank>().get_memories("victim").unwrap();
+        assert_eq!(memories.len(), 1);
+        assert_eq!(memories[0].subject, "thief");
+        assert_eq!(memories[0].valence, MemoryValence::Negative);
+        assert!(!memories[0].is_secret);
+        assert!(memories[0].content.contains("stole 30 grain"));
+
+        // Hidden thefts still move the goods, but nobody remembers them
+        let hidden = (0..50)
+            .map(run_theft)
+            .find(|w| !theft_detected(w))
+            .expect("some seed should leave the theft hidden");
+        assert!(hidden
+            .resource::<MemoryBank>()
+            .get_memories("victim")
+            .is_none_or(|m| m.is_empty()));
+    }
+
+    /// Runs one sabotage of `victim` by `saboteur` with the given seed and
+    /// returns the world afterwards.
+    fn run_sabotage(seed: u64) -> World {
+        use crate::components::faction::{Faction, FactionResources};
+        use rand::rngs::SmallRng;
+        use rand::SeedableRng;
+
+        let mut world = World::new();
+        world.insert_resource(SimRng(SmallRng::seed_from_u64(seed)));
+        world.insert_resource(WorldState::new());
+        world.insert_resource(RelationshipGraph::new());
+        world.insert_resource(TickEvents::new());
+        world.insert_resource(TrustEventQueue::new());
+        let mut registry = FactionRegistry::new();
+        let mut ironmere = Faction::new("ironmere", "Ironmere", "keep");
+        ironmere.resources = FactionResources::new(200, 50, 10);
+        registry.register(ironmere);
+        registry.register(Faction::new("thornwood", "Thornwood", "hall"));
+        world.insert_resource(registry);
+
+        let mut selected = SelectedActions::new();
+        selected.set(
+            "saboteur",
+            Action::Conflict(ConflictAction::sabotage("saboteur", "victim", None)),
+        );
+        world.insert_resource(selected);
+
+        for (id, faction) in [("saboteur", "thornwood"), ("victim", "ironmere")] {
+            world.spawn((
+                AgentId(id.to_string()),
+                AgentName(id.to_string()),
+                Position::new("keep"),
+                FactionMembership::new(faction, Role::Laborer),
+                Traits::default(),
+                Alive::new(),
+            ));
+        }
+
+        let mut schedule = Schedule::default();
+        schedule.add_systems(execute_conflict_actions);
+        schedule.run(&mut world);
+        world
+    }
+
+    fn sabotage_detected(world: &World) -> bool {
+        world.resource::<TickEvents>().events[0]
+            .drama_tags
+            .contains(&"sabotage_detected".to_string())
+    }
+
+    #[test]
+    fn test_undetected_sabotage_destroys_target_resources() {
+        let world = (0..50)
+            .map(run_sabotage)
+            .find(|w| !sabotage_detected(w))
+            .expect("some seed should leave the sabotage undetected");
+
+        let resources = &world.resource::<FactionRegistry>().get("ironmere").unwrap().resources;
+        assert_eq!(resources.grain, 180);
+        assert_eq!(resources.iron, 45);
+        assert_eq!(resources.salt, 10);
+
+        let event = &world.resource::<TickEvents>().events[0];
+        assert!(event.drama_tags.contains(&"sabotage_undetected".to_string()));
+        assert!(world.resource::<TrustEventQueue>().is_empty());
+        assert!(world.resource::<RelationshipGraph>().get("victim", "saboteur").is_none());
+    }
+
+    #[test]
+    fn test_detected_sabotage_costs_trust_and_queues_grudge() {
+        let world = (0..50)
+            .map(run_sabotage)
+            .find(sabotage_detected)
+            .expect("some seed should get the sabotage detected");
+
+        // Foiled sabotage leaves the stores intact
+        let resources = &world.resource::<FactionRegistry>().get("ironmere").unwrap().resources;
+        assert_eq!(resources.grain, 200);
+        assert_eq!(resources.iron, 50);
+
+        let rel = world.resource::<RelationshipGraph>().get("victim", "saboteur").unwrap();
+        assert!(rel.trust.reliability <= -conflict_weights::SABOTAGE_RELATIONSHIP_DAMAGE);
+
+        let queued = &world.resource::<TrustEventQueue>().events;
+        assert_eq!(queued.len(), 1);
+        assert_eq!(queued[0].agent_id, "victim");
+        assert_eq!(queued[0].target_id, "saboteur");
+        assert_eq!(queued[0].event_type, TrustEventType::BetrayedBy);
+    }
+
+    #[test]
+    fn test_recruit_raises_target_defect_weight() {
+        use crate::components::faction::Faction;
+        use crate::systems::action::generate::{generate_faction_actions, PendingActions};
+        use rand::rngs::SmallRng;
+        use rand::SeedableRng;
+
+        let mut world = World::new();
+        world.insert_resource(SimRng(SmallRng::seed_from_u64(7)));
+        world.insert_resource(WorldState::new());
+        world.insert_resource(RelationshipGraph::new());
+        world.insert_resource(TickEvents::new());
+        world.insert_resource(MemoryBank::new());
+        world.insert_resource(AgentsByLocation::new());
+        world.insert_resource(PendingActions::new());
+        let mut registry = FactionRegistry::new();
+        let mut thornwood = Faction::new("thornwood", "Thornwood", "hall");
+        thornwood.leader = Some("elder".to_string());
+        registry.register(thornwood);
+        registry.register(Faction::new("ironmere", "Ironmere", "keep"));
+        world.insert_resource(registry);
+
+        let agents = [
+            ("elder", "thornwood", Role::Leader, "hall", SocialBelonging::Integrated),
+            ("drifter", "thornwood", Role::Laborer, "market", SocialBelonging::Isolated),
+            ("envoy", "ironmere", Role::Laborer, "market", SocialBelonging::Integrated),
+        ];
+        for (id, faction, role, location, belonging) in agents {
+            world.spawn((
+                AgentId(id.to_string()),
+                AgentName(id.to_string()),
+                Position::new(location),
+                FactionMembership::new(faction, role),
+                Needs { social_belonging: belonging, ..Needs::default() },
+                Traits { sociability: 0.9, ..Traits::default() },
+                Goals::new(),
+            ));
+        }
+
+        fn defect_weight(world: &mut World) -> f32 {
+            world.insert_resource(PendingActions::new());
+            let mut schedule = Schedule::default();
+            schedule.add_systems(generate_faction_actions);
+            schedule.run(world);
+            world
+                .resource::<PendingActions>()
+                .get("drifter")
+                .into_iter()
+                .flatten()
+                .find_map(|wa| match &wa.action {
+                    Action::Faction(fa) if fa.new_faction_id.as_deref() == Some("ironmere") => Some(wa.weight),
+                    _ => None,
+                })
+                .unwrap()
+        }
+
+        let before = defect_weight(&mut world);
+
+        let mut selected = SelectedActions::new();
+        selected.set("envoy", Action::Communicate(CommunicationAction::recruit("envoy", "drifter")));
+        world.insert_resource(selected);
+        let mut schedule = Schedule::default();
+        schedule.add_systems(execute_communication_actions);
+        schedule.run(&mut world);
+
+        let events = world.resource::<TickEvents>().events.clone();
+        assert_eq!(events.len(), 1);
+        assert_eq!(events[0].subtype, EventSubtype::Communication(CommunicationSubtype::Recruit));
+        assert!(events[0].drama_tags.contains(&"forbidden_alliance".to_string()));
+        let graph = world.resource::<RelationshipGraph>();
+        assert!(graph.get("drifter", "elder").unwrap().trust.alignment < 0.0);
+
+        let after = defect_weight(&mut world);
+        assert!(after > before, "defect weight {} should exceed {}", after, before);
+    }
+
+    #[test]
+    fn test_loyal_target_refuses_recruitment() {
+        use rand::rngs::SmallRng;
+        use rand::SeedableRng;
+
+        let mut world = World::new();
+        world.insert_resource(SimRng(SmallRng::seed_from_u64(7)));
+        world.insert_resource(WorldState::new());
+        world.insert_resource(RelationshipGraph::new());
+        world.insert_resource(TickEvents::new());
+        world.insert_resource(MemoryBank::new());
+        world.insert_resource(AgentsByLocation::new());
+        world.insert_resource(FactionRegistry::new());
+
+        for (id, faction, loyalty) in [("envoy", "ironmere", 0.5), ("stalwart", "thornwood", 0.9)] {
+            world.spawn((
+                AgentId(id.to_string()),
+                AgentName(id.to_string()),
+                Position::new("market"),
+                FactionMembership::new(faction, Role::Laborer),
+                Traits { loyalty_weight: loyalty, ..Traits::default() },
+            ));
+        }
+
+        let mut selected = SelectedActions::new();
+        selected.set("envoy", Action::Communicate(CommunicationAction::recruit("envoy", "stalwart")));
+        world.insert_resource(selected);
+        let mut schedule = Schedule::default();
+        schedule.add_systems(execute_communication_actions);
+        schedule.run(&mut world);
+
+        let events = world.resource::<TickEvents>().events.clone();
+        assert_eq!(events.len(), 1);
+        assert_eq!(events[0].subtype, EventSubtype::Loyalty(LoyaltySubtype::RefuseBribe));
+        assert_eq!(events[0].actors.primary.agent_id, "stalwart");
+        assert!(events[0].drama_tags.contains(&"refused_bribe".to_string()));
+        let graph = world.resource::<RelationshipGraph>();
+        assert!(graph.get("stalwart", "envoy").unwrap().trust.alignment < 0.0);
+    }
+
+    /// Has a speaker share a memory with everyone else in a group of
+    /// `group_size` and returns the fidelity one listener ends up with.
+    fn group_share_fidelity(seed_fidelity: f32, group_size: usize) -> f32 {
+        use rand::rngs::SmallRng;
+        use rand::SeedableRng;
+
+        let mut world = World::new();
+        world.insert_resource(SimRng(SmallRng::seed_from_u64(7)));
+        world.insert_resource(WorldState::new());
+        world.insert_resource(FactionRegistry::new());
+        world.insert_resource(RelationshipGraph::new());
+        world.insert_resource(TickEvents::new());
+
+        let mut seed = Memory::firsthand("seed_memory", "evt_00001", "outsider", "saw a theft", 0.8, 0, MemoryValence::Negative);
+        seed.fidelity = seed_fidelity;
+        let mut memory_bank = MemoryBank::new();
+        memory_bank.add_memory("speaker", seed);
+        world.insert_resource(memory_bank);
+
+        let mut selected = SelectedActions::new();
+        selected.set(
+            "speaker",
+            Action::Communicate(CommunicationAction::share_memory("speaker", "", "seed_memory", TargetMode::Group)),
+        );
+        world.insert_resource(selected);
+
+        let mut by_location = AgentsByLocation::new();
+        let ids: Vec<String> = std::iter::once("speaker".to_string())
+            .chain((1..group_size).map(|i| format!("listener_{}", i)))
+            .collect();
+        for id in &ids {
+            by_location.add("square", id);
+            world.spawn((
+                AgentId(id.clone()),
+                AgentName(id.clone()),
+                Position::new("square"),
+                FactionMembership::new("thornwood", Role::Laborer),
+            ));
+        }
+        world.insert_resource(by_location);
+
+        let mut schedule = Schedule::default();
+        schedule.add_systems(execute_communication_actions);
+        schedule.run(&mut world);
+
+        world
+            .resource::<MemoryBank>()
+            .memories_about("listener_1", "outsider")[0]
+            .fidelity
+    }
+
+    #[test]
+    fn test_larger_groups_hear_lower_fidelity() {
+        let pair = group_share_fidelity(1.0, 2);
+        let crowd = group_share_fidelity(1.0, 6);
+
+        // A lone listener gets only the flat group penalty
+        let secondhand = 0.7;
+        assert!((pair - secondhand * communication_weights::GROUP_FIDELITY_MULTIPLIER).abs() < 1e-5);
+        assert!(crowd < pair);
+    }
+
+    #[test]
+    fn test_group_telling_keeps_rumor_fidelity_floor() {
+        use crate::components::social::MIN_RUMOR_FIDELITY;
+
+        // A faint rumor told to a crowd takes the smallest group multiplier
+        let faint = group_share_fidelity(0.25, 30);
+        assert_eq!(faint, MIN_RUMOR_FIDELITY);
+    }
 }
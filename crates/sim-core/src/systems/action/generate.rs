@@ -7,10 +7,13 @@
 //! perform actions at specific locations.
 
 use bevy_ecs::prelude::*;
-use std::collections::HashMap;
+use bevy_ecs::schedule::{ExecutorKind, ScheduleLabel};
+use bevy_ecs::system::{Deferred, SystemBuffer, SystemMeta};
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::ops::{Deref, DerefMut};
 
 use crate::actions::movement::{MoveAction, MovementType};
-use crate::actions::communication::{CommunicationAction, CommunicationType, TargetMode};
+use crate::actions::communication::{CommunicationAction, CommunicationType, TargetMode, communication_weights};
 use crate::actions::archive::{ArchiveAction, ArchiveActionType};
 use crate::actions::resource::{ResourceAction, ResourceActionType, resource_weights};
 use crate::actions::social::{SocialAction, SocialActionType, social_weights};
@@ -21,11 +24,15 @@ use crate::components::agent::{AgentId, AgentName, FoodSecurity, Goals, GoalType
 use crate::components::faction::{FactionMembership, FactionRegistry};
 use crate::components::needs::PhysicalNeeds;
 use crate::components::social::{MemoryBank, MemoryValence, RelationshipGraph};
-use crate::components::world::{Location, LocationBenefits, LocationRegistry, Position, ProductionType};
+use crate::components::world::{Location, LocationBenefits, LocationRegistry, Position, ProductionType, WorldState};
 use crate::systems::perception::AgentsByLocation;
-use crate::systems::memory::get_most_interesting_memory;
+use crate::systems::territory::TerritorialFriction;
+use crate::systems::memory::{get_juiciest_memory, GossipWeights};
 
-use super::utility::{self, ActionUtility, calculate_distance_penalty, calculate_idle_weight, calculate_need_utility};
+use super::utility::{
+    self, ActionUtility, calculate_distance_penalty, calculate_idle_weight, calculate_need_utility,
+    calculate_seasonal_travel_penalty, calculate_wander_penalty,
+};
 
 /// Enum representing all possible actions an agent can take
 #[derive(Debug, Clone)]
@@ -50,6 +57,23 @@ pub enum Action {
     Idle,
 }
 
+impl Action {
+    /// Broad category of the action, e.g. "conflict" or "move"
+    pub fn category(&self) -> &'static str {
+        match self {
+            Action::Move(_) => "move",
+            Action::Communicate(_) => "communicate",
+            Action::Archive(_) => "archive",
+            Action::Resource(_) => "resource",
+            Action::Social(_) => "social",
+            Action::Faction(_) => "faction",
+            Action::Conflict(_) => "conflict",
+            Action::Beer(_) => "beer",
+            Action::Idle => "idle",
+        }
+    }
+}
+
 /// A weighted action candidate
 #[derive(Debug, Clone)]
 pub struct WeightedAction {
@@ -69,10 +93,10 @@ impl WeightedAction {
 }
 
 /// Resource storing pending actions for each agent
-#[derive(Resource, Debug, Default)]
+#[derive(Resource, Debug, Clone, Default)]
 pub struct PendingActions {
     /// Maps agent_id -> list of weighted action candidates
-    pub actions: HashMap<String, Vec<WeightedAction>>,
+    pub actions: BTreeMap<String, Vec<WeightedAction>>,
 }
 
 impl PendingActions {
@@ -98,6 +122,82 @@ impl PendingActions {
     pub fn take(&mut self, agent_id: &str) -> Option<Vec<WeightedAction>> {
         self.actions.remove(agent_id)
     }
+
+    /// Move every candidate from `other` into this set.
+    ///
+    /// Each agent's candidates are kept in a canonical order (by reason, then
+    /// weight) so the result doesn't depend on which generator finished first.
+    pub fn merge(&mut self, other: PendingActions) {
+        for (agent_id, mut actions) in other.actions {
+            let merged = self.actions.entry(agent_id).or_default();
+            merged.append(&mut actions);
+            merged.sort_by(|a, b| a.reason.cmp(&b.reason).then(a.weight.total_cmp(&b.weight)));
+        }
+    }
+}
+
+/// Per-system buffer of generated action candidates
+///
+/// Generation systems write here instead of to the shared [`PendingActions`]
+/// resource, so they don't contend for it and Bevy can run them in parallel.
+/// Each buffer is merged into `PendingActions` when the schedule applies
+/// deferred system buffers, which happens before any system ordered after
+/// the generator runs.
+#[derive(Debug, Default)]
+pub struct ActionBuffer(PendingActions);
+
+impl Deref for ActionBuffer {
+    type Target = PendingActions;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl DerefMut for ActionBuffer {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+impl SystemBuffer for ActionBuffer {
+    fn apply(&mut self, _system_meta: &SystemMeta, world: &mut World) {
+        let generated = std::mem::take(&mut self.0);
+        world.resource_mut::<PendingActions>().merge(generated);
+    }
+}
+
+/// Schedule that runs every action generator
+///
+/// The main schedule is single-threaded so systems sharing `SimRng` keep a
+/// fixed order. Generators only read world state and write to their own
+/// [`ActionBuffer`], so they get their own multi-threaded schedule; the
+/// buffers are merged in schedule order when it finishes, which keeps the
+/// result independent of thread timing.
+#[derive(ScheduleLabel, Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ActionGeneration;
+
+/// Build the multi-threaded [`ActionGeneration`] schedule
+pub fn action_generation_schedule() -> Schedule {
+    let mut schedule = Schedule::new(ActionGeneration);
+    schedule.set_executor_kind(ExecutorKind::MultiThreaded);
+    schedule.add_systems((
+        generate_movement_actions,
+        generate_patrol_actions,
+        generate_communication_actions,
+        generate_archive_actions,
+        generate_resource_actions,
+        generate_social_actions,
+        generate_faction_actions,
+        generate_conflict_actions,
+        generate_beer_actions,
+    ));
+    schedule
+}
+
+/// Exclusive system that runs the [`ActionGeneration`] schedule
+pub fn run_action_generation(world: &mut World) {
+    world.run_schedule(ActionGeneration);
 }
 
 /// System to generate desire-based actions for all known locations
@@ -110,14 +210,17 @@ impl PendingActions {
 /// - Need satisfaction (how much does this reduce urgent needs?)
 /// - Social gain (faction standing, relationships)
 /// - Faction benefit (resource production)
+/// - Goal advancement (closing in on a goal target)
 /// - Distance cost (farther = lower weight)
 pub fn generate_desire_based_actions(
+    world_state: Res<WorldState>,
     location_registry: Res<LocationRegistry>,
     faction_registry: Res<FactionRegistry>,
-    mut pending_actions: ResMut<PendingActions>,
-    query: Query<(&AgentId, &Position, &FactionMembership, &Needs, &PhysicalNeeds, &Traits)>,
+    agents_by_location: Res<AgentsByLocation>,
+    mut pending_actions: Deferred<ActionBuffer>,
+    query: Query<(&AgentId, &Position, &FactionMembership, &Needs, &PhysicalNeeds, &Traits, &Goals)>,
 ) {
-    for (agent_id, position, membership, needs, physical_needs, traits) in query.iter() {
+    for (agent_id, position, membership, needs, physical_needs, traits, goals) in query.iter() {
         let current_location_id = &position.location_id;
 
         // Get locations this agent knows about
@@ -134,12 +237,17 @@ pub fn generate_desire_based_actions(
                 continue;
             };
 
-            // Calculate distance penalty once per location
+            // Calculate distance penalty once per location, steeper for long winter trips
             let distance_penalty = calculate_distance_penalty(
                 current_location_id,
                 target_location_id,
                 &location_registry,
                 traits.boldness,
+            ) * calculate_seasonal_travel_penalty(
+                current_location_id,
+                target_location_id,
+                &location_registry,
+                world_state.current_season,
             );
 
             // Generate consumption desires (eat, drink, rest, warm up)
@@ -183,20 +291,29 @@ pub fn generate_desire_based_actions(
             );
         }
 
+        // Head toward the agents our goals are about
+        let goal_locations = generate_goal_pursuit_desires(
+            &agent_id.0,
+            current_location_id,
+            goals,
+            traits,
+            &agents_by_location,
+            &location_registry,
+            &mut pending_actions,
+        );
+
         // Always add a small weight for adjacent exploration
-        for adjacent_id in location_registry.get_adjacent(current_location_id) {
-            pending_actions.add(
-                &agent_id.0,
-                WeightedAction::new(
-                    Action::Move(MoveAction::travel(&agent_id.0, &adjacent_id)),
-                    0.05 * (0.5 + traits.boldness * 0.5), // Bold agents explore more
-                    format!("explore {}", adjacent_id),
-                ),
-            );
-        }
+        generate_exploration_desires(
+            &agent_id.0,
+            current_location_id,
+            &goal_locations,
+            traits,
+            &location_registry,
+            &mut pending_actions,
+        );
 
         // Idle action with need-based penalty
-        let idle_weight = calculate_idle_weight(physical_needs);
+        let idle_weight = calculate_idle_weight(physical_needs, needs);
         pending_actions.add(
             &agent_id.0,
             WeightedAction::new(Action::Idle, idle_weight, "wait and observe"),
@@ -391,6 +508,86 @@ fn generate_production_desires(
     }
 }
 
+/// Generate travel desires toward the current location of each goal target
+///
+/// Returns the locations of the targets so exploration can be steered too.
+fn generate_goal_pursuit_desires(
+    agent_id: &str,
+    current_location: &str,
+    goals: &Goals,
+    traits: &Traits,
+    agents_by_location: &AgentsByLocation,
+    location_registry: &LocationRegistry,
+    pending_actions: &mut PendingActions,
+) -> Vec<String> {
+    let mut goal_locations = Vec::new();
+
+    for goal in &goals.goals {
+        let Some(target_location) = goal
+            .target
+            .as_deref()
+            .and_then(|target| agents_by_location.location_of(target))
+        else {
+            continue;
+        };
+        if target_location == current_location {
+            continue;
+        }
+        let Some(next_step) = location_registry.next_step_toward(current_location, target_location) else {
+            continue;
+        };
+
+        let distance_penalty =
+            calculate_distance_penalty(current_location, target_location, location_registry, traits.boldness);
+        let utility = ActionUtility::new()
+            .with_goal(goal.priority * utility::weights::GOAL * utility::weights::GOAL_PURSUIT_SCALE)
+            .with_distance_cost(distance_penalty);
+
+        pending_actions.add(
+            agent_id,
+            WeightedAction::new(
+                Action::Move(MoveAction::travel(agent_id, &next_step)),
+                utility.total(),
+                format!("pursue {:?} goal toward {}", goal.goal_type, target_location),
+            ),
+        );
+        goal_locations.push(target_location.to_string());
+    }
+
+    goal_locations
+}
+
+/// Generate small wandering desires for each adjacent location
+///
+/// Moves that lead away from an agent's goals are discounted by distance.
+fn generate_exploration_desires(
+    agent_id: &str,
+    current_location: &str,
+    goal_locations: &[String],
+    traits: &Traits,
+    location_registry: &LocationRegistry,
+    pending_actions: &mut PendingActions,
+) {
+    for adjacent_id in location_registry.get_adjacent(current_location) {
+        let wander_penalty = calculate_wander_penalty(
+            current_location,
+            &adjacent_id,
+            goal_locations,
+            location_registry,
+            traits.boldness,
+        );
+        pending_actions.add(
+            agent_id,
+            WeightedAction::new(
+                Action::Move(MoveAction::travel(agent_id, &adjacent_id)),
+                // Bold agents explore more
+                utility::weights::EXPLORE_BASE * (0.5 + traits.boldness * 0.5) * wander_penalty,
+                format!("explore {}", adjacent_id),
+            ),
+        );
+    }
+}
+
 /// Generate belonging/social desires for a target location
 ///
 /// Agents with low social belonging are drawn to social hubs and faction HQs
@@ -482,19 +679,28 @@ fn generate_belonging_desires(
 
 // Keep the old function name as an alias for backwards compatibility during transition
 pub fn generate_movement_actions(
+    world_state: Res<WorldState>,
     location_registry: Res<LocationRegistry>,
     faction_registry: Res<FactionRegistry>,
-    mut pending_actions: ResMut<PendingActions>,
-    query: Query<(&AgentId, &Position, &FactionMembership, &Needs, &PhysicalNeeds, &Traits)>,
+    agents_by_location: Res<AgentsByLocation>,
+    pending_actions: Deferred<ActionBuffer>,
+    query: Query<(&AgentId, &Position, &FactionMembership, &Needs, &PhysicalNeeds, &Traits, &Goals)>,
 ) {
-    generate_desire_based_actions(location_registry, faction_registry, pending_actions, query);
+    generate_desire_based_actions(
+        world_state,
+        location_registry,
+        faction_registry,
+        agents_by_location,
+        pending_actions,
+        query,
+    );
 }
 
 /// System to generate patrol actions for scouts
 pub fn generate_patrol_actions(
     location_registry: Res<LocationRegistry>,
     faction_registry: Res<FactionRegistry>,
-    mut pending_actions: ResMut<PendingActions>,
+    mut pending_actions: Deferred<ActionBuffer>,
     query: Query<(&AgentId, &Position, &FactionMembership)>,
 ) {
     for (agent_id, position, membership) in query.iter() {
@@ -528,28 +734,45 @@ pub fn generate_patrol_actions(
     }
 }
 
+/// Agent components that communication generation reads
+type CommunicationAgents<'w, 's> = Query<
+    'w,
+    's,
+    (
+        &'static AgentId,
+        &'static AgentName,
+        &'static Position,
+        &'static FactionMembership,
+        &'static Traits,
+        &'static Needs,
+        Option<&'static Intoxication>,
+    ),
+>;
+
 /// System to generate communication actions for agents
 ///
 /// Generates share_memory actions when:
 /// - Agent has interesting memories to share
 /// - There are other agents at the same location
+///
+/// Each listener hears the memory that is juiciest for them (see `get_juiciest_memory`).
 pub fn generate_communication_actions(
-    world_state: Res<crate::components::world::WorldState>,
+    world_state: Res<WorldState>,
     agents_by_location: Res<AgentsByLocation>,
     memory_bank: Res<MemoryBank>,
     relationship_graph: Res<RelationshipGraph>,
-    mut pending_actions: ResMut<PendingActions>,
-    query: Query<(&AgentId, &AgentName, &Position, &FactionMembership, &Traits, &Needs)>,
+    mut pending_actions: Deferred<ActionBuffer>,
+    query: CommunicationAgents,
 ) {
-    // Build a map of agent_id -> (name, faction_id) for target info
-    let agent_info: HashMap<String, (String, String)> = query
+    // Build a map of agent_id -> (name, faction_id, belonging) for target info
+    let agent_info: HashMap<String, (String, String, SocialBelonging)> = query
         .iter()
-        .map(|(id, name, _, membership, _, _)| {
-            (id.0.clone(), (name.0.clone(), membership.faction_id.clone()))
+        .map(|(id, name, _, membership, _, needs, _)| {
+            (id.0.clone(), (name.0.clone(), membership.faction_id.clone(), needs.social_belonging))
         })
         .collect();
 
-    for (agent_id, _name, position, membership, traits, _needs) in query.iter() {
+    for (agent_id, _name, position, membership, traits, _needs, intoxication) in query.iter() {
         // Get agents at the same location
         let nearby_agents: Vec<String> = agents_by_location.at_location(&position.location_id).to_vec();
 
@@ -558,67 +781,142 @@ pub fn generate_communication_actions(
             continue;
         }
 
-        // Check if this agent has interesting memories to share
-        let interesting_memory = get_most_interesting_memory(
-            &memory_bank,
-            &agent_id.0,
-            world_state.current_tick,
-        );
+        // Generate share actions for each nearby agent, picking the memory
+        // that is juiciest for that particular listener
+        let gossip_weights = GossipWeights::default();
+        for target_id in &nearby_agents {
+            if target_id == &agent_id.0 {
+                continue;
+            }
 
-        if let Some(memory) = interesting_memory {
-            // Generate share actions for each nearby agent
-            for target_id in &nearby_agents {
-                if target_id == &agent_id.0 {
-                    continue;
+            let Some((target_name, _, _)) = agent_info.get(target_id) else {
+                continue;
+            };
+
+            let Some(memory) = get_juiciest_memory(
+                &memory_bank,
+                &relationship_graph,
+                &agent_id.0,
+                target_id,
+                world_state.current_tick,
+                &gossip_weights,
+            ) else {
+                continue;
+            };
+
+            // Determine target mode based on group_preference
+            let target_mode = if traits.group_preference > 0.7 && nearby_agents.len() >= 4 {
+                TargetMode::Group
+            } else {
+                TargetMode::Individual
+            };
+
+            // Base weight for gossip (from behavioral rules)
+            let base_weight = 0.4;
+
+            // Determine reason based on memory valence
+            let reason = match memory.valence {
+                crate::components::social::MemoryValence::Negative => {
+                    format!("share negative gossip about {} with {}", memory.subject, target_name)
+                }
+                crate::components::social::MemoryValence::Positive => {
+                    format!("share good news about {} with {}", memory.subject, target_name)
+                }
+                crate::components::social::MemoryValence::Neutral => {
+                    format!("share information about {} with {}", memory.subject, target_name)
                 }
+            };
 
-                let Some((target_name, target_faction)) = agent_info.get(target_id) else {
-                    continue;
-                };
+            let action = CommunicationAction::share_memory(
+                &agent_id.0,
+                target_id,
+                &memory.memory_id,
+                target_mode,
+            );
 
-                // Determine target mode based on group_preference
-                let target_mode = if traits.group_preference > 0.7 && nearby_agents.len() >= 4 {
-                    TargetMode::Group
-                } else {
-                    TargetMode::Individual
-                };
+            pending_actions.add(
+                &agent_id.0,
+                WeightedAction::new(
+                    Action::Communicate(action),
+                    base_weight,
+                    reason,
+                ),
+            );
 
-                // Base weight for gossip (from behavioral rules)
-                let base_weight = 0.4;
+            // If targeting group, only add one action
+            if target_mode == TargetMode::Group {
+                break;
+            }
+        }
 
-                // Determine reason based on memory valence
-                let reason = match memory.valence {
-                    crate::components::social::MemoryValence::Negative => {
-                        format!("share negative gossip about {} with {}", memory.subject, target_name)
-                    }
-                    crate::components::social::MemoryValence::Positive => {
-                        format!("share good news about {} with {}", memory.subject, target_name)
-                    }
-                    crate::components::social::MemoryValence::Neutral => {
-                        format!("share information about {} with {}", memory.subject, target_name)
+        // Drink loosens tongues: the secret weighing on the agent most comes out
+        let intox_level = intoxication.map_or(0.0, |i| i.level);
+        if intox_level >= communication_weights::DRUNK_CONFESS_THRESHOLD {
+            let heaviest_secret = memory_bank.get_memories(&agent_id.0).and_then(|memories| {
+                memories
+                    .iter()
+                    .filter(|m| m.is_secret)
+                    .max_by(|a, b| {
+                        a.emotional_weight
+                            .partial_cmp(&b.emotional_weight)
+                            .unwrap_or(std::cmp::Ordering::Equal)
+                    })
+            });
+
+            if let Some(secret) = heaviest_secret {
+                let weight = communication_weights::DRUNK_CONFESS_BASE
+                    + intox_level * communication_weights::DRUNK_CONFESS_INTOX_MULT;
+                for target_id in &nearby_agents {
+                    if target_id == &agent_id.0 {
+                        continue;
                     }
+                    let Some((target_name, _, _)) = agent_info.get(target_id) else {
+                        continue;
+                    };
+
+                    pending_actions.add(
+                        &agent_id.0,
+                        WeightedAction::new(
+                            Action::Communicate(CommunicationAction::confess(
+                                &agent_id.0,
+                                target_id,
+                                &secret.memory_id,
+                            )),
+                            weight,
+                            format!("drunkenly let slip a secret about {} to {}", secret.subject, target_name),
+                        ),
+                    );
+                }
+            }
+        }
+
+        // Sociable agents try to woo poorly integrated members of other factions
+        if traits.sociability >= communication_weights::RECRUIT_MIN_SOCIABILITY && !membership.is_exiled() {
+            for target_id in &nearby_agents {
+                let Some((target_name, target_faction, belonging)) = agent_info.get(target_id) else {
+                    continue;
                 };
+                if target_id == &agent_id.0
+                    || target_faction == &membership.faction_id
+                    || !matches!(belonging, SocialBelonging::Isolated | SocialBelonging::Peripheral)
+                {
+                    continue;
+                }
 
-                let action = CommunicationAction::share_memory(
-                    &agent_id.0,
-                    target_id,
-                    &memory.memory_id,
-                    target_mode,
-                );
+                let mut weight = communication_weights::RECRUIT_BASE
+                    + traits.sociability * communication_weights::RECRUIT_SOCIABILITY_MULT;
+                if *belonging == SocialBelonging::Isolated {
+                    weight += communication_weights::RECRUIT_ISOLATED_BONUS;
+                }
 
                 pending_actions.add(
                     &agent_id.0,
                     WeightedAction::new(
-                        Action::Communicate(action),
-                        base_weight,
-                        reason,
+                        Action::Communicate(CommunicationAction::recruit(&agent_id.0, target_id)),
+                        weight,
+                        format!("recruit {} away from {}", target_name, target_faction),
                     ),
                 );
-
-                // If targeting group, only add one action
-                if target_mode == TargetMode::Group {
-                    break;
-                }
             }
         }
     }
@@ -631,10 +929,10 @@ pub fn generate_communication_actions(
 /// - Agent can write to archive (leader, reader, or council member)
 /// - Agent has a significant memory worth recording
 pub fn generate_archive_actions(
-    world_state: Res<crate::components::world::WorldState>,
+    world_state: Res<WorldState>,
     faction_registry: Res<FactionRegistry>,
     memory_bank: Res<MemoryBank>,
-    mut pending_actions: ResMut<PendingActions>,
+    mut pending_actions: Deferred<ActionBuffer>,
     query: Query<(&AgentId, &AgentName, &Position, &FactionMembership, &Traits)>,
 ) {
     use crate::actions::archive::archive_weights;
@@ -718,10 +1016,11 @@ pub fn generate_archive_actions(
 ///
 /// Generates work, trade, steal, and hoard actions based on location and needs
 pub fn generate_resource_actions(
+    world_state: Res<WorldState>,
     faction_registry: Res<FactionRegistry>,
     agents_by_location: Res<AgentsByLocation>,
     relationship_graph: Res<RelationshipGraph>,
-    mut pending_actions: ResMut<PendingActions>,
+    mut pending_actions: Deferred<ActionBuffer>,
     query: Query<(&AgentId, &Position, &FactionMembership, &Needs, &Traits)>,
 ) {
     // Build agent info map
@@ -730,7 +1029,17 @@ pub fn generate_resource_actions(
         .map(|(id, _, mem, needs, traits)| (id.0.clone(), (mem, needs, traits)))
         .collect();
 
+    // Factions projected to run dry within the horizon start stocking up early
+    let schedule = world_state.season_schedule();
+    let facing_shortfall: HashSet<&str> = faction_registry
+        .all_factions()
+        .filter(|f| f.project_grain(resource_weights::FORECAST_HORIZON_TICKS, &schedule) < 0)
+        .map(|f| f.id.0.as_str())
+        .collect();
+
     for (agent_id, position, membership, needs, traits) in query.iter() {
+        let shortfall = facing_shortfall.contains(membership.faction_id.as_str());
+
         // Work action - available when at a productive location
         let faction = faction_registry.get(&membership.faction_id);
         let at_territory = faction.map_or(false, |f| f.territory.contains(&position.location_id));
@@ -742,6 +1051,10 @@ pub fn generate_resource_actions(
             } else if needs.food_security == FoodSecurity::Desperate {
                 weight += resource_weights::WORK_DESPERATE_BONUS;
             }
+            if shortfall {
+                weight += resource_weights::WORK_FORECAST_SHORTFALL_BONUS;
+            }
+            weight *= world_state.current_season.work_modifier();
 
             let action = ResourceAction::work(&agent_id.0);
             pending_actions.add(
@@ -766,11 +1079,17 @@ pub fn generate_resource_actions(
             if trust > 0.1 {
                 let mut weight = resource_weights::TRADE_BASE;
                 weight += trust * resource_weights::TRADE_TRUST_BONUS;
+                if shortfall {
+                    weight += resource_weights::TRADE_FORECAST_SHORTFALL_BONUS;
+                }
 
-                // Cross-faction trade bonus
+                // Cross-faction trade bonus, larger between allies
                 if let Some((target_mem, _, _)) = agent_info.get(target_id) {
                     if target_mem.faction_id != membership.faction_id {
                         weight += resource_weights::TRADE_CROSS_FACTION_BONUS;
+                        if faction_registry.are_allied(&membership.faction_id, &target_mem.faction_id) {
+                            weight += resource_weights::TRADE_ALLIED_BONUS;
+                        }
                     }
                 }
 
@@ -822,8 +1141,8 @@ pub fn generate_resource_actions(
             }
         }
 
-        // Hoard action - available when stressed and low loyalty
-        if needs.food_security != FoodSecurity::Secure && traits.loyalty_weight < 0.5 {
+        // Hoard action - available when stressed (or a shortfall is coming) and low loyalty
+        if (needs.food_security != FoodSecurity::Secure || shortfall) && traits.loyalty_weight < 0.5 {
             let mut weight = resource_weights::HOARD_BASE;
             if traits.loyalty_weight < 0.3 {
                 weight += resource_weights::HOARD_LOW_LOYALTY_BONUS;
@@ -831,6 +1150,9 @@ pub fn generate_resource_actions(
             if needs.food_security == FoodSecurity::Stressed {
                 weight += resource_weights::HOARD_STRESSED_BONUS;
             }
+            if shortfall {
+                weight += resource_weights::HOARD_FORECAST_SHORTFALL_BONUS;
+            }
             weight -= traits.loyalty_weight * resource_weights::HOARD_HIGH_LOYALTY_PENALTY;
 
             let action = ResourceAction::hoard(&agent_id.0, 2);
@@ -848,7 +1170,7 @@ pub fn generate_resource_actions(
 pub fn generate_social_actions(
     agents_by_location: Res<AgentsByLocation>,
     relationship_graph: Res<RelationshipGraph>,
-    mut pending_actions: ResMut<PendingActions>,
+    mut pending_actions: Deferred<ActionBuffer>,
     query: Query<(&AgentId, &Position, &FactionMembership, &Needs, &Traits)>,
 ) {
     // Build agent info map
@@ -973,7 +1295,7 @@ pub fn generate_social_actions(
 pub fn generate_faction_actions(
     faction_registry: Res<FactionRegistry>,
     relationship_graph: Res<RelationshipGraph>,
-    mut pending_actions: ResMut<PendingActions>,
+    mut pending_actions: Deferred<ActionBuffer>,
     query: Query<(&AgentId, &Position, &FactionMembership, &Needs, &Traits, &Goals)>,
 ) {
     // Collect faction member counts and leader trust
@@ -996,6 +1318,15 @@ pub fn generate_faction_actions(
         }
     }
 
+    // Members of each faction, for spotting cross-faction contacts
+    let mut faction_members: HashMap<String, Vec<String>> = HashMap::new();
+    for (agent_id, _, membership, _, _, _) in query.iter() {
+        faction_members
+            .entry(membership.faction_id.clone())
+            .or_default()
+            .push(agent_id.0.clone());
+    }
+
     // Calculate average leader trust per faction
     let avg_leader_trust: HashMap<String, f32> = faction_leader_trust
         .iter()
@@ -1042,6 +1373,16 @@ pub fn generate_faction_actions(
                 if traits.loyalty_weight > 0.7 {
                     weight -= faction_weights::DEFECT_HIGH_LOYALTY_PENALTY;
                 }
+                let has_contact = faction_members.get(&new_faction.id.0).is_some_and(|members| {
+                    members.iter().any(|member| {
+                        relationship_graph
+                            .get(&agent_id.0, member)
+                            .is_some_and(|r| r.trust.overall() > faction_weights::EXTERNAL_CONTACT_TRUST_THRESHOLD)
+                    })
+                });
+                if has_contact {
+                    weight += faction_weights::DEFECT_EXTERNAL_CONTACT_BONUS;
+                }
 
                 let action = FactionAction::defect(
                     &agent_id.0,
@@ -1059,10 +1400,37 @@ pub fn generate_faction_actions(
             }
         }
 
-        // Exile action - for leaders/council only
-        if matches!(membership.role, Role::Leader | Role::CouncilMember) {
-            // Could add exile generation here based on trust toward specific agents
-            // For now, skipping detailed exile generation
+        // Exile action - leaders/council target distrusted, disloyal members
+        if membership.can_exile() {
+            for (target_id, _, target_membership, _, target_traits, _) in query.iter() {
+                if target_id.0 == agent_id.0
+                    || target_membership.faction_id != membership.faction_id
+                    || target_membership.is_leader()
+                    || target_traits.loyalty_weight >= faction_weights::EXILE_LOW_LOYALTY_THRESHOLD
+                {
+                    continue;
+                }
+
+                let trust = relationship_graph
+                    .get(&agent_id.0, &target_id.0)
+                    .map(|r| r.trust.overall())
+                    .unwrap_or(0.0);
+                if trust >= faction_weights::EXILE_TRUST_THRESHOLD {
+                    continue;
+                }
+
+                let weight = faction_weights::EXILE_BASE
+                    + (-trust) * faction_weights::EXILE_DISTRUST_BONUS;
+                let action = FactionAction::exile(&agent_id.0, &target_id.0);
+                pending_actions.add(
+                    &agent_id.0,
+                    WeightedAction::new(
+                        Action::Faction(action),
+                        weight,
+                        format!("exile {}", target_id.0),
+                    ),
+                );
+            }
         }
 
         // Challenge Leader action - requires high ambition and weak leader
@@ -1129,11 +1497,18 @@ pub fn generate_faction_actions(
 ///
 /// Generates argue, fight, sabotage, and assassinate actions
 pub fn generate_conflict_actions(
+    faction_registry: Res<FactionRegistry>,
     agents_by_location: Res<AgentsByLocation>,
     relationship_graph: Res<RelationshipGraph>,
-    mut pending_actions: ResMut<PendingActions>,
+    territorial_friction: Res<TerritorialFriction>,
+    mut pending_actions: Deferred<ActionBuffer>,
     query: Query<(&AgentId, &Position, &FactionMembership, &Needs, &Traits, &Goals)>,
 ) {
+    let agent_info: HashMap<&str, (&str, &Traits)> = query
+        .iter()
+        .map(|(id, _, mem, _, traits, _)| (id.0.as_str(), (mem.faction_id.as_str(), traits)))
+        .collect();
+
     for (agent_id, position, membership, needs, traits, goals) in query.iter() {
         let nearby_agents = agents_by_location.at_location(&position.location_id);
 
@@ -1154,6 +1529,22 @@ pub fn generate_conflict_actions(
             let has_grudge = trust < -0.2;
             let is_revenge_target = revenge_target.as_ref() == Some(target_id);
 
+            // Alliances dampen hostility toward the other faction's members,
+            // while rivals contesting this location are easier to provoke
+            let target_faction = agent_info.get(target_id.as_str()).map(|(faction, _)| *faction);
+            let allied = target_faction
+                .is_some_and(|target_faction| faction_registry.are_allied(&membership.faction_id, target_faction));
+            let contested = target_faction.is_some_and(|target_faction| {
+                territorial_friction.is_contested(&position.location_id, &membership.faction_id, target_faction)
+            });
+            let hostility = if allied {
+                conflict_weights::ALLIED_HOSTILITY_MULT
+            } else if contested {
+                conflict_weights::TERRITORIAL_FRICTION_MULT
+            } else {
+                1.0
+            };
+
             // Argue action - verbal conflict
             if has_grudge || is_revenge_target {
                 let mut weight = conflict_weights::ARGUE_BASE;
@@ -1172,7 +1563,7 @@ pub fn generate_conflict_actions(
                     &agent_id.0,
                     WeightedAction::new(
                         Action::Conflict(action),
-                        weight,
+                        weight * hostility,
                         format!("argue with {}", target_id),
                     ),
                 );
@@ -1198,12 +1589,46 @@ pub fn generate_conflict_actions(
                     &agent_id.0,
                     WeightedAction::new(
                         Action::Conflict(action),
-                        weight.max(0.001),
+                        weight.max(0.001) * hostility,
                         format!("fight {}", target_id),
                     ),
                 );
             }
 
+            // Duel action - formal challenge between two bold, mutually hostile rivals
+            let target_trust = relationship_graph
+                .get(target_id, &agent_id.0)
+                .map(|r| r.trust.overall())
+                .unwrap_or(0.0);
+            let target_boldness = agent_info
+                .get(target_id.as_str())
+                .map_or(0.0, |(_, t)| t.boldness);
+            if trust < conflict_weights::DUEL_MUTUAL_DISTRUST
+                && target_trust < conflict_weights::DUEL_MUTUAL_DISTRUST
+                && traits.boldness >= conflict_weights::DUEL_MIN_BOLDNESS
+                && target_boldness >= conflict_weights::DUEL_MIN_BOLDNESS
+            {
+                let mut weight = conflict_weights::DUEL_BASE;
+                if is_revenge_target {
+                    weight += conflict_weights::DUEL_REVENGE_BONUS;
+                }
+                weight += traits.boldness * conflict_weights::DUEL_BOLDNESS_MULT;
+
+                let action = ConflictAction::duel(
+                    &agent_id.0,
+                    target_id,
+                    Some("mutual_grudge".to_string()),
+                );
+                pending_actions.add(
+                    &agent_id.0,
+                    WeightedAction::new(
+                        Action::Conflict(action),
+                        weight * hostility,
+                        format!("duel {}", target_id),
+                    ),
+                );
+            }
+
             // Sabotage action - sneaky retaliation
             if has_grudge && traits.honesty < 0.5 {
                 let mut weight = conflict_weights::SABOTAGE_BASE;
@@ -1222,7 +1647,7 @@ pub fn generate_conflict_actions(
                     &agent_id.0,
                     WeightedAction::new(
                         Action::Conflict(action),
-                        weight.max(0.001),
+                        weight.max(0.001) * hostility,
                         format!("sabotage {}", target_id),
                     ),
                 );
@@ -1250,7 +1675,7 @@ pub fn generate_conflict_actions(
                     &agent_id.0,
                     WeightedAction::new(
                         Action::Conflict(action),
-                        weight.max(0.0001),
+                        weight.max(0.0001) * hostility,
                         format!("assassinate {}", target_id),
                     ),
                 );
@@ -1265,8 +1690,9 @@ pub fn generate_conflict_actions(
 /// and sharing beer with others to build trust.
 pub fn generate_beer_actions(
     faction_registry: Res<FactionRegistry>,
+    location_registry: Res<LocationRegistry>,
     agents_by_location: Res<AgentsByLocation>,
-    mut pending_actions: ResMut<PendingActions>,
+    mut pending_actions: Deferred<ActionBuffer>,
     query: Query<(&AgentId, &Position, &FactionMembership, &Needs, &Traits, &Intoxication)>,
 ) {
     for (agent_id, position, membership, needs, traits, intoxication) in query.iter() {
@@ -1274,10 +1700,13 @@ pub fn generate_beer_actions(
             continue;
         };
 
-        let at_territory = faction.territory.contains(&position.location_id);
+        let at_brewery = faction.territory.contains(&position.location_id)
+            && location_registry
+                .get(&position.location_id)
+                .is_some_and(|loc| loc.is_brewery());
 
-        // Brew action - available when at territory with sufficient grain
-        if at_territory && faction.resources.grain >= beer_weights::BREW_GRAIN_THRESHOLD {
+        // Brew action - available at one of the faction's breweries with sufficient grain
+        if at_brewery && faction.resources.grain >= beer_weights::BREW_GRAIN_THRESHOLD {
             let mut weight = beer_weights::BREW_BASE;
 
             // Bonus if we have lots of grain (> 200 per territory)
@@ -1388,6 +1817,594 @@ mod tests {
         assert!(pending.get("agent_001").is_none());
     }
 
+    #[test]
+    fn test_disliked_member_becomes_exile_candidate() {
+        use crate::components::faction::Faction;
+        use crate::components::social::{Relationship, Trust};
+
+        let mut world = World::new();
+        let mut registry = FactionRegistry::new();
+        let mut faction = Faction::new("thornwood", "Thornwood", "hall");
+        faction.leader = Some("leader".to_string());
+        registry.register(faction);
+        world.insert_resource(registry);
+        world.insert_resource(PendingActions::new());
+
+        let mut graph = RelationshipGraph::new();
+        graph.set("leader", Relationship::new("schemer").with_trust(Trust::new(-0.7, -0.6, 0.0)));
+        graph.set("leader", Relationship::new("loyalist").with_trust(Trust::new(-0.7, -0.6, 0.0)));
+        graph.set("leader", Relationship::new("friend").with_trust(Trust::new(0.6, 0.5, 0.5)));
+        world.insert_resource(graph);
+
+        let spawn = |world: &mut World, id: &str, role: Role, loyalty: f32| {
+            world.spawn((
+                AgentId(id.to_string()),
+                Position::new("hall"),
+                FactionMembership::new("thornwood", role),
+                Needs::default(),
+                Traits { loyalty_weight: loyalty, ambition: 0.3, ..Traits::default() },
+                Goals::new(),
+            ));
+        };
+        spawn(&mut world, "leader", Role::Leader, 0.8);
+        spawn(&mut world, "schemer", Role::Laborer, 0.2);
+        spawn(&mut world, "loyalist", Role::Laborer, 0.9);
+        spawn(&mut world, "friend", Role::Laborer, 0.2);
+
+        let mut schedule = Schedule::default();
+        schedule.add_systems(generate_faction_actions);
+        schedule.run(&mut world);
+
+        let pending = world.resource::<PendingActions>();
+        let exile_targets: Vec<&str> = pending
+            .get("leader")
+            .into_iter()
+            .flatten()
+            .filter_map(|wa| match &wa.action {
+                Action::Faction(fa) if fa.action_type == FactionActionType::Exile => Some(fa.target_id.as_str()),
+                _ => None,
+            })
+            .collect();
+
+        // Distrusted but loyal, or disloyal but trusted, are both spared
+        assert_eq!(exile_targets, vec!["schemer"]);
+        // Ordinary members never generate exile actions
+        assert!(pending.get("schemer").is_none_or(|actions| actions.iter().all(|wa| {
+            !matches!(&wa.action, Action::Faction(fa) if fa.action_type == FactionActionType::Exile)
+        })));
+    }
+
+    #[test]
+    fn test_alliance_reduces_conflict_weights() {
+        use crate::components::faction::Faction;
+        use crate::components::social::{Relationship, Trust};
+
+        fn conflict_weight(allied: bool) -> f32 {
+            let mut world = World::new();
+            let mut registry = FactionRegistry::new();
+            registry.register(Faction::new("thornwood", "Thornwood", "hall"));
+            registry.register(Faction::new("ironmere", "Ironmere", "keep"));
+            if allied {
+                registry.form_alliance("thornwood", "ironmere");
+            }
+            world.insert_resource(registry);
+            world.insert_resource(PendingActions::new());
+            world.insert_resource(TerritorialFriction::new());
+
+            let mut by_location = AgentsByLocation::new();
+            by_location.add("market", "hothead");
+            by_location.add("market", "rival");
+            world.insert_resource(by_location);
+
+            let mut graph = RelationshipGraph::new();
+            graph.set("hothead", Relationship::new("rival").with_trust(Trust::new(-0.6, -0.6, 0.0)));
+            world.insert_resource(graph);
+
+            for (id, faction) in [("hothead", "thornwood"), ("rival", "ironmere")] {
+                world.spawn((
+                    AgentId(id.to_string()),
+                    Position::new("market"),
+                    FactionMembership::new(faction, Role::Laborer),
+                    Needs::default(),
+                    Traits { boldness: 0.8, ..Traits::default() },
+                    Goals::new(),
+                ));
+            }
+
+            let mut schedule = Schedule::default();
+            schedule.add_systems(generate_conflict_actions);
+            schedule.run(&mut world);
+
+            world
+                .resource::<PendingActions>()
+                .get("hothead")
+                .into_iter()
+                .flatten()
+                .filter(|wa| matches!(wa.action, Action::Conflict(_)))
+                .map(|wa| wa.weight)
+                .sum()
+        }
+
+        let hostile = conflict_weight(false);
+        let allied = conflict_weight(true);
+        assert!(hostile > 0.0);
+        assert!((allied - hostile * conflict_weights::ALLIED_HOSTILITY_MULT).abs() < 1e-5);
+    }
+
+    #[test]
+    fn test_forecast_shortfall_raises_work_and_hoard() {
+        use crate::components::faction::{Faction, FactionResources};
+        use crate::components::world::Season;
+
+        fn resource_weights_for(grain: u32) -> (f32, f32) {
+            let mut world = World::new();
+            // Autumn surplus cannot cover the coming winter without reserves
+            let mut world_state = WorldState::new();
+            world_state.current_season = Season::Autumn;
+            world.insert_resource(world_state);
+            let mut registry = FactionRegistry::new();
+            let mut faction = Faction::new("thornwood", "Thornwood", "hall")
+                .with_territory(vec!["hall".to_string()])
+                .with_resources(FactionResources::new(grain, 0, 0));
+            faction.member_count = 20;
+            registry.register(faction);
+            world.insert_resource(registry);
+            world.insert_resource(AgentsByLocation::new());
+            world.insert_resource(RelationshipGraph::new());
+            world.insert_resource(PendingActions::new());
+
+            world.spawn((
+                AgentId("farmer".to_string()),
+                Position::new("hall"),
+                FactionMembership::new("thornwood", Role::Laborer),
+                Needs::default(),
+                Traits { loyalty_weight: 0.4, ..Traits::default() },
+            ));
+
+            let mut schedule = Schedule::default();
+            schedule.add_systems(generate_resource_actions);
+            schedule.run(&mut world);
+
+            let weight_of = |kind: ResourceActionType| -> f32 {
+                world
+                    .resource::<PendingActions>()
+                    .get("farmer")
+                    .into_iter()
+                    .flatten()
+                    .filter(|wa| matches!(&wa.action, Action::Resource(ra) if ra.action_type == kind))
+                    .map(|wa| wa.weight)
+                    .sum()
+            };
+            (weight_of(ResourceActionType::Work), weight_of(ResourceActionType::Hoard))
+        }
+
+        let (stocked_work, stocked_hoard) = resource_weights_for(100_000);
+        let (short_work, short_hoard) = resource_weights_for(0);
+
+        let bonus = resource_weights::WORK_FORECAST_SHORTFALL_BONUS * Season::Autumn.work_modifier();
+        assert!((short_work - stocked_work - bonus).abs() < 1e-5);
+        // Well-fed agents only hoard once the forecast turns bad
+        assert_eq!(stocked_hoard, 0.0);
+        assert!(short_hoard > 0.0);
+    }
+
+    #[test]
+    fn test_autumn_stocking_up_outworks_deep_winter() {
+        use crate::components::faction::{Faction, FactionResources};
+        use crate::components::world::Season;
+
+        fn work_weight_in(season: Season) -> f32 {
+            let mut world = World::new();
+            let mut world_state = WorldState::new();
+            world_state.current_season = season;
+            world.insert_resource(world_state);
+            let mut registry = FactionRegistry::new();
+            registry.register(
+                Faction::new("thornwood", "Thornwood", "hall")
+                    .with_territory(vec!["hall".to_string()])
+                    .with_resources(FactionResources::new(100_000, 0, 0)),
+            );
+            world.insert_resource(registry);
+            world.insert_resource(AgentsByLocation::new());
+            world.insert_resource(RelationshipGraph::new());
+            world.insert_resource(PendingActions::new());
+
+            world.spawn((
+                AgentId("farmer".to_string()),
+                Position::new("hall"),
+                FactionMembership::new("thornwood", Role::Laborer),
+                Needs::default(),
+                Traits::default(),
+            ));
+
+            let mut schedule = Schedule::default();
+            schedule.add_systems(generate_resource_actions);
+            schedule.run(&mut world);
+
+            world
+                .resource::<PendingActions>()
+                .get("farmer")
+                .into_iter()
+                .flatten()
+                .filter(|wa| matches!(&wa.action, Action::Resource(ra) if ra.action_type == ResourceActionType::Work))
+                .map(|wa| wa.weight)
+                .sum()
+        }
+
+        let autumn = work_weight_in(Season::Autumn);
+        let winter = work_weight_in(Season::Winter);
+        assert!(winter > 0.0);
+        assert!(autumn > winter);
+        assert!(work_weight_in(Season::Summer) < autumn);
+    }
+
+    #[test]
+    fn test_brewing_limited_to_brewery_locations() {
+        use crate::actions::beer::BeerActionType;
+        use crate::components::faction::{Faction, FactionResources};
+        use crate::components::world::LocationType;
+
+        let mut world = World::new();
+        let mut registry = FactionRegistry::new();
+        registry.register(
+            Faction::new("thornwood", "Thornwood", "hall")
+                .with_territory(vec!["hall".to_string(), "fields".to_string()])
+                .with_resources(FactionResources::new(500, 0, 0)),
+        );
+        world.insert_resource(registry);
+        let mut locations = LocationRegistry::new();
+        locations.register(Location::new("hall", "Thornwood Hall", LocationType::Hall));
+        locations.register(Location::new("fields", "Thornwood Fields", LocationType::Fields));
+        world.insert_resource(locations);
+        world.insert_resource(AgentsByLocation::new());
+        world.insert_resource(PendingActions::new());
+
+        for (id, location) in [("brewer", "hall"), ("farmer", "fields")] {
+            world.spawn((
+                AgentId(id.to_string()),
+                Position::new(location),
+                FactionMembership::new("thornwood", Role::Laborer),
+                Needs::default(),
+                Traits::default(),
+                Intoxication::default(),
+            ));
+        }
+
+        let mut schedule = Schedule::default();
+        schedule.add_systems(generate_beer_actions);
+        schedule.run(&mut world);
+
+        let pending = world.resource::<PendingActions>();
+        let can_brew = |agent: &str| {
+            pending.get(agent).into_iter().flatten().any(|wa| {
+                matches!(&wa.action, Action::Beer(ba) if ba.action_type == BeerActionType::Brew)
+            })
+        };
+        assert!(can_brew("brewer"));
+        // Fields are faction territory but have no brewery
+        assert!(!can_brew("farmer"));
+    }
+
+    #[test]
+    fn test_duel_requires_mutual_hostility() {
+        use crate::components::social::{Relationship, Trust};
+
+        fn duel_targets(mutual: bool) -> Vec<String> {
+            let mut world = World::new();
+            world.insert_resource(FactionRegistry::new());
+            world.insert_resource(PendingActions::new());
+            world.insert_resource(TerritorialFriction::new());
+
+            let mut by_location = AgentsByLocation::new();
+            by_location.add("market", "duelist_a");
+            by_location.add("market", "duelist_b");
+            world.insert_resource(by_location);
+
+            let mut graph = RelationshipGraph::new();
+            graph.set("duelist_a", Relationship::new("duelist_b").with_trust(Trust::new(-0.6, -0.6, 0.0)));
+            if mutual {
+                graph.set("duelist_b", Relationship::new("duelist_a").with_trust(Trust::new(-0.6, -0.6, 0.0)));
+            }
+            world.insert_resource(graph);
+
+            for id in ["duelist_a", "duelist_b"] {
+                world.spawn((
+                    AgentId(id.to_string()),
+                    Position::new("market"),
+                    FactionMembership::new("thornwood", Role::Laborer),
+                    Needs::default(),
+                    Traits { boldness: 0.8, ..Traits::default() },
+                    Goals::new(),
+                ));
+            }
+
+            let mut schedule = Schedule::default();
+            schedule.add_systems(generate_conflict_actions);
+            schedule.run(&mut world);
+
+            world
+                .resource::<PendingActions>()
+                .get("duelist_a")
+                .into_iter()
+                .flatten()
+                .filter_map(|wa| match &wa.action {
+                    Action::Conflict(c) if c.action_type == ConflictActionType::Duel => {
+                        Some(c.target_id.clone())
+                    }
+                    _ => None,
+                })
+                .collect()
+        }
+
+        // One-sided hostility leads to a fight, not a duel
+        assert!(duel_targets(false).is_empty());
+        assert_eq!(duel_targets(true), vec!["duelist_b".to_string()]);
+    }
+
+    #[test]
+    fn test_sociable_outsider_recruits_poorly_integrated_members() {
+        use crate::components::social::MemoryBank;
+        use crate::components::world::WorldState;
+
+        let mut world = World::new();
+        world.insert_resource(WorldState::new());
+        world.insert_resource(MemoryBank::new());
+        world.insert_resource(RelationshipGraph::new());
+        world.insert_resource(PendingActions::new());
+
+        let agents = [
+            ("envoy", "ironmere", SocialBelonging::Integrated, 0.9),
+            ("drifter", "thornwood", SocialBelonging::Isolated, 0.3),
+            ("settled", "thornwood", SocialBelonging::Integrated, 0.3),
+            ("cousin", "ironmere", SocialBelonging::Isolated, 0.3),
+        ];
+        let mut by_location = AgentsByLocation::new();
+        for (id, faction, belonging, sociability) in agents {
+            by_location.add("market", id);
+            world.spawn((
+                AgentId(id.to_string()),
+                AgentName(id.to_string()),
+                Position::new("market"),
+                FactionMembership::new(faction, Role::Laborer),
+                Traits { sociability, ..Traits::default() },
+                Needs { social_belonging: belonging, ..Needs::default() },
+            ));
+        }
+        world.insert_resource(by_location);
+
+        let mut schedule = Schedule::default();
+        schedule.add_systems(generate_communication_actions);
+        schedule.run(&mut world);
+
+        let recruit_targets = |agent: &str| -> Vec<String> {
+            world
+                .resource::<PendingActions>()
+                .get(agent)
+                .into_iter()
+                .flatten()
+                .filter_map(|wa| match &wa.action {
+                    Action::Communicate(ca) if ca.communication_type == CommunicationType::Recruit => {
+                        Some(ca.target_id.clone())
+                    }
+                    _ => None,
+                })
+                .collect()
+        };
+
+        // Only the isolated member of another faction is wooed
+        assert_eq!(recruit_targets("envoy"), vec!["drifter".to_string()]);
+        // Unsociable agents don't recruit
+        assert!(recruit_targets("drifter").is_empty());
+    }
+
+    #[test]
+    fn test_drunk_agent_lets_secrets_slip() {
+        use crate::components::social::{Memory, MemoryBank};
+
+        fn secret_weight(intox_level: f32) -> f32 {
+            let mut world = World::new();
+            world.insert_resource(WorldState::new());
+            let mut memory_bank = MemoryBank::new();
+            let mut secret = Memory::firsthand(
+                "mem_secret", "evt1", "leader", "saw the leader meet the enemy",
+                0.6, 0, MemoryValence::Negative,
+            );
+            secret.is_secret = true;
+            memory_bank.add_memory("drinker", secret);
+            world.insert_resource(memory_bank);
+            world.insert_resource(RelationshipGraph::new());
+            world.insert_resource(PendingActions::new());
+
+            let mut by_location = AgentsByLocation::new();
+            for id in ["drinker", "listener"] {
+                by_location.add("tavern", id);
+                world.spawn((
+                    AgentId(id.to_string()),
+                    AgentName(id.to_string()),
+                    Position::new("tavern"),
+                    FactionMembership::new("thornwood", Role::Laborer),
+                    Traits::default(),
+                    Needs::default(),
+                    Intoxication { level: if id == "drinker" { intox_level } else { 0.0 }, ..Intoxication::default() },
+                ));
+            }
+            world.insert_resource(by_location);
+
+            let mut schedule = Schedule::default();
+            schedule.add_systems(generate_communication_actions);
+            schedule.run(&mut world);
+
+            world
+                .resource::<PendingActions>()
+                .get("drinker")
+                .into_iter()
+                .flatten()
+                .filter(|wa| matches!(
+                    &wa.action,
+                    Action::Communicate(ca) if ca.memory_id.as_deref() == Some("mem_secret")
+                ))
+                .map(|wa| wa.weight)
+                .sum()
+        }
+
+        let sober = secret_weight(0.0);
+        let drunk = secret_weight(0.8);
+        assert!(drunk > sober * 2.0, "drunk {} vs sober {}", drunk, sober);
+        // Drunker still loosens the tongue further
+        assert!(secret_weight(1.0) > drunk);
+    }
+
+    #[test]
+    fn test_move_toward_goal_target_outweighs_move_away() {
+        use crate::components::agent::Goal;
+        use crate::components::world::LocationType;
+
+        // west <-> hall <-> east <-> far_east
+        let mut registry = LocationRegistry::new();
+        for (id, adjacent) in [
+            ("west", vec!["hall"]),
+            ("hall", vec!["west", "east"]),
+            ("east", vec!["hall", "far_east"]),
+            ("far_east", vec!["east"]),
+        ] {
+            registry.register(
+                Location::new(id, id, LocationType::Crossroads)
+                    .with_adjacent(adjacent.into_iter().map(String::from).collect()),
+            );
+        }
+
+        let mut by_location = AgentsByLocation::new();
+        by_location.add("hall", "avenger");
+        by_location.add("far_east", "enemy");
+
+        let mut goals = Goals::new();
+        goals.add(Goal::new(GoalType::Revenge, 0.9).with_target("enemy"));
+        let traits = Traits::default();
+
+        let mut pending = PendingActions::new();
+        let goal_locations = generate_goal_pursuit_desires(
+            "avenger", "hall", &goals, &traits, &by_location, &registry, &mut pending,
+        );
+        generate_exploration_desires("avenger", "hall", &goal_locations, &traits, &registry, &mut pending);
+        assert_eq!(goal_locations, vec!["far_east".to_string()]);
+
+        let weight_toward = |destination: &str| -> f32 {
+            pending
+                .get("avenger")
+                .into_iter()
+                .flatten()
+                .filter(|wa| matches!(&wa.action, Action::Move(m) if m.destination == destination))
+                .map(|wa| wa.weight)
+                .sum()
+        };
+        assert!(weight_toward("east") > weight_toward("west") * 2.0);
+
+        // Without a located goal, wandering is even-handed
+        let mut aimless = PendingActions::new();
+        generate_exploration_desires("avenger", "hall", &[], &traits, &registry, &mut aimless);
+        let weights: Vec<f32> = aimless.get("avenger").unwrap().iter().map(|wa| wa.weight).collect();
+        assert_eq!(weights.len(), 2);
+        assert!((weights[0] - weights[1]).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_parallel_generation_matches_sequential() {
+        use crate::components::faction::Faction;
+        use crate::components::social::{Relationship, Trust};
+        use bevy_ecs::schedule::ExecutorKind;
+
+        fn setup_world() -> World {
+            let mut world = World::new();
+            world.insert_resource(PendingActions::new());
+            world.insert_resource(LocationRegistry::new());
+            world.insert_resource(TerritorialFriction::new());
+            let mut registry = FactionRegistry::new();
+            let mut thornwood = Faction::new("thornwood", "Thornwood", "hall");
+            thornwood.leader = Some("agent_00".to_string());
+            registry.register(thornwood);
+            registry.register(Faction::new("ironmere", "Ironmere", "keep"));
+            world.insert_resource(registry);
+
+            let mut by_location = AgentsByLocation::new();
+            let mut graph = RelationshipGraph::new();
+            for i in 0..12 {
+                let id = format!("agent_{:02}", i);
+                let faction = if i % 2 == 0 { "thornwood" } else { "ironmere" };
+                let role = if i == 0 { Role::Leader } else { Role::Laborer };
+                by_location.add("market", &id);
+                let neighbor = format!("agent_{:02}", (i + 1) % 12);
+                graph.set(&id, Relationship::new(neighbor).with_trust(Trust::new(-0.6, -0.4, 0.2)));
+                world.spawn((
+                    AgentId(id),
+                    Position::new("market"),
+                    FactionMembership::new(faction, role),
+                    Needs::default(),
+                    Traits { boldness: 0.8, ambition: 0.7, sociability: 0.7, ..Traits::default() },
+                    Goals::new(),
+                ));
+            }
+            world.insert_resource(by_location);
+            world.insert_resource(graph);
+            world
+        }
+
+        fn generate(parallel: bool) -> Vec<(String, Vec<(String, f32)>)> {
+            let mut world = setup_world();
+            let mut schedule = Schedule::default();
+            let systems = (
+                generate_social_actions,
+                generate_faction_actions,
+                generate_conflict_actions,
+            );
+            if parallel {
+                schedule.set_executor_kind(ExecutorKind::MultiThreaded);
+                schedule.add_systems(systems);
+            } else {
+                schedule.set_executor_kind(ExecutorKind::SingleThreaded);
+                schedule.add_systems(systems.chain());
+            }
+            schedule.run(&mut world);
+
+            world
+                .resource::<PendingActions>()
+                .actions
+                .iter()
+                .map(|(agent, actions)| {
+                    (agent.clone(), actions.iter().map(|wa| (wa.reason.clone(), wa.weight)).collect())
+                })
+                .collect()
+        }
+
+        let sequential = generate(false);
+        assert!(sequential.iter().any(|(_, actions)| actions.len() > 1));
+        for _ in 0..5 {
+            assert_eq!(generate(true), sequential);
+        }
+    }
+
+    #[test]
+    fn test_merge_orders_candidates_canonically() {
+        let mut a = PendingActions::new();
+        a.add("mira", WeightedAction::new(Action::Idle, 0.2, "wait and observe"));
+        let mut b = PendingActions::new();
+        b.add("mira", WeightedAction::new(Action::Idle, 0.1, "rest"));
+        b.add("tomas", WeightedAction::new(Action::Idle, 0.3, "wait and observe"));
+
+        let mut forward = PendingActions::new();
+        forward.merge(a.clone());
+        forward.merge(b.clone());
+        let mut backward = PendingActions::new();
+        backward.merge(b);
+        backward.merge(a);
+
+        let reasons = |p: &PendingActions| -> Vec<String> {
+            p.get("mira").unwrap().iter().map(|wa| wa.reason.clone()).collect()
+        };
+        assert_eq!(reasons(&forward), vec!["rest", "wait and observe"]);
+        assert_eq!(reasons(&forward), reasons(&backward));
+        assert_eq!(forward.get("tomas").unwrap().len(), 1);
+    }
+
     #[test]
     fn test_weighted_action() {
         let action = WeightedAction::new(Action::Idle, 0.5, "resting");
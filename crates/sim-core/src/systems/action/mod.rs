@@ -13,15 +13,16 @@ pub mod execute;
 pub mod utility;
 
 pub use generate::{
-    Action, PendingActions, WeightedAction,
+    Action, ActionBuffer, ActionGeneration, PendingActions, WeightedAction,
+    action_generation_schedule, run_action_generation,
     generate_movement_actions, generate_desire_based_actions,
     generate_patrol_actions, generate_communication_actions,
     generate_archive_actions, generate_resource_actions, generate_social_actions,
     generate_faction_actions, generate_conflict_actions, generate_beer_actions,
 };
 pub use utility::{ActionUtility, calculate_distance_penalty, calculate_idle_weight, calculate_need_utility};
-pub use weight::apply_trait_weights;
-pub use select::{SelectedActions, select_actions, add_noise_to_weights};
+pub use weight::{apply_trait_weights, apply_intoxication_weights};
+pub use select::{NoiseConfig, SelectedActions, SelectionConfig, select_actions, add_noise_to_weights};
 pub use execute::{
     TickEvents, execute_movement_actions, execute_communication_actions,
     execute_archive_actions, execute_resource_actions, execute_social_actions,
@@ -4,8 +4,9 @@
 
 use bevy_ecs::prelude::*;
 use rand::Rng;
-use std::collections::HashMap;
+use std::collections::BTreeMap;
 
+use crate::components::agent::{AgentId, Alive, Intoxication};
 use crate::SimRng;
 
 use super::generate::{Action, PendingActions, WeightedAction};
@@ -14,7 +15,7 @@ use super::generate::{Action, PendingActions, WeightedAction};
 #[derive(Resource, Debug, Default)]
 pub struct SelectedActions {
     /// Maps agent_id -> selected action
-    pub actions: HashMap<String, Action>,
+    pub actions: BTreeMap<String, Action>,
 }
 
 impl SelectedActions {
@@ -39,20 +40,113 @@ impl SelectedActions {
     }
 }
 
+/// Resource: How much random noise is mixed into action weights
+///
+/// Noise is multiplicative: a scale of 0.2 moves each weight by up to +/- 20%.
+/// A scale of 0 leaves weights untouched, so selection is driven purely by
+/// the generated weights.
+#[derive(Resource, Debug, Clone)]
+pub struct NoiseConfig {
+    /// Noise scale for actions without a category override
+    pub scale: f32,
+    /// Extra scale added for a fully intoxicated agent
+    pub intoxicated_scale: f32,
+    /// Overrides of `scale` by action category (see [`Action::category`])
+    pub category_scales: BTreeMap<String, f32>,
+}
+
+impl Default for NoiseConfig {
+    fn default() -> Self {
+        Self {
+            scale: 0.2,
+            intoxicated_scale: 0.4,
+            category_scales: BTreeMap::new(),
+        }
+    }
+}
+
+impl NoiseConfig {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Config with the given base scale and no category overrides
+    pub fn with_scale(scale: f32) -> Self {
+        Self {
+            scale: scale.max(0.0),
+            ..Self::default()
+        }
+    }
+
+    /// Override the scale for one action category
+    pub fn with_category_scale(mut self, category: impl Into<String>, scale: f32) -> Self {
+        self.category_scales.insert(category.into(), scale.max(0.0));
+        self
+    }
+
+    /// Scale to apply to an action, before intoxication
+    pub fn scale_for(&self, action: &Action) -> f32 {
+        self.category_scales
+            .get(action.category())
+            .copied()
+            .unwrap_or(self.scale)
+    }
+}
+
+/// Resource: How an agent's choice is drawn from its weighted candidates
+///
+/// Without a temperature, each action is picked in proportion to its weight.
+/// With one, actions are picked by a softmax over weight / temperature: low
+/// temperatures approach always taking the heaviest action, high ones approach
+/// a uniform choice.
+#[derive(Resource, Debug, Clone, Default)]
+pub struct SelectionConfig {
+    /// Softmax temperature; `None` selects in proportion to weight
+    pub temperature: Option<f32>,
+}
+
+impl SelectionConfig {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Config selecting by softmax at the given temperature
+    pub fn with_temperature(temperature: f32) -> Self {
+        Self {
+            temperature: Some(temperature.max(0.0)),
+        }
+    }
+}
+
 /// System to select actions using weighted random choice
+///
+/// Dead agents never have an action selected, whatever was generated for them.
+/// Each selection draws once from the RNG, whatever the [`SelectionConfig`], so
+/// seeded runs stay reproducible.
 pub fn select_actions(
     mut rng: ResMut<SimRng>,
+    selection_config: Res<SelectionConfig>,
     mut pending_actions: ResMut<PendingActions>,
     mut selected_actions: ResMut<SelectedActions>,
+    agents: Query<(&AgentId, &Alive)>,
 ) {
     selected_actions.clear();
 
-    for (agent_id, candidates) in pending_actions.actions.drain() {
-        if candidates.is_empty() {
+    let dead: std::collections::HashSet<&str> = agents
+        .iter()
+        .filter(|(_, alive)| !alive.is_alive())
+        .map(|(id, _)| id.0.as_str())
+        .collect();
+
+    for (agent_id, candidates) in std::mem::take(&mut pending_actions.actions) {
+        if candidates.is_empty() || dead.contains(agent_id.as_str()) {
             continue;
         }
 
-        let selected = weighted_random_choice(&mut rng.0, &candidates);
+        let selected = match selection_config.temperature {
+            Some(temperature) => softmax_choice(&mut rng.0, &candidates, temperature),
+            None => weighted_random_choice(&mut rng.0, &candidates),
+        };
         selected_actions.set(agent_id, selected.action.clone());
     }
 }
@@ -82,17 +176,67 @@ fn weighted_random_choice<'a, R: Rng>(rng: &mut R, candidates: &'a [WeightedActi
     candidates.last().unwrap()
 }
 
+/// Perform softmax selection over weight / temperature
+///
+/// A temperature of zero always takes the heaviest action (the first, on ties).
+fn softmax_choice<'a, R: Rng>(rng: &mut R, candidates: &'a [WeightedAction], temperature: f32) -> &'a WeightedAction {
+    // Roll even when the result is fixed, so later draws don't shift
+    let roll: f32 = rng.gen::<f32>();
+
+    let max_weight = candidates.iter().map(|c| c.weight).fold(f32::NEG_INFINITY, f32::max);
+    let heaviest = candidates.iter().find(|c| c.weight == max_weight).unwrap_or(&candidates[0]);
+    if temperature <= f32::EPSILON {
+        return heaviest;
+    }
+
+    // Shift by the max weight so exp() can't overflow
+    let scores: Vec<f32> = candidates
+        .iter()
+        .map(|c| ((c.weight - max_weight) / temperature).exp())
+        .collect();
+    let total: f32 = scores.iter().sum();
+    if !total.is_finite() || total <= 0.0 {
+        return heaviest;
+    }
+
+    let mut remaining = roll * total;
+    for (candidate, score) in candidates.iter().zip(&scores) {
+        remaining -= score;
+        if remaining <= 0.0 {
+            return candidate;
+        }
+    }
+
+    candidates.last().unwrap()
+}
+
 /// Add noise to action weights for variety
+///
+/// The magnitude comes from [`NoiseConfig`]. Intoxicated agents get wider
+/// noise, so their choices are more erratic. Candidates whose noise works out
+/// to zero are left alone and draw nothing from the RNG.
 pub fn add_noise_to_weights(
     mut rng: ResMut<SimRng>,
+    noise_config: Res<NoiseConfig>,
     mut pending_actions: ResMut<PendingActions>,
+    intoxication: Query<(&AgentId, &Intoxication)>,
 ) {
-    const NOISE_FACTOR: f32 = 0.2; // +/- 20% noise
+    let levels: std::collections::HashMap<&str, f32> = intoxication
+        .iter()
+        .map(|(id, intox)| (id.0.as_str(), intox.level.clamp(0.0, 1.0)))
+        .collect();
+
+    for (agent_id, candidates) in pending_actions.actions.iter_mut() {
+        let level = levels.get(agent_id.as_str()).copied().unwrap_or(0.0);
 
-    for (_agent_id, candidates) in pending_actions.actions.iter_mut() {
         for candidate in candidates.iter_mut() {
+            let noise_factor = noise_config.scale_for(&candidate.action) + level * noise_config.intoxicated_scale;
+            if noise_factor <= 0.0 {
+                continue;
+            }
+
             // Add multiplicative noise
-            let noise: f32 = 1.0 + (rng.0.gen::<f32>() - 0.5) * 2.0 * NOISE_FACTOR;
+            let noise: f32 = 1.0 + (rng.0.gen::<f32>() - 0.5) * 2.0 * noise_factor;
             candidate.weight *= noise;
             candidate.weight = candidate.weight.max(0.01); // Ensure positive weight
         }
@@ -158,6 +302,7 @@ mod tests {
     fn test_noise_keeps_positive_weights() {
         let mut world = World::new();
         world.insert_resource(SimRng(SmallRng::seed_from_u64(42)));
+        world.insert_resource(NoiseConfig::default());
 
         let mut pending = PendingActions::new();
         pending.add(
@@ -176,4 +321,122 @@ mod tests {
         let actions = pending.get("agent").unwrap();
         assert!(actions[0].weight > 0.0);
     }
+
+    /// Run the noise system once over a two-way choice and return the weights
+    fn noisy_weights(config: NoiseConfig, seed: u64) -> Vec<f32> {
+        let mut world = World::new();
+        world.insert_resource(SimRng(SmallRng::seed_from_u64(seed)));
+        world.insert_resource(config);
+
+        let mut pending = PendingActions::new();
+        pending.add("agent", WeightedAction::new(Action::Idle, 0.6, "rest"));
+        pending.add(
+            "agent",
+            WeightedAction::new(Action::Move(MoveAction::travel("agent", "village")), 0.4, "travel"),
+        );
+        world.insert_resource(pending);
+
+        let mut schedule = Schedule::default();
+        schedule.add_systems(add_noise_to_weights);
+        schedule.run(&mut world);
+
+        world.resource::<PendingActions>().get("agent").unwrap().iter().map(|c| c.weight).collect()
+    }
+
+    #[test]
+    fn test_zero_noise_scale_is_deterministic() {
+        for seed in 0..20 {
+            assert_eq!(noisy_weights(NoiseConfig::with_scale(0.0), seed), vec![0.6, 0.4]);
+        }
+
+        // Only the overridden category is held still
+        let config = NoiseConfig::with_scale(0.5).with_category_scale("idle", 0.0);
+        let varied: Vec<Vec<f32>> = (0..20).map(|seed| noisy_weights(config.clone(), seed)).collect();
+        assert!(varied.iter().all(|w| w[0] == 0.6));
+        assert!(varied.iter().any(|w| w[1] != 0.4));
+    }
+
+    #[test]
+    fn test_larger_noise_scale_widens_outcomes() {
+        // How often the less likely action ends up with the larger weight
+        let upsets = |scale: f32| {
+            (0..500)
+                .filter(|&seed| {
+                    let weights = noisy_weights(NoiseConfig::with_scale(scale), seed);
+                    weights[1] > weights[0]
+                })
+                .count()
+        };
+
+        assert_eq!(upsets(0.0), 0);
+        assert!(upsets(0.8) > upsets(0.3) + 25);
+    }
+
+    fn three_choices() -> Vec<WeightedAction> {
+        vec![
+            WeightedAction::new(Action::Idle, 0.2, "rest"),
+            WeightedAction::new(Action::Move(MoveAction::travel("agent", "village")), 0.7, "travel"),
+            WeightedAction::new(Action::Move(MoveAction::travel("agent", "fields")), 0.1, "wander"),
+        ]
+    }
+
+    #[test]
+    fn test_zero_temperature_selects_max_weight() {
+        let mut rng = SmallRng::seed_from_u64(7);
+        let candidates = three_choices();
+
+        for temperature in [0.0, 0.001] {
+            for _ in 0..200 {
+                let selected = softmax_choice(&mut rng, &candidates, temperature);
+                assert_eq!(selected.reason, "travel");
+            }
+        }
+    }
+
+    #[test]
+    fn test_high_temperature_approaches_uniform() {
+        let mut rng = SmallRng::seed_from_u64(7);
+        let candidates = three_choices();
+
+        let mut counts: BTreeMap<String, usize> = BTreeMap::new();
+        for _ in 0..3000 {
+            let selected = softmax_choice(&mut rng, &candidates, 1000.0);
+            *counts.entry(selected.reason.clone()).or_default() += 1;
+        }
+
+        assert_eq!(counts.len(), 3);
+        for count in counts.values() {
+            assert!((900..=1100).contains(count), "uneven counts: {:?}", counts);
+        }
+    }
+
+    #[test]
+    fn test_temperature_selection_is_seeded() {
+        let run = |seed: u64| {
+            let mut world = World::new();
+            world.insert_resource(SimRng(SmallRng::seed_from_u64(seed)));
+            world.insert_resource(SelectionConfig::with_temperature(0.3));
+            world.insert_resource(SelectedActions::new());
+            let mut pending = PendingActions::new();
+            for agent in ["a", "b", "c", "d", "e", "f"] {
+                for candidate in three_choices() {
+                    pending.add(agent, candidate);
+                }
+            }
+            world.insert_resource(pending);
+
+            let mut schedule = Schedule::default();
+            schedule.add_systems(select_actions);
+            schedule.run(&mut world);
+
+            let selected = world.resource::<SelectedActions>();
+            selected
+                .actions
+                .iter()
+                .map(|(agent, action)| format!("{}:{:?}", agent, action))
+                .collect::<Vec<_>>()
+        };
+
+        assert_eq!(run(11), run(11));
+    }
 }
@@ -10,9 +10,9 @@
 
 use std::collections::{HashMap, HashSet, VecDeque};
 
-use crate::components::agent::Traits;
+use crate::components::agent::{FoodSecurity, Needs, SocialBelonging, Traits};
 use crate::components::needs::{NeedStatus, PhysicalNeeds};
-use crate::components::world::LocationRegistry;
+use crate::components::world::{LocationRegistry, Season};
 
 /// Utility weight constants for multi-factor calculation
 pub mod weights {
@@ -28,6 +28,20 @@ pub mod weights {
     pub const DISTANCE_PENALTY_BASE: f32 = 0.7;
     /// How much boldness mitigates distance penalty (bold agents travel more)
     pub const BOLDNESS_DISTANCE_MITIGATION: f32 = 0.15;
+    /// Trips of at least this many steps count as long travel
+    pub const LONG_TRAVEL_STEPS: u32 = 2;
+    /// Share of goal utility applied to a step toward a goal target's location
+    pub const GOAL_PURSUIT_SCALE: f32 = 0.25;
+    /// Base weight for wandering to an adjacent location
+    pub const EXPLORE_BASE: f32 = 0.05;
+    /// Idle weight multiplier when food security is stressed
+    pub const IDLE_STRESSED_MULT: f32 = 0.6;
+    /// Idle weight multiplier when food security is desperate
+    pub const IDLE_DESPERATE_MULT: f32 = 0.2;
+    /// Idle weight multiplier for agents on the edge of their faction
+    pub const IDLE_PERIPHERAL_MULT: f32 = 0.8;
+    /// Idle weight multiplier for isolated agents
+    pub const IDLE_ISOLATED_MULT: f32 = 0.5;
 }
 
 /// Multi-factor utility breakdown for an action
@@ -142,6 +156,54 @@ pub fn calculate_distance_penalty(
     adjusted_penalty.powf(distance as f32)
 }
 
+/// Calculate the seasonal penalty for travelling to a location
+///
+/// Returns 1.0 for short trips or in mild seasons; long trips in a harsh
+/// season are scaled down by the season's travel modifier.
+pub fn calculate_seasonal_travel_penalty(
+    from_location: &str,
+    to_location: &str,
+    location_registry: &LocationRegistry,
+    season: Season,
+) -> f32 {
+    if !season.is_harsh() {
+        return 1.0;
+    }
+
+    let distance = path_distance(from_location, to_location, location_registry);
+    if distance >= weights::LONG_TRAVEL_STEPS {
+        season.travel_modifier()
+    } else {
+        1.0
+    }
+}
+
+/// Calculate the penalty for an aimless move given where an agent's goals lie
+///
+/// Returns 1.0 when the agent has no located goals or the move doesn't lead
+/// away from the best-placed one; otherwise the extra distance penalty the
+/// move incurs toward that goal.
+pub fn calculate_wander_penalty(
+    from_location: &str,
+    to_location: &str,
+    goal_locations: &[String],
+    location_registry: &LocationRegistry,
+    boldness: f32,
+) -> f32 {
+    if goal_locations.is_empty() {
+        return 1.0;
+    }
+
+    goal_locations
+        .iter()
+        .map(|goal| {
+            let before = calculate_distance_penalty(from_location, goal, location_registry, boldness);
+            let after = calculate_distance_penalty(to_location, goal, location_registry, boldness);
+            (after / before).min(1.0)
+        })
+        .fold(0.0f32, f32::max)
+}
+
 /// Calculate the path distance between two locations using BFS
 ///
 /// Returns u32::MAX if unreachable
@@ -174,8 +236,10 @@ pub fn path_distance(from: &str, to: &str, registry: &LocationRegistry) -> u32 {
 
 /// Calculate idle action weight based on current needs
 ///
-/// Agents with pressing needs should be less likely to idle
-pub fn calculate_idle_weight(physical_needs: &PhysicalNeeds) -> f32 {
+/// Agents with pressing needs should be less likely to idle. Worsening food
+/// security or belonging cuts idling further so needy agents go and do
+/// something about it.
+pub fn calculate_idle_weight(physical_needs: &PhysicalNeeds, needs: &Needs) -> f32 {
     let base_idle = 0.2;
 
     // Reduce idle weight based on most urgent need
@@ -190,8 +254,19 @@ pub fn calculate_idle_weight(physical_needs: &PhysicalNeeds) -> f32 {
     .into_iter()
     .fold(0.0f32, f32::max);
 
+    let food_mult = match needs.food_security {
+        FoodSecurity::Secure => 1.0,
+        FoodSecurity::Stressed => weights::IDLE_STRESSED_MULT,
+        FoodSecurity::Desperate => weights::IDLE_DESPERATE_MULT,
+    };
+    let belonging_mult = match needs.social_belonging {
+        SocialBelonging::Integrated => 1.0,
+        SocialBelonging::Peripheral => weights::IDLE_PERIPHERAL_MULT,
+        SocialBelonging::Isolated => weights::IDLE_ISOLATED_MULT,
+    };
+
     // Higher urgency = lower idle weight
-    (base_idle * (1.0 - max_urgency * 0.8)).max(0.02)
+    (base_idle * (1.0 - max_urgency * 0.8) * food_mult * belonging_mult).max(0.02)
 }
 
 #[cfg(test)]
@@ -218,20 +293,57 @@ mod tests {
         assert_eq!(penalty, 1.0);
     }
 
+    #[test]
+    fn test_winter_discourages_only_long_travel() {
+        use crate::components::world::{Location, LocationType};
+
+        // hall <-> road <-> keep
+        let mut registry = LocationRegistry::new();
+        for (id, adjacent) in [("hall", vec!["road"]), ("road", vec!["hall", "keep"]), ("keep", vec!["road"])] {
+            registry.register(
+                Location::new(id, id, LocationType::Crossroads)
+                    .with_adjacent(adjacent.into_iter().map(String::from).collect()),
+            );
+        }
+
+        assert_eq!(calculate_seasonal_travel_penalty("hall", "keep", &registry, Season::Summer), 1.0);
+        assert_eq!(calculate_seasonal_travel_penalty("hall", "road", &registry, Season::Winter), 1.0);
+        assert_eq!(
+            calculate_seasonal_travel_penalty("hall", "keep", &registry, Season::Winter),
+            Season::Winter.travel_modifier()
+        );
+    }
+
     #[test]
     fn test_idle_weight_with_urgent_needs() {
         let mut needs = PhysicalNeeds::new();
 
         // All satisfied - high idle weight
-        let idle1 = calculate_idle_weight(&needs);
+        let idle1 = calculate_idle_weight(&needs, &Needs::default());
         assert!(idle1 > 0.15);
 
         // Urgent hunger - low idle weight
         needs.hunger.set_level(0.7);
-        let idle2 = calculate_idle_weight(&needs);
+        let idle2 = calculate_idle_weight(&needs, &Needs::default());
         assert!(idle2 < idle1);
     }
 
+    #[test]
+    fn test_desperate_agent_idles_less_than_secure() {
+        let physical = PhysicalNeeds::new();
+        let secure = calculate_idle_weight(&physical, &Needs::default());
+        let desperate = calculate_idle_weight(
+            &physical,
+            &Needs {
+                food_security: FoodSecurity::Desperate,
+                social_belonging: SocialBelonging::Isolated,
+            },
+        );
+
+        assert!(secure > 0.15);
+        assert!(desperate < secure * 0.25);
+    }
+
     #[test]
     fn test_need_utility_scales_with_urgency() {
         let mut needs = PhysicalNeeds::new();
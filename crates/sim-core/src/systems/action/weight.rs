@@ -12,13 +12,65 @@ use crate::actions::social::{SocialAction, SocialActionType};
 use crate::actions::faction::{FactionAction, FactionActionType};
 use crate::actions::conflict::{ConflictAction, ConflictActionType};
 use crate::actions::beer::{BeerAction, BeerActionType};
-use crate::components::agent::{AgentId, FoodSecurity, Needs, Role, SocialBelonging, Traits};
+use crate::components::agent::{
+    AgentId, FoodSecurity, Intoxication, Needs, Role, SocialBelonging, Traits,
+};
 use crate::components::faction::{FactionMembership, FactionRegistry};
 use crate::components::social::{MemoryValence, RelationshipGraph};
 use crate::components::world::Position;
 
 use super::generate::{Action, PendingActions, WeightedAction};
 
+/// Multiplier bonus to conflict and leadership-challenge weights at full intoxication
+pub const INTOXICATION_BOLDNESS_BONUS: f32 = 1.0;
+
+/// Fraction of archive and work weight lost at full intoxication
+pub const INTOXICATION_FOCUS_PENALTY: f32 = 0.6;
+
+/// Apply intoxication modifiers to pending actions
+///
+/// Drunk agents pick fights and challenge leaders more readily and neglect
+/// careful work like labor and the archive. Scales with `Intoxication::level`.
+pub fn apply_intoxication_weights(
+    mut pending_actions: ResMut<PendingActions>,
+    query: Query<(&AgentId, &Intoxication)>,
+) {
+    for (agent_id, intoxication) in query.iter() {
+        if !intoxication.is_intoxicated() {
+            continue;
+        }
+        let Some(actions) = pending_actions.actions.get_mut(&agent_id.0) else {
+            continue;
+        };
+
+        for weighted_action in actions.iter_mut() {
+            weighted_action.weight *=
+                calculate_intoxication_modifier(&weighted_action.action, intoxication.level);
+            weighted_action.weight = weighted_action.weight.clamp(0.01, 10.0);
+        }
+    }
+}
+
+/// Calculate weight modifier for an action at the given intoxication level
+fn calculate_intoxication_modifier(action: &Action, level: f32) -> f32 {
+    let level = level.clamp(0.0, 1.0);
+    match action {
+        Action::Conflict(_) => 1.0 + level * INTOXICATION_BOLDNESS_BONUS,
+        Action::Faction(faction_action)
+            if faction_action.action_type == FactionActionType::ChallengeLeader =>
+        {
+            1.0 + level * INTOXICATION_BOLDNESS_BONUS
+        }
+        Action::Archive(_) => 1.0 - level * INTOXICATION_FOCUS_PENALTY,
+        Action::Resource(resource_action)
+            if resource_action.action_type == ResourceActionType::Work =>
+        {
+            1.0 - level * INTOXICATION_FOCUS_PENALTY
+        }
+        _ => 1.0,
+    }
+}
+
 /// Apply trait-based weight modifiers to pending actions
 pub fn apply_trait_weights(
     mut pending_actions: ResMut<PendingActions>,
@@ -157,6 +209,10 @@ fn calculate_communication_modifier(
             // Bold agents lie more
             modifier *= 0.7 + traits.boldness * 0.6;
         }
+        CommunicationType::Recruit => {
+            // Charm does the recruiting
+            modifier *= 0.6 + traits.sociability * communication_weights::SOCIABILITY_BONUS;
+        }
         CommunicationType::Confess => {
             // Confessions are driven by high honesty
             modifier *= 0.5 + traits.honesty;
@@ -409,6 +465,12 @@ fn calculate_conflict_modifier(
                 modifier *= 1.5;
             }
         }
+        ConflictActionType::Duel => {
+            // Only the bold accept a duel
+            modifier *= 0.2 + traits.boldness * 1.2;
+            // Long-held grudges drive formal challenges
+            modifier *= 0.7 + traits.grudge_persistence * 0.6;
+        }
         ConflictActionType::Sabotage => {
             // Low honesty enables sabotage
             modifier *= 1.5 - traits.honesty;
@@ -535,6 +597,56 @@ mod tests {
         assert!(modifier > 1.0);
     }
 
+    #[test]
+    fn test_intoxication_raises_conflict_weights() {
+        use crate::actions::conflict::ConflictAction;
+
+        let mut world = World::new();
+        world.spawn((AgentId("sober".to_string()), Intoxication::new()));
+        let mut drunk = Intoxication::new();
+        drunk.apply_drink(0);
+        drunk.apply_drink(0);
+        drunk.apply_drink(0);
+        world.spawn((AgentId("drunk".to_string()), drunk));
+
+        let mut pending = PendingActions::new();
+        for agent in ["sober", "drunk"] {
+            pending.add(
+                agent,
+                WeightedAction::new(
+                    Action::Conflict(ConflictAction::fight(agent, "rival", None)),
+                    0.5,
+                    "fight",
+                ),
+            );
+            pending.add(
+                agent,
+                WeightedAction::new(
+                    Action::Resource(ResourceAction::work(agent)),
+                    0.5,
+                    "work",
+                ),
+            );
+        }
+        world.insert_resource(pending);
+
+        let mut schedule = Schedule::default();
+        schedule.add_systems(apply_intoxication_weights);
+        schedule.run(&mut world);
+
+        let pending = world.resource::<PendingActions>();
+        let sober = pending.get("sober").unwrap();
+        let drunk = pending.get("drunk").unwrap();
+
+        // Sober agent is untouched
+        assert_eq!(sober[0].weight, 0.5);
+        assert_eq!(sober[1].weight, 0.5);
+
+        // Level 0.9: fights weighted 1.9x, work 0.46x
+        assert!(drunk[0].weight > sober[0].weight * 1.8);
+        assert!(drunk[1].weight < sober[1].weight * 0.5);
+    }
+
     #[test]
     fn test_bold_wander_more() {
         let mut bold_traits = Traits::default();
@@ -0,0 +1,190 @@
+//! Alliance System
+//!
+//! Forms alliances between factions whose leaders have come to trust each other.
+
+use bevy_ecs::prelude::*;
+use std::collections::BTreeMap;
+
+use crate::components::agent::{AgentId, AgentName, Alive};
+use crate::components::faction::{FactionMembership, FactionRegistry};
+use crate::components::social::RelationshipGraph;
+use crate::components::world::{Position, WorldState};
+use crate::events::types::{
+    ActorSnapshot, CooperationSubtype, Event, EventActors, EventContext, EventOutcome,
+    EventSubtype, EventTimestamp, EventType, GeneralOutcome,
+};
+use crate::systems::action::TickEvents;
+
+/// Mutual leader trust above which two factions form an alliance
+const ALLIANCE_LEADER_TRUST_THRESHOLD: f32 = 0.5;
+
+/// System to form alliances between factions whose leaders trust each other
+pub fn form_alliances(
+    world_state: Res<WorldState>,
+    mut faction_registry: ResMut<FactionRegistry>,
+    relationship_graph: Res<RelationshipGraph>,
+    mut tick_events: ResMut<TickEvents>,
+    query: Query<(&AgentId, &AgentName, &Position, &Alive, &FactionMembership)>,
+) {
+    // Living leaders keyed by faction
+    let leaders: BTreeMap<String, ActorSnapshot> = faction_registry
+        .all_factions()
+        .filter_map(|faction| {
+            let leader_id = faction.leader.as_ref()?;
+            query
+                .iter()
+                .find(|(id, _, _, alive, membership)| {
+                    &id.0 == leader_id && alive.is_alive() && membership.faction_id == faction.id.0
+                })
+                .map(|(id, name, pos, _, _)| {
+                    (faction.id.0.clone(), ActorSnapshot {
+                        agent_id: id.0.clone(),
+                        name: name.0.clone(),
+                        faction: faction.id.0.clone(),
+                        role: "leader".to_string(),
+                        location: pos.location_id.clone(),
+                    })
+                })
+        })
+        .collect();
+
+    let factions: Vec<&String> = leaders.keys().collect();
+    let mut new_alliances = Vec::new();
+    for (i, faction_a) in factions.iter().enumerate() {
+        for faction_b in &factions[i + 1..] {
+            if faction_registry.are_allied(faction_a, faction_b) {
+                continue;
+            }
+            let leader_a = &leaders[*faction_a];
+            let leader_b = &leaders[*faction_b];
+            let trust_ab = leader_trust(&relationship_graph, &leader_a.agent_id, &leader_b.agent_id);
+            let trust_ba = leader_trust(&relationship_graph, &leader_b.agent_id, &leader_a.agent_id);
+
+            // Both sides must be on board, not just one
+            if trust_ab.min(trust_ba) > ALLIANCE_LEADER_TRUST_THRESHOLD {
+                new_alliances.push((leader_a.clone(), leader_b.clone()));
+            }
+        }
+    }
+
+    for (leader_a, leader_b) in new_alliances {
+        if !faction_registry.form_alliance(&leader_a.faction, &leader_b.faction) {
+            continue;
+        }
+        let event = create_alliance_event(&mut tick_events, &world_state, leader_a, leader_b);
+        tick_events.push(event);
+    }
+}
+
+fn leader_trust(graph: &RelationshipGraph, from: &str, to: &str) -> f32 {
+    graph.get(from, to).map(|rel| rel.trust.overall()).unwrap_or(0.0)
+}
+
+fn create_alliance_event(
+    tick_events: &mut TickEvents,
+    world_state: &WorldState,
+    leader_a: ActorSnapshot,
+    leader_b: ActorSnapshot,
+) -> Event {
+    let description = format!(
+        "{} and {} pledge their factions to each other",
+        leader_a.name, leader_b.name
+    );
+    let location = leader_a.location.clone();
+
+    Event {
+        event_id: tick_events.generate_id(),
+        timestamp: EventTimestamp {
+            tick: world_state.current_tick,
+            date: world_state.formatted_date(),
+        },
+        event_type: EventType::Cooperation,
+        subtype: EventSubtype::Cooperation(CooperationSubtype::AllianceFormed),
+        actors: EventActors {
+            primary: leader_a,
+            secondary: Some(leader_b),
+            affected: None,
+        },
+        context: EventContext {
+            trigger: "leader_trust".to_string(),
+            preconditions: vec!["mutual_leader_trust".to_string()],
+            location_description: Some(format!("at {}", location)),
+        },
+        outcome: EventOutcome::General(GeneralOutcome {
+            description: Some(description),
+            state_changes: Vec::new(),
+        }),
+        drama_tags: vec!["alliance".to_string(), "cross_faction".to_string()],
+        drama_score: 0.5,
+        connected_events: Vec::new(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::components::agent::{Agent, Role};
+    use crate::components::faction::Faction;
+    use crate::components::social::{Relationship, Trust};
+
+    fn setup_world() -> World {
+        let mut world = World::new();
+        world.insert_resource(WorldState::new());
+        world.insert_resource(RelationshipGraph::new());
+        world.insert_resource(TickEvents::new());
+
+        let mut registry = FactionRegistry::new();
+        for (id, leader) in [("thornwood", "thorn_leader"), ("ironmere", "iron_leader")] {
+            let mut faction = Faction::new(id, id, "hall");
+            faction.leader = Some(leader.to_string());
+            registry.register(faction);
+            world.spawn((
+                Agent,
+                AgentId(leader.to_string()),
+                AgentName(leader.to_string()),
+                Position::new("hall"),
+                Alive::new(),
+                FactionMembership::new(id, Role::Leader),
+            ));
+        }
+        world.insert_resource(registry);
+        world
+    }
+
+    fn run(world: &mut World) {
+        let mut schedule = Schedule::default();
+        schedule.add_systems(form_alliances);
+        schedule.run(world);
+    }
+
+    fn set_trust(world: &mut World, from: &str, to: &str, value: f32) {
+        world
+            .resource_mut::<RelationshipGraph>()
+            .set(from, Relationship::new(to).with_trust(Trust::new(value, value, value)));
+    }
+
+    #[test]
+    fn test_mutual_trust_forms_alliance() {
+        let mut world = setup_world();
+
+        // One-sided trust is not enough
+        set_trust(&mut world, "thorn_leader", "iron_leader", 0.8);
+        run(&mut world);
+        assert!(!world.resource::<FactionRegistry>().are_allied("thornwood", "ironmere"));
+
+        set_trust(&mut world, "iron_leader", "thorn_leader", 0.7);
+        run(&mut world);
+
+        let registry = world.resource::<FactionRegistry>();
+        assert!(registry.are_allied("thornwood", "ironmere"));
+        assert!(registry.are_allied("ironmere", "thornwood"));
+
+        let events = &world.resource::<TickEvents>().events;
+        assert_eq!(events.len(), 1);
+        assert_eq!(events[0].subtype, EventSubtype::Cooperation(CooperationSubtype::AllianceFormed));
+
+        // Already allied: no duplicate event
+        run(&mut world);
+        assert_eq!(world.resource::<TickEvents>().len(), 1);
+    }
+}
@@ -1,12 +1,20 @@
 //! Consumption System
 //!
-//! Handles automatic grain consumption, storage caps, spoilage, and intoxication decay.
+//! Handles automatic grain consumption, storage caps, spoilage, intoxication decay,
+//! and starvation.
 
 use bevy_ecs::prelude::*;
 
-use crate::components::agent::{AgentId, Intoxication};
-use crate::components::faction::FactionRegistry;
-use crate::components::world::WorldState;
+use crate::components::agent::{
+    AgentId, AgentName, Alive, FoodSecurity, Intoxication, Needs, StarvationClock,
+};
+use crate::components::faction::{FactionMembership, FactionRegistry};
+use crate::components::world::{Position, WorldState};
+use crate::events::types::{
+    ActorSnapshot, DeathSubtype, Event, EventActors, EventContext, EventOutcome, EventSubtype,
+    EventTimestamp, EventType, GeneralOutcome,
+};
+use crate::systems::action::TickEvents;
 
 /// Constants for consumption (can be overridden by config in future)
 pub mod consumption_constants {
@@ -24,6 +32,14 @@ pub mod consumption_constants {
     pub const TICKS_PER_DAY: u64 = 10;
     /// Ticks per season
     pub const TICKS_PER_SEASON: u64 = 300;
+    /// Consecutive Desperate ticks before an agent starves to death
+    pub const STARVATION_THRESHOLD_TICKS: u64 = 150;
+}
+
+/// Tuning weights for how resources interact during consumption
+pub mod weights {
+    /// Grain units protected from seasonal spoilage by each unit of salt
+    pub const GRAIN_PRESERVED_PER_SALT: u32 = 5;
 }
 
 use consumption_constants::*;
@@ -39,6 +55,8 @@ pub struct ConsumptionTracker {
     pub consumption_interval: u64,
     /// Ticks between spoilage applications
     pub spoilage_interval: u64,
+    /// Consecutive Desperate ticks before an agent starves
+    pub starvation_threshold: u64,
 }
 
 impl Default for ConsumptionTracker {
@@ -48,6 +66,7 @@ impl Default for ConsumptionTracker {
             last_spoilage_tick: 0,
             consumption_interval: TICKS_PER_DAY,
             spoilage_interval: TICKS_PER_SEASON,
+            starvation_threshold: STARVATION_THRESHOLD_TICKS,
         }
     }
 }
@@ -136,10 +155,22 @@ pub fn enforce_storage_caps(
     }
 }
 
+/// Preserve grain from spoilage using salt stocks
+///
+/// Returns (grain actually spoiled, salt consumed). Each unit of salt consumed
+/// saves up to `GRAIN_PRESERVED_PER_SALT` grain from the spoilage roll.
+pub fn preserve_grain_with_salt(grain_spoiled: u32, salt: u32) -> (u32, u32) {
+    let salt_needed = grain_spoiled.div_ceil(weights::GRAIN_PRESERVED_PER_SALT);
+    let salt_used = salt_needed.min(salt);
+    let grain_preserved = (salt_used * weights::GRAIN_PRESERVED_PER_SALT).min(grain_spoiled);
+    (grain_spoiled - grain_preserved, salt_used)
+}
+
 /// System: Apply seasonal spoilage
 ///
 /// Each season, a percentage of stored resources spoils.
 /// Beer spoils at half the rate of grain (better preserved).
+/// Salt is consumed to preserve grain that would otherwise spoil.
 pub fn apply_seasonal_spoilage(
     world_state: Res<WorldState>,
     mut consumption_tracker: ResMut<ConsumptionTracker>,
@@ -151,9 +182,12 @@ pub fn apply_seasonal_spoilage(
 
     for faction in faction_registry.all_factions_mut() {
         let grain_spoiled = (faction.resources.grain as f32 * SPOILAGE_RATE).floor() as u32;
+        // Salted grain keeps through the season
+        let (grain_spoiled, salt_used) = preserve_grain_with_salt(grain_spoiled, faction.resources.salt);
         // Beer spoils at half the rate (fermented = better preserved)
         let beer_spoiled = (faction.resources.beer as f32 * SPOILAGE_RATE * 0.5).floor() as u32;
 
+        faction.resources.salt -= salt_used;
         faction.resources.grain = faction.resources.grain.saturating_sub(grain_spoiled);
         faction.resources.beer = faction.resources.beer.saturating_sub(beer_spoiled);
     }
@@ -173,9 +207,108 @@ pub fn decay_intoxication(mut query: Query<(&AgentId, &mut Intoxication)>) {
     }
 }
 
+/// System: Starve agents who remain Desperate for too long
+///
+/// Each tick spent Desperate advances the agent's starvation clock; recovering
+/// to Stressed or Secure resets it. Past the threshold the agent dies.
+pub fn apply_starvation(
+    world_state: Res<WorldState>,
+    consumption_tracker: Res<ConsumptionTracker>,
+    mut tick_events: ResMut<TickEvents>,
+    mut query: Query<(
+        &AgentId,
+        &AgentName,
+        &FactionMembership,
+        &Position,
+        &Needs,
+        &mut Alive,
+        &mut StarvationClock,
+    )>,
+) {
+    for (agent_id, name, membership, position, needs, mut alive, mut clock) in query.iter_mut() {
+        if !alive.is_alive() {
+            continue;
+        }
+
+        if needs.food_security != FoodSecurity::Desperate {
+            clock.reset();
+            continue;
+        }
+
+        clock.tick();
+        if !clock.has_starved(consumption_tracker.starvation_threshold) {
+            continue;
+        }
+
+        alive.0 = false;
+
+        let event = create_starvation_event(
+            &mut tick_events,
+            &world_state,
+            agent_id,
+            name,
+            membership,
+            &position.location_id,
+            clock.desperate_ticks,
+        );
+        tick_events.push(event);
+    }
+}
+
+/// Create a death event for an agent who starved
+fn create_starvation_event(
+    tick_events: &mut TickEvents,
+    world_state: &WorldState,
+    agent_id: &AgentId,
+    name: &AgentName,
+    membership: &FactionMembership,
+    location: &str,
+    desperate_ticks: u64,
+) -> Event {
+    let event_id = tick_events.generate_id();
+    let timestamp = EventTimestamp {
+        tick: world_state.current_tick,
+        date: world_state.formatted_date(),
+    };
+
+    let actor = ActorSnapshot {
+        agent_id: agent_id.0.clone(),
+        name: name.0.clone(),
+        faction: membership.faction_id.clone(),
+        role: format!("{:?}", membership.role).to_lowercase(),
+        location: location.to_string(),
+    };
+
+    Event {
+        event_id,
+        timestamp,
+        event_type: EventType::Death,
+        subtype: EventSubtype::Death(DeathSubtype::Natural),
+        actors: EventActors {
+            primary: actor,
+            secondary: None,
+            affected: None,
+        },
+        context: EventContext {
+            trigger: "starvation".to_string(),
+            preconditions: vec![format!("desperate_for_{}_ticks", desperate_ticks)],
+            location_description: None,
+        },
+        outcome: EventOutcome::General(GeneralOutcome {
+            description: Some(format!("{} starved to death", name.0)),
+            state_changes: vec!["alive: false".to_string()],
+        }),
+        drama_tags: vec!["winter_crisis".to_string(), "starvation".to_string()],
+        drama_score: 0.6,
+        connected_events: Vec::new(),
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::components::agent::Role;
+    use crate::components::faction::Faction;
 
     #[test]
     fn test_consumption_tracker_timing() {
@@ -207,4 +340,127 @@ mod tests {
         assert!(!tracker.should_consume(15));
         assert!(tracker.should_consume(20));
     }
+
+    fn spawn_starving_agent(world: &mut World) -> Entity {
+        world
+            .spawn((
+                AgentId("agent_001".to_string()),
+                AgentName("Hungry".to_string()),
+                FactionMembership::new("test_faction", Role::Laborer),
+                Position::new("hq"),
+                Needs {
+                    food_security: FoodSecurity::Desperate,
+                    ..Needs::default()
+                },
+                Alive::new(),
+                StarvationClock::new(),
+            ))
+            .id()
+    }
+
+    #[test]
+    fn test_starving_agent_dies_at_threshold() {
+        let mut world = World::new();
+        world.insert_resource(WorldState::new());
+        world.insert_resource(TickEvents::new());
+        let mut tracker = ConsumptionTracker::new();
+        tracker.starvation_threshold = 5;
+        world.insert_resource(tracker);
+
+        let entity = spawn_starving_agent(&mut world);
+
+        let mut schedule = Schedule::default();
+        schedule.add_systems(apply_starvation);
+
+        // Survives up to one tick before the threshold
+        for _ in 0..4 {
+            schedule.run(&mut world);
+        }
+        assert!(world.get::<Alive>(entity).unwrap().is_alive());
+        assert!(world.resource::<TickEvents>().is_empty());
+
+        // Dies exactly at the threshold
+        schedule.run(&mut world);
+        assert!(!world.get::<Alive>(entity).unwrap().is_alive());
+
+        let events = &world.resource::<TickEvents>().events;
+        assert_eq!(events.len(), 1);
+        assert_eq!(events[0].event_type, EventType::Death);
+        assert_eq!(events[0].subtype, EventSubtype::Death(DeathSubtype::Natural));
+        assert!(events[0].drama_tags.contains(&"winter_crisis".to_string()));
+
+        // Dead agents do not die again
+        schedule.run(&mut world);
+        assert_eq!(world.resource::<TickEvents>().len(), 1);
+    }
+
+    #[test]
+    fn test_starvation_clock_resets_after_eating() {
+        let mut world = World::new();
+        world.insert_resource(WorldState::new());
+        world.insert_resource(TickEvents::new());
+        let mut tracker = ConsumptionTracker::new();
+        tracker.starvation_threshold = 5;
+        world.insert_resource(tracker);
+
+        let entity = spawn_starving_agent(&mut world);
+
+        let mut schedule = Schedule::default();
+        schedule.add_systems(apply_starvation);
+
+        for _ in 0..4 {
+            schedule.run(&mut world);
+        }
+        assert_eq!(world.get::<StarvationClock>(entity).unwrap().desperate_ticks, 4);
+
+        // Eating back to Stressed resets the clock
+        world.get_mut::<Needs>(entity).unwrap().food_security = FoodSecurity::Stressed;
+        schedule.run(&mut world);
+        assert_eq!(world.get::<StarvationClock>(entity).unwrap().desperate_ticks, 0);
+        assert!(world.get::<Alive>(entity).unwrap().is_alive());
+    }
+
+    #[test]
+    fn test_salt_reduces_grain_spoilage() {
+        let mut world = World::new();
+        let mut world_state = WorldState::new();
+        world_state.set_tick(TICKS_PER_SEASON);
+        world.insert_resource(world_state);
+        world.insert_resource(ConsumptionTracker::new());
+
+        let mut registry = FactionRegistry::new();
+        let mut unsalted = Faction::new("unsalted", "Unsalted", "hq_a");
+        unsalted.resources.grain = 400;
+        unsalted.resources.salt = 0;
+        registry.register(unsalted);
+        let mut salted = Faction::new("salted", "Salted", "hq_b");
+        salted.resources.grain = 400;
+        salted.resources.salt = 4;
+        registry.register(salted);
+        world.insert_resource(registry);
+
+        let mut schedule = Schedule::default();
+        schedule.add_systems(apply_seasonal_spoilage);
+        schedule.run(&mut world);
+
+        let registry = world.resource::<FactionRegistry>();
+        let unsalted = registry.get("unsalted").unwrap();
+        let salted = registry.get("salted").unwrap();
+
+        // Without salt, the full 10% spoils
+        assert_eq!(unsalted.resources.grain, 360);
+        // 4 salt preserves 20 of the 40 grain that would have spoiled
+        assert_eq!(salted.resources.grain, 380);
+        assert_eq!(salted.resources.salt, 0);
+    }
+
+    #[test]
+    fn test_preserve_grain_with_salt() {
+        // No salt preserves nothing
+        assert_eq!(preserve_grain_with_salt(40, 0), (40, 0));
+        // Excess salt only consumes what is needed
+        assert_eq!(preserve_grain_with_salt(40, 100), (0, 8));
+        // Partial units round up salt usage
+        assert_eq!(preserve_grain_with_salt(7, 100), (0, 2));
+    }
 }
@@ -0,0 +1,361 @@
+//! Death Cascade System
+//!
+//! When an agent dies, the living catch up: goals aimed at the deceased are
+//! dropped, relationships with them are marked, and close allies of someone
+//! who was killed swear revenge on the killer.
+
+use std::collections::BTreeSet;
+
+use bevy_ecs::prelude::*;
+
+use crate::components::agent::{AgentId, AgentName, Alive, Goal, GoalType, Goals};
+use crate::components::faction::FactionMembership;
+use crate::components::social::RelationshipGraph;
+use crate::components::world::{Position, WorldState};
+use crate::events::types::{
+    ActorSnapshot, Event, EventActors, EventContext, EventOutcome, EventSubtype, EventTimestamp,
+    EventType, GeneralOutcome, LoyaltySubtype,
+};
+use crate::systems::action::TickEvents;
+use crate::systems::goals::{actor_snapshot, create_goal_resolved_event, GoalTarget};
+use crate::systems::trust::{grudge_constants, persistence_multiplier, Grudge, GrudgeLedger};
+
+/// Trust in the deceased above which an agent counts as a close ally
+const CLOSE_ALLY_TRUST: f32 = 0.5;
+
+/// Reliability and alignment an ally loses toward the killer
+const KILLER_TRUST_LOSS: f32 = 0.5;
+
+/// Drama score for an ally swearing vengeance
+const VENGEANCE_VOW_DRAMA: f32 = 0.6;
+
+/// Resource: Agents whose deaths have already cascaded
+#[derive(Resource, Debug, Default)]
+pub struct DeathRecord {
+    processed: BTreeSet<String>,
+}
+
+impl DeathRecord {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Check whether an agent's death has already been handled
+    pub fn is_processed(&self, agent_id: &str) -> bool {
+        self.processed.contains(agent_id)
+    }
+
+    /// Mark an agent's death as handled; returns false if it already was
+    pub fn mark_processed(&mut self, agent_id: &str) -> bool {
+        self.processed.insert(agent_id.to_string())
+    }
+}
+
+/// A death this tick, with whoever caused it if anyone did
+struct Death {
+    deceased: ActorSnapshot,
+    killer: Option<String>,
+    event_id: Option<String>,
+}
+
+/// System to propagate each new death to the living
+///
+/// - Goals targeting the deceased are dropped, each with a resolution event
+/// - Relationships pointing at the deceased are marked `target_deceased`
+/// - If the death was a killing, close allies of the deceased (trust above
+///   `CLOSE_ALLY_TRUST`) turn on the killer, gaining a revenge goal and a
+///   grudge, and a vengeance event is emitted for each
+pub fn cascade_deaths(
+    world_state: Res<WorldState>,
+    mut death_record: ResMut<DeathRecord>,
+    mut relationship_graph: ResMut<RelationshipGraph>,
+    mut grudge_ledger: ResMut<GrudgeLedger>,
+    mut tick_events: ResMut<TickEvents>,
+    mut query: Query<(&AgentId, &AgentName, &Alive, &FactionMembership, &Position, &mut Goals)>,
+) {
+    let deaths: Vec<Death> = query
+        .iter()
+        .filter(|(id, _, alive, _, _, _)| !alive.is_alive() && !death_record.is_processed(&id.0))
+        .map(|(id, name, _, membership, position, _)| {
+            let (killer, event_id) = find_cause(&tick_events.events, &id.0);
+            let deceased = actor_snapshot(&id.0, &name.0, membership, &position.location_id);
+            Death { deceased, killer, event_id }
+        })
+        .collect();
+
+    for death in deaths {
+        death_record.mark_processed(&death.deceased.agent_id);
+        relationship_graph.mark_deceased(&death.deceased.agent_id);
+
+        // Only a killer still standing can be the target of revenge
+        let killer = death.killer.as_ref().and_then(|killer| {
+            query
+                .iter()
+                .find(|(id, _, alive, _, _, _)| &id.0 == killer && alive.is_alive())
+                .map(|(id, name, _, membership, position, _)| {
+                    actor_snapshot(&id.0, &name.0, membership, &position.location_id)
+                })
+        });
+
+        for (agent_id, name, alive, membership, position, mut goals) in query.iter_mut() {
+            if !alive.is_alive() {
+                continue;
+            }
+
+            // Nothing left to pursue with, or against, the dead
+            let (moot, remaining): (Vec<Goal>, Vec<Goal>) = goals
+                .goals
+                .drain(..)
+                .partition(|goal| goal.target.as_deref() == Some(death.deceased.agent_id.as_str()));
+            goals.goals = remaining;
+            let actor = actor_snapshot(&agent_id.0, &name.0, membership, &position.location_id);
+            for goal in moot {
+                let target = Some(GoalTarget::Agent(death.deceased.clone()));
+                let event = create_goal_resolved_event(&mut tick_events, &world_state, &actor, &goal, target);
+                tick_events.push(event);
+            }
+
+            let Some(killer) = killer.as_ref() else {
+                continue;
+            };
+            let closeness = relationship_graph
+                .get(&agent_id.0, &death.deceased.agent_id)
+                .map(|rel| rel.trust.overall())
+                .unwrap_or(0.0);
+            if agent_id.0 == killer.agent_id || closeness < CLOSE_ALLY_TRUST {
+                continue;
+            }
+
+            let rel = relationship_graph.ensure_relationship(&agent_id.0, &killer.agent_id);
+            rel.trust.update_reliability(-KILLER_TRUST_LOSS);
+            rel.trust.update_alignment(-KILLER_TRUST_LOSS);
+            rel.last_interaction_tick = world_state.current_tick;
+
+            let duration = (grudge_constants::BASE_REVENGE_DURATION as f32 * persistence_multiplier(0.5)) as u64;
+            let already_vengeful = goals
+                .goals
+                .iter()
+                .any(|g| g.goal_type == GoalType::Revenge && g.target.as_ref() == Some(&killer.agent_id));
+            if !already_vengeful {
+                let mut goal = Goal::new(GoalType::Revenge, grudge_constants::REVENGE_PRIORITY.max(closeness))
+                    .with_target(&killer.agent_id)
+                    .with_expiry(world_state.current_tick + duration);
+                if let Some(event_id) = &death.event_id {
+                    goal = goal.with_origin(event_id);
+                }
+                goals.add(goal);
+            }
+
+            grudge_ledger.record(Grudge {
+                holder_id: agent_id.0.clone(),
+                offender_id: killer.agent_id.clone(),
+                origin_event: death.event_id.clone(),
+                location: Some(position.location_id.clone()),
+                severity: closeness.clamp(0.0, 1.0),
+                formed_tick: world_state.current_tick,
+            });
+
+            let event = create_vengeance_event(&mut tick_events, &world_state, &actor, killer, &death);
+            tick_events.push(event);
+        }
+    }
+}
+
+/// Find who killed an agent this tick, and the event that records the death
+///
+/// A killing names the deceased among its affected actors with reason
+/// "killed"; the killer is whichever of primary and secondary survived. A
+/// death event without a killer (starvation, execution) yields only its ID.
+fn find_cause(events: &[Event], agent_id: &str) -> (Option<String>, Option<String>) {
+    for event in events {
+        let killed_here = event
+            .actors
+            .affected
+            .iter()
+            .flatten()
+            .any(|a| a.agent_id == agent_id && a.reason.as_deref() == Some("killed"));
+        if killed_here {
+            let killer = std::iter::once(&event.actors.primary)
+                .chain(event.actors.secondary.as_ref())
+                .map(|actor| &actor.agent_id)
+                .find(|id| id.as_str() != agent_id)
+                .cloned();
+            return (killer, Some(event.event_id.clone()));
+        }
+    }
+
+    let death_event = events
+        .iter()
+        .find(|e| e.event_type == EventType::Death && e.actors.primary.agent_id == agent_id);
+    (None, death_event.map(|e| e.event_id.clone()))
+}
+
+fn create_vengeance_event(
+    tick_events: &mut TickEvents,
+    world_state: &WorldState,
+    avenger: &ActorSnapshot,
+    killer: &ActorSnapshot,
+    death: &Death,
+) -> Event {
+    Event {
+        event_id: tick_events.generate_id(),
+        timestamp: EventTimestamp {
+            tick: world_state.current_tick,
+            date: world_state.formatted_date(),
+        },
+        event_type: EventType::Loyalty,
+        subtype: EventSubtype::Loyalty(LoyaltySubtype::SwearVengeance),
+        actors: EventActors {
+            primary: avenger.clone(),
+            secondary: Some(killer.clone()),
+            affected: None,
+        },
+        context: EventContext {
+            trigger: "ally_killed".to_string(),
+            preconditions: vec![format!("{}_killed", death.deceased.agent_id)],
+            location_description: Some(format!("at {}", avenger.location)),
+        },
+        outcome: EventOutcome::General(GeneralOutcome {
+            description: Some(format!("{} swears vengeance for {}", avenger.name, death.deceased.name)),
+            state_changes: vec![format!("goal_added: revenge({})", killer.agent_id)],
+        }),
+        drama_tags: vec!["grief".to_string(), "revenge".to_string()],
+        drama_score: VENGEANCE_VOW_DRAMA,
+        connected_events: death.event_id.iter().cloned().collect(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::components::agent::{Agent, Role};
+    use crate::components::social::{Relationship, Trust};
+    use crate::events::types::{AffectedActor, ConflictSubtype, FactionSubtype};
+
+    fn spawn(world: &mut World, id: &str, goals: Goals) -> Entity {
+        world
+            .spawn((
+                Agent,
+                AgentId(id.to_string()),
+                AgentName(id.to_string()),
+                Alive::new(),
+                FactionMembership::new("thornwood", Role::Laborer),
+                Position::new("market"),
+                goals,
+            ))
+            .id()
+    }
+
+    /// A duel `killer` won against `victim`, as the conflict executor records it
+    fn lethal_duel(tick_events: &mut TickEvents, killer: &str, victim: &str) -> String {
+        let actor = |id: &str| ActorSnapshot {
+            agent_id: id.to_string(),
+            name: id.to_string(),
+            faction: "thornwood".to_string(),
+            role: "laborer".to_string(),
+            location: "market".to_string(),
+        };
+        let event_id = tick_events.generate_id();
+        tick_events.push(Event {
+            event_id: event_id.clone(),
+            timestamp: EventTimestamp { tick: 0, date: String::new() },
+            event_type: EventType::Conflict,
+            subtype: EventSubtype::Conflict(ConflictSubtype::Duel),
+            actors: EventActors {
+                primary: actor(killer),
+                secondary: Some(actor(victim)),
+                affected: Some(vec![AffectedActor {
+                    agent_id: victim.to_string(),
+                    name: victim.to_string(),
+                    faction: "thornwood".to_string(),
+                    role: "duel_loser".to_string(),
+                    relationship_to_primary: Some("rival".to_string()),
+                    attended: None,
+                    reason: Some("killed".to_string()),
+                }]),
+            },
+            context: EventContext {
+                trigger: "duel".to_string(),
+                preconditions: Vec::new(),
+                location_description: None,
+            },
+            outcome: EventOutcome::General(GeneralOutcome::default()),
+            drama_tags: Vec::new(),
+            drama_score: 0.9,
+            connected_events: Vec::new(),
+        });
+        event_id
+    }
+
+    #[test]
+    fn test_killing_cascades_to_pursuers_and_allies() {
+        let mut world = World::new();
+        world.insert_resource(WorldState::new());
+        world.insert_resource(DeathRecord::new());
+        world.insert_resource(GrudgeLedger::new());
+        world.insert_resource(TickEvents::new());
+        let mut graph = RelationshipGraph::new();
+        graph.set("ally", Relationship::new("victim").with_trust(Trust::new(0.9, 0.8, 0.5)));
+        graph.set("bystander", Relationship::new("victim").with_trust(Trust::new(0.1, 0.1, 0.1)));
+        world.insert_resource(graph);
+
+        let mut pursuit = Goals::new();
+        pursuit.add(Goal::new(GoalType::Revenge, 0.9).with_target("victim"));
+        pursuit.add(Goal::new(GoalType::Survive, 0.5));
+        let pursuer = spawn(&mut world, "pursuer", pursuit);
+        let victim = spawn(&mut world, "victim", Goals::new());
+        let ally = spawn(&mut world, "ally", Goals::new());
+        let bystander = spawn(&mut world, "bystander", Goals::new());
+        spawn(&mut world, "killer", Goals::new());
+
+        let duel_id = lethal_duel(&mut world.resource_mut::<TickEvents>(), "killer", "victim");
+        world.get_mut::<Alive>(victim).unwrap().0 = false;
+
+        let mut schedule = Schedule::default();
+        schedule.add_systems(cascade_deaths);
+        schedule.run(&mut world);
+
+        // The pursuer's revenge is moot; other goals are untouched
+        let goals = world.get::<Goals>(pursuer).unwrap();
+        assert!(!goals.has_goal(&GoalType::Revenge));
+        assert!(goals.has_goal(&GoalType::Survive));
+
+        // The close ally turns on the killer; a mere acquaintance doesn't
+        let revenge = world.get::<Goals>(ally).unwrap().get_goal(&GoalType::Revenge).cloned().unwrap();
+        assert_eq!(revenge.target.as_deref(), Some("killer"));
+        assert_eq!(revenge.origin_event.as_deref(), Some(duel_id.as_str()));
+        assert!(!world.get::<Goals>(bystander).unwrap().has_goal(&GoalType::Revenge));
+        assert!(world.resource::<GrudgeLedger>().get("ally", "killer").is_some());
+
+        assert!(world.resource::<RelationshipGraph>().get("ally", "victim").unwrap().target_deceased);
+
+        let events = &world.resource::<TickEvents>().events;
+        let closure = events
+            .iter()
+            .find(|e| e.subtype == EventSubtype::Faction(FactionSubtype::GoalResolved))
+            .unwrap();
+        assert_eq!(closure.actors.primary.agent_id, "pursuer");
+        let deceased = closure.actors.secondary.as_ref().unwrap();
+        assert_eq!(deceased.agent_id, "victim");
+        assert_eq!(deceased.faction, "thornwood");
+        assert_eq!(deceased.location, "market");
+
+        let vows: Vec<&Event> = events
+            .iter()
+            .filter(|e| e.subtype == EventSubtype::Loyalty(LoyaltySubtype::SwearVengeance))
+            .collect();
+        assert_eq!(vows.len(), 1);
+        assert_eq!(vows[0].actors.primary.agent_id, "ally");
+        let killer = vows[0].actors.secondary.as_ref().unwrap();
+        assert_eq!(killer.agent_id, "killer");
+        assert_eq!(killer.faction, "thornwood");
+        assert_eq!(killer.role, "laborer");
+        assert_eq!(killer.location, "market");
+        assert_eq!(vows[0].connected_events, vec![duel_id]);
+
+        // A death only cascades once
+        let before = world.resource::<TickEvents>().len();
+        schedule.run(&mut world);
+        assert_eq!(world.resource::<TickEvents>().len(), before);
+    }
+}
@@ -0,0 +1,335 @@
+//! Goal Resolution System
+//!
+//! Clears goals that have already been achieved (or made moot) so agents
+//! stop pursuing them, and records a quiet closure event for each.
+
+use std::collections::HashMap;
+
+use bevy_ecs::prelude::*;
+
+use crate::components::agent::{AgentId, AgentName, Alive, Goal, GoalType, Goals, Role};
+use crate::components::faction::{FactionMembership, FactionRegistry};
+use crate::components::world::{Position, WorldState};
+use crate::events::types::{
+    ActorSnapshot, Event, EventActors, EventContext, EventOutcome, EventSubtype, EventTimestamp,
+    EventType, FactionSubtype, GeneralOutcome,
+};
+use crate::systems::action::TickEvents;
+
+/// Drama score for goal closure events - noteworthy but never a headline
+const GOAL_RESOLVED_DRAMA: f32 = 0.2;
+
+/// System to remove satisfied goals and emit a resolution event for each
+///
+/// - `Revenge`: the target is dead or gone
+/// - `ChallengeLeader`: the agent now leads their faction
+/// - `Defect`: the agent has joined the target faction
+pub fn resolve_goals(
+    world_state: Res<WorldState>,
+    faction_registry: Res<FactionRegistry>,
+    mut tick_events: ResMut<TickEvents>,
+    mut query: Query<(&AgentId, &AgentName, &Position, &FactionMembership, &Alive, &mut Goals)>,
+) {
+    let alive: HashMap<String, bool> = query
+        .iter()
+        .map(|(id, _, _, _, alive, _)| (id.0.clone(), alive.is_alive()))
+        .collect();
+    let snapshots: HashMap<String, ActorSnapshot> = query
+        .iter()
+        .map(|(id, name, position, membership, _, _)| {
+            (id.0.clone(), actor_snapshot(&id.0, &name.0, membership, &position.location_id))
+        })
+        .collect();
+
+    for (agent_id, name, position, membership, agent_alive, mut goals) in query.iter_mut() {
+        if !agent_alive.is_alive() {
+            continue;
+        }
+
+        let (resolved, remaining): (Vec<Goal>, Vec<Goal>) = goals
+            .goals
+            .drain(..)
+            .partition(|goal| is_satisfied(goal, membership, &alive));
+        goals.goals = remaining;
+
+        let actor = actor_snapshot(&agent_id.0, &name.0, membership, &position.location_id);
+        for goal in resolved {
+            let target = match (&goal.goal_type, goal.target.as_deref()) {
+                (GoalType::Defect, Some(faction_id)) => Some(GoalTarget::Faction(
+                    faction_registry
+                        .get(faction_id)
+                        .map(|f| f.name.clone())
+                        .unwrap_or_else(|| faction_id.to_string()),
+                )),
+                (_, Some(target_id)) => snapshots.get(target_id).cloned().map(GoalTarget::Agent),
+                (_, None) => None,
+            };
+            let event = create_goal_resolved_event(&mut tick_events, &world_state, &actor, &goal, target);
+            tick_events.push(event);
+        }
+    }
+}
+
+fn is_satisfied(goal: &Goal, membership: &FactionMembership, alive: &HashMap<String, bool>) -> bool {
+    match goal.goal_type {
+        GoalType::Revenge => goal
+            .target
+            .as_ref()
+            .is_some_and(|target| !alive.get(target).copied().unwrap_or(false)),
+        GoalType::ChallengeLeader => membership.role == Role::Leader,
+        GoalType::Defect => goal
+            .target
+            .as_ref()
+            .is_some_and(|target| target == &membership.faction_id),
+        _ => false,
+    }
+}
+
+fn goal_label(goal_type: &GoalType) -> &'static str {
+    match goal_type {
+        GoalType::Survive => "survive",
+        GoalType::SurviveWinter => "survive_winter",
+        GoalType::Revenge => "revenge",
+        GoalType::RiseInStatus => "rise_in_status",
+        GoalType::Protect => "protect",
+        GoalType::AccumulateResources => "accumulate_resources",
+        GoalType::BuildRelationship => "build_relationship",
+        GoalType::Defect => "defect",
+        GoalType::SupportLeader => "support_leader",
+        GoalType::ChallengeLeader => "challenge_leader",
+    }
+}
+
+/// What a resolved goal was aimed at, as it appears in the closure event
+pub(crate) enum GoalTarget {
+    /// An agent, reported as the event's secondary actor
+    Agent(ActorSnapshot),
+    /// A faction, by display name; named in the description only
+    Faction(String),
+}
+
+/// An agent as it appears in an event
+pub(crate) fn actor_snapshot(
+    agent_id: &str,
+    name: &str,
+    membership: &FactionMembership,
+    location: &str,
+) -> ActorSnapshot {
+    ActorSnapshot {
+        agent_id: agent_id.to_string(),
+        name: name.to_string(),
+        faction: membership.faction_id.clone(),
+        role: format!("{:?}", membership.role).to_lowercase(),
+        location: location.to_string(),
+    }
+}
+
+pub(crate) fn create_goal_resolved_event(
+    tick_events: &mut TickEvents,
+    world_state: &WorldState,
+    actor: &ActorSnapshot,
+    goal: &Goal,
+    target: Option<GoalTarget>,
+) -> Event {
+    let target_name = match &target {
+        Some(GoalTarget::Agent(snapshot)) => snapshot.name.as_str(),
+        Some(GoalTarget::Faction(name)) => name.as_str(),
+        None => goal.target.as_deref().unwrap_or_default(),
+    };
+    let description = match (&goal.goal_type, goal.target.is_some()) {
+        (GoalType::Revenge, true) => {
+            format!("{} no longer seeks revenge against {}", actor.name, target_name)
+        }
+        (GoalType::ChallengeLeader, _) => format!("{}'s bid for leadership is settled", actor.name),
+        (GoalType::Defect, true) => format!("{} has found a place in {}", actor.name, target_name),
+        (goal_type, _) => format!("{} lets go of a goal ({})", actor.name, goal_label(goal_type)),
+    };
+
+    let secondary = match target {
+        Some(GoalTarget::Agent(snapshot)) => Some(snapshot),
+        _ => None,
+    };
+
+    Event {
+        event_id: tick_events.generate_id(),
+        timestamp: EventTimestamp {
+            tick: world_state.current_tick,
+            date: world_state.formatted_date(),
+        },
+        event_type: EventType::Faction,
+        subtype: EventSubtype::Faction(FactionSubtype::GoalResolved),
+        actors: EventActors {
+            primary: actor.clone(),
+            secondary,
+            affected: None,
+        },
+        context: EventContext {
+            trigger: "goal_resolved".to_string(),
+            preconditions: vec![format!("goal_{}", goal_label(&goal.goal_type))],
+            location_description: Some(format!("at {}", actor.location)),
+        },
+        outcome: EventOutcome::General(GeneralOutcome {
+            description: Some(description),
+            state_changes: Vec::new(),
+        }),
+        drama_tags: vec!["goal_resolved".to_string()],
+        drama_score: GOAL_RESOLVED_DRAMA,
+        connected_events: goal.origin_event.iter().cloned().collect(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::actions::conflict::ConflictActionType;
+    use crate::components::agent::{Agent, Needs, SocialBelonging, Traits};
+    use crate::components::faction::Faction;
+    use crate::components::social::{Relationship, RelationshipGraph, Trust};
+    use crate::systems::action::{generate_conflict_actions, Action, PendingActions};
+    use crate::systems::perception::AgentsByLocation;
+    use crate::systems::territory::TerritorialFriction;
+
+    fn setup_world() -> World {
+        let mut world = World::new();
+        world.insert_resource(WorldState::new());
+        world.insert_resource(TickEvents::new());
+        world.insert_resource(FactionRegistry::new());
+        world.insert_resource(PendingActions::new());
+        world.insert_resource(TerritorialFriction::new());
+
+        let mut by_location = AgentsByLocation::new();
+        by_location.add("market", "avenger");
+        by_location.add("market", "enemy");
+        world.insert_resource(by_location);
+
+        let mut graph = RelationshipGraph::new();
+        graph.set("avenger", Relationship::new("enemy").with_trust(Trust::new(-0.8, -0.8, -0.8)));
+        world.insert_resource(graph);
+        world
+    }
+
+    fn spawn(world: &mut World, id: &str, goals: Goals) -> Entity {
+        world
+            .spawn((
+                Agent,
+                AgentId(id.to_string()),
+                AgentName(id.to_string()),
+                Position::new("market"),
+                FactionMembership::new("thornwood", Role::Laborer),
+                Needs {
+                    social_belonging: SocialBelonging::Isolated,
+                    ..Needs::default()
+                },
+                Traits { boldness: 0.8, ..Traits::default() },
+                Alive::new(),
+                goals,
+            ))
+            .id()
+    }
+
+    fn assassinate_targets(world: &mut World) -> Vec<String> {
+        world.resource_mut::<PendingActions>().clear();
+        let mut schedule = Schedule::default();
+        schedule.add_systems(generate_conflict_actions);
+        schedule.run(world);
+
+        world
+            .resource::<PendingActions>()
+            .get("avenger")
+            .into_iter()
+            .flatten()
+            .filter_map(|wa| match &wa.action {
+                Action::Conflict(c) if c.action_type == ConflictActionType::Assassinate => {
+                    Some(c.target_id.clone())
+                }
+                _ => None,
+            })
+            .collect()
+    }
+
+    fn run_resolve(world: &mut World) {
+        let mut schedule = Schedule::default();
+        schedule.add_systems(resolve_goals);
+        schedule.run(world);
+    }
+
+    #[test]
+    fn test_revenge_goal_clears_when_target_dies() {
+        let mut world = setup_world();
+        let mut goals = Goals::new();
+        goals.add(Goal::new(GoalType::Revenge, 0.9).with_target("enemy").with_origin("evt_00000001"));
+        let avenger = spawn(&mut world, "avenger", goals);
+        let enemy = spawn(&mut world, "enemy", Goals::new());
+        world.get_mut::<AgentName>(enemy).unwrap().0 = "Corin Ashdown".to_string();
+
+        // Target alive: the goal stands and drives assassination
+        run_resolve(&mut world);
+        assert!(world.resource::<TickEvents>().is_empty());
+        assert!(world.get::<Goals>(avenger).unwrap().has_goal(&GoalType::Revenge));
+        assert_eq!(assassinate_targets(&mut world), vec!["enemy".to_string()]);
+
+        world.get_mut::<Alive>(enemy).unwrap().0 = false;
+        run_resolve(&mut world);
+
+        assert!(!world.get::<Goals>(avenger).unwrap().has_goal(&GoalType::Revenge));
+        let events = &world.resource::<TickEvents>().events;
+        assert_eq!(events.len(), 1);
+        assert_eq!(events[0].subtype, EventSubtype::Faction(FactionSubtype::GoalResolved));
+        assert_eq!(events[0].actors.primary.agent_id, "avenger");
+        assert_eq!(events[0].connected_events, vec!["evt_00000001".to_string()]);
+        let target = events[0].actors.secondary.as_ref().unwrap();
+        assert_eq!(target.agent_id, "enemy");
+        assert_eq!(target.name, "Corin Ashdown");
+        assert_eq!(target.faction, "thornwood");
+        assert_eq!(target.role, "laborer");
+        assert_eq!(target.location, "market");
+        let EventOutcome::General(outcome) = &events[0].outcome else {
+            panic!("goal resolution should have a general outcome");
+        };
+        assert_eq!(outcome.description.as_deref(), Some("avenger no longer seeks revenge against Corin Ashdown"));
+
+        assert!(assassinate_targets(&mut world).is_empty());
+    }
+
+    #[test]
+    fn test_challenge_goal_clears_once_leader() {
+        let mut world = setup_world();
+        let mut goals = Goals::new();
+        goals.add(Goal::new(GoalType::ChallengeLeader, 0.7));
+        goals.add(Goal::new(GoalType::Survive, 0.5));
+        let climber = spawn(&mut world, "avenger", goals);
+
+        run_resolve(&mut world);
+        assert!(world.get::<Goals>(climber).unwrap().has_goal(&GoalType::ChallengeLeader));
+
+        world.get_mut::<FactionMembership>(climber).unwrap().role = Role::Leader;
+        run_resolve(&mut world);
+
+        let goals = world.get::<Goals>(climber).unwrap();
+        assert!(!goals.has_goal(&GoalType::ChallengeLeader));
+        assert!(goals.has_goal(&GoalType::Survive));
+        assert_eq!(world.resource::<TickEvents>().events.len(), 1);
+    }
+
+    #[test]
+    fn test_defect_goal_names_faction_without_secondary() {
+        let mut world = setup_world();
+        world
+            .resource_mut::<FactionRegistry>()
+            .register(Faction::new("ironmere", "House Ironmere", "ironmere_keep"));
+        let mut goals = Goals::new();
+        goals.add(Goal::new(GoalType::Defect, 0.6).with_target("ironmere"));
+        let defector = spawn(&mut world, "avenger", goals);
+
+        world.get_mut::<FactionMembership>(defector).unwrap().faction_id = "ironmere".to_string();
+        run_resolve(&mut world);
+
+        let events = &world.resource::<TickEvents>().events;
+        assert_eq!(events.len(), 1);
+        assert!(events[0].actors.secondary.is_none());
+        let EventOutcome::General(outcome) = &events[0].outcome else {
+            panic!("goal resolution should have a general outcome");
+        };
+        assert_eq!(outcome.description.as_deref(), Some("avenger has found a place in House Ironmere"));
+    }
+}
@@ -4,6 +4,7 @@
 
 use bevy_ecs::prelude::*;
 
+use crate::actions::communication::communication_weights;
 use crate::components::agent::AgentId;
 use crate::components::social::{MemoryBank, MemoryValence, RelationshipGraph};
 use crate::components::world::WorldState;
@@ -166,6 +167,74 @@ fn calculate_interestingness(memory: &crate::components::social::Memory, current
     memory.emotional_weight * recency_boost * valence_boost * memory.fidelity
 }
 
+/// Weights for how juicy a memory is as gossip for a particular listener
+#[derive(Debug, Clone, Copy)]
+pub struct GossipWeights {
+    /// Added for secrets the speaker was meant to keep
+    pub secret_bonus: f32,
+    /// Added for negative memories
+    pub negative_bonus: f32,
+    /// Added when the listener has a relationship with the memory's subject
+    pub listener_relation_bonus: f32,
+}
+
+impl Default for GossipWeights {
+    fn default() -> Self {
+        Self {
+            secret_bonus: communication_weights::SECRET_GOSSIP_BONUS,
+            negative_bonus: communication_weights::NEGATIVE_GOSSIP_BONUS,
+            listener_relation_bonus: communication_weights::LISTENER_RELATION_GOSSIP_BONUS,
+        }
+    }
+}
+
+/// Query: Get the juiciest memory an agent could tell a particular listener
+///
+/// Unlike `get_most_interesting_memory`, secrets are candidates too, and the
+/// score favors secrets, negative memories, and third-party gossip about
+/// someone the listener knows.
+pub fn get_juiciest_memory<'a>(
+    memory_bank: &'a MemoryBank,
+    relationship_graph: &RelationshipGraph,
+    agent_id: &str,
+    listener_id: &str,
+    current_tick: u64,
+    weights: &GossipWeights,
+) -> Option<&'a crate::components::social::Memory> {
+    memory_bank.get_memories(agent_id)?
+        .iter()
+        .filter(|m| m.emotional_weight > 0.2)
+        .max_by(|a, b| {
+            let score_a = calculate_gossip_score(a, relationship_graph, listener_id, current_tick, weights);
+            let score_b = calculate_gossip_score(b, relationship_graph, listener_id, current_tick, weights);
+            score_a.partial_cmp(&score_b).unwrap_or(std::cmp::Ordering::Equal)
+        })
+}
+
+/// Calculate how juicy a memory is to tell a particular listener
+pub fn calculate_gossip_score(
+    memory: &crate::components::social::Memory,
+    relationship_graph: &RelationshipGraph,
+    listener_id: &str,
+    current_tick: u64,
+    weights: &GossipWeights,
+) -> f32 {
+    let mut score = calculate_interestingness(memory, current_tick);
+
+    if memory.is_secret {
+        score += weights.secret_bonus;
+    }
+    if memory.valence == MemoryValence::Negative {
+        score += weights.negative_bonus;
+    }
+    // Third-party gossip lands harder when the listener knows the subject
+    if memory.subject != listener_id && relationship_graph.get(listener_id, &memory.subject).is_some() {
+        score += weights.listener_relation_bonus;
+    }
+
+    score
+}
+
 /// Query: Get memories about a specific subject that could damage their reputation
 pub fn get_damaging_memories<'a>(
     memory_bank: &'a MemoryBank,
@@ -257,4 +326,56 @@ mod tests {
 
         assert!(score_recent > score_old, "Recent negative memory should be more interesting");
     }
+
+    #[test]
+    fn test_juicy_secret_beats_bland_high_emotion_memory() {
+        let mut memory_bank = MemoryBank::new();
+        memory_bank.add_memory("speaker", Memory::firsthand(
+            "mem_bland", "evt1", "subject1", "a long harvest day",
+            0.9, 100, MemoryValence::Neutral,
+        ));
+        let mut secret = Memory::firsthand(
+            "mem_secret", "evt2", "subject2", "met with the enemy at night",
+            0.4, 100, MemoryValence::Negative,
+        );
+        secret.is_secret = true;
+        memory_bank.add_memory("speaker", secret);
+
+        let graph = RelationshipGraph::new();
+        let weights = GossipWeights::default();
+
+        let juiciest = get_juiciest_memory(&memory_bank, &graph, "speaker", "listener", 110, &weights).unwrap();
+        assert_eq!(juiciest.memory_id, "mem_secret");
+
+        // By plain interestingness the bland memory would have won
+        let interesting = get_most_interesting_memory(&memory_bank, "speaker", 110).unwrap();
+        assert_eq!(interesting.memory_id, "mem_bland");
+    }
+
+    #[test]
+    fn test_gossip_favors_listener_relations() {
+        let about_friend = Memory::firsthand(
+            "mem1", "evt1", "friend", "friend lost a bet",
+            0.5, 100, MemoryValence::Neutral,
+        );
+        let about_stranger = Memory::firsthand(
+            "mem2", "evt2", "stranger", "stranger lost a bet",
+            0.5, 100, MemoryValence::Neutral,
+        );
+
+        let mut graph = RelationshipGraph::new();
+        graph.ensure_relationship("listener", "friend");
+        let weights = GossipWeights::default();
+
+        let friend_score = calculate_gossip_score(&about_friend, &graph, "listener", 110, &weights);
+        let stranger_score = calculate_gossip_score(&about_stranger, &graph, "listener", 110, &weights);
+        assert!((friend_score - stranger_score - weights.listener_relation_bonus).abs() < 1e-6);
+
+        // Disabling the weight removes the preference
+        let flat = GossipWeights { listener_relation_bonus: 0.0, ..weights };
+        assert_eq!(
+            calculate_gossip_score(&about_friend, &graph, "listener", 110, &flat),
+            calculate_gossip_score(&about_stranger, &graph, "listener", 110, &flat),
+        );
+    }
 }
@@ -1,6 +1,6 @@
 //! ECS Systems
 //!
-//! All simulation systems for perception, needs, actions, memory, trust, ritual, tension, and consumption.
+//! All simulation systems for perception, needs, actions, memory, trust, ritual, succession, goals, death, alliance, tension, territory, and consumption.
 
 pub mod action;
 pub mod perception;
@@ -8,7 +8,12 @@ pub mod needs;
 pub mod memory;
 pub mod trust;
 pub mod ritual;
+pub mod succession;
+pub mod goals;
+pub mod death;
+pub mod alliance;
 pub mod tension;
+pub mod territory;
 pub mod consumption;
 
 // Re-export commonly used systems
@@ -18,11 +23,12 @@ pub use needs::{
     InteractionTracker, RitualAttendance,
 };
 pub use action::{
-    Action, PendingActions, SelectedActions, TickEvents, WeightedAction,
+    Action, ActionBuffer, ActionGeneration, NoiseConfig, PendingActions, SelectedActions, SelectionConfig, TickEvents,
+    WeightedAction, action_generation_schedule, run_action_generation,
     generate_movement_actions, generate_patrol_actions, generate_communication_actions, generate_archive_actions,
     generate_resource_actions, generate_social_actions, generate_faction_actions, generate_conflict_actions,
     generate_beer_actions,
-    apply_trait_weights, add_noise_to_weights, select_actions,
+    apply_trait_weights, apply_intoxication_weights, add_noise_to_weights, select_actions,
     execute_movement_actions, execute_communication_actions, execute_archive_actions,
     execute_resource_actions, execute_social_actions, execute_faction_actions, execute_conflict_actions,
     execute_beer_actions,
@@ -30,14 +36,20 @@ pub use action::{
 pub use memory::{
     decay_memories, cleanup_memories, SeasonTracker,
     calculate_secondhand_trust_impact, get_most_interesting_memory,
+    get_juiciest_memory, calculate_gossip_score, GossipWeights,
 };
 pub use trust::{
     process_trust_events, decay_grudges, TrustEventQueue, TrustEvent, TrustEventType,
-    create_trust_event,
+    create_trust_event, Grudge, GrudgeLedger,
 };
 pub use ritual::execute_rituals;
-pub use tension::{detect_tensions, output_tensions};
+pub use succession::handle_succession;
+pub use goals::resolve_goals;
+pub use death::{cascade_deaths, DeathRecord};
+pub use alliance::form_alliances;
+pub use tension::{detect_tensions, output_tensions, record_conflicts, ConflictHistory};
+pub use territory::{detect_territorial_friction, TerritorialFriction};
 pub use consumption::{
     apply_daily_consumption, enforce_storage_caps, apply_seasonal_spoilage,
-    decay_intoxication, ConsumptionTracker,
+    decay_intoxication, apply_starvation, ConsumptionTracker,
 };
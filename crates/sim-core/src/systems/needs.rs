@@ -61,6 +61,8 @@ pub struct RitualAttendance {
     attendance: std::collections::HashMap<String, u32>,
     /// Maps agent_id -> number of rituals missed recently
     missed: std::collections::HashMap<String, u32>,
+    /// Maps agent_id -> rituals missed in a row since last attending
+    missed_streak: std::collections::HashMap<String, u32>,
 }
 
 impl RitualAttendance {
@@ -75,11 +77,18 @@ impl RitualAttendance {
         if let Some(missed) = self.missed.get_mut(agent_id) {
             *missed = missed.saturating_sub(1);
         }
+        self.missed_streak.remove(agent_id);
     }
 
     /// Record ritual missed
     pub fn record_missed(&mut self, agent_id: &str) {
         *self.missed.entry(agent_id.to_string()).or_default() += 1;
+        *self.missed_streak.entry(agent_id.to_string()).or_default() += 1;
+    }
+
+    /// Get the number of consecutive rituals missed (not affected by decay)
+    pub fn missed_streak(&self, agent_id: &str) -> u32 {
+        self.missed_streak.get(agent_id).copied().unwrap_or(0)
     }
 
     /// Get attendance score (attended - missed, clamped to reasonable range)
@@ -197,6 +206,9 @@ pub fn update_food_security(
     }
 }
 
+/// Belonging lost per consecutive missed ritual, on top of the attendance score
+const ABSENCE_STREAK_PENALTY: f32 = 0.05;
+
 /// Thresholds for social belonging state transitions
 struct SocialBelongingThresholds {
     /// Trust received score below which agent is peripheral
@@ -288,6 +300,9 @@ pub fn update_social_belonging(
         // Ritual attendance bonus/penalty
         belonging_score += ritual_score as f32 * 0.1;
 
+        // Repeatedly skipping rituals erodes belonging further
+        belonging_score -= ritual_attendance.missed_streak(&agent_id.0) as f32 * ABSENCE_STREAK_PENALTY;
+
         // Visibility bonus for being with faction
         if has_visible_faction_mates {
             belonging_score += 0.1;
@@ -37,10 +37,16 @@ impl VisibleAgents {
 }
 
 /// Resource tracking agents by location for efficient perception queries
+///
+/// Maintained incrementally: movement execution reports each move through
+/// [`AgentsByLocation::moved`], and [`build_location_index`] only reconciles
+/// positions changed elsewhere (spawns, interventions, snapshot restores).
 #[derive(Resource, Debug, Default)]
 pub struct AgentsByLocation {
     /// Maps location_id -> list of agent IDs present
     locations: HashMap<String, Vec<String>>,
+    /// Maps agent_id -> location_id the agent is indexed under
+    agent_locations: HashMap<String, String>,
 }
 
 impl AgentsByLocation {
@@ -64,29 +70,58 @@ impl AgentsByLocation {
             .unwrap_or(0)
     }
 
-    /// Clear all location data (called before rebuilding)
+    /// Get the location an agent is indexed under
+    pub fn location_of(&self, agent_id: &str) -> Option<&str> {
+        self.agent_locations.get(agent_id).map(|s| s.as_str())
+    }
+
+    /// Clear all location data
     pub fn clear(&mut self) {
         self.locations.clear();
+        self.agent_locations.clear();
     }
 
-    /// Add an agent to a location
+    /// Add an agent to a location, removing it from any previous location
     pub fn add(&mut self, location_id: impl Into<String>, agent_id: impl Into<String>) {
+        let location_id = location_id.into();
+        self.moved(&agent_id.into(), &location_id, &location_id);
+    }
+
+    /// Move an agent between locations without rebuilding the index
+    ///
+    /// If the index already places the agent somewhere, that entry is removed
+    /// even when it disagrees with `from`.
+    pub fn moved(&mut self, agent_id: &str, from: &str, to: &str) {
+        let from = match self.agent_locations.get(agent_id) {
+            Some(indexed) if indexed == to => return,
+            Some(indexed) => indexed.clone(),
+            None => from.to_string(),
+        };
+        if let Some(agents) = self.locations.get_mut(&from) {
+            agents.retain(|id| id != agent_id);
+            if agents.is_empty() {
+                self.locations.remove(&from);
+            }
+        }
         self.locations
-            .entry(location_id.into())
+            .entry(to.to_string())
             .or_default()
-            .push(agent_id.into());
+            .push(agent_id.to_string());
+        self.agent_locations.insert(agent_id.to_string(), to.to_string());
     }
 }
 
-/// System to build the AgentsByLocation index
-/// This runs first to create an efficient lookup structure
+/// System to keep the AgentsByLocation index in sync with agent positions
+///
+/// Only agents whose `Position` changed since the last run are visited, so the
+/// first run indexes everyone and later runs cost nothing for agents that
+/// stayed put or whose moves were already reported via `moved`.
 pub fn build_location_index(
     mut agents_by_location: ResMut<AgentsByLocation>,
-    query: Query<(&AgentId, &Position)>,
+    query: Query<(&AgentId, &Position), Changed<Position>>,
 ) {
-    agents_by_location.clear();
-
     for (agent_id, position) in query.iter() {
+        // No-op when the move was already reported
         agents_by_location.add(&position.location_id, &agent_id.0);
     }
 }
@@ -216,4 +251,56 @@ mod tests {
             }
         }
     }
+
+    #[test]
+    fn test_incremental_index_matches_full_rebuild() {
+        let mut world = World::new();
+        world.insert_resource(AgentsByLocation::new());
+
+        let locations = ["village", "forest", "river", "hall"];
+        let entities: Vec<Entity> = (0..40)
+            .map(|i| {
+                world
+                    .spawn((
+                        AgentId(format!("agent_{:03}", i)),
+                        Position::new(locations[i % locations.len()]),
+                    ))
+                    .id()
+            })
+            .collect();
+
+        let mut schedule = Schedule::default();
+        schedule.add_systems(build_location_index);
+        schedule.run(&mut world);
+
+        for (i, &entity) in entities.iter().enumerate() {
+            let to = locations[(i * 7 + 1) % locations.len()];
+            let id = world.get::<AgentId>(entity).unwrap().0.clone();
+            let from = world.get::<Position>(entity).unwrap().location_id.clone();
+            world.get_mut::<Position>(entity).unwrap().location_id = to.to_string();
+            // Every third move bypasses movement execution, like an intervention
+            if i % 3 != 0 {
+                world.resource_mut::<AgentsByLocation>().moved(&id, &from, to);
+            }
+        }
+        // Someone new arrives mid-run
+        world.spawn((AgentId("agent_new".to_string()), Position::new("river")));
+        schedule.run(&mut world);
+
+        let mut full = AgentsByLocation::new();
+        let mut query = world.query::<(&AgentId, &Position)>();
+        for (agent_id, position) in query.iter(&world) {
+            full.add(&position.location_id, &agent_id.0);
+        }
+
+        let incremental = world.resource::<AgentsByLocation>();
+        for location in locations {
+            let mut expected = full.at_location(location).to_vec();
+            let mut actual = incremental.at_location(location).to_vec();
+            expected.sort();
+            actual.sort();
+            assert_eq!(actual, expected, "mismatch at {}", location);
+        }
+        assert_eq!(incremental.location_of("agent_new"), Some("river"));
+    }
 }
@@ -3,11 +3,12 @@
 //! Handles periodic faction rituals where archive entries are read to attendees.
 
 use bevy_ecs::prelude::*;
+use rand::Rng;
 use std::collections::HashMap;
 
-use crate::components::agent::{AgentId, AgentName};
+use crate::components::agent::{AgentId, AgentName, GoalType, Goals, Traits};
 use crate::components::faction::{FactionMembership, FactionRegistry, RitualSchedule};
-use crate::components::social::{Memory, MemoryBank, MemorySource, MemoryValence};
+use crate::components::social::{Memory, MemoryBank, MemorySource, MemoryValence, RelationshipGraph};
 use crate::components::world::{Position, WorldState};
 use crate::events::types::{
     ActorSnapshot, AffectedActor, Event, EventActors, EventContext, EventOutcome,
@@ -16,25 +17,53 @@ use crate::events::types::{
 use crate::systems::action::TickEvents;
 use crate::systems::needs::RitualAttendance;
 use crate::systems::perception::AgentsByLocation;
+use crate::SimRng;
 
 /// Number of entries to read per ritual
 const ENTRIES_PER_RITUAL: usize = 3;
 
+/// Outsiders whose trust toward the host faction is below this may disrupt
+const DISRUPT_HOSTILITY_THRESHOLD: f32 = -0.3;
+
+/// Base chance a hostile outsider disrupts a reading
+const DISRUPT_BASE_CHANCE: f32 = 0.2;
+
+/// Extra disruption chance per point of boldness
+const DISRUPT_BOLDNESS_MULT: f32 = 0.8;
+
+/// Trust attendees lose toward the disruptor
+const DISRUPTION_TRUST_DAMAGE: f32 = 0.2;
+
+/// Trust the leader loses in a member each time they skip a reading
+const MISSED_LEADER_TRUST_PENALTY: f32 = 0.05;
+
+/// Consecutive absences after which a missed reading is newsworthy
+const NOTABLE_ABSENCE_STREAK: u32 = 2;
+
+/// Status level (Council/Reader) at which any absence is newsworthy
+const NOTABLE_ABSENTEE_STATUS: u8 = 4;
+
 /// System to execute faction rituals when due
 pub fn execute_rituals(
+    mut rng: ResMut<SimRng>,
     world_state: Res<WorldState>,
     mut ritual_schedule: ResMut<RitualSchedule>,
     mut faction_registry: ResMut<FactionRegistry>,
     agents_by_location: Res<AgentsByLocation>,
     mut memory_bank: ResMut<MemoryBank>,
     mut ritual_attendance: ResMut<RitualAttendance>,
+    mut relationship_graph: ResMut<RelationshipGraph>,
     mut tick_events: ResMut<TickEvents>,
-    query: Query<(&AgentId, &AgentName, &Position, &FactionMembership)>,
+    query: Query<(&AgentId, &AgentName, &Position, &FactionMembership, &Traits, &Goals)>,
 ) {
     // Build agent info map
     let agent_info: HashMap<String, (&AgentName, &Position, &FactionMembership)> = query
         .iter()
-        .map(|(id, name, pos, mem)| (id.0.clone(), (name, pos, mem)))
+        .map(|(id, name, pos, mem, _, _)| (id.0.clone(), (name, pos, mem)))
+        .collect();
+    let agent_dispositions: HashMap<String, (&Traits, &Goals)> = query
+        .iter()
+        .map(|(id, _, _, _, traits, goals)| (id.0.clone(), (traits, goals)))
         .collect();
 
     // Get all faction IDs
@@ -74,6 +103,57 @@ pub fn execute_rituals(
             continue;
         }
 
+        // A hostile outsider in the hall may break up the reading
+        let disruptor = agents_by_location
+            .at_location(&hq_location)
+            .iter()
+            .filter(|id| {
+                agent_info
+                    .get(*id)
+                    .is_some_and(|(_, _, mem)| mem.faction_id != faction_id)
+            })
+            .find(|id| {
+                let Some((traits, goals)) = agent_dispositions.get(*id) else {
+                    return false;
+                };
+                let hostile = relationship_graph.sentiment_toward_faction(id, &agents_at_hq)
+                    < DISRUPT_HOSTILITY_THRESHOLD;
+                let vengeful = goals.goals.iter().any(|g| {
+                    g.goal_type == GoalType::Revenge
+                        && g.target.as_ref().is_some_and(|t| agents_at_hq.contains(t))
+                });
+                if !hostile && !vengeful {
+                    return false;
+                }
+                let chance = (DISRUPT_BASE_CHANCE + traits.boldness * DISRUPT_BOLDNESS_MULT).min(1.0);
+                rng.0.gen::<f32>() < chance
+            })
+            .cloned();
+
+        if let Some(disruptor_id) = disruptor {
+            // No memories or attendance credit from a broken-up reading
+            for attendee_id in &agents_at_hq {
+                let rel = relationship_graph.ensure_relationship(attendee_id, &disruptor_id);
+                rel.trust.update_reliability(-DISRUPTION_TRUST_DAMAGE);
+                rel.trust.update_alignment(-DISRUPTION_TRUST_DAMAGE);
+                rel.last_interaction_tick = world_state.current_tick;
+            }
+
+            let event = create_disruption_event(
+                &mut tick_events,
+                &world_state,
+                &faction_name,
+                &hq_location,
+                &disruptor_id,
+                &agents_at_hq,
+                &agent_info,
+            );
+            tick_events.push(event);
+
+            ritual_schedule.advance_ritual(&faction_id);
+            continue;
+        }
+
         // Get entries to read (ritual can still happen with empty archive)
         let entries_to_read = {
             let Some(archive) = faction_registry.get_archive(&faction_id) else {
@@ -133,6 +213,7 @@ pub fn execute_rituals(
                     tick_created: world_state.current_tick,
                     valence,
                     is_secret: false,
+                    corroborations: 0,
                 };
 
                 memory_bank.add_memory(attendee_id, memory);
@@ -149,9 +230,40 @@ pub fn execute_rituals(
         }
 
         // Record missed attendance for faction members not at HQ
-        for (agent_id, (_, _, membership)) in &agent_info {
-            if membership.faction_id == faction_id && !agents_at_hq.contains(agent_id) {
-                ritual_attendance.record_missed(agent_id);
+        let mut absentees: Vec<&String> = agent_info
+            .iter()
+            .filter(|(agent_id, (_, _, membership))| {
+                membership.faction_id == faction_id && !agents_at_hq.contains(*agent_id)
+            })
+            .map(|(agent_id, _)| agent_id)
+            .collect();
+        absentees.sort();
+
+        let leader_id = faction_registry.get(&faction_id).and_then(|f| f.leader.clone());
+        for agent_id in absentees {
+            ritual_attendance.record_missed(agent_id);
+
+            // The leader notices who stays away
+            if let Some(leader_id) = leader_id.as_ref().filter(|leader| *leader != agent_id) {
+                let rel = relationship_graph.ensure_relationship(leader_id, agent_id);
+                rel.trust.update_reliability(-MISSED_LEADER_TRUST_PENALTY);
+                rel.trust.update_alignment(-MISSED_LEADER_TRUST_PENALTY);
+            }
+
+            let streak = ritual_attendance.missed_streak(agent_id);
+            let status = agent_info
+                .get(agent_id)
+                .map_or(0, |(_, _, mem)| mem.role.status_level().value());
+            if streak >= NOTABLE_ABSENCE_STREAK || status >= NOTABLE_ABSENTEE_STATUS {
+                let event = create_missed_event(
+                    &mut tick_events,
+                    &world_state,
+                    &faction_name,
+                    agent_id,
+                    streak,
+                    &agent_info,
+                );
+                tick_events.push(event);
             }
         }
 
@@ -258,12 +370,277 @@ fn create_ritual_event(
     }
 }
 
+/// Create an event for a notable absence from a reading
+fn create_missed_event(
+    tick_events: &mut TickEvents,
+    world_state: &WorldState,
+    faction_name: &str,
+    agent_id: &str,
+    streak: u32,
+    agent_info: &HashMap<String, (&AgentName, &Position, &FactionMembership)>,
+) -> Event {
+    let (name, position, membership) = agent_info
+        .get(agent_id)
+        .map(|(name, pos, mem)| (name.0.clone(), *pos, *mem))
+        .expect("absentee is a known agent");
+
+    Event {
+        event_id: tick_events.generate_id(),
+        timestamp: EventTimestamp {
+            tick: world_state.current_tick,
+            date: world_state.formatted_date(),
+        },
+        event_type: EventType::Ritual,
+        subtype: EventSubtype::Ritual(RitualSubtype::ReadingMissed),
+        actors: EventActors {
+            primary: ActorSnapshot {
+                agent_id: agent_id.to_string(),
+                name: name.clone(),
+                faction: membership.faction_id.clone(),
+                role: format!("{:?}", membership.role).to_lowercase(),
+                location: position.location_id.clone(),
+            },
+            secondary: None,
+            affected: None,
+        },
+        context: EventContext {
+            trigger: "ritual_absence".to_string(),
+            preconditions: vec![format!("missed_{}_in_a_row", streak)],
+            location_description: Some(format!("away from {} faction hall", faction_name)),
+        },
+        outcome: EventOutcome::General(GeneralOutcome {
+            description: Some(format!(
+                "{} is absent from the {} archive reading ({} in a row)",
+                name, faction_name, streak
+            )),
+            state_changes: vec![format!("{}'s standing in {} slips", name, faction_name)],
+        }),
+        drama_tags: vec!["faction_ritual".to_string(), "absent_agent".to_string()],
+        drama_score: (0.15 + streak as f32 * 0.05).min(0.4),
+        connected_events: Vec::new(),
+    }
+}
+
+/// Create a ritual disruption event
+fn create_disruption_event(
+    tick_events: &mut TickEvents,
+    world_state: &WorldState,
+    faction_name: &str,
+    location: &str,
+    disruptor_id: &str,
+    attendees: &[String],
+    agent_info: &HashMap<String, (&AgentName, &Position, &FactionMembership)>,
+) -> Event {
+    let (name, membership) = agent_info
+        .get(disruptor_id)
+        .map(|(name, _, mem)| (name.0.clone(), *mem))
+        .expect("disruptor is a known agent");
+
+    let affected: Vec<AffectedActor> = attendees.iter()
+        .filter_map(|id| {
+            agent_info.get(id).map(|(name, _, mem)| AffectedActor {
+                agent_id: id.clone(),
+                name: name.0.clone(),
+                faction: mem.faction_id.clone(),
+                role: format!("{:?}", mem.role).to_lowercase(),
+                relationship_to_primary: None,
+                attended: Some(true),
+                reason: Some("reading_disrupted".to_string()),
+            })
+        })
+        .collect();
+
+    Event {
+        event_id: tick_events.generate_id(),
+        timestamp: EventTimestamp {
+            tick: world_state.current_tick,
+            date: world_state.formatted_date(),
+        },
+        event_type: EventType::Ritual,
+        subtype: EventSubtype::Ritual(RitualSubtype::ReadingDisrupted),
+        actors: EventActors {
+            primary: ActorSnapshot {
+                agent_id: disruptor_id.to_string(),
+                name: name.clone(),
+                faction: membership.faction_id.clone(),
+                role: format!("{:?}", membership.role).to_lowercase(),
+                location: location.to_string(),
+            },
+            secondary: None,
+            affected: Some(affected),
+        },
+        context: EventContext {
+            trigger: "hostile_outsider".to_string(),
+            preconditions: vec!["ritual_scheduled".to_string()],
+            location_description: Some(format!("at {} faction hall", faction_name)),
+        },
+        outcome: EventOutcome::General(GeneralOutcome {
+            description: Some(format!("{} disrupts the {} archive reading", name, faction_name)),
+            state_changes: vec![format!("{} attendees lost trust in {}", attendees.len(), name)],
+        }),
+        drama_tags: vec!["faction_ritual".to_string(), "ritual_disrupted".to_string(), "cross_faction".to_string()],
+        drama_score: 0.85,
+        connected_events: Vec::new(),
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::components::agent::{Needs, Role, SocialBelonging};
+    use crate::components::faction::Faction;
+    use crate::systems::needs::{update_social_belonging, InteractionTracker};
+    use crate::components::social::{Relationship, Trust};
+    use rand::rngs::SmallRng;
+    use rand::SeedableRng;
 
     #[test]
     fn test_entries_per_ritual() {
         assert_eq!(ENTRIES_PER_RITUAL, 3);
     }
+
+    #[test]
+    fn test_hostile_outsider_disrupts_reading() {
+        let mut world = World::new();
+        world.insert_resource(SimRng(SmallRng::seed_from_u64(7)));
+        world.insert_resource(WorldState::new());
+        world.insert_resource(MemoryBank::new());
+        world.insert_resource(RitualAttendance::new());
+        world.insert_resource(TickEvents::new());
+
+        let mut schedule = RitualSchedule::new(1000);
+        schedule.schedule_ritual("thornwood", 0);
+        world.insert_resource(schedule);
+
+        let mut registry = FactionRegistry::new();
+        let mut faction = Faction::new("thornwood", "Thornwood", "hall");
+        faction.leader = Some("elder".to_string());
+        registry.register(faction);
+        world.insert_resource(registry);
+
+        let mut graph = RelationshipGraph::new();
+        for member in ["elder", "acolyte"] {
+            graph.set("zealot", Relationship::new(member).with_trust(Trust::new(-0.8, -0.8, -0.4)));
+        }
+        world.insert_resource(graph);
+
+        let mut by_location = AgentsByLocation::new();
+        for (id, faction, role, boldness) in [
+            ("elder", "thornwood", Role::Leader, 0.5),
+            ("acolyte", "thornwood", Role::Laborer, 0.5),
+            ("zealot", "ironmere", Role::Laborer, 1.0),
+        ] {
+            by_location.add("hall", id);
+            world.spawn((
+                AgentId(id.to_string()),
+                AgentName(id.to_string()),
+                Position::new("hall"),
+                FactionMembership::new(faction, role),
+                Traits { boldness, ..Traits::default() },
+                Goals::new(),
+            ));
+        }
+        world.insert_resource(by_location);
+
+        let mut systems = Schedule::default();
+        systems.add_systems(execute_rituals);
+        systems.run(&mut world);
+
+        let events = &world.resource::<TickEvents>().events;
+        assert_eq!(events.len(), 1);
+        assert_eq!(events[0].subtype, EventSubtype::Ritual(RitualSubtype::ReadingDisrupted));
+        assert_eq!(events[0].actors.primary.agent_id, "zealot");
+        assert!(events[0].drama_score >= 0.8);
+
+        // Attendees get no credit for the broken reading, and resent the zealot
+        assert_eq!(world.resource::<RitualAttendance>().get_score("acolyte"), 0);
+        let graph = world.resource::<RelationshipGraph>();
+        assert!(graph.get("acolyte", "zealot").unwrap().trust.overall() < 0.0);
+        assert!(world.resource::<RitualSchedule>().next_ritual("thornwood").unwrap() > 0);
+    }
+
+    #[test]
+    fn test_repeated_absence_drops_belonging_tier() {
+        let mut world = World::new();
+        world.insert_resource(SimRng(SmallRng::seed_from_u64(7)));
+        world.insert_resource(WorldState::new());
+        world.insert_resource(MemoryBank::new());
+        world.insert_resource(RitualAttendance::new());
+        world.insert_resource(InteractionTracker::new());
+        world.insert_resource(TickEvents::new());
+
+        let mut schedule = RitualSchedule::new(1000);
+        schedule.schedule_ritual("thornwood", 0);
+        world.insert_resource(schedule);
+
+        let mut registry = FactionRegistry::new();
+        let mut faction = Faction::new("thornwood", "Thornwood", "hall");
+        faction.leader = Some("elder".to_string());
+        registry.register(faction);
+        world.insert_resource(registry);
+
+        let mut graph = RelationshipGraph::new();
+        for member in ["elder", "acolyte"] {
+            graph.set(member, Relationship::new("wanderer").with_trust(Trust::new(0.7, 0.7, 0.7)));
+        }
+        world.insert_resource(graph);
+
+        let mut by_location = AgentsByLocation::new();
+        for (id, role, location) in [
+            ("elder", Role::Leader, "hall"),
+            ("acolyte", Role::Laborer, "hall"),
+            ("wanderer", Role::Laborer, "fields"),
+        ] {
+            by_location.add(location, id);
+            world.spawn((
+                AgentId(id.to_string()),
+                AgentName(id.to_string()),
+                Position::new(location),
+                FactionMembership::new("thornwood", role),
+                Traits::default(),
+                Goals::new(),
+                Needs::default(),
+            ));
+        }
+        world.insert_resource(by_location);
+
+        let mut systems = Schedule::default();
+        systems.add_systems((execute_rituals, update_social_belonging).chain());
+        let belonging = |world: &mut World| {
+            world
+                .query::<(&AgentId, &Needs)>()
+                .iter(world)
+                .find(|(id, _)| id.0 == "wanderer")
+                .map(|(_, needs)| needs.social_belonging)
+                .unwrap()
+        };
+
+        // A single absence is shrugged off
+        systems.run(&mut world);
+        assert_eq!(belonging(&mut world), SocialBelonging::Integrated);
+        assert!(world.resource::<TickEvents>().events.iter().all(|e| {
+            e.subtype != EventSubtype::Ritual(RitualSubtype::ReadingMissed)
+        }));
+
+        for _ in 0..2 {
+            world.resource_mut::<WorldState>().current_tick += 1000;
+            systems.run(&mut world);
+        }
+
+        assert_eq!(world.resource::<RitualAttendance>().missed_streak("wanderer"), 3);
+        assert_eq!(belonging(&mut world), SocialBelonging::Peripheral);
+
+        let missed: Vec<&Event> = world
+            .resource::<TickEvents>()
+            .events
+            .iter()
+            .filter(|e| e.subtype == EventSubtype::Ritual(RitualSubtype::ReadingMissed))
+            .collect();
+        assert_eq!(missed.len(), 2);
+        assert_eq!(missed[0].actors.primary.agent_id, "wanderer");
+        assert!(missed[0].drama_tags.contains(&"absent_agent".to_string()));
+
+        let graph = world.resource::<RelationshipGraph>();
+        assert!(graph.get("elder", "wanderer").unwrap().trust.overall() < 0.7);
+    }
 }
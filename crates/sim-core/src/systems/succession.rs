@@ -0,0 +1,373 @@
+//! Succession System
+//!
+//! Fills leadership vacancies when a faction's leader dies or defects.
+
+use bevy_ecs::prelude::*;
+
+use crate::actions::faction::faction_weights;
+use crate::components::agent::{AgentId, AgentName, Alive, Role, Traits};
+use crate::components::faction::{FactionMembership, FactionRegistry};
+use crate::components::social::RelationshipGraph;
+use crate::components::world::{Position, WorldState};
+use crate::events::types::{
+    ActorSnapshot, Event, EventActors, EventContext, EventOutcome, EventSubtype, EventTimestamp,
+    EventType, FactionSubtype, GeneralOutcome,
+};
+use crate::output::tension::{Tension, TensionStream, TensionType};
+use crate::systems::action::TickEvents;
+
+/// Ambition above which a passed-over member counts as a rival claimant
+const RIVAL_AMBITION_THRESHOLD: f32 = 0.7;
+
+/// Minimum status value (Specialist) for a rival to have a credible claim
+const RIVAL_MIN_STATUS: u8 = 3;
+
+/// Number of rivals needed to spark a succession crisis
+const CRISIS_MIN_RIVALS: usize = 2;
+
+struct Candidate {
+    entity: Entity,
+    id: String,
+    name: String,
+    location: String,
+    status: u8,
+    leader_trust: f32,
+    ambition: f32,
+}
+
+/// System to promote a new leader in any faction whose leader is dead or gone
+pub fn handle_succession(
+    world_state: Res<WorldState>,
+    mut faction_registry: ResMut<FactionRegistry>,
+    relationship_graph: Res<RelationshipGraph>,
+    mut tension_stream: ResMut<TensionStream>,
+    mut tick_events: ResMut<TickEvents>,
+    mut query: Query<(Entity, &AgentId, &AgentName, &Position, &Traits, &Alive, &mut FactionMembership)>,
+) {
+    let faction_ids: Vec<String> = faction_registry.faction_ids().into_iter().cloned().collect();
+
+    for faction_id in faction_ids {
+        let previous_leader = faction_registry.get(&faction_id).and_then(|f| f.leader.clone());
+
+        // A leader only counts if they are alive and still in the faction
+        let leader_present = previous_leader.as_ref().is_some_and(|leader_id| {
+            query.iter().any(|(_, id, _, _, _, alive, membership)| {
+                &id.0 == leader_id && alive.is_alive() && membership.faction_id == faction_id
+            })
+        });
+        if leader_present {
+            continue;
+        }
+
+        let candidates: Vec<Candidate> = query
+            .iter()
+            .filter(|(_, id, _, _, _, alive, membership)| {
+                alive.is_alive()
+                    && membership.faction_id == faction_id
+                    && Some(&id.0) != previous_leader.as_ref()
+            })
+            .map(|(entity, id, name, pos, traits, _, membership)| Candidate {
+                entity,
+                id: id.0.clone(),
+                name: name.0.clone(),
+                location: pos.location_id.clone(),
+                status: membership.role.status_level().value(),
+                leader_trust: previous_leader
+                    .as_ref()
+                    .and_then(|leader| relationship_graph.get(&id.0, leader))
+                    .map(|rel| rel.trust.overall())
+                    .unwrap_or(0.0),
+                ambition: traits.ambition,
+            })
+            .collect();
+
+        let Some(successor) = choose_successor(&candidates) else {
+            // Nobody left to lead
+            if let Some(faction) = faction_registry.get_mut(&faction_id) {
+                faction.leader = None;
+            }
+            continue;
+        };
+
+        let Some(faction_name) = faction_registry.get(&faction_id).map(|f| f.name.clone()) else {
+            continue;
+        };
+        if let Ok((_, _, _, _, _, _, mut membership)) = query.get_mut(successor.entity) {
+            install_leader(&mut faction_registry, &faction_id, &successor.id, &mut membership);
+        }
+
+        let event = create_succession_event(
+            &mut tick_events,
+            &world_state,
+            successor,
+            &faction_id,
+            &faction_name,
+            previous_leader.as_deref(),
+        );
+        tick_events.push(event);
+
+        // Ambitious members who were passed over may contest the new leader
+        let rivals: Vec<&Candidate> = candidates
+            .iter()
+            .filter(|c| c.id != successor.id)
+            .filter(|c| c.ambition > RIVAL_AMBITION_THRESHOLD && c.status >= RIVAL_MIN_STATUS)
+            .collect();
+
+        if rivals.len() >= CRISIS_MIN_RIVALS {
+            let tension_id = format!("succession_{}", faction_id);
+            let severity = (0.5 + rivals.len() as f32 * 0.1).clamp(0.5, 0.9);
+
+            if let Some(existing) = tension_stream.get_mut(&tension_id) {
+                existing.update_severity(severity, world_state.current_tick);
+            } else {
+                let mut tension = Tension::new(
+                    &tension_id,
+                    TensionType::SuccessionCrisis,
+                    world_state.current_tick,
+                    format!("{}'s rise to lead {} is contested", successor.name, faction_name),
+                );
+                tension.severity = severity;
+                tension.confidence = 0.8;
+                tension.add_agent(&successor.id, "new_leader", "insecure");
+                for rival in rivals.iter().take(4) {
+                    tension.add_agent(&rival.id, "rival_claimant", "scheming");
+                }
+                tension.add_predicted_outcome("leadership_challenge", 0.5, "high");
+                tension.add_predicted_outcome("faction_split", 0.2, "very_high");
+                tension.narrative_hooks.push("The crown sits uneasily".to_string());
+                tension_stream.upsert(tension);
+            }
+        }
+    }
+}
+
+/// Pick the highest-status loyal member, using trust in the previous leader as
+/// the tiebreaker. Falls back to disloyal members if no loyal ones remain.
+fn choose_successor<'a>(candidates: &'a [Candidate]) -> Option<&'a Candidate> {
+    let rank = |c: &&Candidate| (c.status, c.leader_trust);
+    let best = |pool: Vec<&'a Candidate>| -> Option<&'a Candidate> {
+        pool.into_iter().max_by(|a, b| {
+            rank(a)
+                .partial_cmp(&rank(b))
+                .unwrap_or(std::cmp::Ordering::Equal)
+                // Deterministic: prefer the lower agent ID on a tie
+                .then_with(|| b.id.cmp(&a.id))
+        })
+    };
+
+    let loyal: Vec<&Candidate> = candidates.iter().filter(|c| c.leader_trust >= 0.0).collect();
+    if loyal.is_empty() {
+        best(candidates.iter().collect())
+    } else {
+        best(loyal)
+    }
+}
+
+/// Compare a challenger's claim against the incumbent's standing.
+///
+/// Each side's support is the average trust the rest of the faction places in
+/// them; the challenger's ambition is added to their claim. Returns
+/// `(challenger_support, incumbent_support)`.
+pub(crate) fn challenge_support(
+    relationship_graph: &RelationshipGraph,
+    members: &[String],
+    challenger_id: &str,
+    incumbent_id: &str,
+    challenger_ambition: f32,
+) -> (f32, f32) {
+    let voters: Vec<&String> = members
+        .iter()
+        .filter(|m| m.as_str() != challenger_id && m.as_str() != incumbent_id)
+        .collect();
+    let average_trust = |target: &str| {
+        if voters.is_empty() {
+            return 0.0;
+        }
+        voters
+            .iter()
+            .map(|voter| {
+                relationship_graph
+                    .get(voter, target)
+                    .map(|rel| rel.trust.overall())
+                    .unwrap_or(0.0)
+            })
+            .sum::<f32>()
+            / voters.len() as f32
+    };
+
+    let challenger = average_trust(challenger_id)
+        + challenger_ambition * faction_weights::CHALLENGE_AMBITION_CLAIM_WEIGHT;
+    (challenger, average_trust(incumbent_id))
+}
+
+/// Make an agent the leader of their faction
+pub(crate) fn install_leader(
+    faction_registry: &mut FactionRegistry,
+    faction_id: &str,
+    leader_id: &str,
+    membership: &mut FactionMembership,
+) {
+    membership.role = Role::Leader;
+    membership.status_level = Role::Leader.status_level() as u8;
+    if let Some(faction) = faction_registry.get_mut(faction_id) {
+        faction.leader = Some(leader_id.to_string());
+    }
+}
+
+/// Step a deposed leader down to the council
+pub(crate) fn step_down(membership: &mut FactionMembership) {
+    membership.role = Role::CouncilMember;
+    membership.status_level = Role::CouncilMember.status_level() as u8;
+}
+
+fn create_succession_event(
+    tick_events: &mut TickEvents,
+    world_state: &WorldState,
+    successor: &Candidate,
+    faction_id: &str,
+    faction_name: &str,
+    previous_leader: Option<&str>,
+) -> Event {
+    let description = match previous_leader {
+        Some(previous) => format!("{} succeeds {} as leader of {}", successor.name, previous, faction_name),
+        None => format!("{} takes leadership of {}", successor.name, faction_name),
+    };
+
+    Event {
+        event_id: tick_events.generate_id(),
+        timestamp: EventTimestamp {
+            tick: world_state.current_tick,
+            date: world_state.formatted_date(),
+        },
+        event_type: EventType::Faction,
+        subtype: EventSubtype::Faction(FactionSubtype::Promotion),
+        actors: EventActors {
+            primary: ActorSnapshot {
+                agent_id: successor.id.clone(),
+                name: successor.name.clone(),
+                faction: faction_id.to_string(),
+                role: "leader".to_string(),
+                location: successor.location.clone(),
+            },
+            secondary: None,
+            affected: None,
+        },
+        context: EventContext {
+            trigger: "succession".to_string(),
+            preconditions: vec!["leadership_vacant".to_string()],
+            location_description: Some(format!("at {}", successor.location)),
+        },
+        outcome: EventOutcome::General(GeneralOutcome {
+            description: Some(description),
+            state_changes: Vec::new(),
+        }),
+        drama_tags: vec!["succession".to_string(), "leadership_change".to_string()],
+        drama_score: 0.7,
+        connected_events: Vec::new(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::components::agent::Agent;
+    use crate::components::faction::Faction;
+    use crate::components::social::{Relationship, Trust};
+
+    fn spawn_member(world: &mut World, id: &str, faction_id: &str, role: Role, ambition: f32) -> Entity {
+        world
+            .spawn((
+                Agent,
+                AgentId(id.to_string()),
+                AgentName(id.to_string()),
+                Position::new("hall"),
+                Traits {
+                    ambition,
+                    ..Traits::default()
+                },
+                Alive::new(),
+                FactionMembership::new(faction_id, role),
+            ))
+            .id()
+    }
+
+    fn setup_world() -> World {
+        let mut world = World::new();
+        world.insert_resource(WorldState::new());
+        world.insert_resource(RelationshipGraph::new());
+        world.insert_resource(TensionStream::new());
+        world.insert_resource(TickEvents::new());
+
+        let mut registry = FactionRegistry::new();
+        let mut faction = Faction::new("thornwood", "Thornwood", "hall");
+        faction.leader = Some("old_leader".to_string());
+        registry.register(faction);
+        world.insert_resource(registry);
+        world
+    }
+
+    fn run(world: &mut World) {
+        let mut schedule = Schedule::default();
+        schedule.add_systems(handle_succession);
+        schedule.run(world);
+    }
+
+    #[test]
+    fn test_killed_leader_is_replaced() {
+        let mut world = setup_world();
+        let leader = spawn_member(&mut world, "old_leader", "thornwood", Role::Leader, 0.5);
+        spawn_member(&mut world, "laborer", "thornwood", Role::Laborer, 0.5);
+        let loyal = spawn_member(&mut world, "loyal_council", "thornwood", Role::CouncilMember, 0.3);
+        spawn_member(&mut world, "bitter_council", "thornwood", Role::CouncilMember, 0.3);
+        {
+            let mut graph = world.resource_mut::<RelationshipGraph>();
+            graph.set("loyal_council", Relationship::new("old_leader").with_trust(Trust::new(0.6, 0.5, 0.4)));
+            graph.set("bitter_council", Relationship::new("old_leader").with_trust(Trust::new(-0.2, 0.1, 0.4)));
+        }
+
+        // Leader alive: nothing happens
+        run(&mut world);
+        assert!(world.resource::<TickEvents>().is_empty());
+
+        world.get_mut::<Alive>(leader).unwrap().0 = false;
+        run(&mut world);
+
+        let registry = world.resource::<FactionRegistry>();
+        assert_eq!(registry.get("thornwood").unwrap().leader.as_deref(), Some("loyal_council"));
+        assert_eq!(world.get::<FactionMembership>(loyal).unwrap().role, Role::Leader);
+
+        let events = &world.resource::<TickEvents>().events;
+        assert_eq!(events.len(), 1);
+        assert_eq!(events[0].subtype, EventSubtype::Faction(FactionSubtype::Promotion));
+        assert_eq!(events[0].actors.primary.agent_id, "loyal_council");
+    }
+
+    #[test]
+    fn test_ambitious_rivals_create_crisis() {
+        let mut world = setup_world();
+        let leader = spawn_member(&mut world, "old_leader", "thornwood", Role::Leader, 0.5);
+        spawn_member(&mut world, "heir", "thornwood", Role::Reader, 0.2);
+        spawn_member(&mut world, "rival_a", "thornwood", Role::CouncilMember, 0.9);
+        spawn_member(&mut world, "rival_b", "thornwood", Role::Smith, 0.85);
+        world.get_mut::<Alive>(leader).unwrap().0 = false;
+
+        run(&mut world);
+
+        let stream = world.resource::<TensionStream>();
+        let crisis = stream.get("succession_thornwood").expect("rivals should spark a crisis");
+        assert_eq!(crisis.tension_type, TensionType::SuccessionCrisis);
+        assert_eq!(crisis.key_agents.len(), 3);
+    }
+
+    #[test]
+    fn test_empty_faction_stays_leaderless() {
+        let mut world = setup_world();
+        let leader = spawn_member(&mut world, "old_leader", "thornwood", Role::Leader, 0.5);
+        world.get_mut::<Alive>(leader).unwrap().0 = false;
+
+        run(&mut world);
+
+        assert!(world.resource::<FactionRegistry>().get("thornwood").unwrap().leader.is_none());
+        assert!(world.resource::<TickEvents>().is_empty());
+    }
+}
@@ -4,13 +4,15 @@
 //! Tensions are higher-level patterns detected from agent states and relationships.
 
 use bevy_ecs::prelude::*;
-use std::collections::HashMap;
+use std::collections::{BTreeMap, VecDeque};
 
 use crate::components::agent::{AgentId, AgentName, Goals, GoalType, Traits};
 use crate::components::faction::{FactionMembership, FactionRegistry};
 use crate::components::social::{RelationshipGraph, MemoryBank};
 use crate::components::world::WorldState;
+use crate::events::types::EventType;
 use crate::output::tension::{Tension, TensionStream, TensionType};
+use crate::systems::action::TickEvents;
 
 /// Threshold for trust to be considered "eroding" toward betrayal
 const BETRAYAL_TRUST_THRESHOLD: f32 = -0.2;
@@ -27,6 +29,122 @@ const ALLIANCE_TRUST_THRESHOLD: f32 = 0.3;
 /// Detection interval in ticks (don't run every tick for performance)
 const DETECTION_INTERVAL: u64 = 10;
 
+/// Cross-faction conflicts within the window needed before war is brewing
+const FACTION_WAR_MIN_CONFLICTS: usize = 3;
+
+/// Average cross-faction trust must fall below this for a faction war
+const FACTION_WAR_TRUST_THRESHOLD: f32 = -0.1;
+
+/// How far back (in ticks) conflicts count toward a faction war
+const FACTION_WAR_WINDOW_TICKS: u64 = 200;
+
+/// A conflict event between members of two different factions
+#[derive(Debug, Clone)]
+pub struct ConflictRecord {
+    pub tick: u64,
+    pub event_id: String,
+    pub aggressor_id: String,
+    pub aggressor_faction: String,
+    pub target_id: String,
+    pub target_faction: String,
+    pub location: String,
+}
+
+/// Resource: Recent cross-faction conflicts, used to detect faction wars
+#[derive(Resource, Debug)]
+pub struct ConflictHistory {
+    records: VecDeque<ConflictRecord>,
+    /// How many ticks of history to keep
+    pub window_ticks: u64,
+}
+
+impl Default for ConflictHistory {
+    fn default() -> Self {
+        Self {
+            records: VecDeque::new(),
+            window_ticks: FACTION_WAR_WINDOW_TICKS,
+        }
+    }
+}
+
+impl ConflictHistory {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Record a conflict and drop anything older than the window
+    pub fn record(&mut self, record: ConflictRecord) {
+        let cutoff = record.tick.saturating_sub(self.window_ticks);
+        self.records.push_back(record);
+        while self.records.front().is_some_and(|r| r.tick < cutoff) {
+            self.records.pop_front();
+        }
+    }
+
+    /// Conflicts between two factions (in either direction) since `since_tick`
+    pub fn between(&self, faction_a: &str, faction_b: &str, since_tick: u64) -> Vec<&ConflictRecord> {
+        self.records
+            .iter()
+            .filter(|r| r.tick >= since_tick)
+            .filter(|r| {
+                (r.aggressor_faction == faction_a && r.target_faction == faction_b)
+                    || (r.aggressor_faction == faction_b && r.target_faction == faction_a)
+            })
+            .collect()
+    }
+
+    pub fn len(&self) -> usize {
+        self.records.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.records.is_empty()
+    }
+}
+
+/// System to remember this tick's cross-faction conflict events
+pub fn record_conflicts(
+    world_state: Res<WorldState>,
+    tick_events: Res<TickEvents>,
+    mut conflict_history: ResMut<ConflictHistory>,
+    query: Query<(&AgentId, &FactionMembership)>,
+) {
+    for event in &tick_events.events {
+        if event.event_type != EventType::Conflict {
+            continue;
+        }
+        let Some(target) = &event.actors.secondary else {
+            continue;
+        };
+
+        // Conflict events don't carry the target's faction, so resolve both sides here
+        let faction_of = |agent_id: &str| {
+            query
+                .iter()
+                .find(|(id, _)| id.0 == agent_id)
+                .map(|(_, membership)| membership.faction_id.clone())
+        };
+        let (Some(aggressor_faction), Some(target_faction)) =
+            (faction_of(&event.actors.primary.agent_id), faction_of(&target.agent_id))
+        else {
+            continue;
+        };
+        if aggressor_faction == target_faction {
+            continue;
+        }
+
+        conflict_history.record(ConflictRecord {
+            tick: world_state.current_tick,
+            event_id: event.event_id.clone(),
+            aggressor_id: event.actors.primary.agent_id.clone(),
+            aggressor_faction,
+            target_id: target.agent_id.clone(),
+            target_faction,
+            location: event.actors.primary.location.clone(),
+        });
+    }
+}
+
 /// Agent data collected for tension detection
 struct AgentData {
     id: String,
@@ -43,6 +161,7 @@ pub fn detect_tensions(
     relationship_graph: Res<RelationshipGraph>,
     memory_bank: Res<MemoryBank>,
     faction_registry: Res<FactionRegistry>,
+    conflict_history: Res<ConflictHistory>,
     query: Query<(&AgentId, &AgentName, &FactionMembership, &Goals, &Traits)>,
 ) {
     // Only run detection periodically
@@ -53,7 +172,7 @@ pub fn detect_tensions(
     let current_tick = world_state.current_tick;
 
     // Build lookup maps
-    let mut agents_by_faction: HashMap<String, Vec<AgentData>> = HashMap::new();
+    let mut agents_by_faction: BTreeMap<String, Vec<AgentData>> = BTreeMap::new();
     let mut all_agents: Vec<AgentData> = Vec::new();
 
     for (id, name, membership, goals, traits) in query.iter() {
@@ -164,6 +283,19 @@ pub fn detect_tensions(
         current_tick,
     );
 
+    // 10. Detect Faction War
+    detect_faction_war(
+        &mut tension_stream,
+        &relationship_graph,
+        &conflict_history,
+        &faction_registry,
+        &agents_by_faction,
+        current_tick,
+    );
+
+    // Fold duplicate views of the same conflict together before output
+    tension_stream.merge_overlapping();
+
     // Update existing tensions
     update_tension_statuses(&mut tension_stream);
 }
@@ -582,6 +714,125 @@ fn detect_external_threat(
     }
 }
 
+/// Detect faction war: repeated cross-faction conflicts plus collapsing trust
+fn detect_faction_war(
+    tension_stream: &mut TensionStream,
+    relationships: &RelationshipGraph,
+    conflict_history: &ConflictHistory,
+    faction_registry: &FactionRegistry,
+    agents_by_faction: &BTreeMap<String, Vec<AgentData>>,
+    current_tick: u64,
+) {
+    let since_tick = current_tick.saturating_sub(conflict_history.window_ticks);
+    let faction_ids = faction_registry.faction_ids();
+
+    for (i, faction_a) in faction_ids.iter().enumerate() {
+        for faction_b in faction_ids.iter().skip(i + 1) {
+            let tension_id = format!("war_{}_vs_{}", faction_a, faction_b);
+            let conflicts = conflict_history.between(faction_a, faction_b, since_tick);
+
+            let members_a = agents_by_faction.get(*faction_a).map(|v| v.as_slice()).unwrap_or(&[]);
+            let members_b = agents_by_faction.get(*faction_b).map(|v| v.as_slice()).unwrap_or(&[]);
+            let avg_trust = average_cross_faction_trust(relationships, members_a, members_b);
+
+            let hostile = conflicts.len() >= FACTION_WAR_MIN_CONFLICTS
+                && avg_trust.is_some_and(|t| t < FACTION_WAR_TRUST_THRESHOLD);
+
+            if !hostile {
+                if let Some(existing) = tension_stream.get_mut(&tension_id) {
+                    // Hostilities cooling off
+                    existing.update_severity(0.1, current_tick);
+                }
+                continue;
+            }
+
+            let avg_trust = avg_trust.unwrap_or(0.0);
+            let intensity = conflicts.len() as f32 / (FACTION_WAR_MIN_CONFLICTS * 3) as f32;
+            let severity = (0.4 + intensity * 0.4 - avg_trust * 0.3).clamp(0.4, 1.0);
+
+            if let Some(existing) = tension_stream.get_mut(&tension_id) {
+                existing.update_severity(severity, current_tick);
+                for record in &conflicts {
+                    if !existing.trigger_events.contains(&record.event_id) {
+                        existing.add_trigger_event(&record.event_id);
+                    }
+                }
+                continue;
+            }
+
+            let name_of = |id: &str| {
+                faction_registry.get(id).map(|f| f.name.clone()).unwrap_or_else(|| id.to_string())
+            };
+            let mut tension = Tension::new(
+                &tension_id,
+                TensionType::FactionWar,
+                current_tick,
+                format!(
+                    "Bloodshed between {} and {} is becoming open war",
+                    name_of(faction_a),
+                    name_of(faction_b)
+                ),
+            );
+            tension.severity = severity;
+            tension.confidence = (conflicts.len() as f32 / 10.0).clamp(0.5, 0.9);
+
+            // Most active combatants first
+            let mut involvement: BTreeMap<&str, usize> = BTreeMap::new();
+            for record in &conflicts {
+                *involvement.entry(record.aggressor_id.as_str()).or_default() += 1;
+                *involvement.entry(record.target_id.as_str()).or_default() += 1;
+            }
+            let mut combatants: Vec<(&str, usize)> = involvement.into_iter().collect();
+            combatants.sort_by_key(|&(_, count)| std::cmp::Reverse(count));
+            for (agent_id, _) in combatants.iter().take(4) {
+                let role = if conflicts.iter().any(|r| r.aggressor_id == *agent_id) {
+                    "aggressor"
+                } else {
+                    "victim"
+                };
+                tension.add_agent(*agent_id, role, "hardening");
+            }
+
+            for record in &conflicts {
+                if !tension.key_locations.contains(&record.location) {
+                    tension.add_location(&record.location);
+                }
+                tension.add_trigger_event(&record.event_id);
+            }
+
+            tension.add_predicted_outcome("open_war", (severity * 0.6).clamp(0.2, 0.7), "very_high");
+            tension.add_predicted_outcome("uneasy_truce", 0.3, "medium");
+            tension.narrative_hooks.push("Every skirmish leaves a debt to be repaid".to_string());
+            tension_stream.upsert(tension);
+        }
+    }
+}
+
+/// Average trust members of one faction hold toward the other, both ways.
+/// Returns `None` if no cross-faction relationships exist.
+fn average_cross_faction_trust(
+    relationships: &RelationshipGraph,
+    members_a: &[AgentData],
+    members_b: &[AgentData],
+) -> Option<f32> {
+    let mut total = 0.0;
+    let mut count = 0;
+
+    for a in members_a {
+        for b in members_b {
+            for rel in [relationships.get(&a.id, &b.id), relationships.get(&b.id, &a.id)]
+                .into_iter()
+                .flatten()
+            {
+                total += rel.trust.overall();
+                count += 1;
+            }
+        }
+    }
+
+    (count > 0).then(|| total / count as f32)
+}
+
 /// Update tension statuses and cleanup resolved ones
 fn update_tension_statuses(tension_stream: &mut TensionStream) {
     // Cleanup resolved tensions periodically
@@ -633,6 +884,7 @@ mod tests {
         // Insert required resources
         world.insert_resource(WorldState::new());
         world.insert_resource(TensionStream::new());
+        world.insert_resource(ConflictHistory::new());
         world.insert_resource(RelationshipGraph::new());
         world.insert_resource(MemoryBank::new());
 
@@ -728,6 +980,7 @@ mod tests {
         // Insert required resources
         world.insert_resource(WorldState::new());
         world.insert_resource(TensionStream::new());
+        world.insert_resource(ConflictHistory::new());
         world.insert_resource(RelationshipGraph::new());
         world.insert_resource(MemoryBank::new());
 
@@ -821,6 +1074,7 @@ mod tests {
         // Insert required resources
         world.insert_resource(WorldState::new());
         world.insert_resource(TensionStream::new());
+        world.insert_resource(ConflictHistory::new());
         world.insert_resource(RelationshipGraph::new());
         world.insert_resource(MemoryBank::new());
 
@@ -871,6 +1125,7 @@ mod tests {
         // Insert required resources
         world.insert_resource(WorldState::new());
         world.insert_resource(TensionStream::new());
+        world.insert_resource(ConflictHistory::new());
         world.insert_resource(RelationshipGraph::new());
         world.insert_resource(MemoryBank::new());
 
@@ -961,6 +1216,7 @@ mod tests {
         // Insert required resources
         world.insert_resource(WorldState::new());
         world.insert_resource(TensionStream::new());
+        world.insert_resource(ConflictHistory::new());
         world.insert_resource(RelationshipGraph::new());
         world.insert_resource(MemoryBank::new());
 
@@ -998,4 +1254,115 @@ mod tests {
         assert!(types.contains(&TensionType::ResourceConflict));
         assert!(types.contains(&TensionType::RevengeArc));
     }
+
+    #[test]
+    fn test_faction_war_detection() {
+        let mut world = World::new();
+
+        world.insert_resource(WorldState::new());
+        world.insert_resource(TensionStream::new());
+        world.insert_resource(ConflictHistory::new());
+        world.insert_resource(RelationshipGraph::new());
+        world.insert_resource(MemoryBank::new());
+
+        let mut faction_registry = FactionRegistry::new();
+        faction_registry.register(Faction::new("thornwood", "Thornwood", "thornwood_hall"));
+        faction_registry.register(Faction::new("ironmere", "Ironmere", "ironmere_keep"));
+        world.insert_resource(faction_registry);
+
+        spawn_agent(&mut world, "raider_a", "Raider A", "thornwood", Role::Laborer, 0.5, 0.5);
+        spawn_agent(&mut world, "raider_b", "Raider B", "thornwood", Role::Laborer, 0.5, 0.5);
+        spawn_agent(&mut world, "guard_a", "Guard A", "ironmere", Role::Laborer, 0.5, 0.5);
+
+        // Mutual hostility across the faction line
+        {
+            let mut graph = world.resource_mut::<RelationshipGraph>();
+            for (from, to) in [("raider_a", "guard_a"), ("guard_a", "raider_a"), ("raider_b", "guard_a")] {
+                graph.set(from, Relationship::new(to).with_trust(Trust::new(-0.6, -0.5, 0.0)));
+            }
+        }
+
+        // Seed hostile events: one conflict per tick for several ticks
+        let mut schedule = Schedule::default();
+        schedule.add_systems((record_conflicts, detect_tensions.after(record_conflicts)));
+
+        world.insert_resource(TickEvents::new());
+        for (i, aggressor) in ["raider_a", "raider_b", "raider_a", "raider_b"].iter().enumerate() {
+            let tick = DETECTION_INTERVAL * 2 - 3 + i as u64;
+            world.resource_mut::<WorldState>().current_tick = tick;
+            let event_id = format!("evt_{:08}", i);
+            let mut event = test_conflict_event(&event_id, aggressor, "guard_a");
+            event.timestamp.tick = tick;
+            world.resource_mut::<TickEvents>().events = vec![event];
+            schedule.run(&mut world);
+        }
+
+        assert_eq!(world.resource::<ConflictHistory>().len(), 4);
+
+        let tension_stream = world.resource::<TensionStream>();
+        let war = tension_stream
+            .get("war_ironmere_vs_thornwood")
+            .expect("Should detect FactionWar after repeated cross-faction conflicts");
+        assert_eq!(war.tension_type, TensionType::FactionWar);
+        assert!(war.severity >= 0.4);
+        assert!(war.key_agents.iter().any(|a| a.agent_id == "guard_a"));
+        assert_eq!(war.key_locations, vec!["border".to_string()]);
+        assert!(!war.predicted_outcomes.is_empty());
+        assert_eq!(war.trigger_events.len(), 4);
+    }
+
+    #[test]
+    fn test_same_faction_conflicts_not_recorded() {
+        let mut world = World::new();
+        world.insert_resource(WorldState::new());
+        world.insert_resource(ConflictHistory::new());
+        spawn_agent(&mut world, "a", "A", "thornwood", Role::Laborer, 0.5, 0.5);
+        spawn_agent(&mut world, "b", "B", "thornwood", Role::Laborer, 0.5, 0.5);
+
+        let mut tick_events = TickEvents::new();
+        tick_events.push(test_conflict_event("evt_00000001", "a", "b"));
+        world.insert_resource(tick_events);
+
+        let mut schedule = Schedule::default();
+        schedule.add_systems(record_conflicts);
+        schedule.run(&mut world);
+
+        assert!(world.resource::<ConflictHistory>().is_empty());
+    }
+
+    fn test_conflict_event(event_id: &str, aggressor: &str, target: &str) -> crate::events::types::Event {
+        use crate::events::types::*;
+
+        let snapshot = |id: &str| ActorSnapshot {
+            agent_id: id.to_string(),
+            name: id.to_string(),
+            faction: "unknown".to_string(),
+            role: "combatant".to_string(),
+            location: "border".to_string(),
+        };
+
+        Event {
+            event_id: event_id.to_string(),
+            timestamp: EventTimestamp { tick: 0, date: "year_1.spring.day_1".to_string() },
+            event_type: EventType::Conflict,
+            subtype: EventSubtype::Conflict(ConflictSubtype::Fight),
+            actors: EventActors {
+                primary: snapshot(aggressor),
+                secondary: Some(snapshot(target)),
+                affected: None,
+            },
+            context: EventContext {
+                trigger: "physical_altercation".to_string(),
+                preconditions: Vec::new(),
+                location_description: None,
+            },
+            outcome: EventOutcome::General(GeneralOutcome {
+                description: None,
+                state_changes: Vec::new(),
+            }),
+            drama_tags: vec!["violence".to_string()],
+            drama_score: 0.6,
+            connected_events: Vec::new(),
+        }
+    }
 }
@@ -0,0 +1,236 @@
+//! Territory System
+//!
+//! Detects locations where members of rival factions rub shoulders. Shared
+//! ground breeds territorial friction, which makes conflict there more likely.
+
+use bevy_ecs::prelude::*;
+use std::collections::{BTreeMap, BTreeSet};
+
+use crate::components::agent::{AgentId, Alive};
+use crate::components::faction::{FactionMembership, FactionRegistry};
+use crate::components::social::RelationshipGraph;
+use crate::components::world::{LocationProperty, LocationRegistry, Position};
+
+/// Average cross-faction trust below which co-located factions count as rivals
+const RIVAL_TRUST_THRESHOLD: f32 = 0.0;
+
+/// Resource: Rival faction pairs sharing each location this tick
+#[derive(Resource, Debug, Default)]
+pub struct TerritorialFriction {
+    /// Maps location_id -> rival faction pairs present, each pair sorted
+    contested: BTreeMap<String, BTreeSet<(String, String)>>,
+}
+
+impl TerritorialFriction {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Mark two factions as contesting a location
+    pub fn contest(&mut self, location_id: &str, faction_a: &str, faction_b: &str) {
+        self.contested
+            .entry(location_id.to_string())
+            .or_default()
+            .insert(ordered_pair(faction_a, faction_b));
+    }
+
+    /// Check whether two factions are contesting a location
+    pub fn is_contested(&self, location_id: &str, faction_a: &str, faction_b: &str) -> bool {
+        self.contested
+            .get(location_id)
+            .is_some_and(|pairs| pairs.contains(&ordered_pair(faction_a, faction_b)))
+    }
+
+    /// Locations with at least one rival pair present
+    pub fn contested_locations(&self) -> impl Iterator<Item = &String> {
+        self.contested.keys()
+    }
+
+    pub fn clear(&mut self) {
+        self.contested.clear();
+    }
+}
+
+fn ordered_pair(a: &str, b: &str) -> (String, String) {
+    if a <= b {
+        (a.to_string(), b.to_string())
+    } else {
+        (b.to_string(), a.to_string())
+    }
+}
+
+/// System to find rival factions sharing non-neutral locations
+///
+/// Two factions are rivals at a location if they are not allied and their
+/// members there distrust each other on average. Locations flagged
+/// `LocationProperty::Neutral` (markets, meeting grounds) never breed friction.
+pub fn detect_territorial_friction(
+    location_registry: Res<LocationRegistry>,
+    faction_registry: Res<FactionRegistry>,
+    relationship_graph: Res<RelationshipGraph>,
+    mut friction: ResMut<TerritorialFriction>,
+    query: Query<(&AgentId, &Position, &FactionMembership, Option<&Alive>)>,
+) {
+    friction.clear();
+
+    // location -> faction -> members present
+    let mut presence: BTreeMap<&str, BTreeMap<&str, Vec<&str>>> = BTreeMap::new();
+    for (agent_id, position, membership, alive) in query.iter() {
+        if alive.is_some_and(|a| !a.is_alive()) {
+            continue;
+        }
+        presence
+            .entry(position.location_id.as_str())
+            .or_default()
+            .entry(membership.faction_id.as_str())
+            .or_default()
+            .push(agent_id.0.as_str());
+    }
+
+    for (location_id, factions) in presence {
+        let neutral = location_registry
+            .get(location_id)
+            .is_some_and(|loc| loc.has_property(&LocationProperty::Neutral));
+        if neutral || factions.len() < 2 {
+            continue;
+        }
+
+        let factions: Vec<(&str, Vec<&str>)> = factions.into_iter().collect();
+        for (i, (faction_a, members_a)) in factions.iter().enumerate() {
+            for (faction_b, members_b) in &factions[i + 1..] {
+                if faction_registry.are_allied(faction_a, faction_b) {
+                    continue;
+                }
+                let trust = average_trust(&relationship_graph, members_a, members_b);
+                if trust < RIVAL_TRUST_THRESHOLD {
+                    friction.contest(location_id, faction_a, faction_b);
+                }
+            }
+        }
+    }
+}
+
+/// Average overall trust across both directions between two groups
+fn average_trust(graph: &RelationshipGraph, group_a: &[&str], group_b: &[&str]) -> f32 {
+    let mut total = 0.0;
+    let mut count = 0;
+    for a in group_a {
+        for b in group_b {
+            for (from, to) in [(a, b), (b, a)] {
+                total += graph.get(from, to).map(|r| r.trust.overall()).unwrap_or(0.0);
+                count += 1;
+            }
+        }
+    }
+    if count == 0 {
+        0.0
+    } else {
+        total / count as f32
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::components::agent::{Goals, Needs, Role, Traits};
+    use crate::components::faction::Faction;
+    use crate::components::social::{Relationship, Trust};
+    use crate::components::world::{Location, LocationType};
+    use crate::systems::action::{generate_conflict_actions, Action, PendingActions};
+    use crate::systems::perception::AgentsByLocation;
+
+    /// Total conflict weight `hothead` gets toward `rival`, given where each stands
+    fn conflict_weight(hothead_at: &str, rival_at: &str, neutral: bool) -> f32 {
+        let mut world = World::new();
+        let mut registry = FactionRegistry::new();
+        registry.register(Faction::new("thornwood", "Thornwood", "hall"));
+        registry.register(Faction::new("ironmere", "Ironmere", "keep"));
+        world.insert_resource(registry);
+        world.insert_resource(PendingActions::new());
+        world.insert_resource(TerritorialFriction::new());
+
+        let mut locations = LocationRegistry::new();
+        let mut square = Location::new("square", "Square", LocationType::Village);
+        if neutral {
+            square = square.with_properties(vec![LocationProperty::Neutral]);
+        }
+        locations.register(square);
+        locations.register(Location::new("fields", "Fields", LocationType::Fields));
+        world.insert_resource(locations);
+
+        let mut by_location = AgentsByLocation::new();
+        by_location.add(hothead_at, "hothead");
+        by_location.add(rival_at, "rival");
+        world.insert_resource(by_location);
+
+        let mut graph = RelationshipGraph::new();
+        graph.set("hothead", Relationship::new("rival").with_trust(Trust::new(-0.6, -0.6, 0.0)));
+        graph.set("rival", Relationship::new("hothead").with_trust(Trust::new(-0.6, -0.6, 0.0)));
+        world.insert_resource(graph);
+
+        for (id, faction, location) in [("hothead", "thornwood", hothead_at), ("rival", "ironmere", rival_at)] {
+            world.spawn((
+                AgentId(id.to_string()),
+                Position::new(location),
+                FactionMembership::new(faction, Role::Laborer),
+                Needs::default(),
+                Traits { boldness: 0.8, ..Traits::default() },
+                Goals::new(),
+            ));
+        }
+
+        let mut schedule = Schedule::default();
+        schedule.add_systems((detect_territorial_friction, generate_conflict_actions).chain());
+        schedule.run(&mut world);
+
+        world
+            .resource::<PendingActions>()
+            .get("hothead")
+            .into_iter()
+            .flatten()
+            .filter(|wa| matches!(wa.action, Action::Conflict(_)))
+            .map(|wa| wa.weight)
+            .sum()
+    }
+
+    #[test]
+    fn test_co_located_rivals_raise_conflict_weights() {
+        let contested = conflict_weight("square", "square", false);
+        let on_neutral_ground = conflict_weight("square", "square", true);
+        let apart = conflict_weight("square", "fields", false);
+
+        assert_eq!(apart, 0.0);
+        assert!(on_neutral_ground > 0.0);
+        assert!(contested > on_neutral_ground);
+    }
+
+    #[test]
+    fn test_allies_do_not_contest_territory() {
+        let mut world = World::new();
+        let mut registry = FactionRegistry::new();
+        registry.register(Faction::new("thornwood", "Thornwood", "hall"));
+        registry.register(Faction::new("ironmere", "Ironmere", "keep"));
+        registry.form_alliance("thornwood", "ironmere");
+        world.insert_resource(registry);
+        world.insert_resource(LocationRegistry::new());
+        world.insert_resource(TerritorialFriction::new());
+
+        let mut graph = RelationshipGraph::new();
+        graph.set("a", Relationship::new("b").with_trust(Trust::new(-0.6, -0.6, 0.0)));
+        world.insert_resource(graph);
+
+        for (id, faction) in [("a", "thornwood"), ("b", "ironmere")] {
+            world.spawn((
+                AgentId(id.to_string()),
+                Position::new("square"),
+                FactionMembership::new(faction, Role::Laborer),
+            ));
+        }
+
+        let mut schedule = Schedule::default();
+        schedule.add_systems(detect_territorial_friction);
+        schedule.run(&mut world);
+
+        assert!(!world.resource::<TerritorialFriction>().is_contested("square", "thornwood", "ironmere"));
+    }
+}
@@ -3,10 +3,12 @@
 //! Systems for processing trust updates from events and forming grudges.
 
 use bevy_ecs::prelude::*;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
 
 use crate::components::agent::{AgentId, Goal, GoalType, Goals, Traits};
 use crate::components::social::RelationshipGraph;
-use crate::components::world::WorldState;
+use crate::components::world::{Position, WorldState};
 
 /// Constants for grudge formation
 pub mod grudge_constants {
@@ -20,6 +22,25 @@ pub mod grudge_constants {
     pub const REVENGE_PRIORITY: f32 = 0.7;
 }
 
+/// How much longer than the base duration an agent's grudges last
+pub(crate) fn persistence_multiplier(persistence: f32) -> f32 {
+    1.0 + persistence * (grudge_constants::MAX_PERSISTENCE_MULTIPLIER - 1.0)
+}
+
+/// Constants for trust recovery
+pub mod trust_weights {
+    /// Fraction of remaining damage a fully forgiving agent recovers per positive interaction
+    pub const FORGIVENESS_RATE: f32 = 0.15;
+    /// Maximum recovery per positive interaction, so one gift can't undo a betrayal
+    pub const MAX_FORGIVENESS_PER_EVENT: f32 = 0.04;
+    /// Reliability a betrayed agent loses in their betrayer
+    pub const BETRAYED_RELIABILITY_LOSS: f32 = 0.6;
+    /// Alignment a betrayed agent loses in their betrayer; kept small so old sympathies linger
+    pub const BETRAYED_ALIGNMENT_LOSS: f32 = 0.1;
+    /// Alignment a betrayer loses toward their victim out of guilt
+    pub const BETRAYER_GUILT_ALIGNMENT_LOSS: f32 = 0.02;
+}
+
 /// Represents a trust-affecting event to be processed
 #[derive(Debug, Clone)]
 pub struct TrustEvent {
@@ -48,12 +69,83 @@ pub enum TrustEventType {
     CapabilityFailed,
     /// Betrayal (major negative - faction betrayal, theft, etc.)
     Betrayal,
+    /// The agent was betrayed by the target. Unlike `Betrayal`, this updates
+    /// both directions: the victim's reliability in the betrayer craters while
+    /// the betrayer's view of the victim barely moves.
+    BetrayedBy,
     /// Shared a secret that was kept
     SecretKept,
     /// Secret was leaked
     SecretLeaked,
 }
 
+/// Why an agent holds a grudge: who wronged them, where, and how badly
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Grudge {
+    /// Agent holding the grudge
+    pub holder_id: String,
+    /// Agent the grudge is held against
+    pub offender_id: String,
+    /// Event that started it, if known
+    pub origin_event: Option<String>,
+    /// Where the holder was when it happened
+    pub location: Option<String>,
+    /// How strongly it is held (0.0 to 1.0); fades over time
+    pub severity: f32,
+    /// Tick the grudge formed
+    pub formed_tick: u64,
+}
+
+/// Resource: Grudges agents hold, keyed by (holder, offender)
+#[derive(Resource, Debug, Default)]
+pub struct GrudgeLedger {
+    grudges: BTreeMap<(String, String), Grudge>,
+}
+
+impl GrudgeLedger {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Record a grudge. A fresh offense against someone already resented
+    /// deepens the grudge but keeps the inciting incident.
+    pub fn record(&mut self, grudge: Grudge) {
+        let key = (grudge.holder_id.clone(), grudge.offender_id.clone());
+        match self.grudges.get_mut(&key) {
+            Some(existing) => existing.severity = existing.severity.max(grudge.severity),
+            None => {
+                self.grudges.insert(key, grudge);
+            }
+        }
+    }
+
+    /// Get the grudge one agent holds against another
+    pub fn get(&self, holder_id: &str, offender_id: &str) -> Option<&Grudge> {
+        self.grudges.get(&(holder_id.to_string(), offender_id.to_string()))
+    }
+
+    /// All grudges, ordered by holder then offender
+    pub fn all(&self) -> impl Iterator<Item = &Grudge> {
+        self.grudges.values()
+    }
+
+    pub fn len(&self) -> usize {
+        self.grudges.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.grudges.is_empty()
+    }
+
+    /// Fade each grudge by `rate(holder_id)` and forget those that fade out
+    pub fn decay(&mut self, rate: impl Fn(&str) -> f32) {
+        for grudge in self.grudges.values_mut() {
+            grudge.severity -= rate(&grudge.holder_id);
+        }
+        self.grudges.retain(|_, grudge| grudge.severity > 0.0);
+    }
+}
+
 /// Resource: Queue of trust events to process
 #[derive(Resource, Debug, Default)]
 pub struct TrustEventQueue {
@@ -83,14 +175,15 @@ pub fn process_trust_events(
     world_state: Res<WorldState>,
     mut trust_events: ResMut<TrustEventQueue>,
     mut relationship_graph: ResMut<RelationshipGraph>,
-    mut query: Query<(&AgentId, &Traits, &mut Goals)>,
+    mut grudge_ledger: ResMut<GrudgeLedger>,
+    mut query: Query<(&AgentId, &Traits, &mut Goals, Option<&Position>)>,
 ) {
     let events = trust_events.drain();
 
     // Build lookup for agent traits
     let traits_map: std::collections::HashMap<String, (Traits, Entity)> = query
         .iter()
-        .map(|(id, traits, _)| (id.0.clone(), (traits.clone(), Entity::PLACEHOLDER)))
+        .map(|(id, traits, _, _)| (id.0.clone(), (traits.clone(), Entity::PLACEHOLDER)))
         .collect();
 
     for event in events {
@@ -101,6 +194,16 @@ pub fn process_trust_events(
             TrustEventType::PositiveInteraction => {
                 rel.trust.apply_positive_interaction(1.0);
                 rel.last_interaction_tick = world_state.current_tick;
+
+                // Honest, sociable agents let go of old hurts more readily
+                let disposition = traits_map
+                    .get(&event.agent_id)
+                    .map(|(t, _)| (t.honesty + t.sociability) / 2.0)
+                    .unwrap_or(0.5);
+                rel.trust.apply_forgiveness(
+                    trust_weights::FORGIVENESS_RATE * disposition,
+                    trust_weights::MAX_FORGIVENESS_PER_EVENT,
+                );
             }
             TrustEventType::PromiseKept => {
                 rel.trust.update_reliability(0.1);
@@ -117,6 +220,11 @@ pub fn process_trust_events(
             TrustEventType::Betrayal => {
                 rel.trust.apply_betrayal();
             }
+            TrustEventType::BetrayedBy => {
+                rel.trust.update_reliability(-trust_weights::BETRAYED_RELIABILITY_LOSS);
+                rel.trust.update_alignment(-trust_weights::BETRAYED_ALIGNMENT_LOSS);
+                rel.last_interaction_tick = world_state.current_tick;
+            }
             TrustEventType::SecretKept => {
                 rel.trust.update_reliability(0.15);
             }
@@ -131,10 +239,19 @@ pub fn process_trust_events(
             && matches!(
                 event.event_type,
                 TrustEventType::Betrayal
+                    | TrustEventType::BetrayedBy
                     | TrustEventType::PromiseBroken
                     | TrustEventType::SecretLeaked
             );
 
+        // The betrayer's own view of the victim only picks up a twinge of guilt
+        if event.event_type == TrustEventType::BetrayedBy {
+            relationship_graph
+                .ensure_relationship(&event.target_id, &event.agent_id)
+                .trust
+                .update_alignment(-trust_weights::BETRAYER_GUILT_ALIGNMENT_LOSS);
+        }
+
         if should_form_grudge {
             // Get agent's grudge persistence to determine duration
             let persistence = traits_map
@@ -143,12 +260,21 @@ pub fn process_trust_events(
                 .unwrap_or(0.5);
 
             // Calculate revenge goal duration
-            let duration_multiplier = 1.0 + persistence * (grudge_constants::MAX_PERSISTENCE_MULTIPLIER - 1.0);
-            let duration = (grudge_constants::BASE_REVENGE_DURATION as f32 * duration_multiplier) as u64;
+            let duration =
+                (grudge_constants::BASE_REVENGE_DURATION as f32 * persistence_multiplier(persistence)) as u64;
 
             // Find the agent and add revenge goal
-            for (agent_id, _traits, mut goals) in query.iter_mut() {
+            for (agent_id, _traits, mut goals, position) in query.iter_mut() {
                 if agent_id.0 == event.agent_id {
+                    grudge_ledger.record(Grudge {
+                        holder_id: event.agent_id.clone(),
+                        offender_id: event.target_id.clone(),
+                        origin_event: event.origin_event.clone(),
+                        location: position.map(|p| p.location_id.clone()),
+                        severity: (-trust_overall).clamp(0.0, 1.0),
+                        formed_tick: world_state.current_tick,
+                    });
+
                     // Only add if they don't already have a revenge goal against this target
                     let has_existing = goals.goals.iter().any(|g| {
                         g.goal_type == GoalType::Revenge
@@ -177,14 +303,25 @@ pub fn process_trust_events(
 }
 
 /// System: Decay grudges over time based on trait
-/// Removes expired revenge goals
+/// Removes expired revenge goals and fades grudge records, so a grudge of full
+/// severity lasts about as long as the revenge goal it came with
 pub fn decay_grudges(
     world_state: Res<WorldState>,
-    mut query: Query<(&AgentId, &mut Goals)>,
+    mut grudge_ledger: ResMut<GrudgeLedger>,
+    mut query: Query<(&AgentId, &mut Goals, Option<&Traits>)>,
 ) {
-    for (_agent_id, mut goals) in query.iter_mut() {
+    let mut persistence: std::collections::HashMap<String, f32> = std::collections::HashMap::new();
+    for (agent_id, mut goals, traits) in query.iter_mut() {
         goals.remove_expired(world_state.current_tick);
+        if let Some(traits) = traits {
+            persistence.insert(agent_id.0.clone(), traits.grudge_persistence);
+        }
     }
+
+    grudge_ledger.decay(|holder_id| {
+        let persistence = persistence.get(holder_id).copied().unwrap_or(0.5);
+        1.0 / (grudge_constants::BASE_REVENGE_DURATION as f32 * persistence_multiplier(persistence))
+    });
 }
 
 /// Helper function to create a trust event
@@ -240,4 +377,136 @@ mod tests {
         assert!(high_duration > low_duration);
         assert!(high_duration <= grudge_constants::BASE_REVENGE_DURATION * 3);
     }
+
+    #[test]
+    fn test_betrayal_hurts_victim_far_more_than_betrayer() {
+        use crate::components::social::{Relationship, Trust};
+
+        let mut world = World::new();
+        world.insert_resource(WorldState::new());
+        let mut graph = RelationshipGraph::new();
+        graph.set("victim", Relationship::new("betrayer").with_trust(Trust::new(0.5, 0.5, 0.5)));
+        graph.set("betrayer", Relationship::new("victim").with_trust(Trust::new(0.5, 0.5, 0.5)));
+        world.insert_resource(graph);
+        let mut queue = TrustEventQueue::new();
+        queue.push(create_trust_event("victim", "betrayer", TrustEventType::BetrayedBy, None));
+        world.insert_resource(queue);
+        world.insert_resource(GrudgeLedger::new());
+
+        for id in ["victim", "betrayer"] {
+            world.spawn((AgentId(id.to_string()), Traits::default(), Goals::new()));
+        }
+
+        let mut schedule = Schedule::default();
+        schedule.add_systems(process_trust_events);
+        schedule.run(&mut world);
+
+        let graph = world.resource::<RelationshipGraph>();
+        let victim_view = &graph.get("victim", "betrayer").unwrap().trust;
+        let betrayer_view = &graph.get("betrayer", "victim").unwrap().trust;
+
+        // The victim's faith in the betrayer collapses, but some sympathy lingers
+        assert!(victim_view.reliability < 0.0);
+        assert!(0.5 - victim_view.reliability >= trust_weights::BETRAYED_RELIABILITY_LOSS - 1e-5);
+        assert!(victim_view.alignment > 0.0);
+        // The betrayer's view is nearly unchanged
+        assert_eq!(betrayer_view.reliability, 0.5);
+        assert!(0.5 - betrayer_view.alignment < 0.05);
+    }
+
+    #[test]
+    fn test_grudge_records_origin_and_fades() {
+        use crate::components::social::{Relationship, Trust};
+
+        let mut world = World::new();
+        world.insert_resource(WorldState::new());
+        world.insert_resource(GrudgeLedger::new());
+        let mut graph = RelationshipGraph::new();
+        graph.set("victim", Relationship::new("betrayer").with_trust(Trust::new(-0.2, -0.2, 0.0)));
+        world.insert_resource(graph);
+        let mut queue = TrustEventQueue::new();
+        queue.push(create_trust_event(
+            "victim",
+            "betrayer",
+            TrustEventType::BetrayedBy,
+            Some("evt_00042".to_string()),
+        ));
+        world.insert_resource(queue);
+
+        world.spawn((
+            AgentId("victim".to_string()),
+            Traits { grudge_persistence: 0.0, ..Traits::default() },
+            Goals::new(),
+            Position::new("eastern_bridge"),
+        ));
+
+        let mut schedule = Schedule::default();
+        schedule.add_systems(process_trust_events);
+        schedule.run(&mut world);
+
+        let grudge = world.resource::<GrudgeLedger>().get("victim", "betrayer").unwrap().clone();
+        assert_eq!(grudge.origin_event.as_deref(), Some("evt_00042"));
+        assert_eq!(grudge.location.as_deref(), Some("eastern_bridge"));
+        assert!(grudge.severity > 0.0);
+
+        // Grudges fade tick by tick until they are forgotten
+        let mut decay = Schedule::default();
+        decay.add_systems(decay_grudges);
+        decay.run(&mut world);
+        let faded = world.resource::<GrudgeLedger>().get("victim", "betrayer").unwrap().severity;
+        assert!(faded < grudge.severity);
+
+        for _ in 0..grudge_constants::BASE_REVENGE_DURATION {
+            decay.run(&mut world);
+        }
+        assert!(world.resource::<GrudgeLedger>().is_empty());
+    }
+
+    #[test]
+    fn test_gifts_partially_restore_soured_trust() {
+        use crate::components::social::{Relationship, Trust};
+
+        fn soured_trust_after_gifts(honesty: f32, sociability: f32, gifts: usize) -> f32 {
+            let mut world = World::new();
+            world.insert_resource(WorldState::new());
+            world.insert_resource(TrustEventQueue::new());
+            world.insert_resource(GrudgeLedger::new());
+
+            let mut graph = RelationshipGraph::new();
+            graph.set("victim", Relationship::new("betrayer").with_trust(Trust::new(-0.7, -0.6, 0.2)));
+            world.insert_resource(graph);
+
+            world.spawn((
+                AgentId("victim".to_string()),
+                Traits { honesty, sociability, ..Traits::default() },
+                Goals::new(),
+            ));
+
+            let mut schedule = Schedule::default();
+            schedule.add_systems(process_trust_events);
+            for _ in 0..gifts {
+                world.resource_mut::<TrustEventQueue>().push(create_trust_event(
+                    "victim",
+                    "betrayer",
+                    TrustEventType::PositiveInteraction,
+                    None,
+                ));
+                schedule.run(&mut world);
+            }
+
+            world.resource::<RelationshipGraph>().get("victim", "betrayer").unwrap().trust.overall()
+        }
+
+        let before = soured_trust_after_gifts(0.8, 0.8, 0);
+        let one_gift = soured_trust_after_gifts(0.8, 0.8, 1);
+        let forgiving = soured_trust_after_gifts(0.8, 0.8, 5);
+        let unforgiving = soured_trust_after_gifts(0.1, 0.1, 5);
+
+        // A single gift barely dents a major betrayal
+        assert!(one_gift - before < 0.1);
+        // Several gifts help, more so for forgiving agents, but don't erase it
+        assert!(forgiving > unforgiving);
+        assert!(unforgiving > before);
+        assert!(forgiving < 0.0);
+    }
 }
@@ -0,0 +1,79 @@
+//! Deterministic replay verification
+//!
+//! Runs the simulation binary twice with the same seed and checks that the
+//! snapshots and event log it writes are byte-for-byte identical.
+
+use std::collections::hash_map::DefaultHasher;
+use std::fs;
+use std::hash::{Hash, Hasher};
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+const SEED: &str = "42";
+const TICKS: &str = "200";
+const SNAPSHOT_INTERVAL: &str = "50";
+
+/// Run the simulation in a fresh working directory and return that directory
+fn run_simulation(label: &str) -> PathBuf {
+    let dir = std::env::temp_dir().join(format!(
+        "emergent_sim_determinism_{}_{}",
+        std::process::id(),
+        label
+    ));
+    let _ = fs::remove_dir_all(&dir);
+    fs::create_dir_all(&dir).expect("failed to create run directory");
+
+    let status = Command::new(env!("CARGO_BIN_EXE_emergent_sim"))
+        .args([
+            "--seed",
+            SEED,
+            "--ticks",
+            TICKS,
+            "--snapshot-interval",
+            SNAPSHOT_INTERVAL,
+        ])
+        .current_dir(&dir)
+        .status()
+        .expect("failed to launch simulation");
+    assert!(status.success(), "simulation run '{}' failed", label);
+
+    dir
+}
+
+/// Hash every snapshot (in filename order) followed by the event log
+fn hash_outputs(dir: &Path) -> (u64, usize) {
+    let snapshot_dir = dir.join("output/snapshots");
+    let mut snapshots: Vec<PathBuf> = fs::read_dir(&snapshot_dir)
+        .expect("snapshot directory missing")
+        .filter_map(|entry| entry.ok().map(|e| e.path()))
+        .filter(|path| path.extension().is_some_and(|ext| ext == "json"))
+        .collect();
+    snapshots.sort();
+
+    let mut hasher = DefaultHasher::new();
+    for path in &snapshots {
+        path.file_name().hash(&mut hasher);
+        fs::read(path).expect("failed to read snapshot").hash(&mut hasher);
+    }
+    fs::read(dir.join("output/events.jsonl"))
+        .expect("failed to read event log")
+        .hash(&mut hasher);
+
+    (hasher.finish(), snapshots.len())
+}
+
+#[test]
+fn test_same_seed_produces_identical_output() {
+    let first = run_simulation("a");
+    let second = run_simulation("b");
+
+    let (hash_a, count_a) = hash_outputs(&first);
+    let (hash_b, count_b) = hash_outputs(&second);
+
+    assert!(count_a > 0, "no snapshots were written");
+    assert_eq!(count_a, count_b, "runs wrote different numbers of snapshots");
+    assert_eq!(hash_a, hash_b, "same seed produced different snapshots or events");
+
+    let _ = fs::remove_dir_all(first);
+    let _ = fs::remove_dir_all(second);
+}
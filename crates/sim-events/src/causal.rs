@@ -0,0 +1,194 @@
+//! Causal Tracing
+//!
+//! Events only point back at their antecedents through `connected_events`.
+//! `CausalGraph` indexes those links in both directions so a viewer can walk
+//! from a setup to its payoffs as easily as from a payoff to its setup.
+
+use std::collections::{BTreeMap, BTreeSet, VecDeque};
+
+use crate::event::Event;
+
+/// Cause and effect links between a set of events.
+///
+/// Links to events outside the ingested slice are dropped. A link that would
+/// close a cycle is dropped too and recorded in `broken_links`, so every walk
+/// terminates.
+#[derive(Debug, Clone, Default)]
+pub struct CausalGraph {
+    /// Event ID -> IDs of the events it follows from
+    causes: BTreeMap<String, Vec<String>>,
+    /// Event ID -> IDs of the events that follow from it
+    effects: BTreeMap<String, Vec<String>>,
+    /// `(event, antecedent)` links dropped to break cycles
+    broken_links: Vec<(String, String)>,
+}
+
+impl CausalGraph {
+    /// Builds the graph from a slice of events.
+    pub fn from_events(events: &[Event]) -> Self {
+        let mut graph = Self::default();
+        let mut ticks: BTreeMap<&str, u64> = BTreeMap::new();
+        for event in events {
+            graph.causes.entry(event.event_id.clone()).or_default();
+            graph.effects.entry(event.event_id.clone()).or_default();
+            ticks.insert(&event.event_id, event.timestamp.tick);
+        }
+
+        // Links that point back in time go in first, so when a loop has to be
+        // broken it is a link claiming a cause from the future that gets dropped
+        let mut links: Vec<(&Event, &String)> = events
+            .iter()
+            .flat_map(|event| event.connected_events.iter().map(move |id| (event, id)))
+            .filter(|(_, antecedent)| ticks.contains_key(antecedent.as_str()))
+            .collect();
+        links.sort_by_key(|(event, antecedent)| ticks[antecedent.as_str()] > event.timestamp.tick);
+
+        for (event, antecedent) in links {
+            if graph.causes[&event.event_id].contains(antecedent) {
+                continue;
+            }
+            // Linking would close a loop if the event already leads to its antecedent
+            if *antecedent == event.event_id || graph.reaches(&event.event_id, antecedent) {
+                graph
+                    .broken_links
+                    .push((event.event_id.clone(), antecedent.clone()));
+                continue;
+            }
+            graph
+                .causes
+                .get_mut(&event.event_id)
+                .unwrap()
+                .push(antecedent.clone());
+            graph
+                .effects
+                .get_mut(antecedent)
+                .unwrap()
+                .push(event.event_id.clone());
+        }
+
+        graph
+    }
+
+    /// Returns true if the event was ingested.
+    pub fn contains(&self, event_id: &str) -> bool {
+        self.causes.contains_key(event_id)
+    }
+
+    /// Number of events in the graph.
+    pub fn len(&self) -> usize {
+        self.causes.len()
+    }
+
+    /// Returns true if no events were ingested.
+    pub fn is_empty(&self) -> bool {
+        self.causes.is_empty()
+    }
+
+    /// Direct antecedents of an event.
+    pub fn causes(&self, event_id: &str) -> &[String] {
+        self.causes.get(event_id).map(Vec::as_slice).unwrap_or(&[])
+    }
+
+    /// Events that directly follow from an event.
+    pub fn effects(&self, event_id: &str) -> &[String] {
+        self.effects.get(event_id).map(Vec::as_slice).unwrap_or(&[])
+    }
+
+    /// Every event the given event follows from, nearest first.
+    pub fn ancestors(&self, event_id: &str) -> Vec<String> {
+        Self::walk(&self.causes, event_id)
+    }
+
+    /// Every event that follows from the given event, nearest first.
+    pub fn descendants(&self, event_id: &str) -> Vec<String> {
+        Self::walk(&self.effects, event_id)
+    }
+
+    /// Links that were dropped because they would have closed a cycle.
+    pub fn broken_links(&self) -> &[(String, String)] {
+        &self.broken_links
+    }
+
+    /// Returns true if `to` is an effect, direct or indirect, of `from`.
+    fn reaches(&self, from: &str, to: &str) -> bool {
+        Self::walk(&self.effects, from).iter().any(|id| id == to)
+    }
+
+    /// Breadth-first walk along `links`, excluding the start event.
+    fn walk(links: &BTreeMap<String, Vec<String>>, start: &str) -> Vec<String> {
+        let mut seen = BTreeSet::from([start.to_string()]);
+        let mut queue: VecDeque<&str> = VecDeque::from([start]);
+        let mut order = Vec::new();
+
+        while let Some(id) = queue.pop_front() {
+            for next in links.get(id).into_iter().flatten() {
+                if seen.insert(next.clone()) {
+                    order.push(next.clone());
+                    queue.push_back(next);
+                }
+            }
+        }
+
+        order
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{create_movement_event, ActorSnapshot, Season, SimTimestamp};
+
+    fn event(event_id: &str, tick: u64, connected: &[&str]) -> Event {
+        create_movement_event(
+            event_id,
+            SimTimestamp::new(tick, 1, Season::Spring, 1),
+            ActorSnapshot::new("agent_mira", "Mira", "thornwood", "scout", "village_center"),
+            "test",
+            "eastern_bridge",
+        )
+        .with_connected_events(connected.iter().map(|id| id.to_string()).collect())
+    }
+
+    /// a <- b <- c, with d also following from b
+    fn chain_with_fork() -> Vec<Event> {
+        vec![
+            event("evt_a", 1, &[]),
+            event("evt_b", 2, &["evt_a"]),
+            event("evt_c", 3, &["evt_b"]),
+            event("evt_d", 4, &["evt_b", "evt_missing"]),
+        ]
+    }
+
+    #[test]
+    fn test_chain_with_fork() {
+        let graph = CausalGraph::from_events(&chain_with_fork());
+
+        assert_eq!(graph.len(), 4);
+        assert_eq!(graph.ancestors("evt_c"), vec!["evt_b", "evt_a"]);
+        assert_eq!(graph.ancestors("evt_d"), vec!["evt_b", "evt_a"]);
+        assert_eq!(graph.descendants("evt_a"), vec!["evt_b", "evt_c", "evt_d"]);
+        assert_eq!(graph.effects("evt_b"), ["evt_c", "evt_d"]);
+        assert!(graph.descendants("evt_c").is_empty());
+        assert!(graph.ancestors("evt_a").is_empty());
+
+        // Links outside the slice are ignored rather than invented
+        assert_eq!(graph.causes("evt_d"), ["evt_b"]);
+        assert!(!graph.contains("evt_missing"));
+        assert!(graph.broken_links().is_empty());
+    }
+
+    #[test]
+    fn test_cycle_is_broken() {
+        let mut events = chain_with_fork();
+        // evt_a claims to follow from its own descendant
+        events[0].connected_events.push("evt_c".to_string());
+        events.push(event("evt_e", 5, &["evt_e"]));
+
+        let graph = CausalGraph::from_events(&events);
+
+        assert_eq!(graph.broken_links().len(), 2);
+        assert!(graph.ancestors("evt_c").contains(&"evt_a".to_string()));
+        assert!(!graph.descendants("evt_c").contains(&"evt_a".to_string()));
+        assert!(graph.ancestors("evt_e").is_empty());
+    }
+}
@@ -0,0 +1,320 @@
+//! Snapshot Deltas
+//!
+//! Incremental differences between two world snapshots, so viewers can apply
+//! only what changed instead of re-rendering the whole world.
+
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeSet;
+
+use crate::snapshot::{AgentSnapshot, FactionResourcesSnapshot, RelationshipSnapshot, WorldSnapshot};
+
+/// Relationship dimensions must move by more than this to count as changed.
+pub const DEFAULT_RELATIONSHIP_EPSILON: f32 = 0.01;
+
+/// An agent that changed location between snapshots
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AgentMove {
+    pub agent_id: String,
+    pub from: String,
+    pub to: String,
+}
+
+/// A relationship that was created, removed, or changed beyond the epsilon
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RelationshipChange {
+    pub from_agent: String,
+    pub to_agent: String,
+    /// Value in the previous snapshot (`None` if the relationship is new)
+    pub previous: Option<RelationshipSnapshot>,
+    /// Value in the current snapshot (`None` if the relationship is gone)
+    pub current: Option<RelationshipSnapshot>,
+}
+
+/// Signed change in a faction's stockpiles
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct FactionResourceChange {
+    pub faction_id: String,
+    pub grain: i64,
+    pub iron: i64,
+    pub salt: i64,
+    pub beer: i64,
+}
+
+impl FactionResourceChange {
+    fn between(
+        faction_id: &str,
+        previous: &FactionResourcesSnapshot,
+        current: &FactionResourcesSnapshot,
+    ) -> Self {
+        Self {
+            faction_id: faction_id.to_string(),
+            grain: current.grain as i64 - previous.grain as i64,
+            iron: current.iron as i64 - previous.iron as i64,
+            salt: current.salt as i64 - previous.salt as i64,
+            beer: current.beer as i64 - previous.beer as i64,
+        }
+    }
+
+    /// Returns true if no resource changed.
+    pub fn is_zero(&self) -> bool {
+        self.grain == 0 && self.iron == 0 && self.salt == 0 && self.beer == 0
+    }
+}
+
+/// Everything that changed between two snapshots
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SnapshotDelta {
+    pub from_snapshot: String,
+    pub to_snapshot: String,
+    pub from_tick: u64,
+    pub to_tick: u64,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub moved_agents: Vec<AgentMove>,
+    /// Agents present now but not in the previous snapshot
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub added_agents: Vec<AgentSnapshot>,
+    /// IDs of agents present previously but missing now
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub removed_agents: Vec<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub relationship_changes: Vec<RelationshipChange>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub faction_resource_changes: Vec<FactionResourceChange>,
+}
+
+impl SnapshotDelta {
+    /// Returns true if nothing changed between the two snapshots.
+    pub fn is_empty(&self) -> bool {
+        self.moved_agents.is_empty()
+            && self.added_agents.is_empty()
+            && self.removed_agents.is_empty()
+            && self.relationship_changes.is_empty()
+            && self.faction_resource_changes.is_empty()
+    }
+
+    /// Serializes the delta to compact JSON.
+    pub fn to_json(&self) -> Result<String, serde_json::Error> {
+        serde_json::to_string(self)
+    }
+
+    /// Deserializes a delta from JSON.
+    pub fn from_json(json: &str) -> Result<Self, serde_json::Error> {
+        serde_json::from_str(json)
+    }
+}
+
+fn relationship_changed(a: &RelationshipSnapshot, b: &RelationshipSnapshot, epsilon: f32) -> bool {
+    (a.reliability - b.reliability).abs() > epsilon
+        || (a.alignment - b.alignment).abs() > epsilon
+        || (a.capability - b.capability).abs() > epsilon
+}
+
+impl WorldSnapshot {
+    /// Computes what changed since `previous`, using the default relationship epsilon.
+    pub fn diff(&self, previous: &WorldSnapshot) -> SnapshotDelta {
+        self.diff_with_epsilon(previous, DEFAULT_RELATIONSHIP_EPSILON)
+    }
+
+    /// Computes what changed since `previous`.
+    ///
+    /// Relationships count as changed when any trust dimension moves by more
+    /// than `epsilon`, or when they exist in only one of the two snapshots.
+    pub fn diff_with_epsilon(&self, previous: &WorldSnapshot, epsilon: f32) -> SnapshotDelta {
+        let mut moved_agents = Vec::new();
+        let mut added_agents = Vec::new();
+
+        for agent in &self.agents {
+            match previous.find_agent(&agent.agent_id) {
+                Some(before) if before.location != agent.location => {
+                    moved_agents.push(AgentMove {
+                        agent_id: agent.agent_id.clone(),
+                        from: before.location.clone(),
+                        to: agent.location.clone(),
+                    });
+                }
+                Some(_) => {}
+                None => added_agents.push(agent.clone()),
+            }
+        }
+
+        let removed_agents = previous
+            .agents
+            .iter()
+            .filter(|a| self.find_agent(&a.agent_id).is_none())
+            .map(|a| a.agent_id.clone())
+            .collect();
+
+        let mut pairs: BTreeSet<(&str, &str)> = BTreeSet::new();
+        for snapshot in [self, previous] {
+            for (from, targets) in &snapshot.relationships {
+                for to in targets.keys() {
+                    pairs.insert((from.as_str(), to.as_str()));
+                }
+            }
+        }
+
+        let relationship_changes = pairs
+            .into_iter()
+            .filter_map(|(from, to)| {
+                let before = previous.get_relationship(from, to);
+                let after = self.get_relationship(from, to);
+                let changed = match (before, after) {
+                    (Some(b), Some(a)) => relationship_changed(b, a, epsilon),
+                    _ => true,
+                };
+                changed.then(|| RelationshipChange {
+                    from_agent: from.to_string(),
+                    to_agent: to.to_string(),
+                    previous: before.cloned(),
+                    current: after.cloned(),
+                })
+            })
+            .collect();
+
+        let empty = FactionResourcesSnapshot::default();
+        let mut faction_resource_changes: Vec<FactionResourceChange> = self
+            .factions
+            .iter()
+            .map(|faction| {
+                let before = previous
+                    .find_faction(&faction.faction_id)
+                    .map_or(&empty, |f| &f.resources);
+                FactionResourceChange::between(&faction.faction_id, before, &faction.resources)
+            })
+            .collect();
+        faction_resource_changes.extend(
+            previous
+                .factions
+                .iter()
+                .filter(|f| self.find_faction(&f.faction_id).is_none())
+                .map(|f| FactionResourceChange::between(&f.faction_id, &f.resources, &empty)),
+        );
+        faction_resource_changes.retain(|change| !change.is_zero());
+
+        SnapshotDelta {
+            from_snapshot: previous.snapshot_id.clone(),
+            to_snapshot: self.snapshot_id.clone(),
+            from_tick: previous.timestamp.tick,
+            to_tick: self.timestamp.tick,
+            moved_agents,
+            added_agents,
+            removed_agents,
+            relationship_changes,
+            faction_resource_changes,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::snapshot::FactionSnapshot;
+    use crate::{Season, SimTimestamp};
+
+    fn base_snapshot(tick: u64) -> WorldSnapshot {
+        let ts = SimTimestamp::new(tick, 1, Season::Spring, 10);
+        let mut snapshot = WorldSnapshot::new(format!("snap_{:06}", tick), ts, "scheduled");
+        snapshot.agents.push(AgentSnapshot::new(
+            "agent_001", "Alice", "thornwood", "scout", "market"
+        ));
+        snapshot.agents.push(AgentSnapshot::new(
+            "agent_002", "Bob", "ironmere", "trader", "market"
+        ));
+        let mut rels = std::collections::BTreeMap::new();
+        rels.insert("agent_002".to_string(), RelationshipSnapshot::new(0.5, 0.5, 0.5));
+        snapshot.relationships.insert("agent_001".to_string(), rels);
+        snapshot.factions.push(FactionSnapshot::new(
+            "thornwood", "The Thornwood Council", "thornwood_village"
+        ));
+        snapshot
+    }
+
+    #[test]
+    fn test_identical_snapshots_have_empty_delta() {
+        let a = base_snapshot(100);
+        let b = base_snapshot(200);
+        let delta = b.diff(&a);
+
+        assert!(delta.is_empty());
+        assert_eq!(delta.from_tick, 100);
+        assert_eq!(delta.to_tick, 200);
+    }
+
+    #[test]
+    fn test_diff_moved_agent() {
+        let previous = base_snapshot(100);
+        let mut current = base_snapshot(200);
+        current.agents[0].location = "village_a".to_string();
+
+        let delta = current.diff(&previous);
+
+        assert_eq!(
+            delta.moved_agents,
+            vec![AgentMove {
+                agent_id: "agent_001".to_string(),
+                from: "market".to_string(),
+                to: "village_a".to_string(),
+            }]
+        );
+        assert!(delta.relationship_changes.is_empty());
+    }
+
+    #[test]
+    fn test_diff_changed_relationship() {
+        let previous = base_snapshot(100);
+        let mut current = base_snapshot(200);
+
+        // Below epsilon: ignored
+        current.relationships.get_mut("agent_001").unwrap()
+            .get_mut("agent_002").unwrap().reliability = 0.505;
+        assert!(current.diff(&previous).relationship_changes.is_empty());
+
+        current.relationships.get_mut("agent_001").unwrap()
+            .get_mut("agent_002").unwrap().reliability = 0.2;
+        let delta = current.diff(&previous);
+
+        assert_eq!(delta.relationship_changes.len(), 1);
+        let change = &delta.relationship_changes[0];
+        assert_eq!(change.from_agent, "agent_001");
+        assert_eq!(change.to_agent, "agent_002");
+        assert_eq!(change.previous.as_ref().unwrap().reliability, 0.5);
+        assert_eq!(change.current.as_ref().unwrap().reliability, 0.2);
+    }
+
+    #[test]
+    fn test_diff_removed_and_added_agents() {
+        let previous = base_snapshot(100);
+        let mut current = base_snapshot(200);
+        current.agents.retain(|a| a.agent_id != "agent_002");
+        current.relationships.clear();
+        current.agents.push(AgentSnapshot::new(
+            "agent_003", "Carol", "thornwood", "leader", "village_a"
+        ));
+
+        let delta = current.diff(&previous);
+
+        assert_eq!(delta.removed_agents, vec!["agent_002".to_string()]);
+        assert_eq!(delta.added_agents.len(), 1);
+        assert_eq!(delta.added_agents[0].agent_id, "agent_003");
+        assert!(delta.moved_agents.is_empty());
+        assert_eq!(delta.relationship_changes.len(), 1);
+        assert!(delta.relationship_changes[0].current.is_none());
+    }
+
+    #[test]
+    fn test_diff_faction_resources_and_roundtrip() {
+        let previous = base_snapshot(100);
+        let mut current = base_snapshot(200);
+        current.factions[0].resources.grain = 40;
+        current.factions[0].resources.iron = 3;
+
+        let delta = current.diff(&previous);
+        assert_eq!(delta.faction_resource_changes.len(), 1);
+        assert_eq!(delta.faction_resource_changes[0].grain, 40);
+        assert_eq!(delta.faction_resource_changes[0].iron, 3);
+
+        let parsed = SnapshotDelta::from_json(&delta.to_json().unwrap()).unwrap();
+        assert_eq!(parsed.faction_resource_changes, delta.faction_resource_changes);
+        assert_eq!(parsed.to_snapshot, "snap_000200");
+    }
+}
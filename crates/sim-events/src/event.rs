@@ -29,10 +29,10 @@ impl EventType {
             EventType::Movement => &["travel", "flee", "pursue", "patrol"],
             EventType::Communication => &["share_memory", "spread_rumor", "lie", "confess"],
             EventType::Betrayal => &["secret_shared_with_enemy", "sabotage", "defection", "false_testimony"],
-            EventType::Loyalty => &["defend_ally", "sacrifice_for_faction", "refuse_bribe"],
+            EventType::Loyalty => &["defend_ally", "sacrifice_for_faction", "refuse_bribe", "swear_vengeance"],
             EventType::Conflict => &["argument", "fight", "duel", "raid"],
             EventType::Cooperation => &["trade", "alliance_formed", "gift", "favor"],
-            EventType::Faction => &["join", "leave", "exile", "promotion", "demotion"],
+            EventType::Faction => &["join", "leave", "exile", "promotion", "demotion", "goal_resolved"],
             EventType::Archive => &["write_entry", "read_entry", "destroy_entry", "forge_entry"],
             EventType::Ritual => &["reading_held", "reading_disrupted", "reading_attended", "reading_missed"],
             EventType::Resource => &["acquire", "lose", "trade", "steal", "hoard"],
@@ -144,6 +144,7 @@ pub enum LoyaltySubtype {
     SacrificeForFaction,
     RefuseBribe,
     ReportSuspicion,
+    SwearVengeance,
 }
 
 /// Conflict event subtypes
@@ -179,6 +180,7 @@ pub enum FactionSubtype {
     Demotion,
     ChallengeLeader,
     SupportLeader,
+    GoalResolved,
 }
 
 /// Archive event subtypes
@@ -389,7 +391,9 @@ impl ActorSet {
 
     /// Checks if a specific agent is involved in this event.
     pub fn involves_agent(&self, agent_id: &str) -> bool {
-        self.all_agent_ids().contains(&agent_id)
+        self.primary.agent_id == agent_id
+            || self.secondary.as_ref().is_some_and(|s| s.agent_id == agent_id)
+            || self.affected.iter().any(|a| a.agent_id == agent_id)
     }
 }
 
@@ -427,10 +431,14 @@ impl EventContext {
 }
 
 /// Flexible outcome data for events
+///
+/// Untagged, so variants are tried in order: ones with required fields must
+/// come before those that would accept any object.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(untagged)]
 pub enum EventOutcome {
     Movement(MovementOutcome),
+    Conflict(ConflictOutcome),
     Communication(CommunicationOutcome),
     Relationship(RelationshipOutcome),
     Archive(ArchiveOutcome),
@@ -453,6 +461,21 @@ pub struct ArchiveOutcome {
     pub is_authentic: bool,
 }
 
+/// Conflict event outcome
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ConflictOutcome {
+    /// Agent who came out on top, if the conflict was decided
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub winner_id: Option<String>,
+    /// Whether someone died
+    pub lethal: bool,
+    /// Trust changes between the combatants
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub relationship_changes: Vec<RelationshipChange>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+}
+
 /// Movement event outcome
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct MovementOutcome {
@@ -605,6 +628,14 @@ impl Event {
         self
     }
 
+    /// Returns the winner of a conflict, if the outcome records one.
+    pub fn conflict_winner(&self) -> Option<&str> {
+        match &self.outcome {
+            EventOutcome::Conflict(outcome) => outcome.winner_id.as_deref(),
+            _ => None,
+        }
+    }
+
     /// Returns all agent IDs involved in this event.
     pub fn all_agent_ids(&self) -> Vec<&str> {
         self.actors.all_agent_ids()
@@ -643,6 +674,22 @@ pub fn generate_event_id(sequence: u64) -> String {
     format!("evt_{:08}", sequence)
 }
 
+/// Returns the events of the given type, in order.
+pub fn filter_by_type<'a>(
+    events: impl IntoIterator<Item = &'a Event>,
+    event_type: EventType,
+) -> impl Iterator<Item = &'a Event> {
+    events.into_iter().filter(move |e| e.event_type == event_type)
+}
+
+/// Returns the events involving the given agent in any actor role, in order.
+pub fn events_involving<'a>(
+    events: impl IntoIterator<Item = &'a Event>,
+    agent_id: &'a str,
+) -> impl Iterator<Item = &'a Event> {
+    events.into_iter().filter(move |e| e.involves_agent(agent_id))
+}
+
 /// Builder for creating events with a fluent API.
 ///
 /// # Example
@@ -842,6 +889,7 @@ fn string_to_event_subtype(event_type: &EventType, subtype: &str) -> EventSubtyp
             "defend_ally" => LoyaltySubtype::DefendAlly,
             "sacrifice_for_faction" => LoyaltySubtype::SacrificeForFaction,
             "refuse_bribe" => LoyaltySubtype::RefuseBribe,
+            "swear_vengeance" => LoyaltySubtype::SwearVengeance,
             _ => LoyaltySubtype::DefendAlly,
         }),
         EventType::Conflict => EventSubtype::Conflict(match subtype {
@@ -864,6 +912,7 @@ fn string_to_event_subtype(event_type: &EventType, subtype: &str) -> EventSubtyp
             "exile" => FactionSubtype::Exile,
             "promotion" => FactionSubtype::Promotion,
             "demotion" => FactionSubtype::Demotion,
+            "goal_resolved" => FactionSubtype::GoalResolved,
             _ => FactionSubtype::Join,
         }),
         EventType::Archive => EventSubtype::Archive(match subtype {
@@ -1013,6 +1062,45 @@ mod tests {
         assert_eq!(parsed.event_id, "evt_00000001");
     }
 
+    #[test]
+    fn test_conflict_outcome_serialization() {
+        let outcome = EventOutcome::Conflict(ConflictOutcome {
+            winner_id: Some("agent_voss".to_string()),
+            lethal: true,
+            relationship_changes: vec![RelationshipChange {
+                from: "agent_mira".to_string(),
+                to: "agent_voss".to_string(),
+                dimension: "reliability".to_string(),
+                old_value: 0.4,
+                new_value: 0.05,
+            }],
+            description: Some("Mira was slain in the duel".to_string()),
+        });
+
+        let json = serde_json::to_string(&outcome).unwrap();
+        assert!(json.contains(r#""winner_id":"agent_voss""#));
+        assert!(json.contains(r#""lethal":true"#));
+
+        // Untagged, but the required `lethal` field keeps it a conflict outcome
+        let parsed: EventOutcome = serde_json::from_str(&json).unwrap();
+        match parsed {
+            EventOutcome::Conflict(conflict) => {
+                assert_eq!(conflict.winner_id.as_deref(), Some("agent_voss"));
+                assert!(conflict.lethal);
+                assert_eq!(conflict.relationship_changes.len(), 1);
+                assert_eq!(conflict.relationship_changes[0].new_value, 0.05);
+            }
+            other => panic!("expected conflict outcome, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_general_outcome_not_read_as_conflict() {
+        let json = r#"{"description":"Conflict unresolved"}"#;
+        let parsed: EventOutcome = serde_json::from_str(json).unwrap();
+        assert!(!matches!(parsed, EventOutcome::Conflict(_)));
+    }
+
     #[test]
     fn test_event_with_drama() {
         let actor = ActorSnapshot::new("agent_1", "Test", "faction", "role", "loc");
@@ -1071,6 +1159,51 @@ mod tests {
         assert!(!actors.involves_agent("agent_2"));
     }
 
+    #[test]
+    fn test_event_involves_affected_only_agent() {
+        let event = EventBuilder::new(EventType::Ritual, "reading_held")
+            .id("evt_00000001")
+            .timestamp(SimTimestamp::new(10, 1, Season::Spring, 1))
+            .primary_actor(ActorSnapshot::new("agent_1", "Leader", "thornwood", "leader", "hall"))
+            .add_affected(AffectedActor::new("agent_3", "Listener", "thornwood", "laborer"))
+            .build();
+
+        assert!(event.involves_agent("agent_1"));
+        assert!(event.involves_agent("agent_3"));
+        assert!(!event.involves_agent("agent_2"));
+    }
+
+    #[test]
+    fn test_event_query_helpers() {
+        let timestamp = SimTimestamp::new(1, 1, Season::Spring, 1);
+        let movement = create_movement_event(
+            "evt_1",
+            timestamp.clone(),
+            ActorSnapshot::new("agent_1", "A", "f", "r", "loc"),
+            "test",
+            "loc2",
+        );
+        let ritual = EventBuilder::new(EventType::Ritual, "reading_held")
+            .id("evt_2")
+            .timestamp(timestamp)
+            .primary_actor(ActorSnapshot::new("agent_2", "B", "f", "leader", "hall"))
+            .add_affected(AffectedActor::new("agent_1", "A", "f", "r"))
+            .build();
+        let events = vec![movement, ritual];
+
+        let rituals: Vec<&str> = filter_by_type(&events, EventType::Ritual)
+            .map(|e| e.event_id.as_str())
+            .collect();
+        assert_eq!(rituals, vec!["evt_2"]);
+
+        // agent_1 is only an affected actor in the ritual
+        let involving: Vec<&str> = events_involving(&events, "agent_1")
+            .map(|e| e.event_id.as_str())
+            .collect();
+        assert_eq!(involving, vec!["evt_1", "evt_2"]);
+        assert_eq!(events_involving(&events, "agent_9").count(), 0);
+    }
+
     #[test]
     fn test_affected_actor_builder() {
         let affected = AffectedActor::new("agent_1", "Test", "faction", "role")
@@ -7,7 +7,10 @@
 //!
 //! - `test-fixtures`: Enables the `fixtures` module with sample test data.
 
+pub mod causal;
+pub mod delta;
 pub mod event;
+pub mod reader;
 pub mod snapshot;
 pub mod tension;
 pub mod timestamp;
@@ -23,16 +26,28 @@ pub use timestamp::{
 // Re-export event types
 pub use event::*;
 
+// Re-export event log reading
+pub use reader::{EventReadError, EventReader};
+
 // Re-export tension types
 pub use tension::{
     generate_tension_id, CameraFocus, CameraRecommendation, PredictedOutcome, Tension,
     TensionAgent, TensionStatus, TensionType,
 };
 
+// Re-export causal tracing
+pub use causal::CausalGraph;
+
+// Re-export delta types
+pub use delta::{
+    AgentMove, FactionResourceChange, RelationshipChange, SnapshotDelta,
+    DEFAULT_RELATIONSHIP_EPSILON,
+};
+
 // Re-export snapshot types
 pub use snapshot::{
     generate_snapshot_id, AgentSnapshot, ComputedMetrics, FactionResourcesSnapshot,
-    FactionSnapshot, GlobalResources, GoalSnapshot, LocationResourcesSnapshot, LocationSnapshot,
-    NeedsSnapshot, RelationshipSnapshot, SocialBridge, SocialHub, SocialIsolate,
+    FactionSnapshot, GlobalResources, GoalSnapshot, GrudgeSnapshot, LocationResourcesSnapshot,
+    LocationSnapshot, NeedsSnapshot, RelationshipSnapshot, SocialBridge, SocialHub, SocialIsolate,
     SocialNetworkSnapshot, StatusSnapshot, TraitsSnapshot, WorldSnapshot, WorldStateSnapshot,
 };
@@ -0,0 +1,167 @@
+//! Event Log Reading
+//!
+//! Streams events back out of an `events.jsonl` log one line at a time.
+//! A malformed line is reported with its line number and reading carries
+//! on with the next line.
+
+use std::fs::File;
+use std::io::{BufRead, BufReader, Lines};
+use std::path::Path;
+
+use crate::event::Event;
+
+/// Error for a single line of an event log
+#[derive(Debug)]
+pub enum EventReadError {
+    /// The underlying reader failed; no further lines are read
+    Io { line: usize, source: std::io::Error },
+    /// The line was not a valid event
+    Parse { line: usize, source: serde_json::Error },
+}
+
+impl EventReadError {
+    /// 1-based line number the error occurred on
+    pub fn line(&self) -> usize {
+        match self {
+            EventReadError::Io { line, .. } | EventReadError::Parse { line, .. } => *line,
+        }
+    }
+}
+
+impl std::fmt::Display for EventReadError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            EventReadError::Io { line, source } => write!(f, "line {}: I/O error: {}", line, source),
+            EventReadError::Parse { line, source } => {
+                write!(f, "line {}: invalid event: {}", line, source)
+            }
+        }
+    }
+}
+
+impl std::error::Error for EventReadError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            EventReadError::Io { source, .. } => Some(source),
+            EventReadError::Parse { source, .. } => Some(source),
+        }
+    }
+}
+
+/// Iterator over the events in a JSON Lines log.
+///
+/// Blank lines are skipped. Each item is either an event or the error for
+/// that line.
+#[derive(Debug)]
+pub struct EventReader<R> {
+    lines: Lines<R>,
+    line_number: usize,
+    failed: bool,
+}
+
+impl EventReader<BufReader<File>> {
+    /// Opens an event log file for reading.
+    pub fn from_path(path: impl AsRef<Path>) -> std::io::Result<Self> {
+        Ok(Self::new(BufReader::new(File::open(path)?)))
+    }
+}
+
+impl<R: BufRead> EventReader<R> {
+    /// Reads events from any buffered reader.
+    pub fn new(reader: R) -> Self {
+        Self {
+            lines: reader.lines(),
+            line_number: 0,
+            failed: false,
+        }
+    }
+}
+
+impl<R: BufRead> Iterator for EventReader<R> {
+    type Item = Result<Event, EventReadError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.failed {
+            return None;
+        }
+
+        loop {
+            let line = self.lines.next()?;
+            self.line_number += 1;
+
+            let line = match line {
+                Ok(line) => line,
+                Err(source) => {
+                    self.failed = true;
+                    return Some(Err(EventReadError::Io {
+                        line: self.line_number,
+                        source,
+                    }));
+                }
+            };
+
+            if line.trim().is_empty() {
+                continue;
+            }
+
+            return Some(Event::from_jsonl(&line).map_err(|source| EventReadError::Parse {
+                line: self.line_number,
+                source,
+            }));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{create_movement_event, ActorSnapshot, Season, SimTimestamp};
+    use std::io::Cursor;
+
+    fn movement(event_id: &str, tick: u64) -> Event {
+        create_movement_event(
+            event_id,
+            SimTimestamp::new(tick, 1, Season::Spring, 1),
+            ActorSnapshot::new("agent_mira", "Mira", "thornwood", "scout", "village_center"),
+            "test",
+            "eastern_bridge",
+        )
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let events = vec![movement("evt_00000001", 1), movement("evt_00000002", 2)];
+        let mut log = String::new();
+        for event in &events {
+            log.push_str(&event.to_jsonl().unwrap());
+            log.push_str("\n\n");
+        }
+
+        let read: Vec<Event> = EventReader::new(Cursor::new(log))
+            .collect::<Result<_, _>>()
+            .unwrap();
+
+        assert_eq!(read.len(), 2);
+        assert_eq!(read[0].event_id, "evt_00000001");
+        assert_eq!(read[1].event_id, "evt_00000002");
+        assert_eq!(read[1].timestamp.tick, 2);
+    }
+
+    #[test]
+    fn test_malformed_line_does_not_stop_reading() {
+        let log = format!(
+            "{}\n{{not an event\n{}\n",
+            movement("evt_00000001", 1).to_jsonl().unwrap(),
+            movement("evt_00000003", 3).to_jsonl().unwrap()
+        );
+
+        let results: Vec<_> = EventReader::new(Cursor::new(log)).collect();
+
+        assert_eq!(results.len(), 3);
+        assert_eq!(results[0].as_ref().unwrap().event_id, "evt_00000001");
+        let error = results[1].as_ref().unwrap_err();
+        assert_eq!(error.line(), 2);
+        assert!(error.to_string().starts_with("line 2:"));
+        assert_eq!(results[2].as_ref().unwrap().event_id, "evt_00000003");
+    }
+}
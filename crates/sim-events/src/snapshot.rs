@@ -6,7 +6,7 @@
 //! used for analysis, visualization, and debugging.
 
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
+use std::collections::BTreeMap;
 
 use crate::SimTimestamp;
 
@@ -53,8 +53,8 @@ pub struct FactionSnapshot {
     pub archive_entry_count: usize,
     #[serde(default)]
     pub cohesion_score: f32,
-    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
-    pub external_reputation: HashMap<String, f32>,
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub external_reputation: BTreeMap<String, f32>,
 }
 
 /// Faction resources
@@ -123,6 +123,12 @@ pub struct AgentSnapshot {
     pub needs: NeedsSnapshot,
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub goals: Vec<GoalSnapshot>,
+    /// Overall standing (-1.0 to 1.0), weighted average of how others trust this agent
+    #[serde(default)]
+    pub reputation: f32,
+    /// IDs of the events this agent holds a memory of, firsthand or heard
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub remembered_events: Vec<String>,
 }
 
 /// Relationship snapshot
@@ -146,6 +152,9 @@ pub struct LocationSnapshot {
     pub agents_present: Vec<String>,
     pub resources: LocationResourcesSnapshot,
     pub properties: Vec<String>,
+    /// Whether beer can be brewed here
+    #[serde(default)]
+    pub is_brewery: bool,
 }
 
 /// Location resources
@@ -203,9 +212,9 @@ pub struct SocialNetworkSnapshot {
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct ComputedMetrics {
     #[serde(default)]
-    pub faction_power_balance: HashMap<String, f32>,
-    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
-    pub war_probability_30_days: HashMap<String, f32>,
+    pub faction_power_balance: BTreeMap<String, f32>,
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub war_probability_30_days: BTreeMap<String, f32>,
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub agents_at_defection_risk: Vec<String>,
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
@@ -214,6 +223,19 @@ pub struct ComputedMetrics {
     pub social_network: SocialNetworkSnapshot,
 }
 
+/// A grudge one agent holds against another, and where it started
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GrudgeSnapshot {
+    pub holder_id: String,
+    pub offender_id: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub origin_event: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub location: Option<String>,
+    pub severity: f32,
+    pub formed_tick: u64,
+}
+
 /// Complete world snapshot
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct WorldSnapshot {
@@ -223,9 +245,11 @@ pub struct WorldSnapshot {
     pub world: WorldStateSnapshot,
     pub factions: Vec<FactionSnapshot>,
     pub agents: Vec<AgentSnapshot>,
-    pub relationships: HashMap<String, HashMap<String, RelationshipSnapshot>>,
+    pub relationships: BTreeMap<String, BTreeMap<String, RelationshipSnapshot>>,
     pub locations: Vec<LocationSnapshot>,
     pub computed_metrics: ComputedMetrics,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub grudges: Vec<GrudgeSnapshot>,
 }
 
 impl WorldSnapshot {
@@ -246,9 +270,10 @@ impl WorldSnapshot {
             },
             factions: Vec::new(),
             agents: Vec::new(),
-            relationships: HashMap::new(),
+            relationships: BTreeMap::new(),
             locations: Vec::new(),
             computed_metrics: ComputedMetrics::default(),
+            grudges: Vec::new(),
         }
     }
 
@@ -323,6 +348,8 @@ impl AgentSnapshot {
             status: StatusSnapshot::default(),
             needs: NeedsSnapshot::default(),
             goals: Vec::new(),
+            reputation: 0.0,
+            remembered_events: Vec::new(),
         }
     }
 }
@@ -354,11 +381,12 @@ impl Default for StatusSnapshot {
     }
 }
 
+/// Matches the values sim-core writes for an agent with no pressing needs
 impl Default for NeedsSnapshot {
     fn default() -> Self {
         Self {
-            food_security: "satisfied".to_string(),
-            social_belonging: "satisfied".to_string(),
+            food_security: "secure".to_string(),
+            social_belonging: "integrated".to_string(),
         }
     }
 }
@@ -381,7 +409,7 @@ impl FactionSnapshot {
             reader: None,
             archive_entry_count: 0,
             cohesion_score: 0.5,
-            external_reputation: HashMap::new(),
+            external_reputation: BTreeMap::new(),
         }
     }
 }
@@ -401,6 +429,7 @@ impl LocationSnapshot {
             agents_present: Vec::new(),
             resources: LocationResourcesSnapshot::default(),
             properties: Vec::new(),
+            is_brewery: false,
         }
     }
 }
@@ -534,6 +563,21 @@ mod tests {
         assert_eq!(parsed.agents.len(), 1);
     }
 
+    #[test]
+    fn test_agent_without_needs_or_traits_deserializes() {
+        let json = r#"{
+            "agent_id": "agent_001",
+            "name": "Alice",
+            "faction": "thornwood",
+            "role": "scout",
+            "location": "market"
+        }"#;
+
+        let agent: AgentSnapshot = serde_json::from_str(json).unwrap();
+        assert_eq!(agent.needs.food_security, "secure");
+        assert_eq!(agent.traits.honesty, 0.5);
+    }
+
     #[test]
     fn test_relationship_snapshot() {
         let rel = RelationshipSnapshot::new(0.8, 0.6, 0.5);
@@ -552,6 +596,7 @@ mod tests {
         assert_eq!(status.level, 1);
 
         let needs = NeedsSnapshot::default();
-        assert_eq!(needs.food_security, "satisfied");
+        assert_eq!(needs.food_security, "secure");
+        assert_eq!(needs.social_belonging, "integrated");
     }
 }
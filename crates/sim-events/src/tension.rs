@@ -29,6 +29,30 @@ pub enum TensionType {
     SecretExposed,
     /// Upcoming ritual at risk
     RitualDisruption,
+    /// Sustained hostility between two factions
+    FactionWar,
+}
+
+impl TensionType {
+    /// Every tension type, in declaration order.
+    pub const ALL: [TensionType; 11] = [
+        TensionType::BrewingBetrayal,
+        TensionType::SuccessionCrisis,
+        TensionType::ResourceConflict,
+        TensionType::ForbiddenAlliance,
+        TensionType::RevengeArc,
+        TensionType::RisingPower,
+        TensionType::FactionFracture,
+        TensionType::ExternalThreat,
+        TensionType::SecretExposed,
+        TensionType::RitualDisruption,
+        TensionType::FactionWar,
+    ];
+
+    /// Parse a tension type from its snake_case name
+    pub fn from_name(name: &str) -> Option<Self> {
+        serde_json::from_value(serde_json::Value::String(name.to_string())).ok()
+    }
 }
 
 /// Status of a tension's lifecycle.
@@ -300,6 +324,55 @@ impl Tension {
     pub fn is_high_severity(&self) -> bool {
         self.severity > 0.7
     }
+
+    /// Check if this tension shares at least one key agent with another.
+    pub fn shares_agents_with(&self, other: &Tension) -> bool {
+        self.key_agents
+            .iter()
+            .any(|a| other.key_agents.iter().any(|b| b.agent_id == a.agent_id))
+    }
+
+    /// Merge an overlapping tension into this one.
+    ///
+    /// Returns `None` unless both tensions have the same type and share a key
+    /// agent. The merged tension keeps this tension's ID and summary, unions
+    /// agents, trigger events, and narrative hooks, and takes the higher
+    /// severity and the earlier detection tick.
+    pub fn try_merge(&self, other: &Tension) -> Option<Tension> {
+        if self.tension_type != other.tension_type || !self.shares_agents_with(other) {
+            return None;
+        }
+
+        let mut merged = self.clone();
+        for agent in &other.key_agents {
+            if !merged.key_agents.iter().any(|a| a.agent_id == agent.agent_id) {
+                merged.key_agents.push(agent.clone());
+            }
+        }
+        union_into(&mut merged.key_locations, &other.key_locations);
+        union_into(&mut merged.trigger_events, &other.trigger_events);
+        union_into(&mut merged.narrative_hooks, &other.narrative_hooks);
+        union_into(&mut merged.connected_tensions, &other.connected_tensions);
+
+        if other.severity > merged.severity {
+            merged.severity = other.severity;
+            merged.status = other.status;
+        }
+        merged.confidence = merged.confidence.max(other.confidence);
+        merged.detected_at_tick = merged.detected_at_tick.min(other.detected_at_tick);
+        merged.last_updated_tick = merged.last_updated_tick.max(other.last_updated_tick);
+
+        Some(merged)
+    }
+}
+
+/// Append items from `extra` that are not already in `target`, keeping order.
+fn union_into(target: &mut Vec<String>, extra: &[String]) {
+    for item in extra {
+        if !target.contains(item) {
+            target.push(item.clone());
+        }
+    }
 }
 
 /// Generates a tension ID with the given sequence number.
@@ -389,6 +462,46 @@ mod tests {
         assert_eq!(parsed.key_agents.len(), 2);
     }
 
+    #[test]
+    fn test_try_merge_overlapping_agents() {
+        let mut a = Tension::new("tens_00001", TensionType::BrewingBetrayal, 1000, "Mira wavers");
+        a.severity = 0.4;
+        a.add_agent_inline("agent_mira", "potential_traitor", "uncertain");
+        a.add_agent_inline("agent_corin", "leader", "unaware");
+        a.add_trigger_event("evt_00010");
+        a.add_narrative_hook("Mira has been quiet");
+
+        let mut b = Tension::new("tens_00002", TensionType::BrewingBetrayal, 900, "Mira meets Voss");
+        b.severity = 0.7;
+        b.add_agent_inline("agent_mira", "potential_traitor", "worsening");
+        b.add_agent_inline("agent_voss", "handler", "patient");
+        b.add_trigger_event("evt_00010");
+        b.add_trigger_event("evt_00020");
+        b.add_narrative_hook("A meeting at the bridge");
+
+        let merged = a.try_merge(&b).expect("overlapping tensions should merge");
+        assert_eq!(merged.tension_id, "tens_00001");
+        assert_eq!(merged.severity, 0.7);
+        assert_eq!(merged.detected_at_tick, 900);
+        assert_eq!(merged.key_agents.len(), 3);
+        assert_eq!(merged.trigger_events, vec!["evt_00010", "evt_00020"]);
+        assert_eq!(merged.narrative_hooks.len(), 2);
+    }
+
+    #[test]
+    fn test_try_merge_rejects_different_types_or_agents() {
+        let mut a = Tension::new("tens_00001", TensionType::BrewingBetrayal, 1000, "Test");
+        a.add_agent_inline("agent_mira", "potential_traitor", "uncertain");
+
+        let mut b = Tension::new("tens_00002", TensionType::RevengeArc, 1000, "Test");
+        b.add_agent_inline("agent_mira", "avenger", "hunting");
+        assert!(a.try_merge(&b).is_none());
+
+        let mut c = Tension::new("tens_00003", TensionType::BrewingBetrayal, 1000, "Test");
+        c.add_agent_inline("agent_voss", "potential_traitor", "uncertain");
+        assert!(a.try_merge(&c).is_none());
+    }
+
     #[test]
     fn test_generate_tension_id() {
         assert_eq!(generate_tension_id(1), "tens_00001");
@@ -47,6 +47,26 @@ impl Season {
     pub fn is_year_end(self) -> bool {
         matches!(self, Season::Winter)
     }
+
+    /// Position of the season within the year (spring = 0).
+    fn index(self) -> u64 {
+        match self {
+            Season::Spring => 0,
+            Season::Summer => 1,
+            Season::Autumn => 2,
+            Season::Winter => 3,
+        }
+    }
+
+    /// Season at the given position within the year (wraps every four).
+    fn from_index(index: u64) -> Self {
+        match index % 4 {
+            0 => Season::Spring,
+            1 => Season::Summer,
+            2 => Season::Autumn,
+            _ => Season::Winter,
+        }
+    }
 }
 
 impl fmt::Display for Season {
@@ -111,6 +131,30 @@ impl SimDate {
             }
         }
     }
+
+    /// Advances the date by several days, handling season and year rollovers.
+    pub fn advance_days(&mut self, days: u64) {
+        *self = Self::from_day_index(self.day_index() + days);
+    }
+
+    /// Days elapsed since year 1, spring, day 1.
+    fn day_index(&self) -> u64 {
+        let days_per_season = DAYS_PER_SEASON as u64;
+        (self.year.max(1) as u64 - 1) * 4 * days_per_season
+            + self.season.index() * days_per_season
+            + (self.day.max(1) as u64 - 1)
+    }
+
+    /// Inverse of `day_index`.
+    fn from_day_index(index: u64) -> Self {
+        let days_per_season = DAYS_PER_SEASON as u64;
+        let seasons = index / days_per_season;
+        Self {
+            year: (seasons / 4 + 1) as u32,
+            season: Season::from_index(seasons),
+            day: (index % days_per_season + 1) as u8,
+        }
+    }
 }
 
 impl fmt::Display for SimDate {
@@ -208,7 +252,7 @@ impl<'de> Deserialize<'de> for SimDate {
 /// let ts = SimTimestamp::new(100, 1, Season::Spring, 15);
 /// assert_eq!(ts.tick, 100);
 /// ```
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct SimTimestamp {
     /// Monotonically increasing simulation tick.
     pub tick: u64,
@@ -265,6 +309,42 @@ impl SimTimestamp {
     pub fn day(&self) -> u8 {
         self.date.day
     }
+
+    /// Signed number of ticks from this timestamp to `other`.
+    ///
+    /// Positive when `other` is later.
+    pub fn ticks_between(&self, other: &SimTimestamp) -> i64 {
+        other.tick as i64 - self.tick as i64
+    }
+
+    /// Returns the timestamp `ticks` later.
+    ///
+    /// The date moves forward one day for every `TICKS_PER_DAY` boundary
+    /// crossed, rolling over seasons and years.
+    pub fn advance(&self, ticks: u64) -> SimTimestamp {
+        let tick = self.tick + ticks;
+        let mut date = self.date;
+        date.advance_days(tick / TICKS_PER_DAY - self.tick / TICKS_PER_DAY);
+        SimTimestamp { tick, date }
+    }
+}
+
+impl PartialOrd for SimTimestamp {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for SimTimestamp {
+    /// Orders by tick; the date only breaks ties between inconsistent timestamps.
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        self.tick
+            .cmp(&other.tick)
+            .then_with(|| {
+                let date = |ts: &Self| (ts.date.year, ts.date.season.index(), ts.date.day);
+                date(self).cmp(&date(other))
+            })
+    }
 }
 
 #[cfg(test)]
@@ -389,6 +469,77 @@ mod tests {
         assert_eq!(ts.date.day, 2);
     }
 
+    #[test]
+    fn test_sim_timestamp_ticks_between() {
+        let earlier = SimTimestamp::new(100, 1, Season::Spring, 2);
+        let later = SimTimestamp::new(350, 1, Season::Spring, 4);
+        assert_eq!(earlier.ticks_between(&later), 250);
+        assert_eq!(later.ticks_between(&earlier), -250);
+        assert_eq!(earlier.ticks_between(&earlier), 0);
+    }
+
+    #[test]
+    fn test_sim_timestamp_advance_within_day() {
+        let ts = SimTimestamp::new(110, 1, Season::Spring, 2);
+        let advanced = ts.advance(89);
+        assert_eq!(advanced.tick, 199);
+        assert_eq!(advanced.date, SimDate::new(1, Season::Spring, 2));
+
+        // Crossing the next day boundary moves the date
+        assert_eq!(ts.advance(90).date, SimDate::new(1, Season::Spring, 3));
+    }
+
+    #[test]
+    fn test_sim_timestamp_advance_season_boundary() {
+        // Last tick of spring day 30 in year 1
+        let ts = SimTimestamp::new(30 * TICKS_PER_DAY - 1, 1, Season::Spring, 30);
+        let advanced = ts.advance(1);
+        assert_eq!(advanced.tick, 30 * TICKS_PER_DAY);
+        assert_eq!(advanced.date, SimDate::new(1, Season::Summer, 1));
+
+        // Skipping a whole season lands on the same day of the season after
+        let advanced = ts.advance(DAYS_PER_SEASON as u64 * TICKS_PER_DAY);
+        assert_eq!(advanced.date, SimDate::new(1, Season::Summer, 30));
+    }
+
+    #[test]
+    fn test_sim_timestamp_advance_year_boundary() {
+        let ts = SimTimestamp::new(50, 1, Season::Winter, 30);
+        let advanced = ts.advance(TICKS_PER_DAY);
+        assert_eq!(advanced.date, SimDate::new(2, Season::Spring, 1));
+
+        // A full year of ticks lands on the same date next year
+        let year_ticks = 4 * DAYS_PER_SEASON as u64 * TICKS_PER_DAY;
+        let ts = SimTimestamp::new(1234, 3, Season::Autumn, 17);
+        let advanced = ts.advance(year_ticks);
+        assert_eq!(advanced.tick, 1234 + year_ticks);
+        assert_eq!(advanced.date, SimDate::new(4, Season::Autumn, 17));
+    }
+
+    #[test]
+    fn test_sim_timestamp_advance_matches_advance_day() {
+        let mut stepped = SimTimestamp::new(0, 1, Season::Autumn, 28);
+        let jumped = stepped.advance(5 * TICKS_PER_DAY);
+        for _ in 0..5 {
+            stepped.advance_day();
+        }
+        assert_eq!(stepped, jumped);
+    }
+
+    #[test]
+    fn test_sim_timestamp_ordering() {
+        let a = SimTimestamp::new(100, 1, Season::Spring, 2);
+        let b = SimTimestamp::new(101, 1, Season::Spring, 2);
+        let c = SimTimestamp::new(5000, 1, Season::Spring, 21);
+        assert!(a < b);
+        assert!(c > b);
+        assert_eq!(a.clone().max(b.clone()), b);
+
+        let mut sorted = vec![c.clone(), a.clone(), b.clone()];
+        sorted.sort();
+        assert_eq!(sorted, vec![a, b, c]);
+    }
+
     #[test]
     fn test_sim_timestamp_serialization() {
         let ts = SimTimestamp::new(84729, 3, Season::Winter, 12);
@@ -1,25 +1,41 @@
 //! Debug overlay for development information display.
 //!
 //! Shows FPS, camera info, agent count, and other debug information.
-//! Toggle with F3 key.
+//! Toggle with F3 key. Also builds the agent inspection shown in the
+//! agent info panel when an agent is selected.
 
 use bevy::prelude::*;
+use sim_events::{GoalSnapshot, NeedsSnapshot, TraitsSnapshot, WorldSnapshot};
 use std::collections::VecDeque;
 
 use crate::agents::VisualAgent;
 use crate::camera::CameraController;
+use crate::live_commentary::{AgentEventHistory, SimEvent};
+use crate::overlay::SelectedAgentInfo;
 use crate::state_loader::SimulationState;
 
+/// Maximum relationships listed in an agent inspection.
+pub const MAX_INSPECTED_RELATIONSHIPS: usize = 5;
+
+/// Maximum recent events listed in an agent inspection.
+pub const MAX_INSPECTED_EVENTS: usize = 5;
+
 /// Plugin for the debug overlay.
 pub struct DebugPlugin;
 
 impl Plugin for DebugPlugin {
     fn build(&self, app: &mut App) {
         app.init_resource::<DebugOverlay>()
+            .init_resource::<AgentInspector>()
             .add_systems(Startup, setup_debug_overlay)
             .add_systems(
                 Update,
-                (toggle_debug_overlay, update_debug_display, update_debug_text),
+                (
+                    toggle_debug_overlay,
+                    update_debug_display,
+                    update_debug_text,
+                    update_agent_inspection,
+                ),
             );
     }
 }
@@ -51,6 +67,208 @@ impl Default for DebugOverlay {
     }
 }
 
+/// Whether an inspected agent is still in the world.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AgentPresence {
+    /// Alive and present in the latest snapshot.
+    Present,
+    /// Present in the latest snapshot but dead.
+    Dead,
+    /// No longer in the latest snapshot; data is from when they were last seen.
+    Departed,
+}
+
+/// One of the inspected agent's relationships, as they see the other agent.
+#[derive(Debug, Clone, PartialEq)]
+pub struct RelationshipSummary {
+    pub other_id: String,
+    pub other_name: String,
+    pub other_faction: Option<String>,
+    pub reliability: f32,
+    pub alignment: f32,
+    pub capability: f32,
+    pub memory_count: u32,
+}
+
+impl RelationshipSummary {
+    /// Overall trust, weighted the same way as the simulation's trust.
+    pub fn overall(&self) -> f32 {
+        self.reliability * 0.4 + self.alignment * 0.35 + self.capability * 0.25
+    }
+}
+
+/// Everything the agent info panel shows about one agent.
+#[derive(Debug, Clone)]
+pub struct AgentInspection {
+    pub agent_id: String,
+    pub name: String,
+    pub faction: String,
+    pub role: String,
+    pub location: String,
+    pub presence: AgentPresence,
+    /// Tick of the snapshot this was built from.
+    pub tick: u64,
+    pub traits: TraitsSnapshot,
+    pub status_title: String,
+    pub status_level: u8,
+    pub needs: NeedsSnapshot,
+    pub goals: Vec<GoalSnapshot>,
+    pub reputation: f32,
+    /// Strongest relationships first, by absolute overall trust.
+    pub relationships: Vec<RelationshipSummary>,
+    /// Recent events involving the agent, newest first.
+    pub recent_events: Vec<SimEvent>,
+}
+
+impl AgentInspection {
+    /// Build an inspection for `agent_id` from a snapshot.
+    ///
+    /// Returns `None` if the agent is not in the snapshot.
+    pub fn from_snapshot(snapshot: &WorldSnapshot, agent_id: &str) -> Option<Self> {
+        let agent = snapshot.agents.iter().find(|a| a.agent_id == agent_id)?;
+
+        let mut relationships: Vec<RelationshipSummary> = snapshot
+            .relationships
+            .get(agent_id)
+            .map(|rels| {
+                rels.iter()
+                    .map(|(other_id, rel)| {
+                        let other = snapshot.agents.iter().find(|a| &a.agent_id == other_id);
+                        RelationshipSummary {
+                            other_id: other_id.clone(),
+                            other_name: other
+                                .map(|a| a.name.clone())
+                                .unwrap_or_else(|| other_id.clone()),
+                            other_faction: other.map(|a| a.faction.clone()),
+                            reliability: rel.reliability,
+                            alignment: rel.alignment,
+                            capability: rel.capability,
+                            memory_count: rel.memory_count,
+                        }
+                    })
+                    .collect()
+            })
+            .unwrap_or_default();
+        // Relationships are keyed by id, so the stable sort breaks ties by id
+        relationships.sort_by(|a, b| {
+            b.overall()
+                .abs()
+                .partial_cmp(&a.overall().abs())
+                .unwrap_or(std::cmp::Ordering::Equal)
+        });
+        relationships.truncate(MAX_INSPECTED_RELATIONSHIPS);
+
+        Some(Self {
+            agent_id: agent.agent_id.clone(),
+            name: agent.name.clone(),
+            faction: agent.faction.clone(),
+            role: agent.role.clone(),
+            location: agent.location.clone(),
+            presence: if agent.alive {
+                AgentPresence::Present
+            } else {
+                AgentPresence::Dead
+            },
+            tick: snapshot.timestamp.tick,
+            traits: agent.traits.clone(),
+            status_title: agent.status.role_title.clone(),
+            status_level: agent.status.level,
+            needs: agent.needs.clone(),
+            goals: agent.goals.clone(),
+            reputation: agent.reputation,
+            relationships,
+            recent_events: Vec::new(),
+        })
+    }
+
+    /// Attach the agent's recent events (newest first).
+    pub fn with_recent_events<'a>(
+        mut self,
+        events: impl IntoIterator<Item = &'a SimEvent>,
+    ) -> Self {
+        self.recent_events = events
+            .into_iter()
+            .take(MAX_INSPECTED_EVENTS)
+            .cloned()
+            .collect();
+        self
+    }
+
+    /// Short label for the agent's presence, empty when present.
+    pub fn presence_label(&self) -> String {
+        match self.presence {
+            AgentPresence::Present => String::new(),
+            AgentPresence::Dead => " [DEAD]".to_string(),
+            AgentPresence::Departed => format!(" [LEFT, last seen tick {}]", self.tick),
+        }
+    }
+
+    /// Traits, needs, goals, and relationship lines for the info panel.
+    pub fn detail_text(&self) -> String {
+        let t = &self.traits;
+        let mut lines = vec![
+            format!("Status: {} (level {})", self.status_title, self.status_level),
+            format!("Reputation: {:+.2}", self.reputation),
+            "--- Traits ---".to_string(),
+            format!(
+                "Bold {:.2}  Loyal {:.2}  Grudge {:.2}  Ambition {:.2}",
+                t.boldness, t.loyalty_weight, t.grudge_persistence, t.ambition
+            ),
+            format!(
+                "Honest {:.2}  Social {:.2}  Group {:.2}",
+                t.honesty, t.sociability, t.group_preference
+            ),
+            "--- Needs ---".to_string(),
+            format!(
+                "Food: {}  Belonging: {}",
+                self.needs.food_security, self.needs.social_belonging
+            ),
+            "--- Goals ---".to_string(),
+        ];
+
+        if self.goals.is_empty() {
+            lines.push("(None)".to_string());
+        }
+        for goal in &self.goals {
+            match &goal.target {
+                Some(target) => {
+                    lines.push(format!("{} -> {} ({:.2})", goal.goal, target, goal.priority))
+                }
+                None => lines.push(format!("{} ({:.2})", goal.goal, goal.priority)),
+            }
+        }
+
+        lines.push("--- Relationships ---".to_string());
+        if self.relationships.is_empty() {
+            lines.push("(None)".to_string());
+        }
+        for rel in &self.relationships {
+            let faction = rel
+                .other_faction
+                .as_deref()
+                .map(|f| format!(" [{}]", f))
+                .unwrap_or_default();
+            lines.push(format!(
+                "{}{}: trust {:+.2} (R {:+.2} A {:+.2} C {:+.2})",
+                rel.other_name,
+                faction,
+                rel.overall(),
+                rel.reliability,
+                rel.alignment,
+                rel.capability
+            ));
+        }
+
+        lines.join("\n")
+    }
+}
+
+/// Resource holding the inspection for the selected agent.
+#[derive(Resource, Default)]
+pub struct AgentInspector {
+    pub inspection: Option<AgentInspection>,
+}
+
 /// Component marking the debug overlay container.
 #[derive(Component)]
 pub struct DebugOverlayContainer;
@@ -259,6 +477,52 @@ fn update_debug_text_with_follow(
     }
 }
 
+/// System to rebuild the inspection for the selected agent.
+///
+/// An agent missing from the latest snapshot keeps their last-known
+/// inspection, marked as departed.
+fn update_agent_inspection(
+    selected: Res<SelectedAgentInfo>,
+    state: Res<SimulationState>,
+    event_history: Option<Res<AgentEventHistory>>,
+    mut inspector: ResMut<AgentInspector>,
+) {
+    let history_changed = event_history.as_ref().map_or(false, |h| h.is_changed());
+    if !selected.is_changed() && !state.is_changed() && !history_changed {
+        return;
+    }
+
+    let Some(ref agent_id) = selected.agent_id else {
+        inspector.inspection = None;
+        return;
+    };
+
+    let fresh = state
+        .snapshot
+        .as_ref()
+        .and_then(|snapshot| AgentInspection::from_snapshot(snapshot, agent_id));
+
+    inspector.inspection = match fresh {
+        Some(inspection) => Some(
+            inspection.with_recent_events(
+                event_history
+                    .as_ref()
+                    .and_then(|h| h.get_history(agent_id))
+                    .into_iter()
+                    .flatten(),
+            ),
+        ),
+        None => inspector
+            .inspection
+            .take()
+            .filter(|previous| &previous.agent_id == agent_id)
+            .map(|mut previous| {
+                previous.presence = AgentPresence::Departed;
+                previous
+            }),
+    };
+}
+
 /// System to update debug text styling based on performance.
 fn update_debug_text(
     debug_overlay: Res<DebugOverlay>,
@@ -294,6 +558,118 @@ fn update_debug_text(
 #[cfg(test)]
 mod tests {
     use super::*;
+    use sim_events::{AgentSnapshot, RelationshipSnapshot, Season, SimTimestamp, StatusSnapshot};
+    use std::collections::BTreeMap;
+
+    fn agent(id: &str, name: &str, faction: &str, role: &str, alive: bool) -> AgentSnapshot {
+        AgentSnapshot {
+            agent_id: id.to_string(),
+            name: name.to_string(),
+            alive,
+            faction: faction.to_string(),
+            role: role.to_string(),
+            location: "village_center".to_string(),
+            traits: TraitsSnapshot::default(),
+            status: StatusSnapshot::default(),
+            needs: NeedsSnapshot::default(),
+            goals: Vec::new(),
+            reputation: 0.0,
+            remembered_events: Vec::new(),
+        }
+    }
+
+    fn relationship(reliability: f32, alignment: f32, capability: f32) -> RelationshipSnapshot {
+        RelationshipSnapshot {
+            reliability,
+            alignment,
+            capability,
+            last_interaction_tick: 0,
+            memory_count: 1,
+        }
+    }
+
+    fn sample_snapshot() -> WorldSnapshot {
+        let mut snapshot =
+            WorldSnapshot::new("snap_test", SimTimestamp::new(120, 1, Season::Spring, 2), "test");
+        snapshot.agents.push(agent("agent_mira", "Mira", "thornwood", "scout", true));
+        snapshot.agents.push(agent("agent_corin", "Corin", "thornwood", "leader", true));
+        snapshot.agents.push(agent("agent_vess", "Vess", "ironmere", "laborer", false));
+
+        let mut rels = BTreeMap::new();
+        rels.insert("agent_corin".to_string(), relationship(0.5, 0.5, 0.5));
+        rels.insert("agent_vess".to_string(), relationship(-0.8, -0.7, 0.2));
+        snapshot.relationships.insert("agent_mira".to_string(), rels);
+        snapshot
+    }
+
+    #[test]
+    fn test_inspection_populates_faction_role_and_relationships() {
+        let snapshot = sample_snapshot();
+        let inspection = AgentInspection::from_snapshot(&snapshot, "agent_mira").unwrap();
+
+        assert_eq!(inspection.name, "Mira");
+        assert_eq!(inspection.faction, "thornwood");
+        assert_eq!(inspection.role, "scout");
+        assert_eq!(inspection.presence, AgentPresence::Present);
+        assert_eq!(inspection.tick, 120);
+
+        // Strongest feeling first, whether trust or distrust
+        let names: Vec<&str> = inspection
+            .relationships
+            .iter()
+            .map(|r| r.other_name.as_str())
+            .collect();
+        assert_eq!(names, vec!["Vess", "Corin"]);
+        assert_eq!(inspection.relationships[0].other_faction.as_deref(), Some("ironmere"));
+        assert!(inspection.relationships[0].overall() < 0.0);
+        assert!(inspection.detail_text().contains("Corin [thornwood]: trust +0.50"));
+    }
+
+    #[test]
+    fn test_inspection_of_dead_or_missing_agent() {
+        let snapshot = sample_snapshot();
+
+        let dead = AgentInspection::from_snapshot(&snapshot, "agent_vess").unwrap();
+        assert_eq!(dead.presence, AgentPresence::Dead);
+        assert!(dead.relationships.is_empty());
+        assert_eq!(dead.presence_label(), " [DEAD]");
+
+        assert!(AgentInspection::from_snapshot(&snapshot, "agent_gone").is_none());
+    }
+
+    #[test]
+    fn test_inspector_keeps_departed_agent() {
+        let mut world = World::new();
+        world.insert_resource(SelectedAgentInfo {
+            agent_id: Some("agent_corin".to_string()),
+            ..Default::default()
+        });
+        world.insert_resource(SimulationState {
+            snapshot: Some(sample_snapshot()),
+            ..Default::default()
+        });
+        world.init_resource::<AgentInspector>();
+
+        let mut schedule = Schedule::default();
+        schedule.add_systems(update_agent_inspection);
+        schedule.run(&mut world);
+        assert_eq!(
+            world.resource::<AgentInspector>().inspection.as_ref().unwrap().presence,
+            AgentPresence::Present
+        );
+
+        // Corin leaves the world
+        let mut snapshot = sample_snapshot();
+        snapshot.timestamp.tick = 240;
+        snapshot.agents.retain(|a| a.agent_id != "agent_corin");
+        world.resource_mut::<SimulationState>().snapshot = Some(snapshot);
+        schedule.run(&mut world);
+
+        let inspection = world.resource::<AgentInspector>().inspection.clone().unwrap();
+        assert_eq!(inspection.presence, AgentPresence::Departed);
+        assert_eq!(inspection.role, "leader");
+        assert_eq!(inspection.presence_label(), " [LEFT, last seen tick 120]");
+    }
 
     #[test]
     fn test_debug_overlay_default() {
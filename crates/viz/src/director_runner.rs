@@ -8,7 +8,7 @@ use bevy::prelude::*;
 use std::io::BufRead;
 use std::path::Path;
 
-use director::Director;
+use director::{Director, DirectorConfig, DirectorError};
 use sim_events::{Event, Tension};
 
 use crate::director_state::DirectorState;
@@ -33,6 +33,21 @@ pub struct DirectorRunner {
     last_processed_tick: u64,
 }
 
+impl DirectorRunner {
+    /// Creates a runner whose Director uses the given configuration.
+    pub fn with_config(config: DirectorConfig) -> Result<Self, DirectorError> {
+        Ok(Self {
+            director: Director::new(config)?,
+            last_processed_tick: 0,
+        })
+    }
+
+    /// Marks everything up to `tick` as processed, e.g. after seeking playback.
+    pub fn skip_to(&mut self, tick: u64) {
+        self.last_processed_tick = tick;
+    }
+}
+
 impl Default for DirectorRunner {
     fn default() -> Self {
         Self {
@@ -128,4 +143,11 @@ mod tests {
         let runner = DirectorRunner::default();
         assert_eq!(runner.last_processed_tick, 0);
     }
+
+    #[test]
+    fn test_director_runner_with_preset() {
+        let config = DirectorConfig::preset("fast_cut").unwrap();
+        let runner = DirectorRunner::with_config(config).unwrap();
+        assert_eq!(runner.director.config().focus.min_event_score, 0.5);
+    }
 }
@@ -382,6 +382,30 @@ fn apply_camera_instruction(
                 }),
             };
         }
+        director::CameraMode::Establishing { location_ids, zoom } => {
+            // Center on the thread's locations at the requested wide zoom
+            let positions: Vec<Vec2> = location_ids
+                .iter()
+                .map(|id| location_positions.get(id))
+                .collect();
+            let min = positions.iter().fold(Vec2::MAX, |a, &b| a.min(b));
+            let max = positions.iter().fold(Vec2::MIN, |a, &b| a.max(b));
+            let (center, size) = if positions.is_empty() {
+                (Vec2::ZERO, Vec2::new(2000.0, 2000.0))
+            } else {
+                ((min + max) / 2.0, (max - min) + Vec2::splat(200.0))
+            };
+            let zoom_value = zoom_level_to_f32(zoom);
+            let duration = pacing_to_duration(&instruction.pacing);
+            camera.begin_transition(center, zoom_value, duration);
+            camera.mode = CameraMode::Director {
+                instruction: Some(crate::camera::CameraInstruction {
+                    target: crate::camera::CameraTarget::Region { center, size },
+                    zoom: zoom_value,
+                    duration,
+                }),
+            };
+        }
         director::CameraMode::Cinematic {
             path,
             duration_ticks: _,
@@ -10,6 +10,7 @@ pub mod debug;
 pub mod director_runner;
 pub mod director_state;
 pub mod intervention;
+pub mod live_commentary;
 pub mod overlay;
 pub mod sim_runner;
 pub mod state_loader;
@@ -5,10 +5,14 @@
 //! Examples:
 //!   cargo run -p viz -- --ticks 2000 --auto-start
 //!   cargo run -p viz -- --replay output/
+//!   cargo run -p viz -- --replay output/ --start-tick 500
+//!   cargo run -p viz -- --director-preset documentary
 
 use bevy::prelude::*;
 use clap::Parser;
+use director::{DirectorConfig, PRESET_NAMES};
 use std::path::PathBuf;
+use viz::director_runner::DirectorRunner;
 use viz::sim_runner::SimConfig;
 use viz::SimVizPlugin;
 
@@ -37,6 +41,10 @@ struct Args {
     #[arg(long)]
     replay: Option<PathBuf>,
 
+    /// Tick to seek playback to once snapshots reaching it are available
+    #[arg(long)]
+    start_tick: Option<u64>,
+
     /// Output directory for simulation files
     #[arg(long, default_value = "output")]
     output_dir: PathBuf,
@@ -44,6 +52,14 @@ struct Args {
     /// Maximum ticks ahead of playback the simulation can run
     #[arg(long, default_value_t = 300)]
     max_ticks_ahead: u64,
+
+    /// Simulation speed in ticks per second (0 = unthrottled; , and . adjust)
+    #[arg(long, default_value_t = 0.0)]
+    tick_rate: f64,
+
+    /// Named director preset (documentary, fast_cut, observational)
+    #[arg(long)]
+    director_preset: Option<String>,
 }
 
 fn main() {
@@ -57,12 +73,23 @@ fn main() {
         auto_start: args.auto_start,
         output_dir: args.output_dir,
         from_snapshot: None,
-        start_tick: None,
+        start_tick: args.start_tick,
         max_ticks_ahead: args.max_ticks_ahead,
+        tick_rate: args.tick_rate,
     };
 
-    App::new()
-        .insert_resource(sim_config)
-        .add_plugins(SimVizPlugin)
-        .run();
+    let mut app = App::new();
+    app.insert_resource(sim_config);
+
+    // Inserted before the plugin so it isn't replaced by the default runner
+    if let Some(name) = &args.director_preset {
+        let Some(config) = DirectorConfig::preset(name) else {
+            eprintln!("Unknown director preset '{}'. Available: {}", name, PRESET_NAMES.join(", "));
+            std::process::exit(1);
+        };
+        let runner = DirectorRunner::with_config(config).expect("Director presets are always valid");
+        app.insert_resource(runner);
+    }
+
+    app.add_plugins(SimVizPlugin).run();
 }
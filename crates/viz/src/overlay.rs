@@ -5,6 +5,7 @@ use std::collections::VecDeque;
 
 use crate::agents::{AgentSelectedEvent, VisualAgent};
 use crate::camera::CameraController;
+use crate::debug::AgentInspector;
 use crate::director_state::DirectorState;
 use crate::live_commentary::AgentEventHistory;
 use crate::sim_runner::{SimRunner, SimStatus};
@@ -19,14 +20,14 @@ impl Plugin for OverlayPlugin {
         app.init_resource::<PlaybackState>()
             .init_resource::<SelectedAgentInfo>()
             .init_resource::<CommentaryHistory>()
+            .init_resource::<CommentaryScheduler>()
             .add_systems(Startup, setup_overlay_ui)
             .add_systems(
                 Update,
                 (
                     update_status_bar,
                     update_sim_status_display,
-                    update_commentary_display,
-                    fade_commentary,
+                    (update_commentary_display, fade_commentary).chain(),
                     update_playback_controls,
                     handle_playback_input,
                     update_agent_selection_info,
@@ -139,12 +140,129 @@ pub struct CommentaryContainer;
 pub struct DisplayedCommentary {
     /// Unique ID of this commentary item.
     pub item_id: String,
-    /// Remaining time to display (seconds).
-    pub time_remaining: f32,
     /// Current opacity (for fade effects).
     pub opacity: f32,
 }
 
+/// Maximum commentary items on screen at once.
+const MAX_ON_SCREEN_COMMENTARY: usize = 3;
+
+/// Fraction of an item's display duration spent fading out.
+const COMMENTARY_FADE_FRACTION: f64 = 0.2;
+
+/// A commentary item currently scheduled on screen.
+#[derive(Debug, Clone)]
+pub struct ActiveCommentary {
+    /// Unique ID of the commentary item.
+    pub item_id: String,
+    /// Text to display.
+    pub content: String,
+    /// Type of commentary, used for styling.
+    pub commentary_type: director::CommentaryType,
+    /// Priority from the director (higher = more important).
+    pub priority: f32,
+    /// Tick the item starts showing.
+    pub start_tick: u64,
+    /// Tick the item is gone by.
+    pub end_tick: u64,
+}
+
+/// Decides which director commentary is on screen at the playback tick.
+///
+/// Independent of rendering: items become eligible at their timestamp plus
+/// `start_offset_ticks`, are shown highest priority first up to a cap, and
+/// expire after their `display_duration_ticks`.
+#[derive(Resource, Debug)]
+pub struct CommentaryScheduler {
+    /// Items on screen, highest priority first.
+    pub active: Vec<ActiveCommentary>,
+    /// Maximum simultaneous items.
+    pub max_on_screen: usize,
+    /// IDs of items that have already been shown.
+    shown: std::collections::HashSet<String>,
+}
+
+impl Default for CommentaryScheduler {
+    fn default() -> Self {
+        Self {
+            active: Vec::new(),
+            max_on_screen: MAX_ON_SCREEN_COMMENTARY,
+            shown: std::collections::HashSet::new(),
+        }
+    }
+}
+
+impl CommentaryScheduler {
+    /// Update the on-screen set for `current_tick`.
+    ///
+    /// Returns the newly admitted items so callers can log them.
+    pub fn update<'a>(
+        &mut self,
+        queue: impl IntoIterator<Item = &'a director::CommentaryItem>,
+        current_tick: u64,
+    ) -> Vec<ActiveCommentary> {
+        self.active
+            .retain(|a| a.start_tick <= current_tick && current_tick < a.end_tick);
+
+        let mut candidates: Vec<ActiveCommentary> = queue
+            .into_iter()
+            .filter(|item| !self.shown.contains(&item.item_id))
+            .map(|item| {
+                let start_tick = item.timestamp.tick + item.start_offset_ticks;
+                ActiveCommentary {
+                    item_id: item.item_id.clone(),
+                    content: item.content.clone(),
+                    commentary_type: item.commentary_type,
+                    priority: item.priority,
+                    start_tick,
+                    end_tick: start_tick + item.display_duration_ticks.max(1) as u64,
+                }
+            })
+            .filter(|a| a.start_tick <= current_tick && current_tick < a.end_tick)
+            .collect();
+        candidates.sort_by(|a, b| {
+            b.priority
+                .partial_cmp(&a.priority)
+                .unwrap_or(std::cmp::Ordering::Equal)
+                .then(a.start_tick.cmp(&b.start_tick))
+        });
+
+        let mut admitted = Vec::new();
+        for candidate in candidates {
+            if self.active.len() >= self.max_on_screen {
+                // Bump the least important item if this one matters more
+                let Some(lowest) = self.active.last() else {
+                    break;
+                };
+                if candidate.priority <= lowest.priority {
+                    continue;
+                }
+                self.active.pop();
+            }
+            self.shown.insert(candidate.item_id.clone());
+            admitted.push(candidate.clone());
+            self.active.push(candidate);
+            self.active.sort_by(|a, b| {
+                b.priority
+                    .partial_cmp(&a.priority)
+                    .unwrap_or(std::cmp::Ordering::Equal)
+            });
+        }
+        admitted
+    }
+
+    /// Opacity of an active item at a (fractional) playback tick.
+    pub fn opacity(&self, item_id: &str, tick: f64) -> f32 {
+        let Some(active) = self.active.iter().find(|a| a.item_id == item_id) else {
+            return 0.0;
+        };
+        let duration = (active.end_tick - active.start_tick) as f64;
+        let fade_ticks = (duration * COMMENTARY_FADE_FRACTION).max(1.0);
+        let remaining = active.end_tick as f64 - tick;
+        (remaining / fade_ticks).clamp(0.0, 1.0) as f32
+    }
+}
+
 /// Component marking the playback controls container.
 #[derive(Component)]
 pub struct PlaybackControls;
@@ -629,6 +747,12 @@ fn update_sim_status_display(
         let (status_text, color) = match &sim_runner.status {
             SimStatus::Idle => ("Simulated: None | [S] Start".to_string(), Color::srgb(0.6, 0.6, 0.6)),
             SimStatus::Starting => ("Simulating...".to_string(), Color::srgb(0.8, 0.8, 0.5)),
+            SimStatus::Running { current_tick, max_ticks } if sim_runner.control.is_paused() => {
+                (
+                    format!("Simulated: {}/{} (paused - [P] resume, [N] step)", current_tick, max_ticks),
+                    Color::srgb(0.8, 0.7, 0.4),
+                )
+            }
             SimStatus::Running { current_tick, max_ticks } => {
                 let percent = (*current_tick as f32 / *max_ticks as f32 * 100.0) as u32;
                 (
@@ -660,64 +784,67 @@ fn update_sim_status_display(
     }
 }
 
-/// System to update commentary display.
+/// Display name for a commentary type in the history panel.
+fn commentary_type_name(commentary_type: director::CommentaryType) -> &'static str {
+    match commentary_type {
+        director::CommentaryType::EventCaption => "Event",
+        director::CommentaryType::DramaticIrony => "Irony",
+        director::CommentaryType::ContextReminder => "Context",
+        director::CommentaryType::TensionTeaser => "Tension",
+        director::CommentaryType::NarratorVoice => "Narrator",
+    }
+}
+
+/// Font size, color, and prefix for a commentary type.
+fn commentary_style(commentary_type: director::CommentaryType) -> (f32, Color, &'static str) {
+    match commentary_type {
+        director::CommentaryType::EventCaption => (18.0, Color::WHITE, ""),
+        director::CommentaryType::DramaticIrony => (16.0, Color::srgb(0.9, 0.8, 0.5), "// "),
+        director::CommentaryType::ContextReminder => (14.0, Color::srgb(0.7, 0.7, 0.7), ""),
+        director::CommentaryType::TensionTeaser => (16.0, Color::srgb(0.8, 0.6, 0.6), "... "),
+        director::CommentaryType::NarratorVoice => (18.0, Color::srgb(1.0, 0.95, 0.8), ""),
+    }
+}
+
+/// System to schedule director commentary and sync it to the screen.
 fn update_commentary_display(
     mut commands: Commands,
     director: Res<DirectorState>,
+    playback: Res<PlaybackState>,
+    mut scheduler: ResMut<CommentaryScheduler>,
     mut history: ResMut<CommentaryHistory>,
     container_query: Query<Entity, With<CommentaryContainer>>,
-    existing: Query<&DisplayedCommentary>,
-    mut displayed_ids: Local<VecDeque<String>>,
+    existing: Query<(Entity, &DisplayedCommentary)>,
 ) {
     let Ok(container) = container_query.get_single() else {
         return;
     };
 
-    // Check for new commentary items
-    for item in &director.commentary_queue {
-        // Skip if already displayed
-        if existing.iter().any(|d| d.item_id == item.item_id) {
-            continue;
-        }
-
-        if displayed_ids.contains(&item.item_id) {
-            continue;
-        }
-
-        // Add to history for scrollable viewing
-        let type_name = match item.commentary_type {
-            director::CommentaryType::EventCaption => "Event",
-            director::CommentaryType::DramaticIrony => "Irony",
-            director::CommentaryType::ContextReminder => "Context",
-            director::CommentaryType::TensionTeaser => "Tension",
-            director::CommentaryType::NarratorVoice => "Narrator",
-        };
+    let before: Vec<String> = scheduler.active.iter().map(|a| a.item_id.clone()).collect();
+    let admitted = scheduler.update(&director.commentary_queue, playback.tick_for_snapshot());
+    for item in &admitted {
         history.add(HistoricalCommentary {
-            tick: item.timestamp.tick,
+            tick: item.start_tick,
             content: item.content.clone(),
-            commentary_type: type_name.to_string(),
+            commentary_type: commentary_type_name(item.commentary_type).to_string(),
         });
+    }
 
-        // Determine style based on commentary type
-        let (font_size, color, style_prefix) = match item.commentary_type {
-            director::CommentaryType::EventCaption => (18.0, Color::WHITE, ""),
-            director::CommentaryType::DramaticIrony => {
-                (16.0, Color::srgb(0.9, 0.8, 0.5), "// ")
-            }
-            director::CommentaryType::ContextReminder => {
-                (14.0, Color::srgb(0.7, 0.7, 0.7), "")
-            }
-            director::CommentaryType::TensionTeaser => {
-                (16.0, Color::srgb(0.8, 0.6, 0.6), "")
-            }
-            director::CommentaryType::NarratorVoice => (18.0, Color::srgb(1.0, 0.95, 0.8), ""),
-        };
+    let after: Vec<String> = scheduler.active.iter().map(|a| a.item_id.clone()).collect();
+    if before == after {
+        return;
+    }
 
-        // Spawn commentary text with longer display time
+    // Rebuild so on-screen order follows priority
+    for (entity, _) in existing.iter() {
+        commands.entity(entity).despawn_recursive();
+    }
+    for active in &scheduler.active {
+        let (font_size, color, style_prefix) = commentary_style(active.commentary_type);
         let text_entity = commands
             .spawn((
                 TextBundle::from_section(
-                    format!("{}{}", style_prefix, item.content),
+                    format!("{}{}", style_prefix, active.content),
                     TextStyle {
                         font_size,
                         color,
@@ -729,46 +856,25 @@ fn update_commentary_display(
                     ..default()
                 }),
                 DisplayedCommentary {
-                    item_id: item.item_id.clone(),
-                    time_remaining: (item.display_duration_ticks as f32 / 60.0).max(5.0), // At least 5 seconds
+                    item_id: active.item_id.clone(),
                     opacity: 1.0,
                 },
             ))
             .id();
-
         commands.entity(container).add_child(text_entity);
-        displayed_ids.push_back(item.item_id.clone());
-
-        // Limit displayed items
-        if displayed_ids.len() > 3 {
-            displayed_ids.pop_front();
-        }
     }
 }
 
-/// System to fade and remove old commentary.
+/// System to fade commentary out as it nears the end of its display time.
 fn fade_commentary(
-    mut commands: Commands,
-    time: Res<Time>,
-    mut commentary: Query<(Entity, &mut DisplayedCommentary, &mut Text)>,
+    playback: Res<PlaybackState>,
+    scheduler: Res<CommentaryScheduler>,
+    mut commentary: Query<(&mut DisplayedCommentary, &mut Text)>,
 ) {
-    let dt = time.delta_seconds();
-
-    for (entity, mut displayed, mut text) in commentary.iter_mut() {
-        displayed.time_remaining -= dt;
-
-        // Fade out during last second
-        if displayed.time_remaining < 1.0 && displayed.time_remaining > 0.0 {
-            displayed.opacity = displayed.time_remaining;
-            if let Some(section) = text.sections.first_mut() {
-                let base_color = section.style.color;
-                section.style.color = base_color.with_alpha(displayed.opacity);
-            }
-        }
-
-        // Remove when expired
-        if displayed.time_remaining <= 0.0 {
-            commands.entity(entity).despawn_recursive();
+    for (mut displayed, mut text) in commentary.iter_mut() {
+        displayed.opacity = scheduler.opacity(&displayed.item_id, playback.current_tick);
+        if let Some(section) = text.sections.first_mut() {
+            section.style.color = section.style.color.with_alpha(displayed.opacity);
         }
     }
 }
@@ -881,6 +987,7 @@ fn update_agent_selection_info(
 fn update_agent_info_panel(
     selected_info: Res<SelectedAgentInfo>,
     event_history: Res<AgentEventHistory>,
+    inspector: Res<AgentInspector>,
     mut panel_query: Query<&mut Visibility, With<AgentInfoPanel>>,
     mut text_query: Query<&mut Text, With<AgentInfoText>>,
 ) {
@@ -906,20 +1013,24 @@ fn update_agent_info_panel(
                 .map(|p| format!("({:.0}, {:.0})", p.x, p.y))
                 .unwrap_or_else(|| "?".to_string());
 
-            // Build recent activity section from event history
-            let activity_section = if let Some(events) = event_history.get_history(agent_id) {
-                let activity_lines: Vec<String> = events
-                    .iter()
-                    .take(5) // Show last 5 events
-                    .map(|e| format_event_summary(e))
-                    .collect();
-                if activity_lines.is_empty() {
-                    "\n--- Recent Activity ---\n(No recent events)".to_string()
-                } else {
-                    format!("\n--- Recent Activity ---\n{}", activity_lines.join("\n"))
-                }
+            // Traits, needs, goals, and relationships from the inspection
+            let inspection = inspector
+                .inspection
+                .as_ref()
+                .filter(|i| &i.agent_id == agent_id);
+            let presence = inspection.map(|i| i.presence_label()).unwrap_or_default();
+            let details_section = inspection
+                .map(|i| format!("\n{}", i.detail_text()))
+                .unwrap_or_default();
+
+            // Build recent activity section from the inspection's events
+            let activity_lines: Vec<String> = inspection
+                .map(|i| i.recent_events.iter().map(format_event_summary).collect())
+                .unwrap_or_default();
+            let activity_section = if activity_lines.is_empty() {
+                "\n--- Recent Events ---\n(No recent events)".to_string()
             } else {
-                "\n--- Recent Activity ---\n(No recent events)".to_string()
+                format!("\n--- Recent Events ---\n{}", activity_lines.join("\n"))
             };
 
             // Build locations visited section
@@ -941,8 +1052,16 @@ fn update_agent_info_panel(
             };
 
             text.sections[0].value = format!(
-                "Name: {}\nFaction: {}\nRole: {}\nLocation: {}\nCoords: {}{}{}",
-                name, faction, role, location, coords, activity_section, locations_section
+                "Name: {}{}\nFaction: {}\nRole: {}\nLocation: {}\nCoords: {}{}{}{}",
+                name,
+                presence,
+                faction,
+                role,
+                location,
+                coords,
+                details_section,
+                activity_section,
+                locations_section
             );
         }
     } else {
@@ -1071,4 +1190,75 @@ mod tests {
         assert!(state.playing);
         assert_eq!(state.speed, 1.0);
     }
+
+    fn commentary(id: &str, tick: u64, priority: f32, duration: u32) -> director::CommentaryItem {
+        director::CommentaryItem::new(
+            id,
+            sim_events::SimTimestamp::new(tick, 1, sim_events::Season::Spring, 1),
+            director::CommentaryType::EventCaption,
+            format!("{} happened", id),
+        )
+        .with_priority(priority)
+        .with_duration(duration)
+    }
+
+    fn active_ids(scheduler: &CommentaryScheduler) -> Vec<&str> {
+        scheduler.active.iter().map(|a| a.item_id.as_str()).collect()
+    }
+
+    #[test]
+    fn test_commentary_scheduler_orders_by_priority_and_caps() {
+        let queue = vec![
+            commentary("low", 10, 0.2, 100),
+            commentary("high", 10, 0.9, 100),
+            commentary("mid", 10, 0.5, 100),
+            commentary("lowest", 10, 0.1, 100),
+            commentary("future", 50, 1.0, 100),
+        ];
+        let mut scheduler = CommentaryScheduler::default();
+
+        let admitted = scheduler.update(&queue, 20);
+        assert_eq!(admitted.len(), 3);
+        assert_eq!(active_ids(&scheduler), vec!["high", "mid", "low"]);
+
+        // A more important item bumps the least important one off screen
+        scheduler.update(&queue, 50);
+        assert_eq!(active_ids(&scheduler), vec!["future", "high", "mid"]);
+    }
+
+    #[test]
+    fn test_commentary_scheduler_fades_and_expires() {
+        let queue = vec![commentary("caption", 10, 0.5, 100)];
+        let mut scheduler = CommentaryScheduler::default();
+
+        scheduler.update(&queue, 10);
+        assert_eq!(scheduler.opacity("caption", 50.0), 1.0);
+        // Fades over the last 20% of its 100 ticks
+        assert!((scheduler.opacity("caption", 100.0) - 0.5).abs() < 1e-6);
+
+        scheduler.update(&queue, 110);
+        assert!(scheduler.active.is_empty());
+        assert_eq!(scheduler.opacity("caption", 110.0), 0.0);
+
+        // Shown items are not scheduled again
+        scheduler.update(&queue, 50);
+        assert!(scheduler.active.is_empty());
+    }
+
+    #[test]
+    fn test_commentary_scheduler_honours_start_offset() {
+        let mut staggered = commentary("second", 10, 0.5, 100);
+        staggered.start_offset_ticks = 100;
+        let queue = vec![commentary("first", 10, 0.5, 100), staggered];
+        let mut scheduler = CommentaryScheduler::default();
+
+        // Same tick, but the second item waits for the first to finish
+        scheduler.update(&queue, 10);
+        assert_eq!(active_ids(&scheduler), vec!["first"]);
+
+        scheduler.update(&queue, 110);
+        assert_eq!(active_ids(&scheduler), vec!["second"]);
+        assert_eq!(scheduler.active[0].start_tick, 110);
+        assert_eq!(scheduler.active[0].end_tick, 210);
+    }
 }
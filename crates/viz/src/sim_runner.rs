@@ -5,12 +5,13 @@
 
 use bevy::prelude::*;
 use std::path::PathBuf;
-use std::process::{Child, Command, Stdio};
-use std::io::{BufRead, BufReader};
+use std::process::{Child, ChildStdin, Command, Stdio};
+use std::io::{BufRead, BufReader, Write};
 use std::sync::mpsc::{self, Receiver, TryRecvError};
 use std::sync::Mutex;
 
 use crate::overlay::PlaybackState;
+use crate::state_loader::snapshot_tick_from_name;
 
 /// Plugin for managing simulation subprocess.
 pub struct SimRunnerPlugin;
@@ -22,8 +23,9 @@ impl Plugin for SimRunnerPlugin {
         if !app.world().contains_resource::<SimConfig>() {
             app.init_resource::<SimConfig>();
         }
+        let tick_rate = app.world().resource::<SimConfig>().tick_rate;
 
-        app.init_resource::<SimRunner>()
+        app.insert_resource(SimRunner::with_tick_rate(tick_rate))
             .add_event::<SimulationEvent>()
             .add_systems(
                 Update,
@@ -47,7 +49,8 @@ pub struct SimConfig {
     pub seed: u64,
     /// Path to snapshot file for resuming (intervention workflow).
     pub from_snapshot: Option<PathBuf>,
-    /// Starting tick when resuming.
+    /// Starting tick when resuming; without `from_snapshot`, the tick replay
+    /// playback seeks to.
     pub start_tick: Option<u64>,
     /// Output directory.
     pub output_dir: PathBuf,
@@ -56,6 +59,8 @@ pub struct SimConfig {
     /// Maximum ticks ahead of playback the simulation can run.
     /// When exceeded, simulation pauses until playback catches up.
     pub max_ticks_ahead: u64,
+    /// Initial simulation speed in ticks per second (0 = unthrottled).
+    pub tick_rate: f64,
 }
 
 impl Default for SimConfig {
@@ -69,6 +74,7 @@ impl Default for SimConfig {
             output_dir: PathBuf::from("output"),
             auto_start: false,
             max_ticks_ahead: 300,
+            tick_rate: 0.0,
         }
     }
 }
@@ -104,6 +110,98 @@ impl Default for SimStatus {
     }
 }
 
+/// Selectable simulation speeds in ticks per second (0 = unthrottled).
+const TICK_RATE_STEPS: [f64; 6] = [1.0, 5.0, 10.0, 25.0, 50.0, 0.0];
+
+/// User-requested run state of the simulation process.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum RunState {
+    /// Ticks run freely (subject to the tick rate).
+    Running,
+    /// No ticks run until resumed or stepped.
+    Paused,
+    /// A single tick was requested; pauses again once it is reported.
+    Stepping,
+}
+
+/// Pause/step/rate controls, mirrored to the process as stdin commands.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SimControl {
+    /// Current run state.
+    pub state: RunState,
+    /// Ticks per second (0 = unthrottled).
+    pub ticks_per_second: f64,
+}
+
+impl SimControl {
+    /// Create running controls at the given rate.
+    pub fn new(ticks_per_second: f64) -> Self {
+        Self {
+            state: RunState::Running,
+            ticks_per_second: ticks_per_second.max(0.0),
+        }
+    }
+
+    /// Whether the process should hold off on free-running ticks.
+    pub fn is_paused(&self) -> bool {
+        self.state != RunState::Running
+    }
+
+    /// Pause; returns the command to send, if the state changed.
+    pub fn pause(&mut self) -> Option<String> {
+        if self.state == RunState::Running {
+            self.state = RunState::Paused;
+            return Some("pause".to_string());
+        }
+        None
+    }
+
+    /// Resume; returns the command to send, if the state changed.
+    pub fn resume(&mut self) -> Option<String> {
+        if self.is_paused() {
+            self.state = RunState::Running;
+            return Some("resume".to_string());
+        }
+        None
+    }
+
+    /// Request a single tick while paused.
+    pub fn step(&mut self) -> Option<String> {
+        if self.state == RunState::Paused {
+            self.state = RunState::Stepping;
+            return Some("step".to_string());
+        }
+        None
+    }
+
+    /// Record a tick reported by the process.
+    pub fn observe_tick(&mut self, _tick: u64) {
+        if self.state == RunState::Stepping {
+            self.state = RunState::Paused;
+        }
+    }
+
+    /// Set the tick rate; returns the command to send.
+    pub fn set_rate(&mut self, ticks_per_second: f64) -> String {
+        self.ticks_per_second = ticks_per_second.max(0.0);
+        format!("rate {}", self.ticks_per_second)
+    }
+
+    /// Move to the next faster (`faster = true`) or slower preset rate.
+    pub fn cycle_rate(&mut self, faster: bool) -> String {
+        let current = TICK_RATE_STEPS
+            .iter()
+            .position(|&r| r == self.ticks_per_second)
+            .unwrap_or(TICK_RATE_STEPS.len() - 1);
+        let next = if faster {
+            (current + 1).min(TICK_RATE_STEPS.len() - 1)
+        } else {
+            current.saturating_sub(1)
+        };
+        self.set_rate(TICK_RATE_STEPS[next])
+    }
+}
+
 /// Events emitted by the simulation runner.
 #[derive(Event, Debug, Clone)]
 pub enum SimulationEvent {
@@ -132,6 +230,10 @@ pub struct SimRunner {
     pub last_tick_seen: u64,
     /// Whether we've sent auto-start.
     pub auto_started: bool,
+    /// Stdin of the running process, for control commands.
+    control_tx: Option<Mutex<ChildStdin>>,
+    /// Pause/step/rate controls; kept across restarts.
+    pub control: SimControl,
 }
 
 impl Default for SimRunner {
@@ -142,11 +244,62 @@ impl Default for SimRunner {
             output_rx: None,
             last_tick_seen: 0,
             auto_started: false,
+            control_tx: None,
+            control: SimControl::new(0.0),
         }
     }
 }
 
 impl SimRunner {
+    /// Create a runner with the given initial tick rate.
+    pub fn with_tick_rate(ticks_per_second: f64) -> Self {
+        Self {
+            control: SimControl::new(ticks_per_second),
+            ..Self::default()
+        }
+    }
+
+    /// Pause the simulation.
+    pub fn pause(&mut self) {
+        if let Some(command) = self.control.pause() {
+            self.send_command(&command);
+        }
+    }
+
+    /// Resume a paused simulation.
+    pub fn resume(&mut self) {
+        if let Some(command) = self.control.resume() {
+            self.send_command(&command);
+        }
+    }
+
+    /// Advance a paused simulation by exactly one tick.
+    pub fn step(&mut self) {
+        if let Some(command) = self.control.step() {
+            self.send_command(&command);
+        }
+    }
+
+    /// Switch to the next faster or slower tick rate.
+    pub fn cycle_tick_rate(&mut self, faster: bool) {
+        let command = self.control.cycle_rate(faster);
+        self.send_command(&command);
+    }
+
+    /// Write a control command to the process, if one is running.
+    ///
+    /// Without a process the change still lands via launch flags on the next start.
+    fn send_command(&mut self, command: &str) {
+        let Some(ref stdin_mutex) = self.control_tx else {
+            return;
+        };
+        if let Ok(mut stdin) = stdin_mutex.lock() {
+            if let Err(e) = writeln!(stdin, "{}", command) {
+                tracing::warn!("Failed to send '{}' to simulation: {}", command, e);
+            }
+        }
+    }
+
     /// Start a new simulation with the given config.
     pub fn start(&mut self, config: &SimConfig) -> Result<(), String> {
         self.start_internal(config, true)
@@ -172,7 +325,13 @@ impl SimRunner {
             .arg("--snapshot-interval")
             .arg(config.snapshot_interval.to_string())
             .arg("--seed")
-            .arg(config.seed.to_string());
+            .arg(config.seed.to_string())
+            .arg("--control-stdin")
+            .arg("--tick-rate")
+            .arg(self.control.ticks_per_second.to_string());
+        if self.control.is_paused() {
+            cmd.arg("--start-paused");
+        }
 
         // Add from-snapshot if specified (intervention workflow)
         if let Some(ref snapshot_path) = config.from_snapshot {
@@ -184,7 +343,8 @@ impl SimRunner {
             }
         }
 
-        cmd.stdout(Stdio::piped())
+        cmd.stdin(Stdio::piped())
+            .stdout(Stdio::piped())
             .stderr(Stdio::piped());
 
         tracing::info!("Starting simulation: {:?}", cmd);
@@ -217,10 +377,17 @@ impl SimRunner {
                     });
                 }
 
+                self.control_tx = child.stdin.take().map(Mutex::new);
                 self.process = Some(Mutex::new(child));
                 self.output_rx = Some(Mutex::new(rx));
                 self.status = SimStatus::Starting;
-                self.last_tick_seen = config.start_tick.unwrap_or(0);
+                // A start tick without a snapshot only positions replay playback
+                self.last_tick_seen = config.from_snapshot.as_ref().and(config.start_tick).unwrap_or(0);
+
+                // A step requested while no process was running
+                if self.control.state == RunState::Stepping {
+                    self.send_command("step");
+                }
 
                 tracing::info!("Simulation process started");
                 Ok(())
@@ -243,6 +410,7 @@ impl SimRunner {
             }
         }
         self.output_rx = None;
+        self.control_tx = None;
         self.status = SimStatus::Idle;
         self.last_tick_seen = 0;
     }
@@ -288,6 +456,7 @@ impl SimRunner {
                             // Expected format: "Tick 100/2000" or similar
                             if let Some(tick_info) = parse_tick_progress(&line) {
                                 self.last_tick_seen = tick_info.0;
+                                self.control.observe_tick(tick_info.0);
                                 let max_ticks = tick_info.1;
 
                                 if matches!(self.status, SimStatus::Starting) {
@@ -355,6 +524,7 @@ impl SimRunner {
         if process_ended {
             self.process = None;
             self.output_rx = None;
+            self.control_tx = None;
         }
 
         events
@@ -449,6 +619,28 @@ fn handle_sim_control_input(
     mut sim_runner: ResMut<SimRunner>,
     config: Res<SimConfig>,
 ) {
+    // P key: Pause/resume simulation
+    if keyboard.just_pressed(KeyCode::KeyP) {
+        if sim_runner.control.is_paused() {
+            sim_runner.resume();
+        } else {
+            sim_runner.pause();
+        }
+    }
+
+    // N key: Step one tick while paused
+    if keyboard.just_pressed(KeyCode::KeyN) {
+        sim_runner.step();
+    }
+
+    // , and . keys: Slower/faster tick rate
+    if keyboard.just_pressed(KeyCode::Comma) {
+        sim_runner.cycle_tick_rate(false);
+    }
+    if keyboard.just_pressed(KeyCode::Period) {
+        sim_runner.cycle_tick_rate(true);
+    }
+
     // S key: Start/restart simulation
     if keyboard.just_pressed(KeyCode::KeyS) {
         if sim_runner.is_running() {
@@ -538,13 +730,11 @@ pub fn find_snapshot_at_or_before(output_dir: &PathBuf, tick: u64) -> Option<Pat
     if let Ok(entries) = std::fs::read_dir(&snapshots_dir) {
         for entry in entries.filter_map(Result::ok) {
             let path = entry.path();
-            if path.extension().map_or(false, |e| e == "json") {
-                // Parse tick from filename like "snap_000500.json"
-                if let Some(file_tick) = parse_snapshot_tick(&path) {
-                    if file_tick <= tick {
-                        if best_match.as_ref().map_or(true, |(best, _)| file_tick > *best) {
-                            best_match = Some((file_tick, path));
-                        }
+            // Parse tick from filename like "snap_000500.json(.gz)"
+            if let Some(file_tick) = parse_snapshot_tick(&path) {
+                if file_tick <= tick {
+                    if best_match.as_ref().map_or(true, |(best, _)| file_tick > *best) {
+                        best_match = Some((file_tick, path));
                     }
                 }
             }
@@ -556,10 +746,7 @@ pub fn find_snapshot_at_or_before(output_dir: &PathBuf, tick: u64) -> Option<Pat
 
 /// Parse tick number from snapshot filename.
 fn parse_snapshot_tick(path: &PathBuf) -> Option<u64> {
-    let stem = path.file_stem()?.to_str()?;
-    // Expected format: "snap_000500"
-    let tick_str = stem.strip_prefix("snap_")?;
-    tick_str.parse().ok()
+    snapshot_tick_from_name(path.file_name()?.to_str()?)
 }
 
 #[cfg(test)]
@@ -589,6 +776,32 @@ mod tests {
             parse_snapshot_tick(&PathBuf::from("snap_001000.json")),
             Some(1000)
         );
+        assert_eq!(
+            parse_snapshot_tick(&PathBuf::from("snap_001000.json.gz")),
+            Some(1000)
+        );
+        assert_eq!(parse_snapshot_tick(&PathBuf::from("snap_001000.txt")), None);
+    }
+
+    #[test]
+    fn test_find_snapshot_at_or_before_includes_compressed() {
+        let dir = tempfile::tempdir().unwrap();
+        let snapshots_dir = dir.path().join("snapshots");
+        std::fs::create_dir_all(&snapshots_dir).unwrap();
+        std::fs::write(snapshots_dir.join("snap_000100.json"), "{}").unwrap();
+        std::fs::write(snapshots_dir.join("snap_000200.json.gz"), "").unwrap();
+        std::fs::write(snapshots_dir.join("snap_000300.json.gz"), "").unwrap();
+
+        let output_dir = dir.path().to_path_buf();
+        assert_eq!(
+            find_snapshot_at_or_before(&output_dir, 250),
+            Some(snapshots_dir.join("snap_000200.json.gz"))
+        );
+        assert_eq!(
+            find_snapshot_at_or_before(&output_dir, 150),
+            Some(snapshots_dir.join("snap_000100.json"))
+        );
+        assert_eq!(find_snapshot_at_or_before(&output_dir, 50), None);
     }
 
     #[test]
@@ -599,6 +812,7 @@ mod tests {
         assert_eq!(config.seed, 42);
         assert!(!config.auto_start);
         assert_eq!(config.max_ticks_ahead, 300);
+        assert_eq!(config.tick_rate, 0.0);
     }
 
     #[test]
@@ -606,4 +820,52 @@ mod tests {
         let status = SimStatus::default();
         assert!(matches!(status, SimStatus::Idle));
     }
+
+    #[test]
+    fn test_sim_control_pause_step_resume() {
+        let mut control = SimControl::new(0.0);
+        assert_eq!(control.step(), None, "stepping only applies while paused");
+
+        assert_eq!(control.pause().as_deref(), Some("pause"));
+        assert_eq!(control.pause(), None);
+        assert_eq!(control.step().as_deref(), Some("step"));
+        assert_eq!(control.state, RunState::Stepping);
+        // A second step waits for the first to land
+        assert_eq!(control.step(), None);
+
+        control.observe_tick(11);
+        assert_eq!(control.state, RunState::Paused);
+
+        assert_eq!(control.resume().as_deref(), Some("resume"));
+        assert_eq!(control.state, RunState::Running);
+    }
+
+    #[test]
+    fn test_sim_control_rate_cycling() {
+        let mut control = SimControl::new(0.0);
+        assert_eq!(control.cycle_rate(true), "rate 0");
+        assert_eq!(control.cycle_rate(false), "rate 50");
+        control.set_rate(1.0);
+        assert_eq!(control.cycle_rate(false), "rate 1");
+        assert_eq!(control.cycle_rate(true), "rate 5");
+    }
+
+    #[test]
+    fn test_runner_step_advances_one_tick_then_pauses() {
+        let (tx, rx) = mpsc::channel();
+        let mut runner = SimRunner::default();
+        runner.output_rx = Some(Mutex::new(rx));
+        runner.status = SimStatus::Running { current_tick: 10, max_ticks: 100 };
+        runner.last_tick_seen = 10;
+
+        runner.pause();
+        runner.step();
+        assert_eq!(runner.control.state, RunState::Stepping);
+
+        tx.send("tick=11 max=100".to_string()).unwrap();
+        runner.poll();
+
+        assert_eq!(runner.last_tick_seen, 11);
+        assert_eq!(runner.control.state, RunState::Paused);
+    }
 }
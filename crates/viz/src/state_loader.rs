@@ -2,16 +2,22 @@
 //!
 //! Watches simulation output files and triggers updates when they change.
 //! Also handles playback control - advancing through snapshots based on
-//! PlaybackState settings.
+//! PlaybackState settings - and seeking to an arbitrary tick.
 
 use bevy::prelude::*;
 use notify::{Event as NotifyEvent, RecommendedWatcher, RecursiveMode, Watcher};
-use sim_events::WorldSnapshot;
-use std::path::PathBuf;
+use sim_events::{Event, EventOutcome, WorldSnapshot};
+use std::io::{BufRead, Read};
+use std::path::{Path, PathBuf};
 use std::sync::mpsc::{channel, Receiver};
 use std::time::Instant;
 
+use crate::director_runner::DirectorRunner;
 use crate::overlay::PlaybackState;
+use crate::sim_runner::SimConfig;
+
+/// Ticks jumped per press of the seek keys.
+const SEEK_STEP_TICKS: u64 = 100;
 
 /// Plugin for loading simulation state from files.
 pub struct StateLoaderPlugin;
@@ -21,11 +27,15 @@ impl Plugin for StateLoaderPlugin {
         app.init_resource::<SimulationState>()
             .init_resource::<SnapshotCache>()
             .add_event::<StateUpdatedEvent>()
+            .add_event::<SeekRequest>()
             .add_systems(
                 Update,
                 (
                     check_file_updates,
                     scan_available_snapshots,
+                    seek_to_start_tick,
+                    handle_seek_keys,
+                    handle_seek_requests,
                     advance_playback,
                     load_snapshot_for_playback,
                     handle_reload_key,
@@ -78,6 +88,13 @@ pub struct StateUpdatedEvent {
     pub tick: u64,
 }
 
+/// Event requesting playback jump to a specific tick.
+#[derive(Event, Debug, Clone, Copy)]
+pub struct SeekRequest {
+    /// The tick to jump to.
+    pub target_tick: u64,
+}
+
 /// File watching state stored in Local (doesn't need Send+Sync).
 #[derive(Default)]
 struct FileWatcherState {
@@ -248,25 +265,16 @@ fn handle_reload_key(
 
 /// Load state from a file.
 fn load_state_file(path: &PathBuf, state: &mut SimulationState) -> bool {
-    match std::fs::read_to_string(path) {
-        Ok(contents) => match serde_json::from_str::<WorldSnapshot>(&contents) {
-            Ok(snapshot) => {
-                let tick = snapshot.timestamp.tick;
-                state.snapshot = Some(snapshot);
-                state.last_update = Some(Instant::now());
-                state.last_error = None;
-                tracing::info!("Loaded state from {:?} (tick {})", path, tick);
-                true
-            }
-            Err(e) => {
-                let error_msg = format!("Failed to parse state file: {}", e);
-                tracing::error!("{}", error_msg);
-                state.last_error = Some(error_msg);
-                false
-            }
-        },
-        Err(e) => {
-            let error_msg = format!("Failed to read state file: {}", e);
+    match read_snapshot(path) {
+        Ok(snapshot) => {
+            let tick = snapshot.timestamp.tick;
+            state.snapshot = Some(snapshot);
+            state.last_update = Some(Instant::now());
+            state.last_error = None;
+            tracing::info!("Loaded state from {:?} (tick {})", path, tick);
+            true
+        }
+        Err(error_msg) => {
             tracing::error!("{}", error_msg);
             state.last_error = Some(error_msg);
             false
@@ -274,6 +282,41 @@ fn load_state_file(path: &PathBuf, state: &mut SimulationState) -> bool {
     }
 }
 
+/// Read and parse a snapshot file, gunzipping `.json.gz` files.
+fn read_snapshot(path: &Path) -> Result<WorldSnapshot, String> {
+    let contents = if path.extension().map_or(false, |e| e == "gz") {
+        let file = std::fs::File::open(path)
+            .map_err(|e| format!("Failed to read state file: {}", e))?;
+        let mut contents = String::new();
+        flate2::read::GzDecoder::new(file)
+            .read_to_string(&mut contents)
+            .map_err(|e| format!("Failed to decompress state file: {}", e))?;
+        contents
+    } else {
+        std::fs::read_to_string(path).map_err(|e| format!("Failed to read state file: {}", e))?
+    };
+    serde_json::from_str::<WorldSnapshot>(&contents)
+        .map_err(|e| format!("Failed to parse state file: {}", e))
+}
+
+/// Path of the snapshot for `tick`, preferring a compressed `.json.gz` file.
+fn snapshot_path(snapshots_dir: &Path, tick: u64) -> PathBuf {
+    let compressed = snapshots_dir.join(format!("snap_{:06}.json.gz", tick));
+    if compressed.exists() {
+        compressed
+    } else {
+        snapshots_dir.join(format!("snap_{:06}.json", tick))
+    }
+}
+
+/// Parse the tick from a snapshot file name like "snap_000500.json(.gz)".
+pub(crate) fn snapshot_tick_from_name(file_name: &str) -> Option<u64> {
+    let stem = file_name
+        .strip_suffix(".json.gz")
+        .or_else(|| file_name.strip_suffix(".json"))?;
+    stem.strip_prefix("snap_")?.parse().ok()
+}
+
 /// Cache of available snapshot files and their ticks.
 #[derive(Resource, Default)]
 pub struct SnapshotCache {
@@ -300,6 +343,76 @@ impl SnapshotCache {
             }
         }
     }
+
+    /// Find the snapshot tick to seek from: the latest one at or before the target.
+    ///
+    /// Unlike `find_snapshot_tick`, never returns a snapshot after the target.
+    pub fn seek_snapshot_tick(&self, target_tick: u64) -> Option<u64> {
+        let idx = self.available_ticks.partition_point(|&tick| tick <= target_tick);
+        idx.checked_sub(1).map(|i| self.available_ticks[i])
+    }
+}
+
+/// Outcome of seeking to a tick.
+#[derive(Debug)]
+pub struct SeekResult {
+    /// Tick of the snapshot the seek started from.
+    pub snapshot_tick: u64,
+    /// The snapshot, fast-forwarded to the target tick.
+    pub snapshot: WorldSnapshot,
+    /// Number of logged events replayed on top of the snapshot.
+    pub events_replayed: usize,
+}
+
+/// Load the nearest snapshot at or before `target_tick` and fast-forward it.
+///
+/// Events logged after the snapshot and up to the target are replayed without
+/// rendering; movement events update agent locations so the scene matches the
+/// target tick. A missing event log just means no fast-forward.
+pub fn seek_to_tick(
+    cache: &SnapshotCache,
+    snapshots_dir: &Path,
+    events_path: &Path,
+    target_tick: u64,
+) -> Result<SeekResult, String> {
+    let snapshot_tick = cache
+        .seek_snapshot_tick(target_tick)
+        .ok_or_else(|| format!("No snapshot at or before tick {}", target_tick))?;
+    let path = snapshot_path(snapshots_dir, snapshot_tick);
+    let mut snapshot = read_snapshot(&path)?;
+
+    let events = read_events_between(events_path, snapshot_tick, target_tick);
+    for event in &events {
+        if let EventOutcome::Movement(movement) = &event.outcome {
+            if let Some(agent) = snapshot
+                .agents
+                .iter_mut()
+                .find(|a| a.agent_id == event.actors.primary.agent_id)
+            {
+                agent.location = movement.new_location.clone();
+            }
+        }
+    }
+
+    Ok(SeekResult {
+        snapshot_tick,
+        snapshot,
+        events_replayed: events.len(),
+    })
+}
+
+/// Read logged events with from_tick < tick <= to_tick.
+fn read_events_between(path: &Path, from_tick: u64, to_tick: u64) -> Vec<Event> {
+    let Ok(file) = std::fs::File::open(path) else {
+        return Vec::new();
+    };
+
+    std::io::BufReader::new(file)
+        .lines()
+        .map_while(Result::ok)
+        .filter_map(|line| serde_json::from_str::<Event>(&line).ok())
+        .filter(|e| e.timestamp.tick > from_tick && e.timestamp.tick <= to_tick)
+        .collect()
 }
 
 /// System to scan for available snapshot files periodically.
@@ -330,20 +443,12 @@ fn scan_available_snapshots(
 
     let mut ticks: Vec<u64> = entries
         .filter_map(Result::ok)
-        .filter_map(|entry| {
-            let path = entry.path();
-            if path.extension().map_or(false, |e| e == "json") {
-                // Parse tick from filename like "snap_000500.json"
-                let stem = path.file_stem()?.to_str()?;
-                let tick_str = stem.strip_prefix("snap_")?;
-                tick_str.parse().ok()
-            } else {
-                None
-            }
-        })
+        .filter_map(|entry| snapshot_tick_from_name(entry.file_name().to_str()?))
         .collect();
 
     ticks.sort_unstable();
+    // A run may leave both a plain and a compressed file for the same tick
+    ticks.dedup();
 
     // Update max available tick
     if let Some(&max_tick) = ticks.last() {
@@ -353,6 +458,105 @@ fn scan_available_snapshots(
     cache.available_ticks = ticks;
 }
 
+/// System to request a seek to `--start-tick` once snapshots are available.
+fn seek_to_start_tick(
+    mut done: Local<bool>,
+    config: Option<Res<SimConfig>>,
+    cache: Res<SnapshotCache>,
+    mut requests: EventWriter<SeekRequest>,
+) {
+    if *done {
+        return;
+    }
+
+    // With --from-snapshot the start tick belongs to the simulation run instead
+    let target_tick = match config {
+        Some(config) if config.from_snapshot.is_none() => config.start_tick,
+        _ => None,
+    };
+    let Some(target_tick) = target_tick else {
+        *done = true;
+        return;
+    };
+
+    // Wait until a live run has written far enough to seek into
+    if cache.available_ticks.last().map_or(false, |&last| last >= target_tick) {
+        *done = true;
+        requests.send(SeekRequest { target_tick });
+    }
+}
+
+/// Handle [ and ] keys to seek backward and forward.
+fn handle_seek_keys(
+    keyboard: Res<ButtonInput<KeyCode>>,
+    playback: Res<PlaybackState>,
+    mut requests: EventWriter<SeekRequest>,
+) {
+    let current = playback.tick_for_snapshot();
+    if keyboard.just_pressed(KeyCode::BracketLeft) {
+        requests.send(SeekRequest {
+            target_tick: current.saturating_sub(SEEK_STEP_TICKS),
+        });
+    }
+    if keyboard.just_pressed(KeyCode::BracketRight) {
+        requests.send(SeekRequest {
+            target_tick: current + SEEK_STEP_TICKS,
+        });
+    }
+}
+
+/// System to jump playback to the most recently requested tick.
+fn handle_seek_requests(
+    mut requests: EventReader<SeekRequest>,
+    mut cache: ResMut<SnapshotCache>,
+    mut playback: ResMut<PlaybackState>,
+    mut state: ResMut<SimulationState>,
+    director_runner: Option<ResMut<DirectorRunner>>,
+    mut events: EventWriter<StateUpdatedEvent>,
+) {
+    let Some(request) = requests.read().last().copied() else {
+        return;
+    };
+
+    let target_tick = if playback.max_available_tick > 0 {
+        request.target_tick.min(playback.max_available_tick)
+    } else {
+        request.target_tick
+    };
+
+    match seek_to_tick(
+        &cache,
+        Path::new("output/snapshots"),
+        Path::new("output/events.jsonl"),
+        target_tick,
+    ) {
+        Ok(result) => {
+            tracing::info!(
+                "Seeked to tick {} from snapshot {} ({} events replayed)",
+                target_tick,
+                result.snapshot_tick,
+                result.events_replayed
+            );
+            state.snapshot = Some(result.snapshot);
+            state.last_update = Some(Instant::now());
+            state.last_error = None;
+            cache.loaded_tick = Some(result.snapshot_tick);
+            playback.current_tick = target_tick as f64;
+            // Don't narrate the stretch we skipped over
+            if let Some(mut director_runner) = director_runner {
+                director_runner.skip_to(result.snapshot_tick);
+            }
+            events.send(StateUpdatedEvent {
+                tick: state.current_tick(),
+            });
+        }
+        Err(error_msg) => {
+            tracing::warn!("Seek failed: {}", error_msg);
+            state.last_error = Some(error_msg);
+        }
+    }
+}
+
 /// System to advance playback tick based on time and speed.
 fn advance_playback(time: Res<Time>, mut playback: ResMut<PlaybackState>) {
     if !playback.playing {
@@ -399,7 +603,7 @@ fn load_snapshot_for_playback(
     }
 
     // Load the snapshot
-    let path = PathBuf::from(format!("output/snapshots/snap_{:06}.json", snapshot_tick));
+    let path = snapshot_path(Path::new("output/snapshots"), snapshot_tick);
     if load_state_file(&path, &mut state) {
         cache.loaded_tick = Some(snapshot_tick);
         events.send(StateUpdatedEvent {
@@ -444,4 +648,134 @@ mod tests {
         assert_eq!(state.current_tick(), 0);
         assert_eq!(state.current_season(), "unknown");
     }
+
+    fn make_snapshot(tick: u64, location: &str) -> WorldSnapshot {
+        use sim_events::{
+            AgentSnapshot, NeedsSnapshot, Season, SimTimestamp, StatusSnapshot, TraitsSnapshot,
+        };
+
+        let mut snapshot = WorldSnapshot::new(
+            format!("snap_{:06}", tick),
+            SimTimestamp::new(tick, 1, Season::Spring, 1),
+            "periodic",
+        );
+        snapshot.agents.push(AgentSnapshot {
+            agent_id: "agent_mira".to_string(),
+            name: "Mira".to_string(),
+            alive: true,
+            faction: "thornwood".to_string(),
+            role: "scout".to_string(),
+            location: location.to_string(),
+            traits: TraitsSnapshot::default(),
+            status: StatusSnapshot::default(),
+            needs: NeedsSnapshot::default(),
+            goals: Vec::new(),
+            reputation: 0.0,
+            remembered_events: Vec::new(),
+        });
+        snapshot
+    }
+
+    fn write_snapshot(dir: &Path, tick: u64, location: &str) {
+        let snapshot = make_snapshot(tick, location);
+        let path = dir.join(format!("snap_{:06}.json", tick));
+        std::fs::write(path, serde_json::to_string(&snapshot).unwrap()).unwrap();
+    }
+
+    fn movement_line(event_id: &str, tick: u64, to: &str) -> String {
+        use sim_events::{
+            ActorSet, ActorSnapshot, EventContext, EventSubtype, EventType, MovementOutcome,
+            MovementSubtype, Season, SimTimestamp,
+        };
+
+        let event = Event {
+            event_id: event_id.to_string(),
+            timestamp: SimTimestamp::new(tick, 1, Season::Spring, 1),
+            event_type: EventType::Movement,
+            subtype: EventSubtype::Movement(MovementSubtype::Travel),
+            actors: ActorSet::primary_only(ActorSnapshot::new(
+                "agent_mira", "Mira", "thornwood", "scout", "village_center",
+            )),
+            context: EventContext::new("random_wandering"),
+            outcome: EventOutcome::Movement(MovementOutcome {
+                new_location: to.to_string(),
+                travel_duration_ticks: None,
+            }),
+            drama_tags: Vec::new(),
+            drama_score: 0.1,
+            connected_events: Vec::new(),
+        };
+        serde_json::to_string(&event).unwrap()
+    }
+
+    #[test]
+    fn test_seek_between_snapshots_uses_preceding_snapshot() {
+        let dir = tempfile::tempdir().unwrap();
+        let snapshots_dir = dir.path().join("snapshots");
+        std::fs::create_dir_all(&snapshots_dir).unwrap();
+        for (tick, location) in [(0, "village_center"), (50, "thornwood_hall"), (100, "river_crossing")] {
+            write_snapshot(&snapshots_dir, tick, location);
+        }
+        let events_path = dir.path().join("events.jsonl");
+        let lines = [
+            movement_line("evt_00000040", 40, "eastern_woods"),
+            movement_line("evt_00000060", 60, "old_mill"),
+            movement_line("evt_00000090", 90, "river_crossing"),
+        ];
+        std::fs::write(&events_path, lines.join("\n")).unwrap();
+
+        let cache = SnapshotCache {
+            available_ticks: vec![0, 50, 100],
+            ..Default::default()
+        };
+        assert_eq!(cache.seek_snapshot_tick(75), Some(50));
+        assert_eq!(cache.seek_snapshot_tick(100), Some(100));
+
+        let result = seek_to_tick(&cache, &snapshots_dir, &events_path, 75).unwrap();
+        assert_eq!(result.snapshot_tick, 50);
+        assert_eq!(result.snapshot.timestamp.tick, 50);
+        // Only the move at tick 60 falls between the snapshot and the target
+        assert_eq!(result.events_replayed, 1);
+        assert_eq!(result.snapshot.agents[0].location, "old_mill");
+    }
+
+    #[test]
+    fn test_seek_before_first_snapshot_fails() {
+        let cache = SnapshotCache {
+            available_ticks: vec![50, 100],
+            ..Default::default()
+        };
+        assert_eq!(cache.seek_snapshot_tick(10), None);
+        assert!(seek_to_tick(&cache, Path::new("missing"), Path::new("missing.jsonl"), 10).is_err());
+    }
+
+    #[test]
+    fn test_snapshot_tick_from_name() {
+        assert_eq!(snapshot_tick_from_name("snap_000500.json"), Some(500));
+        assert_eq!(snapshot_tick_from_name("snap_000500.json.gz"), Some(500));
+        assert_eq!(snapshot_tick_from_name("current_state.json"), None);
+        assert_eq!(snapshot_tick_from_name("snap_000500.txt"), None);
+    }
+
+    #[test]
+    fn test_read_compressed_snapshot() {
+        use std::io::Write;
+
+        let dir = tempfile::tempdir().unwrap();
+        let snapshot = make_snapshot(250, "old_mill");
+        let path = dir.path().join("snap_000250.json.gz");
+        let mut encoder = flate2::write::GzEncoder::new(
+            std::fs::File::create(&path).unwrap(),
+            flate2::Compression::default(),
+        );
+        encoder.write_all(serde_json::to_string(&snapshot).unwrap().as_bytes()).unwrap();
+        encoder.finish().unwrap();
+
+        assert_eq!(snapshot_path(dir.path(), 250), path);
+        let loaded = read_snapshot(&path).unwrap();
+        assert_eq!(
+            serde_json::to_value(&loaded).unwrap(),
+            serde_json::to_value(&snapshot).unwrap()
+        );
+    }
 }